@@ -0,0 +1,170 @@
+//! Generates the table of third party crates used by the `thirdparty`
+//! subcommand from cargo-lichking's own dependency graph.
+
+#[allow(dead_code)]
+#[path = "src/discovery.rs"]
+mod discovery;
+#[allow(dead_code)]
+#[path = "src/license.rs"]
+mod license;
+#[allow(dead_code)]
+#[path = "src/licensed.rs"]
+mod licensed;
+
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+
+use cargo_metadata::{DependencyKind, Metadata, MetadataCommand, Package};
+
+use crate::discovery::{
+    find_generic_license_text, find_license_text, Confidence, DiscoveryConfig, LicenseText,
+};
+use crate::license::License;
+use crate::licensed::LicensedCache;
+
+fn packages(metadata: &Metadata) -> Vec<&Package> {
+    let resolve = match &metadata.resolve {
+        Some(resolve) => resolve,
+        None => return Vec::new(),
+    };
+    let root = match &resolve.root {
+        Some(root) => root,
+        None => return Vec::new(),
+    };
+
+    let mut result = Vec::new();
+    let mut added = HashSet::new();
+    let mut to_check = vec![root];
+    while let Some(id) = to_check.pop() {
+        if added.insert(id) {
+            if let Some(package) = metadata.packages.iter().find(|p| &p.id == id) {
+                result.push(package);
+            }
+            if let Some(node) = resolve.nodes.iter().find(|node| &node.id == id) {
+                for dep in &node.deps {
+                    if dep
+                        .dep_kinds
+                        .iter()
+                        .any(|info| info.kind == DependencyKind::Normal)
+                    {
+                        to_check.push(&dep.pkg);
+                    }
+                }
+            }
+        }
+    }
+
+    result.sort_by_key(|p| (&p.name, &p.version));
+    result
+}
+
+/// Picks the most confident text, any failure to read the package sources
+/// (e.g. when they're not available locally) results in no text
+fn best(texts: anyhow::Result<Vec<LicenseText>>) -> Option<String> {
+    let mut texts = texts.ok()?;
+    texts.sort_by_key(|text| match text.confidence {
+        Confidence::Confident => 0,
+        Confidence::SemiConfident => 1,
+        Confidence::Unsure => 2,
+    });
+    texts.into_iter().next().map(|text| text.text)
+}
+
+fn text(text: Option<String>) -> String {
+    text.map(|text| format!("Some({:?})", text))
+        .unwrap_or_else(|| "None".to_owned())
+}
+
+fn generate(out: &mut String, package: &Package, licenses: &LicensedCache) -> std::fmt::Result {
+    let config = DiscoveryConfig::default();
+    let license = licenses.resolve(package).license.clone();
+    writeln!(
+        out,
+        "
+    LicensedCrate {{
+        name: {:?},
+        version: {:?},
+        licenses: Licenses {{
+            name: {:?},
+            licenses: &[",
+        package.name,
+        package.version.to_string(),
+        license.to_string(),
+    )?;
+
+    let generic = find_generic_license_text(package, &license, &config)
+        .ok()
+        .flatten()
+        .map(|text| text.text);
+    let licenses = match license {
+        License::Unspecified => Vec::new(),
+        _ if generic.is_some() => vec![(license.to_string(), generic)],
+        License::Multiple(licenses) | License::All(licenses) => licenses
+            .iter()
+            .map(|license| {
+                let texts = find_license_text(package, license, &config);
+                (license.to_string(), best(texts))
+            })
+            .collect(),
+        license => {
+            let texts = find_license_text(package, &license, &config);
+            vec![(license.to_string(), best(texts))]
+        }
+    };
+
+    for (name, license_text) in licenses {
+        writeln!(
+            out,
+            "
+                License {{
+                    name: {:?},
+                    text: {},
+                }},",
+            name,
+            text(license_text)
+        )?;
+    }
+
+    writeln!(
+        out,
+        "
+            ],
+        }},
+    }},"
+    )
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=Cargo.lock");
+
+    let metadata = MetadataCommand::new()
+        .manifest_path(manifest_dir.join("Cargo.toml"))
+        .other_options(vec!["--offline".to_owned()])
+        .exec();
+
+    let mut out = String::from("&[\n");
+    match &metadata {
+        Ok(metadata) => {
+            let licenses = LicensedCache::new(false, false);
+            for package in packages(metadata) {
+                generate(&mut out, package, &licenses).unwrap();
+            }
+        }
+        Err(err) => {
+            println!(
+                "cargo:warning=Couldn't load dependency metadata, thirdparty licenses will be empty: {}",
+                err
+            );
+        }
+    }
+    out.push_str("]\n");
+
+    let path = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("thirdparty_generated.rs");
+    fs::write(path, out).unwrap();
+}
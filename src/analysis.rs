@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use cargo_metadata::Package;
+
+use crate::licensed::{LicensedCache, ResolvedLicense};
+
+/// A package that is present in multiple versions which don't all share the
+/// same license
+pub struct LicenseChange<'a> {
+    pub name: &'a str,
+    pub versions: Vec<(&'a Package, Arc<ResolvedLicense>)>,
+}
+
+pub fn license_changes<'a>(
+    packages: &[&'a Package],
+    licenses: &LicensedCache,
+) -> Vec<LicenseChange<'a>> {
+    let mut by_name = BTreeMap::new();
+    for &package in packages {
+        by_name
+            .entry(package.name.as_str())
+            .or_insert_with(Vec::new)
+            .push((package, licenses.resolve(package)));
+    }
+
+    by_name
+        .into_iter()
+        .filter(|(_, versions)| {
+            versions
+                .iter()
+                .any(|(_, resolved)| resolved.license != versions[0].1.license)
+        })
+        .map(|(name, mut versions)| {
+            versions.sort_by(|(a, _), (b, _)| a.version.cmp(&b.version));
+            LicenseChange { name, versions }
+        })
+        .collect()
+}
+
+/// Logs any license changes across versions, as errors if `deny` is set,
+/// returns whether any were found
+pub fn report_license_changes(packages: &[&Package], licenses: &LicensedCache, deny: bool) -> bool {
+    let changes = license_changes(packages, licenses);
+    for change in &changes {
+        let versions = change
+            .versions
+            .iter()
+            .map(|(package, resolved)| format!("{} ({})", package.version, resolved.license))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if deny {
+            log::error!(
+                "{} has different licenses across versions: {}",
+                change.name,
+                versions
+            );
+        } else {
+            log::warn!(
+                "{} has different licenses across versions: {}",
+                change.name,
+                versions
+            );
+        }
+    }
+    !changes.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::testutil::package;
+
+    #[test]
+    fn license_changes_across_versions() {
+        let dir = Path::new("/nonexistent");
+        let old = package("changed", "1.0.0", Some("MIT"), dir);
+        let new = package("changed", "2.0.0", Some("BUSL-1.1"), dir);
+        let unchanged = package("unchanged", "1.0.0", Some("MIT OR Apache-2.0"), dir);
+        let respelled = package("unchanged", "2.0.0", Some("Apache-2.0/MIT"), dir);
+        let single = package("lone", "1.0.0", Some("GPL-3.0"), dir);
+
+        let licenses = LicensedCache::new(false, false);
+        let changes = license_changes(&[&new, &unchanged, &single, &old, &respelled], &licenses);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].name, "changed");
+        let versions = changes[0]
+            .versions
+            .iter()
+            .map(|(package, resolved)| (package.version.to_string(), resolved.license.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            versions,
+            [
+                ("1.0.0".to_owned(), "MIT".to_owned()),
+                ("2.0.0".to_owned(), "BUSL-1.1".to_owned()),
+            ]
+        );
+
+        assert!(license_changes(&[&unchanged, &respelled, &single], &licenses).is_empty());
+    }
+}
@@ -0,0 +1,418 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fs::File;
+use std::io::{self, Write};
+
+use anyhow::anyhow;
+use cargo_metadata::PackageId;
+use serde::{Deserialize, Serialize};
+
+use crate::licensed::{Licensed, Resolution};
+use crate::load::ResolvedPackage;
+use crate::options::DiffFormat;
+use crate::outcome::{Outcome, Summary};
+
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub struct Entry {
+    pub name: String,
+    pub version: String,
+    pub license: String,
+    /// The `license` field as declared in the manifest, before normalization
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub declared: Option<String>,
+    /// The `license-file` field as declared in the manifest
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license_file: Option<String>,
+    /// How `license` was arrived at, missing from baselines saved by older
+    /// versions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<Resolution>,
+    /// Whether this version has been yanked from crates.io, only checked when
+    /// requested
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub yanked: bool,
+}
+
+impl Entry {
+    pub fn of(resolved: &ResolvedPackage, yanked: bool) -> Entry {
+        let package = resolved.package;
+        Entry {
+            name: package.name.clone(),
+            version: package.version.to_string(),
+            license: resolved.license.license.to_string(),
+            declared: package.declared_license_str().map(ToOwned::to_owned),
+            license_file: package
+                .license_file
+                .as_ref()
+                .map(|file| file.display().to_string()),
+            resolution: Some(resolved.license.resolution.clone()),
+            yanked,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Inventory {
+    pub packages: Vec<Entry>,
+    /// Only included in `list --format json` output, not saved baselines
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<Summary>,
+}
+
+/// A root's own dependencies, for `list --scope per-root --format json`
+#[derive(Debug, Serialize)]
+pub struct RootInventory {
+    pub root: String,
+    #[serde(flatten)]
+    pub inventory: Inventory,
+}
+
+#[derive(Debug, Serialize)]
+struct VersionChange {
+    name: String,
+    license: String,
+    old: Vec<String>,
+    new: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct LicenseChange {
+    name: String,
+    version: String,
+    old: Vec<String>,
+    new: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Diff {
+    added: Vec<Entry>,
+    removed: Vec<Entry>,
+    version_changed: Vec<VersionChange>,
+    license_changed: Vec<LicenseChange>,
+    /// Added packages whose license was not used by any package in the baseline
+    new_licenses: Vec<Entry>,
+}
+
+impl Inventory {
+    pub fn collect(resolved: &[ResolvedPackage]) -> Inventory {
+        Inventory::collect_with_yanked(resolved, &HashSet::new())
+    }
+
+    pub fn collect_with_yanked(
+        resolved: &[ResolvedPackage],
+        yanked: &HashSet<&PackageId>,
+    ) -> Inventory {
+        let mut packages = resolved
+            .iter()
+            .map(|resolved| Entry::of(resolved, yanked.contains(&resolved.package.id)))
+            .collect::<Vec<_>>();
+        packages.sort();
+        packages.dedup();
+        Inventory {
+            packages,
+            summary: None,
+        }
+    }
+
+    pub fn load(path: &str) -> anyhow::Result<Inventory> {
+        let file = File::open(path).map_err(|err| anyhow!("Couldn't open {}: {}", path, err))?;
+        Ok(serde_json::from_reader(file)?)
+    }
+
+    pub fn write(&self, out: &mut dyn io::Write) -> anyhow::Result<()> {
+        serde_json::to_writer_pretty(&mut *out, self)?;
+        writeln!(out)?;
+        Ok(())
+    }
+
+    pub fn save(&self, path: &str) -> anyhow::Result<()> {
+        self.write(&mut File::create(path)?)
+    }
+
+    fn by_name(&self) -> BTreeMap<&str, Vec<&Entry>> {
+        let mut by_name = BTreeMap::new();
+        for entry in &self.packages {
+            by_name
+                .entry(entry.name.as_str())
+                .or_insert_with(Vec::new)
+                .push(entry);
+        }
+        by_name
+    }
+}
+
+fn diff(baseline: &Inventory, current: &Inventory) -> Diff {
+    let baseline_licenses = baseline
+        .packages
+        .iter()
+        .map(|entry| entry.license.as_str())
+        .collect::<BTreeSet<_>>();
+
+    let old = baseline.by_name();
+    let new = current.by_name();
+    let mut diff = Diff::default();
+
+    for (name, entries) in &new {
+        let old_entries = if let Some(old_entries) = old.get(name) {
+            old_entries
+        } else {
+            for &entry in entries {
+                if !baseline_licenses.contains(entry.license.as_str()) {
+                    diff.new_licenses.push(entry.clone());
+                }
+                diff.added.push(entry.clone());
+            }
+            continue;
+        };
+
+        let old_licenses = old_entries
+            .iter()
+            .map(|entry| entry.license.clone())
+            .collect::<BTreeSet<_>>();
+        let mut changed = false;
+        for entry in entries {
+            if !old_licenses.contains(&entry.license) {
+                changed = true;
+                diff.license_changed.push(LicenseChange {
+                    name: entry.name.clone(),
+                    version: entry.version.clone(),
+                    old: old_licenses.iter().cloned().collect(),
+                    new: entry.license.clone(),
+                });
+            }
+        }
+
+        let old_versions = old_entries
+            .iter()
+            .map(|entry| entry.version.clone())
+            .collect::<Vec<_>>();
+        let new_versions = entries
+            .iter()
+            .map(|entry| entry.version.clone())
+            .collect::<Vec<_>>();
+        if !changed && old_versions != new_versions {
+            diff.version_changed.push(VersionChange {
+                name: (*name).to_owned(),
+                license: old_licenses.iter().cloned().collect::<Vec<_>>().join(", "),
+                old: old_versions,
+                new: new_versions,
+            });
+        }
+    }
+
+    for (name, entries) in &old {
+        if !new.contains_key(name) {
+            diff.removed
+                .extend(entries.iter().map(|&entry| entry.clone()));
+        }
+    }
+
+    diff
+}
+
+fn print_text(diff: &Diff) {
+    for entry in &diff.added {
+        println!(
+            "added: {} {} ({})",
+            entry.name, entry.version, entry.license
+        );
+    }
+    for entry in &diff.removed {
+        println!(
+            "removed: {} {} ({})",
+            entry.name, entry.version, entry.license
+        );
+    }
+    for change in &diff.version_changed {
+        println!(
+            "version changed: {} {} -> {} ({})",
+            change.name,
+            change.old.join(", "),
+            change.new.join(", "),
+            change.license
+        );
+    }
+    for change in &diff.license_changed {
+        println!(
+            "license changed: {} {} ({} -> {})",
+            change.name,
+            change.version,
+            change.old.join(", "),
+            change.new
+        );
+    }
+}
+
+pub fn run(
+    baseline: &str,
+    resolved: &[ResolvedPackage],
+    format: DiffFormat,
+) -> anyhow::Result<Outcome> {
+    let baseline = Inventory::load(baseline)?;
+    let current = Inventory::collect(resolved);
+    let diff = diff(&baseline, &current);
+
+    match format {
+        DiffFormat::Json => {
+            let stdout = io::stdout();
+            let mut out = stdout.lock();
+            serde_json::to_writer_pretty(&mut out, &diff)?;
+            writeln!(out)?;
+        }
+        DiffFormat::Text => print_text(&diff),
+    }
+
+    for entry in &diff.new_licenses {
+        log::error!(
+            "{} {} is licensed under {} which is not used by any package in the baseline",
+            entry.name,
+            entry.version,
+            entry.license
+        );
+    }
+
+    let mut outcome = Outcome::default();
+    if !diff.license_changed.is_empty() || !diff.new_licenses.is_empty() {
+        outcome.fail("Licenses changed since baseline");
+    }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, version: &str, license: &str) -> Entry {
+        Entry {
+            name: name.to_owned(),
+            version: version.to_owned(),
+            license: license.to_owned(),
+            declared: None,
+            license_file: None,
+            resolution: None,
+            yanked: false,
+        }
+    }
+
+    fn inventory(entries: &[(&str, &str, &str)]) -> Inventory {
+        let mut packages = entries
+            .iter()
+            .map(|&(name, version, license)| entry(name, version, license))
+            .collect::<Vec<_>>();
+        packages.sort();
+        Inventory {
+            packages,
+            summary: None,
+        }
+    }
+
+    fn names(entries: &[Entry]) -> Vec<String> {
+        entries
+            .iter()
+            .map(|entry| format!("{} {}", entry.name, entry.version))
+            .collect()
+    }
+
+    #[test]
+    fn unchanged() {
+        let baseline = inventory(&[("a", "1.0.0", "MIT"), ("b", "0.1.0", "Apache-2.0")]);
+        let current = inventory(&[("b", "0.1.0", "Apache-2.0"), ("a", "1.0.0", "MIT")]);
+        let diff = diff(&baseline, &current);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert!(diff.version_changed.is_empty());
+        assert!(diff.license_changed.is_empty());
+        assert!(diff.new_licenses.is_empty());
+    }
+
+    #[test]
+    fn added_and_removed() {
+        let baseline = inventory(&[("a", "1.0.0", "MIT"), ("gone", "0.1.0", "Zlib")]);
+        let current = inventory(&[
+            ("a", "1.0.0", "MIT"),
+            ("same", "0.2.0", "MIT"),
+            ("fresh", "0.1.0", "GPL-3.0-only"),
+        ]);
+        let diff = diff(&baseline, &current);
+        // Sorted by name whatever order they came in
+        assert_eq!(names(&diff.added), ["fresh 0.1.0", "same 0.2.0"]);
+        assert_eq!(names(&diff.removed), ["gone 0.1.0"]);
+        // Only a license no package in the baseline had is new, a removed
+        // package's license still counts as known
+        assert_eq!(names(&diff.new_licenses), ["fresh 0.1.0"]);
+        assert!(diff.version_changed.is_empty());
+        assert!(diff.license_changed.is_empty());
+
+        let current = inventory(&[("a", "1.0.0", "MIT"), ("other", "0.1.0", "Zlib")]);
+        assert!(super::diff(&baseline, &current).new_licenses.is_empty());
+    }
+
+    #[test]
+    fn version_only_changes() {
+        let baseline = inventory(&[("a", "1.0.0", "MIT"), ("b", "0.1.0", "MIT")]);
+        let current = inventory(&[("a", "1.1.0", "MIT"), ("b", "0.1.0", "MIT")]);
+        let diff = diff(&baseline, &current);
+        assert_eq!(diff.version_changed.len(), 1);
+        let change = &diff.version_changed[0];
+        assert_eq!(change.name, "a");
+        assert_eq!(change.license, "MIT");
+        assert_eq!(change.old, ["1.0.0"]);
+        assert_eq!(change.new, ["1.1.0"]);
+        assert!(diff.added.is_empty());
+        assert!(diff.removed.is_empty());
+        assert!(diff.license_changed.is_empty());
+    }
+
+    #[test]
+    fn license_changes() {
+        let baseline = inventory(&[("a", "1.0.0", "MIT")]);
+        let current = inventory(&[("a", "2.0.0", "Apache-2.0")]);
+        let diff = diff(&baseline, &current);
+        assert_eq!(diff.license_changed.len(), 1);
+        let change = &diff.license_changed[0];
+        assert_eq!(change.name, "a");
+        assert_eq!(change.version, "2.0.0");
+        assert_eq!(change.old, ["MIT"]);
+        assert_eq!(change.new, "Apache-2.0");
+        // Reported as a license change rather than also a version change
+        assert!(diff.version_changed.is_empty());
+        assert!(diff.new_licenses.is_empty());
+    }
+
+    #[test]
+    fn multiple_versions() {
+        let baseline = inventory(&[("a", "0.9.0", "MIT"), ("b", "1.0.0", "MIT")]);
+
+        // A second version under a license the first had
+        let current = inventory(&[
+            ("a", "0.9.0", "MIT"),
+            ("a", "1.0.0", "MIT"),
+            ("b", "1.0.0", "MIT"),
+        ]);
+        let diff = diff(&baseline, &current);
+        assert!(diff.license_changed.is_empty());
+        assert!(diff.added.is_empty());
+        assert_eq!(diff.version_changed.len(), 1);
+        assert_eq!(diff.version_changed[0].old, ["0.9.0"]);
+        assert_eq!(diff.version_changed[0].new, ["0.9.0", "1.0.0"]);
+
+        // Only the version with a different license is reported
+        let current = inventory(&[
+            ("a", "0.9.0", "MIT"),
+            ("a", "1.0.0", "MIT OR Apache-2.0"),
+            ("b", "1.0.0", "MIT"),
+        ]);
+        let diff = super::diff(&baseline, &current);
+        assert_eq!(diff.license_changed.len(), 1);
+        assert_eq!(diff.license_changed[0].version, "1.0.0");
+        assert_eq!(diff.license_changed[0].new, "MIT OR Apache-2.0");
+        assert!(diff.version_changed.is_empty());
+
+        // Dropping one of two versions is a version change, not a removal
+        let diff = super::diff(&current, &baseline);
+        assert!(diff.removed.is_empty());
+        assert!(diff.license_changed.is_empty());
+        assert_eq!(diff.version_changed.len(), 1);
+        assert_eq!(diff.version_changed[0].old, ["0.9.0", "1.0.0"]);
+        assert_eq!(diff.version_changed[0].new, ["0.9.0"]);
+    }
+}
@@ -1,85 +1,425 @@
-use std::fs::{self, File};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::env;
+use std::error::Error;
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Write};
-use std::path::Path;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
-use anyhow::anyhow;
-use cargo_metadata::Package;
+use anyhow::{anyhow, Context as _};
+use cargo_metadata::{Package, PackageId};
+use serde::Serialize;
+use tera::Tera;
 
-use crate::discovery::{find_generic_license_text, find_license_text, Confidence, LicenseText};
+use crate::cache::Cache;
+use crate::date;
+use crate::discovery::{
+    contradicting_license_text, find_generic_license_text_traced, find_license_text_traced,
+    find_readme_license_text, find_readme_license_text_traced, package_dir, source_available,
+    split_license_text, text_issues, virtual_manifest, Confidence, DiscoveryConfig, DiscoveryTrace,
+    LicenseText, Origin, SkippedFile,
+};
+use crate::holders::{self, copyright_lines};
 use crate::license::License;
-use crate::licensed::Licensed;
-use crate::options::Bundle;
+use crate::licensed::{self, Licensed, LicensedCache, Resolution, SourceHeaders};
+use crate::load::{self, ResolvedPackage, SourceKind};
+use crate::manifest;
+use crate::options::{Bundle, BundleOptions, Layout};
+use crate::outcome::{Outcome, Summary};
+use crate::policy::Election;
+use crate::progress::Progress;
+use crate::textutil::{self, LineEnding};
 
 struct Context<'a> {
     roots_name: String,
     packages: &'a [&'a Package],
+    /// Other copies of packages coming from multiple sources
+    duplicates: HashMap<&'a PackageId, Vec<&'a Package>>,
+    workspace_root: &'a Path,
+    licenses: &'a LicensedCache,
+    config: DiscoveryConfig,
+    cache: Option<&'a Cache>,
+    /// Liches of earlier bundles of the same invocation
+    collected: Option<&'a Collected>,
+    require_sources: bool,
+    cross_check: bool,
+    reproducible: bool,
+    preamble: Option<String>,
+    postamble: Option<String>,
+    raw_html: bool,
+    /// Annotate the texts of inline and split bundles with their provenance
+    print_hashes: bool,
+    /// Add each package's description, upstream link and authors to entries
+    with_metadata: bool,
+    /// Warn about texts whose copyright holder matches none of the package's
+    /// metadata
+    check_holders: bool,
+    /// Use packages offering a choice of licenses under just one of them
+    election: Option<&'a Election>,
+    /// Clean up the line endings and whitespace of the chosen texts
+    normalize: bool,
+    line_ending: LineEnding,
 
-    missing_license: bool,
-    low_quality_license: bool,
+    missing_license: AtomicBool,
+    contradicted_license: AtomicBool,
+    missing_sources: AtomicBool,
+    /// Packages whose manifest is a workspace's virtual manifest
+    virtual_manifests: AtomicBool,
+    low_quality_license: AtomicBool,
+    /// Packages shipping license files too large or binary to use
+    skipped_files: AtomicBool,
+    /// Tallies of the chosen texts' confidence
+    summary: Mutex<Summary>,
+    /// Records discovery's decisions when inspecting a package, bypassing the
+    /// cache
+    trace: Option<Mutex<DiscoveryTrace>>,
+}
+
+/// What every bundle of a run shares
+pub struct Shared<'a> {
+    pub workspace_root: &'a Path,
+    /// The licenses of the packages, each worked out once for the whole run
+    pub licenses: &'a LicensedCache,
+    /// Use packages offering a choice of licenses under just one of them, the
+    /// same for every root
+    pub election: Option<Election>,
+}
+
+pub fn run(
+    roots: &[&Package],
+    resolved: &[ResolvedPackage],
+    shared: &Shared,
+    variant: Bundle,
+    options: &BundleOptions,
+    cache: Option<Cache>,
+) -> anyhow::Result<Outcome> {
+    let outcome = bundle(
+        roots,
+        resolved,
+        shared,
+        variant,
+        options,
+        cache.as_ref(),
+        None,
+    )?;
+    save(cache);
+    Ok(outcome)
+}
+
+/// Bundles each root's own dependencies into a file of its own in `dir`,
+/// along with an index of how each went
+pub fn run_per_root(
+    roots: &[(&Package, Vec<ResolvedPackage>)],
+    shared: &Shared,
+    variant: Bundle,
+    options: &BundleOptions,
+    cache: Option<Cache>,
+    dir: &Path,
+) -> anyhow::Result<Outcome> {
+    create_dir(dir)?;
+    // Dependencies shared by several roots are only searched once
+    let collected = Collected::default();
+    let mut outcome = Outcome::default();
+    let mut index = Vec::new();
+    for (root, resolved) in roots {
+        let name = format!("{}-THIRDPARTY.{}", root.name, extension(&variant));
+        let file = dir.join(&name);
+        log::info!(
+            "Bundling the licenses of {} into {}",
+            root.name,
+            file.display()
+        );
+        let root_outcome = bundle(
+            &[root],
+            resolved,
+            shared,
+            retarget(variant.clone(), file.display().to_string()),
+            options,
+            cache.as_ref(),
+            Some(&collected),
+        )?;
+        let status = if !root_outcome.failures.is_empty() {
+            format!("failed: {}", root_outcome.failures.join(", "))
+        } else if !root_outcome.missing_texts.is_empty() {
+            format!(
+                "missing licenses: {}",
+                root_outcome.missing_texts.join(", ")
+            )
+        } else if !root_outcome.warnings.is_empty() {
+            format!(
+                "succeeded with warnings: {}",
+                root_outcome.warnings.join(", ")
+            )
+        } else {
+            "succeeded".to_owned()
+        };
+        index.push(format!(
+            "{} {}: {} ({})",
+            root.name, root.version, name, status
+        ));
+        outcome.merge(prefixed(root, root_outcome));
+    }
+    save(cache);
+
+    let mut out = textutil::with_line_endings(
+        create(&dir.join("index.txt").display().to_string(), false)?,
+        options.line_ending,
+    );
+    writeln!(out, "Third party license bundles, one per package:")?;
+    writeln!(out)?;
+    for line in index {
+        writeln!(out, "{}", line)?;
+    }
+    Ok(outcome)
+}
+
+/// Bundles each root's own dependencies one after the other into the same
+/// output, each section opening with the header naming its root
+pub fn run_sections(
+    roots: &[(&Package, Vec<ResolvedPackage>)],
+    shared: &Shared,
+    variant: Bundle,
+    options: &BundleOptions,
+    cache: Option<Cache>,
+) -> anyhow::Result<Outcome> {
+    let collected = Collected::default();
+    let mut outcome = Outcome::default();
+    for (i, (root, resolved)) in roots.iter().enumerate() {
+        // The preamble and postamble frame the whole output, and later
+        // sections follow the earlier ones
+        let mut options = options.clone();
+        if i > 0 {
+            options.preamble = None;
+            options.append = true;
+            let out: Box<dyn io::Write> = match file(&variant) {
+                Some(file) => Box::new(create(file, true)?),
+                None => Box::new(io::stdout()),
+            };
+            writeln!(textutil::with_line_endings(out, options.line_ending))?;
+        }
+        if i + 1 < roots.len() {
+            options.postamble = None;
+        }
+        let root_outcome = bundle(
+            &[root],
+            resolved,
+            shared,
+            variant.clone(),
+            &options,
+            cache.as_ref(),
+            Some(&collected),
+        )?;
+        outcome.merge(prefixed(root, root_outcome));
+    }
+    save(cache);
+    Ok(outcome)
+}
+
+/// A root's outcome with each reason naming the root
+fn prefixed(root: &Package, outcome: Outcome) -> Outcome {
+    let prefix = |reasons: Vec<String>| {
+        reasons
+            .into_iter()
+            .map(|reason| format!("{}: {}", root.name, reason))
+            .collect()
+    };
+    Outcome {
+        failures: prefix(outcome.failures),
+        warnings: prefix(outcome.warnings),
+        missing_texts: prefix(outcome.missing_texts),
+        summary: outcome.summary,
+    }
+}
+
+fn save(cache: Option<Cache>) {
+    if let Some(cache) = cache {
+        if let Err(err) = cache.save() {
+            log::warn!("Couldn't save discovery cache: {}", err);
+        }
+    }
+}
+
+/// The file extension matching the bundle variant's format
+fn extension(variant: &Bundle) -> String {
+    match variant {
+        Bundle::Source { .. } => "rs".to_owned(),
+        Bundle::Markdown { .. } => "md".to_owned(),
+        Bundle::Html { .. } => "html".to_owned(),
+        Bundle::Json { .. } => "json".to_owned(),
+        // Named after what's rendered, e.g. `attribution.md.tera`
+        Bundle::Template { template, .. } => Path::new(template.trim_end_matches(".tera"))
+            .extension()
+            .map_or_else(
+                || "txt".to_owned(),
+                |extension| extension.to_string_lossy().into_owned(),
+            ),
+        _ => "txt".to_owned(),
+    }
+}
+
+/// The file the variant writes to, `None` for standard out
+fn file(variant: &Bundle) -> Option<&str> {
+    match variant {
+        Bundle::Inline { file, .. }
+        | Bundle::NameOnly { file }
+        | Bundle::Source { file }
+        | Bundle::Markdown { file }
+        | Bundle::Html { file }
+        | Bundle::Grouped { file }
+        | Bundle::Json { file }
+        | Bundle::Paths { file, .. }
+        | Bundle::Split { file, .. }
+        | Bundle::Template { file, .. } => file.as_deref(),
+    }
+}
+
+/// The variant writing to `file` instead
+fn retarget(variant: Bundle, file: String) -> Bundle {
+    let file = Some(file);
+    match variant {
+        Bundle::Inline { layout, .. } => Bundle::Inline { file, layout },
+        Bundle::NameOnly { .. } => Bundle::NameOnly { file },
+        Bundle::Source { .. } => Bundle::Source { file },
+        Bundle::Markdown { .. } => Bundle::Markdown { file },
+        Bundle::Html { .. } => Bundle::Html { file },
+        Bundle::Grouped { .. } => Bundle::Grouped { file },
+        Bundle::Json { .. } => Bundle::Json { file },
+        Bundle::Paths { relative_to, .. } => Bundle::Paths { file, relative_to },
+        Bundle::Split {
+            dir,
+            verbatim,
+            force,
+            ..
+        } => Bundle::Split {
+            file,
+            dir,
+            verbatim,
+            force,
+        },
+        Bundle::Template { template, .. } => Bundle::Template { file, template },
+    }
 }
 
-pub fn run(roots: &[&Package], packages: &[&Package], variant: Bundle) -> anyhow::Result<()> {
+fn bundle(
+    roots: &[&Package],
+    resolved: &[ResolvedPackage],
+    shared: &Shared,
+    variant: Bundle,
+    options: &BundleOptions,
+    cache: Option<&Cache>,
+    collected: Option<&Collected>,
+) -> anyhow::Result<Outcome> {
     let packages = {
-        let mut packages = packages.to_owned();
+        let mut packages = load::packages(resolved);
         packages.sort_by_key(|p| (&p.name, &p.version));
         packages
     };
 
     let roots_name = {
+        // Each root along with its own license when it has one
+        let license = |root: &Package| match &shared.licenses.resolve(root).license {
+            License::Unspecified => String::new(),
+            license => format!(" ({})", license),
+        };
+        let name = |root: &Package| format!("{}{}", root.name, license(root));
         if roots.len() == 1 {
-            format!("{} package", roots[0].name)
+            format!("{} package{}", roots[0].name, license(roots[0]))
         } else {
             let mut roots_name = String::new();
-            roots_name += roots[0].name.as_str();
+            roots_name += &name(roots[0]);
             for root in roots.iter().take(roots.len() - 1).skip(1) {
                 roots_name += ", ";
-                roots_name += root.name.as_str();
+                roots_name += &name(root);
             }
             roots_name += " and ";
-            roots_name += roots.last().unwrap().name.as_str();
+            roots_name += &name(roots.last().unwrap());
             roots_name += " packages";
             roots_name
         }
     };
-    let mut context = Context {
+    // Read up front so a missing file doesn't leave a half written bundle
+    let read = |path: &Option<String>, what: &str| {
+        path.as_deref()
+            .map(|path| {
+                fs::read_to_string(path).with_context(|| format!("Couldn't read {} {}", what, path))
+            })
+            .transpose()
+    };
+    let preamble = read(&options.preamble, "preamble")?;
+    let postamble = read(&options.postamble, "postamble")?;
+
+    let context = Context {
         roots_name,
         packages: &packages,
-        missing_license: false,
-        low_quality_license: false,
+        duplicates: load::duplicates(resolved),
+        workspace_root: shared.workspace_root,
+        licenses: shared.licenses,
+        config: options.discovery.clone(),
+        cache,
+        collected,
+        require_sources: options.require_sources,
+        cross_check: options.cross_check,
+        reproducible: options.reproducible,
+        preamble,
+        postamble,
+        raw_html: options.raw_html,
+        print_hashes: options.print_hashes,
+        with_metadata: options.with_metadata,
+        check_holders: options.check_holders,
+        election: shared.election.as_ref(),
+        normalize: !options.no_normalize,
+        line_ending: options.line_ending,
+        missing_license: AtomicBool::new(false),
+        contradicted_license: AtomicBool::new(false),
+        missing_sources: AtomicBool::new(false),
+        virtual_manifests: AtomicBool::new(false),
+        low_quality_license: AtomicBool::new(false),
+        skipped_files: AtomicBool::new(false),
+        summary: Mutex::new(Summary::of(resolved)),
+        trace: None,
     };
 
-    match variant {
-        Bundle::Inline { file } => {
-            if let Some(file) = file {
-                inline(&mut context, &mut File::create(file)?)?;
-            } else {
-                inline(&mut context, &mut io::stdout())?;
-            }
-        }
-        Bundle::NameOnly { file } => {
-            if let Some(file) = file {
-                name_only(&mut context, &mut File::create(file)?)?;
-            } else {
-                name_only(&mut context, &mut io::stdout())?;
-            }
+    // Before creating the file, which may well be in the split bundle's
+    // directory, or be truncated by a bad --relative-to
+    let relative_to = match &variant {
+        Bundle::Split { dir, force, .. } => {
+            prepare_dir(Path::new(dir), *force, context.workspace_root)?;
+            None
         }
-        Bundle::Source { file } => {
-            if let Some(file) = file {
-                source(&mut context, &mut File::create(file)?)?;
-            } else {
-                source(&mut context, &mut io::stdout())?;
-            }
+        Bundle::Paths { relative_to, .. } => {
+            relative_to.as_ref().map(fs::canonicalize).transpose()?
         }
-        Bundle::Split { file, dir } => {
-            if let Some(file) = file {
-                split(&mut context, &mut File::create(file)?, dir)?;
-            } else {
-                split(&mut context, &mut io::stdout(), dir)?;
-            }
+        _ => None,
+    };
+    let mut out = match file(&variant) {
+        Some(file) => {
+            textutil::with_line_endings(create(file, options.append)?, context.line_ending)
         }
+        None => textutil::with_line_endings(io::stdout(), context.line_ending),
+    };
+    let out = &mut *out;
+    let written = match variant {
+        Bundle::Inline { layout, .. } => inline(&context, &layout, out)?,
+        Bundle::NameOnly { .. } => name_only(&context, out)?,
+        Bundle::Source { .. } => source(&context, out)?,
+        Bundle::Markdown { .. } => markdown(&context, out)?,
+        Bundle::Html { .. } => html(&context, out)?,
+        Bundle::Grouped { .. } => grouped(&context, out)?,
+        Bundle::Json { .. } => json(&context, out)?,
+        Bundle::Paths { .. } => paths(&context, out, relative_to)?,
+        Bundle::Split { dir, verbatim, .. } => split(&context, out, dir, verbatim)?,
+        Bundle::Template { template, .. } => templated(&context, out, &template)?,
+    };
+    out.flush()?;
+    if options.verify {
+        verify(&packages, written)?;
     }
 
-    if context.missing_license {
+    if context.missing_license.load(Ordering::SeqCst) {
         log::error!(
             "
   Our liches failed to recognise a license in one or more packages.
@@ -97,7 +437,15 @@ pub fn run(roots: &[&Package], packages: &[&Package], variant: Bundle) -> anyhow
         );
     }
 
-    if context.low_quality_license {
+    if context.missing_sources.load(Ordering::SeqCst) {
+        log::error!(
+            "\
+             The sources of one or more packages are not available locally, so their \
+             licenses could not be bundled. Running `cargo fetch` should download them.",
+        );
+    }
+
+    if context.low_quality_license.load(Ordering::SeqCst) {
         log::error!(
             "\
              Our liches are very unsure about one or more licenses that were put into the \
@@ -105,371 +453,2239 @@ pub fn run(roots: &[&Package], packages: &[&Package], variant: Bundle) -> anyhow
         );
     }
 
-    if context.missing_license || context.low_quality_license {
-        Err(anyhow!("Generating bundle finished with error(s)"))
-    } else {
-        Ok(())
+    let mut outcome = Outcome {
+        summary: context.summary.into_inner().unwrap(),
+        ..Outcome::default()
+    };
+    if context.missing_license.load(Ordering::SeqCst) {
+        outcome.missing_text("Couldn't find license texts for some packages");
+    }
+    if context.missing_sources.load(Ordering::SeqCst) {
+        outcome.missing_text("Sources of some packages are not available");
+    }
+    if context.virtual_manifests.load(Ordering::SeqCst) {
+        outcome.missing_text("Some packages point at a workspace virtual manifest");
+    }
+    if context.contradicted_license.load(Ordering::SeqCst) {
+        outcome.fail("License files contradict the declared licenses");
     }
+    if context.low_quality_license.load(Ordering::SeqCst) {
+        outcome.warn("Some license texts are low confidence matches");
+    }
+    if context.skipped_files.load(Ordering::SeqCst) {
+        outcome.warn("Some license files were skipped as too large or binary");
+    }
+    Ok(outcome)
 }
 
-fn inline(context: &mut Context, out: &mut dyn io::Write) -> anyhow::Result<()> {
-    writeln!(
-        out,
-        "The {} uses some third party libraries under their own license terms:",
-        context.roots_name
-    )?;
-    writeln!(out)?;
-    for package in context.packages {
-        writeln!(
-            out,
-            " * {} {} under the terms of {}:",
-            package.name,
-            package.version,
-            package.license(),
-        )?;
-        writeln!(out)?;
-        inline_package(context, package, out)?;
-        writeln!(out)?;
+/// The name and version of a package a writer emitted an entry for
+type Entry = (String, String);
+
+fn entry(package: &Package) -> Entry {
+    (package.name.clone(), package.version.to_string())
+}
+
+/// Reads the entries back out of the packages a writer serialized, so what's
+/// verified is what was written rather than what the writer was handed
+fn written_entries(packages: Option<&serde_json::Value>) -> anyhow::Result<Vec<Entry>> {
+    let field = |package: &serde_json::Value, key| {
+        package
+            .get(key)
+            .and_then(|value| value.as_str())
+            .map(str::to_owned)
+            .ok_or_else(|| anyhow!("A serialized package has no {}: {}", key, package))
+    };
+    packages
+        .and_then(|packages| packages.as_array())
+        .ok_or_else(|| anyhow!("The serialized bundle has no list of packages"))?
+        .iter()
+        .map(|package| Ok((field(package, "name")?, field(package, "version")?)))
+        .collect()
+}
+
+/// Checks that the writer emitted exactly one entry for each package, a
+/// package dropped or repeated between collecting and writing is a bug
+fn verify(packages: &[&Package], written: Vec<Entry>) -> anyhow::Result<()> {
+    let mut counts = BTreeMap::<Entry, (usize, usize)>::new();
+    for &package in packages {
+        counts.entry(entry(package)).or_default().0 += 1;
     }
-    Ok(())
+    for entry in written {
+        counts.entry(entry).or_default().1 += 1;
+    }
+    let discrepancies = counts
+        .iter()
+        .filter(|(_, (expected, written))| expected != written)
+        .map(|((name, version), &(expected, written))| {
+            if written < expected {
+                format!("{} {} is missing", name, version)
+            } else {
+                format!(
+                    "{} {} is written {} times instead of {}",
+                    name, version, written, expected
+                )
+            }
+        })
+        .collect::<Vec<_>>();
+    if discrepancies.is_empty() {
+        return Ok(());
+    }
+    Err(anyhow!(
+        "The bundle doesn't match the packages it was generated from, this is a bug:\n  {}\n\
+         Set {}=1 to skip this check",
+        discrepancies.join("\n  "),
+        crate::options::NO_VERIFY_VAR
+    ))
 }
 
-fn name_only(context: &mut Context, out: &mut dyn io::Write) -> anyhow::Result<()> {
-    writeln!(
-        out,
-        "The {} uses some third party libraries under their own license terms:",
-        context.roots_name
-    )?;
-    writeln!(out)?;
-    for package in context.packages {
-        writeln!(
-            out,
-            " * {} {} under the terms of {}",
-            package.name,
-            package.version,
-            package.license(),
-        )?;
+/// Discovers the license texts of a single package the way bundling does,
+/// recording every file considered and why each text was chosen
+pub fn inspect(
+    package: &Package,
+    workspace_root: &Path,
+    config: &DiscoveryConfig,
+    licenses: &LicensedCache,
+) -> anyhow::Result<DiscoveryTrace> {
+    let packages = [package];
+    let context = Context {
+        roots_name: String::new(),
+        packages: &packages,
+        duplicates: HashMap::new(),
+        workspace_root,
+        licenses,
+        config: config.clone(),
+        cache: None,
+        collected: None,
+        require_sources: false,
+        cross_check: false,
+        reproducible: false,
+        preamble: None,
+        postamble: None,
+        raw_html: false,
+        print_hashes: false,
+        with_metadata: false,
+        check_holders: false,
+        election: None,
+        normalize: false,
+        line_ending: LineEnding::Lf,
+        missing_license: AtomicBool::new(false),
+        contradicted_license: AtomicBool::new(false),
+        missing_sources: AtomicBool::new(false),
+        virtual_manifests: AtomicBool::new(false),
+        low_quality_license: AtomicBool::new(false),
+        skipped_files: AtomicBool::new(false),
+        summary: Mutex::new(Summary::default()),
+        trace: Some(Mutex::new(DiscoveryTrace::new())),
+    };
+    collect(&context, package)?;
+    Ok(context.trace.unwrap().into_inner().unwrap())
+}
+
+/// A license and the text chosen for it, if any could be found
+#[derive(Clone)]
+struct Chosen {
+    license: License,
+    text: Option<LicenseText>,
+    provenance: Option<Provenance>,
+}
+
+impl Chosen {
+    /// Only the chosen texts are hashed, not every candidate
+    fn new(
+        context: &Context,
+        package: &Package,
+        license: License,
+        text: Option<LicenseText>,
+    ) -> Chosen {
+        let provenance = text
+            .as_ref()
+            .and_then(|text| Provenance::of(context, package, text));
+        Chosen {
+            license,
+            text,
+            provenance,
+        }
     }
-    Ok(())
 }
 
-fn source(context: &mut Context, out: &mut dyn io::Write) -> anyhow::Result<()> {
-    out.write_all(
-        b"\
-//! Licenses of dependencies
-//!
-//! This file was generated by [`cargo-lichking`](https://github.com/Nemo157/cargo-lichking)
+/// The file a chosen text came from, so that it can be checked against the
+/// package's sources
+#[derive(Clone, Serialize)]
+struct Provenance {
+    /// Relative to the directory of the package's manifest
+    path: String,
+    /// Left out of reproducible bundles
+    #[serde(skip_serializing_if = "Option::is_none")]
+    absolute_path: Option<String>,
+    /// Of the whole file, the text may only be part of it or have been
+    /// decoded lossily
+    size: u64,
+    sha256: String,
+    /// The file is in the package's copy in the `--vendor-dir`
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    vendored: bool,
+}
 
-pub struct License {
-    pub name: &'static str,
-    pub text: Option<&'static str>,
+impl Provenance {
+    /// `None` for standard texts, which don't come from the package
+    fn of(context: &Context, package: &Package, text: &LicenseText) -> Option<Provenance> {
+        let (size, sha256) = text.digest()?;
+        let dir = context.source_dir(package, &text.path)?;
+        let path = match text.path.strip_prefix(dir) {
+            Ok(path) => path.to_owned(),
+            Err(_) => relative(&text.path, dir),
+        };
+        Some(Provenance {
+            path: path.display().to_string(),
+            absolute_path: Some(text.path.display().to_string()).filter(|_| !context.reproducible),
+            size,
+            sha256,
+            vendored: context
+                .licenses
+                .vendored(package)
+                .and_then(|copy| copy.manifest_path.parent())
+                .is_some_and(|dir| text.path.starts_with(dir)),
+        })
+    }
 }
 
-pub struct Licenses {
-    pub name: &'static str,
-    pub licenses: &'static [License],
+impl std::fmt::Display for Provenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "source: {}", self.path)?;
+        if self.vendored {
+            write!(f, " (vendored)")?;
+        }
+        write!(f, ", sha256: {}", self.sha256)
+    }
+}
+
+/// The description, upstream link and authors of a package that
+/// `--with-metadata` adds to its entry, missing ones are left out
+struct Attribution<'a> {
+    /// The first line of the description, shortened to `DESCRIPTION_LIMIT`
+    /// characters
+    description: Option<String>,
+    /// The repository, or the homepage without one
+    url: Option<String>,
+    authors: &'a [String],
+}
+
+const DESCRIPTION_LIMIT: usize = 120;
+
+impl Attribution<'_> {
+    fn of(package: &Package) -> Attribution<'_> {
+        let description = package
+            .description
+            .as_deref()
+            .and_then(|description| {
+                description
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+            })
+            .map(|line| {
+                if line.chars().count() <= DESCRIPTION_LIMIT {
+                    return line.to_owned();
+                }
+                let shortened = line.chars().take(DESCRIPTION_LIMIT - 1).collect::<String>();
+                format!("{}…", shortened.trim_end())
+            });
+        Attribution {
+            description,
+            url: package
+                .repository
+                .clone()
+                .or_else(|| manifest::homepage(package)),
+            authors: &package.authors,
+        }
+    }
+
+    fn byline(&self) -> Option<String> {
+        Some(format!("by {}", self.authors.join(", "))).filter(|_| !self.authors.is_empty())
+    }
+
+    /// All of it on one line for the plain text variants, `None` if there's
+    /// nothing to add
+    fn line(&self) -> Option<String> {
+        let parts =
+            IntoIterator::into_iter([self.description.clone(), self.url.clone(), self.byline()])
+                .flatten()
+                .collect::<Vec<_>>();
+        Some(parts.join(" — ")).filter(|_| !parts.is_empty())
+    }
+}
+
+/// The license texts chosen for a single package, either a single text for
+/// the whole license or one per part of a `License::Multiple` or `License::All`
+struct Lich<'a> {
+    package: &'a Package,
+    license: License,
+    texts: Vec<Chosen>,
+    problems: Problems,
+}
+
+/// What went wrong collecting a package's license texts, recorded with its
+/// lich so that reusing it reports the same problems
+#[derive(Clone, Default)]
+struct Problems {
+    missing_license: bool,
+    contradicted_license: bool,
+    missing_sources: bool,
+    virtual_manifest: bool,
+    low_quality_license: bool,
+    /// License files that couldn't be used, each search may skip the same
+    skipped: Vec<SkippedFile>,
+}
+
+impl Problems {
+    fn skip(&mut self, skipped: Vec<SkippedFile>) {
+        for file in skipped {
+            if !self.skipped.contains(&file) {
+                self.skipped.push(file);
+            }
+        }
+    }
+
+    /// The problems listed with the package's entry in structured bundles
+    fn issues(&self, context: &Context, package: &Package) -> Vec<String> {
+        let mut issues = Vec::new();
+        if self.virtual_manifest {
+            issues.push(format!(
+                "manifest {} is a workspace virtual manifest, there are no package sources",
+                context.path(package, &package.manifest_path)
+            ));
+        }
+        for file in &self.skipped {
+            issues.push(format!(
+                "license file {} was skipped, {}",
+                context.path(package, &file.path),
+                file.reason
+            ));
+        }
+        issues
+    }
+}
+
+/// Liches already collected by an earlier bundle of the same invocation, so
+/// dependencies shared by several roots are only searched once
+#[derive(Default)]
+pub struct Collected(Mutex<HashMap<PackageId, Reusable>>);
+
+/// A lich without the package it's for, that may be another copy
+type Reusable = (License, Vec<Chosen>, Problems);
+
+impl Context<'_> {
+    /// The directory of the copy of the package `path` is in, one from another
+    /// source's or its own
+    fn source_dir<'b>(&'b self, package: &'b Package, path: &Path) -> Option<&'b Path> {
+        for copy in self.duplicates.get(&package.id).into_iter().flatten() {
+            let dir = copy.manifest_path.parent()?;
+            if path.starts_with(dir) {
+                return Some(dir);
+            }
+        }
+        package.manifest_path.parent()
+    }
+
+    /// The license `package` is used under, only the elected alternative of a
+    /// choice of licenses when electing one
+    fn license(&self, package: &Package) -> License {
+        let resolved = self.licenses.resolve(package);
+        self.election
+            .and_then(|election| election.elect(package, &resolved.license))
+            .unwrap_or_else(|| resolved.license.clone())
+    }
+
+    /// Records why `text` was chosen for `license` when tracing
+    fn choice(
+        &self,
+        license: &License,
+        text: Option<&LicenseText>,
+        reason: impl FnOnce() -> String,
+    ) {
+        if let Some(trace) = &self.trace {
+            trace.lock().unwrap().choice(license, text, reason);
+        }
+    }
+
+    /// What `--with-metadata` adds to `package`'s entry, `None` without it
+    fn attribution<'b>(&self, package: &'b Package) -> Option<Attribution<'b>> {
+        self.with_metadata.then(|| Attribution::of(package))
+    }
+
+    /// How to show a path in the package's sources, when reproducible relative
+    /// to the workspace root or for packages outside of it, e.g. from a
+    /// registry, relative to the directory containing the package
+    fn path(&self, package: &Package, path: &Path) -> String {
+        if !self.reproducible {
+            return path.display().to_string();
+        }
+        let base = if path.starts_with(self.workspace_root) {
+            self.workspace_root
+        } else {
+            let dir = package_dir(package).unwrap_or(&package.manifest_path);
+            dir.parent().unwrap_or(dir)
+        };
+        relative(path, base).display().to_string()
+    }
+}
+
+/// Records the files skipped in `problems`
+fn generic_license_text(
+    context: &Context,
+    package: &Package,
+    license: &License,
+    problems: &mut Problems,
+) -> anyhow::Result<Option<LicenseText>> {
+    if let Some(trace) = &context.trace {
+        let trace = &mut trace.lock().unwrap();
+        let text = find_generic_license_text_traced(package, license, &context.config, trace)?;
+        problems.skip(trace.skipped.clone());
+        return Ok(text);
+    }
+    let (text, skipped) = match &context.cache {
+        Some(cache) => cache.find_generic_license_text(package, license, &context.config)?,
+        None => {
+            let mut trace = DiscoveryTrace::default();
+            let text =
+                find_generic_license_text_traced(package, license, &context.config, &mut trace)?;
+            (text, trace.skipped)
+        }
+    };
+    problems.skip(skipped);
+    Ok(text)
+}
+
+/// Records the files skipped in `problems`
+fn license_texts(
+    context: &Context,
+    package: &Package,
+    license: &License,
+    problems: &mut Problems,
+) -> anyhow::Result<Vec<LicenseText>> {
+    if let Some(trace) = &context.trace {
+        let trace = &mut trace.lock().unwrap();
+        let texts = find_license_text_traced(package, license, &context.config, trace)?;
+        problems.skip(trace.skipped.clone());
+        return Ok(texts);
+    }
+    let (texts, skipped) = match &context.cache {
+        Some(cache) => cache.find_license_text(package, license, &context.config)?,
+        None => {
+            let mut trace = DiscoveryTrace::default();
+            let texts = find_license_text_traced(package, license, &context.config, &mut trace)?;
+            (texts, trace.skipped)
+        }
+    };
+    problems.skip(skipped);
+    Ok(texts)
+}
+
+fn readme_license_text(
+    context: &Context,
+    package: &Package,
+    license: &License,
+) -> anyhow::Result<Option<LicenseText>> {
+    match &context.trace {
+        Some(trace) => find_readme_license_text_traced(
+            package,
+            license,
+            &context.config,
+            &mut trace.lock().unwrap(),
+        ),
+        None => find_readme_license_text(package, license, &context.config),
+    }
+}
+
+/// How many parts of the package's license have a confidently matching text
+/// in its sources
+fn confident_texts(context: &Context, package: &Package) -> anyhow::Result<usize> {
+    if !source_available(package) {
+        return Ok(0);
+    }
+    let resolved = context.licenses.resolve(package);
+    let license = &resolved.license;
+    let parts = match license {
+        License::Multiple(licenses) | License::All(licenses) => licenses.iter().collect(),
+        license => vec![license],
+    };
+    // Only deciding which copy to use, the problems are recorded collecting it
+    let problems = &mut Problems::default();
+    if generic_license_text(context, package, license, problems)?
+        .is_some_and(|text| text.confidence == Confidence::Confident)
+    {
+        return Ok(parts.len());
+    }
+    let mut count = 0;
+    for part in parts {
+        if license_texts(context, package, part, problems)?
+            .iter()
+            .any(|text| text.confidence == Confidence::Confident)
+        {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Collects the license texts of a package, or reuses those collected by an
+/// earlier bundle, and records the problems found
+fn collect<'a>(context: &Context<'a>, package: &'a Package) -> anyhow::Result<Lich<'a>> {
+    let earlier = context
+        .collected
+        .and_then(|collected| collected.0.lock().unwrap().get(&package.id).cloned());
+    let lich = match earlier {
+        Some((license, texts, problems)) => Lich {
+            package,
+            license,
+            texts,
+            problems,
+        },
+        None => {
+            let lich = collect_best(context, package)?;
+            if let Some(collected) = context.collected {
+                collected.0.lock().unwrap().insert(
+                    package.id.clone(),
+                    (
+                        lich.license.clone(),
+                        lich.texts.clone(),
+                        lich.problems.clone(),
+                    ),
+                );
+            }
+            lich
+        }
+    };
+
+    let problems = &lich.problems;
+    let flags = [
+        (problems.missing_license, &context.missing_license),
+        (problems.contradicted_license, &context.contradicted_license),
+        (problems.missing_sources, &context.missing_sources),
+        (problems.virtual_manifest, &context.virtual_manifests),
+        (problems.low_quality_license, &context.low_quality_license),
+        (!problems.skipped.is_empty(), &context.skipped_files),
+    ];
+    for (problem, flag) in flags {
+        if problem {
+            flag.store(true, Ordering::SeqCst);
+        }
+    }
+    {
+        let mut summary = context.summary.lock().unwrap();
+        for chosen in &lich.texts {
+            match chosen.text.as_ref().map(|text| &text.confidence) {
+                Some(Confidence::Confident) => summary.confident += 1,
+                Some(Confidence::SemiConfident) => summary.semi_confident += 1,
+                Some(Confidence::Unsure) => summary.unsure += 1,
+                None => summary.missing_texts += 1,
+            }
+        }
+    }
+    Ok(lich)
+}
+
+/// Collects the license texts of a package, for one that comes from multiple
+/// sources from whichever copy has the most confidently matching texts
+fn collect_best<'a>(context: &Context<'a>, package: &'a Package) -> anyhow::Result<Lich<'a>> {
+    // Vendored copies may have had their license files patched, so are used
+    // unless they have no texts for the license where the package does
+    if let Some(vendored) = context.licenses.vendored(package) {
+        if confident_texts(context, vendored)? > 0 || confident_texts(context, package)? == 0 {
+            log::info!(
+                "Using the license texts of {} {} vendored in {}",
+                package.name,
+                package.version,
+                context.path(vendored, package_dir(vendored)?)
+            );
+            let mut lich = collect_from(context, vendored)?;
+            lich.package = package;
+            return Ok(lich);
+        }
+        log::info!(
+            "The vendored copy of {} {} has no license texts, using those of its sources",
+            package.name,
+            package.version
+        );
+    }
+    let duplicates = match context.duplicates.get(&package.id) {
+        Some(duplicates) => duplicates,
+        None => return collect_from(context, package),
+    };
+    let mut best = (confident_texts(context, package)?, package);
+    for &duplicate in duplicates {
+        let count = confident_texts(context, duplicate)?;
+        if count > best.0 {
+            best = (count, duplicate);
+        }
+    }
+    let source = best.1;
+    if source.id != package.id {
+        log::info!(
+            "Using the license texts of {} {} from {}",
+            package.name,
+            package.version,
+            SourceKind::of(source)
+        );
+    }
+    let mut lich = collect_from(context, source)?;
+    lich.package = package;
+    Ok(lich)
+}
+
+fn collect_from<'a>(context: &Context, package: &'a Package) -> anyhow::Result<Lich<'a>> {
+    let license = context.license(package);
+    let mut texts = Vec::new();
+    let mut problems = Problems::default();
+    if !context.require_sources && !source_available(package) {
+        log::error!(
+            "{} {} sources are not available at {}",
+            package.name,
+            package.version,
+            context.path(
+                package,
+                package_dir(package).unwrap_or(&package.manifest_path)
+            )
+        );
+        problems.missing_sources = true;
+        return Ok(Lich {
+            package,
+            license,
+            texts,
+            problems,
+        });
+    }
+    if virtual_manifest(package) {
+        log::error!(
+            "{} {} manifest at {} is a workspace virtual manifest, its directory has no package \
+             sources to find license texts in",
+            package.name,
+            package.version,
+            context.path(package, &package.manifest_path)
+        );
+        problems.virtual_manifest = true;
+        return Ok(Lich {
+            package,
+            license,
+            texts,
+            problems,
+        });
+    }
+    if context.cross_check {
+        let mut trace = DiscoveryTrace::default();
+        let contradiction =
+            contradicting_license_text(package, &license, &context.config, &mut trace)?;
+        problems.skip(trace.skipped);
+        if let Some((text, found)) = contradiction {
+            log::error!(
+                "{} {} declares license {} but {} matches {} with high confidence",
+                package.name,
+                package.version,
+                license,
+                context.path(package, &text.path),
+                found
+            );
+            problems.contradicted_license = true;
+        }
+    }
+    let generic = generic_license_text(context, package, &license, &mut problems)?;
+    // A poor match for a combination of licenses may be a file containing each
+    // of their texts one after another
+    let segments = generic
+        .as_ref()
+        .filter(|text| text.confidence != Confidence::Confident)
+        .and_then(|text| split_license_text(text, &license, &context.config));
+    if let Some(segments) = segments {
+        log::info!(
+            "{} has a single license file containing the texts of {}, using the matching part for each",
+            package.name,
+            license
+        );
+        match license.clone() {
+            License::Multiple(licenses) | License::All(licenses)
+                if licenses.len() == segments.len() =>
+            {
+                for (license, text) in licenses.into_iter().zip(segments) {
+                    context.choice(&license, Some(&text), || {
+                        "the part of the generic license file matching it".to_owned()
+                    });
+                    texts.push(Chosen::new(context, package, license, Some(text)));
+                }
+            }
+            license => {
+                return Err(anyhow!(
+                    "{} {} license file was split into {} texts which don't match its license {}",
+                    package.name,
+                    package.version,
+                    segments.len(),
+                    license
+                ))
+            }
+        }
+    } else if let Some(text) = generic {
+        match text.confidence {
+            Confidence::Confident => (),
+            Confidence::SemiConfident => {
+                log::warn!(
+                    "{} has only a low-confidence candidate for license {}:",
+                    package.name,
+                    license
+                );
+                log::warn!("    {}", candidate(context, package, &text, &license));
+            }
+            Confidence::Unsure => {
+                problems.low_quality_license = true;
+                log::error!(
+                    "{} has only a very low-confidence candidate for license {}:",
+                    package.name,
+                    license
+                );
+                log::error!("    {}", candidate(context, package, &text, &license));
+            }
+        }
+        context.choice(&license, Some(&text), || {
+            format!(
+                "a generic license file takes precedence, matched {:?}",
+                text.confidence
+            )
+        });
+        texts.push(Chosen::new(context, package, license.clone(), Some(text)));
+    } else {
+        match license.clone() {
+            License::Unspecified => {
+                log::error!("{} does not specify a license", package.name);
+                if let Some(SourceHeaders::Conflicting(expressions)) =
+                    &context.licenses.resolve(package).headers
+                {
+                    log::error!(
+                        "    the SPDX-License-Identifier headers of its sources conflict: {}",
+                        licensed::describe_conflict(expressions)
+                    );
+                }
+                context.choice(&License::Unspecified, None, || {
+                    "no license is specified".to_owned()
+                });
+            }
+            License::Multiple(licenses) | License::All(licenses) => {
+                for license in licenses {
+                    let candidates = license_texts(context, package, &license, &mut problems)?;
+                    let text = choose(context, package, &license, candidates, &mut problems)?;
+                    texts.push(Chosen::new(context, package, license, text));
+                }
+            }
+            license => {
+                let candidates = license_texts(context, package, &license, &mut problems)?;
+                let text = choose(context, package, &license, candidates, &mut problems)?;
+                texts.push(Chosen::new(context, package, license, text));
+            }
+        }
+    }
+    for file in &problems.skipped {
+        log::warn!(
+            "{} {} license file {} was skipped, {}",
+            package.name,
+            package.version,
+            context.path(package, &file.path),
+            file.reason
+        );
+    }
+    for chosen in &texts {
+        if let Some(text) = &chosen.text {
+            for issue in text_issues(&text.text, &chosen.license, &context.config) {
+                log::warn!(
+                    "{} license text for {} at {} {}",
+                    package.name,
+                    chosen.license,
+                    context.path(package, &text.path),
+                    issue
+                );
+                problems.low_quality_license = true;
+            }
+            // Only a hint, the text is still what the package ships
+            if context.check_holders {
+                if let Some(holder) = holders::mismatched_holder(&text.text, package) {
+                    log::warn!(
+                        "{} license text for {} at {} has copyright holder '{}' which doesn't \
+                         match the package's name, authors or repository",
+                        package.name,
+                        chosen.license,
+                        context.path(package, &text.path),
+                        holder
+                    );
+                }
+            }
+        }
+    }
+    Ok(Lich {
+        package,
+        license,
+        texts,
+        problems,
+    })
+}
+
+/// Collects the liches of all packages in parallel, calling `f` with each in
+/// package order as soon as it and all preceding ones are available so the
+/// writers can stream their output
+fn for_each_lich<'a>(
+    context: &Context<'a>,
+    mut f: impl FnMut(Lich<'a>) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let packages = context.packages;
+    let jobs = jobs(&context.config).min(packages.len().max(1));
+    let progress = Progress::new("Collecting licenses", packages.len());
+    let start = Instant::now();
+
+    let result = collect_in_order(context, jobs, |lich| {
+        let mut lich = lich?;
+        let package = lich.package;
+        // Only the texts written out are normalized, discovery scores them as
+        // they are
+        if context.normalize {
+            for text in lich
+                .texts
+                .iter_mut()
+                .filter_map(|chosen| chosen.text.as_mut())
+            {
+                text.text = textutil::normalize(&text.text);
+            }
+        }
+        progress.suspend(|| f(lich))?;
+        progress.inc(&package.name);
+        Ok(())
+    });
+
+    log::debug!(
+        "collected licenses of {} packages using {} threads in {:?}",
+        packages.len(),
+        jobs,
+        start.elapsed()
+    );
+    result
+}
+
+/// How many packages to collect the licenses of at once
+#[cfg(feature = "parallel")]
+fn jobs(config: &DiscoveryConfig) -> usize {
+    config
+        .jobs
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |jobs| jobs.get()))
+}
+
+/// How many packages to collect the licenses of at once, only one at a time
+/// without the `parallel` feature
+#[cfg(not(feature = "parallel"))]
+fn jobs(config: &DiscoveryConfig) -> usize {
+    if config.jobs.is_some_and(|jobs| jobs > 1) {
+        log::debug!("built without the parallel feature, --jobs is ignored");
+    }
+    1
+}
+
+fn collect_timed<'a>(context: &Context<'a>, package: &'a Package) -> anyhow::Result<Lich<'a>> {
+    let start = Instant::now();
+    let lich = collect(context, package);
+    log::debug!(
+        "collected licenses of {} in {:?}",
+        package.name,
+        start.elapsed()
+    );
+    lich
+}
+
+/// Collects the liches of all packages on a pool of `jobs` threads, calling
+/// `deliver` with each in order, stopping early if it fails
+#[cfg(feature = "parallel")]
+fn collect_in_order<'a>(
+    context: &Context<'a>,
+    jobs: usize,
+    mut deliver: impl FnMut(anyhow::Result<Lich<'a>>) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    use rayon::prelude::*;
+    use std::sync::mpsc;
+
+    let packages = context.packages;
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs).build()?;
+    pool.in_place_scope(|scope| {
+        // Bounded so that the pool can't get too far ahead of the writer, and
+        // once the writer stops the pool does too
+        let (sender, receiver) = mpsc::sync_channel(jobs * 2);
+        // Bridged rather than split into ranges so packages are picked up in
+        // order, and few wait to be written
+        scope.spawn(move |_| {
+            let _ = packages
+                .iter()
+                .enumerate()
+                .par_bridge()
+                .try_for_each_with(sender, |sender, (index, package)| {
+                    sender.send((index, collect_timed(context, package)))
+                });
+        });
+
+        let mut pending = BTreeMap::new();
+        let mut expected = 0;
+        for (index, lich) in receiver {
+            pending.insert(index, lich);
+            while let Some(lich) = pending.remove(&expected) {
+                deliver(lich)?;
+                expected += 1;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Collects the liches of all packages one after another
+#[cfg(not(feature = "parallel"))]
+fn collect_in_order<'a>(
+    context: &Context<'a>,
+    _jobs: usize,
+    mut deliver: impl FnMut(anyhow::Result<Lich<'a>>) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    for &package in context.packages {
+        deliver(collect_timed(context, package))?;
+    }
+    Ok(())
+}
+
+/// One of the texts in a package's entry of an inline bundle
+pub struct InlineText<'a> {
+    pub license: String,
+    /// Where the text came from when it's not obvious
+    pub note: Option<String>,
+    pub text: Option<&'a str>,
+    /// Put on a line of its own after the text, `--print-hashes`
+    pub annotation: Option<String>,
+}
+
+/// Creates the `--file` of a bundle or graph, or opens it for appending,
+/// creating any missing parent directories
+pub fn create(file: &str, append: bool) -> anyhow::Result<File> {
+    let path = Path::new(file);
+    if let Some(parent) = path.parent() {
+        create_dir(parent)?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)
+        .with_context(|| format!("Couldn't open {} for writing", absolute(path).display()))
+}
+
+fn create_dir(dir: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Couldn't create directory {}", absolute(dir).display()))
+}
+
+/// The path as attempted, relative paths being resolved against the current
+/// directory
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_owned())
+}
+
+/// Creates the split bundle's directory, refusing to mix the new texts with
+/// files already in it unless `force` deletes them first. Only the files
+/// directly in it are deleted, never subdirectories, and never in what looks
+/// like a source tree rather than a bundle.
+fn prepare_dir(dir: &Path, force: bool, workspace_root: &Path) -> anyhow::Result<()> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .map(|entry| Ok(entry?.path()))
+            .collect::<io::Result<Vec<_>>>(),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+    .with_context(|| format!("Couldn't read directory {}", absolute(dir).display()))?;
+    if entries.is_empty() {
+        return create_dir(dir);
+    }
+    if !force {
+        return Err(anyhow!(
+            "{} already has files in it, pass --force to delete them so that texts of removed \
+             dependencies aren't left behind",
+            absolute(dir).display()
+        ));
+    }
+    let canonical = fs::canonicalize(dir).unwrap_or_else(|_| absolute(dir));
+    let workspace_root =
+        fs::canonicalize(workspace_root).unwrap_or_else(|_| absolute(workspace_root));
+    if let Some(reason) = ["Cargo.toml", ".git"]
+        .iter()
+        .find(|name| dir.join(name).exists())
+        .map(|name| format!("it has a {}", name))
+        .or_else(|| {
+            workspace_root
+                .starts_with(&canonical)
+                .then(|| "it holds the workspace".to_owned())
+        })
+    {
+        return Err(anyhow!(
+            "Refusing to delete the files in {} with --force, {}",
+            canonical.display(),
+            reason
+        ));
+    }
+    log::info!("Deleting the files already in {}", dir.display());
+    for path in entries {
+        if fs::symlink_metadata(&path).is_ok_and(|meta| meta.is_dir()) {
+            log::warn!(
+                "Leaving directory {} in {} alone, only files are deleted",
+                path.display(),
+                dir.display()
+            );
+            continue;
+        }
+        fs::remove_file(&path)
+            .with_context(|| format!("Couldn't delete {}", absolute(&path).display()))?;
+    }
+    Ok(())
+}
+
+/// The `--preamble` text, separated from what follows by a blank line
+fn write_preamble(context: &Context, out: &mut dyn io::Write) -> io::Result<()> {
+    if let Some(preamble) = &context.preamble {
+        out.write_all(preamble.as_bytes())?;
+        if !preamble.ends_with('\n') {
+            writeln!(out)?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// The `--postamble` text, separated from what precedes it by a blank line
+fn write_postamble(context: &Context, out: &mut dyn io::Write) -> io::Result<()> {
+    if let Some(postamble) = &context.postamble {
+        writeln!(out)?;
+        out.write_all(postamble.as_bytes())?;
+        if !postamble.ends_with('\n') {
+            writeln!(out)?;
+        }
+    }
+    Ok(())
+}
+
+/// A preamble or postamble for HTML bundles, paragraphs separated by blank
+/// lines become `<p>` elements unless `--preamble-raw-html` was given
+fn write_html_block(context: &Context, out: &mut dyn io::Write, text: &str) -> io::Result<()> {
+    if context.raw_html {
+        writeln!(out, "{}", text.trim_end())?;
+        return Ok(());
+    }
+    for paragraph in text.split("\n\n").map(str::trim).filter(|p| !p.is_empty()) {
+        writeln!(out, "<p>{}</p>", escape_html(paragraph))?;
+    }
+    Ok(())
+}
+
+/// The opening line of inline and name-only bundles
+pub fn write_header(out: &mut dyn io::Write, roots_name: &str) -> io::Result<()> {
+    writeln!(
+        out,
+        "The {} uses some third party libraries under their own license terms:",
+        roots_name
+    )?;
+    writeln!(out)
+}
+
+/// A package's entry, just a line naming its license for name-only bundles or
+/// followed by its license texts for inline bundles
+pub fn write_entry(
+    out: &mut dyn io::Write,
+    name: &str,
+    version: &str,
+    license: &str,
+    attribution: Option<&str>,
+    texts: Option<&[InlineText]>,
+    layout: &Layout,
+) -> io::Result<()> {
+    let indent = " ".repeat(layout.indent);
+    write!(
+        out,
+        " * {} {} under the terms of {}",
+        name, version, license
+    )?;
+    let texts = match texts {
+        Some(texts) => texts,
+        None => return writeln!(out),
+    };
+    writeln!(out, ":")?;
+    writeln!(out)?;
+    if let Some(attribution) = attribution {
+        writeln!(out, "{}({})", indent, attribution)?;
+        writeln!(out)?;
+    }
+    // With a text per part of the license each is introduced by which part
+    // it is, so readers can tell where one ends and the next begins
+    let labelled = texts.len() > 1;
+    let mut first = true;
+    for text in texts {
+        if first {
+            first = false;
+        } else {
+            writeln!(out)?;
+        }
+        if labelled {
+            match &text.text {
+                Some(_) => writeln!(out, "{}--- {} ---", indent, text.license)?,
+                None => writeln!(
+                    out,
+                    "{}--- {} --- (license text not found)",
+                    indent, text.license
+                )?,
+            }
+            writeln!(out)?;
+        }
+        if let Some(body) = text.text {
+            if let Some(note) = &text.note {
+                writeln!(out, "{}({})", indent, note)?;
+                writeln!(out)?;
+            }
+            let lines = match layout.wrap {
+                Some(wrap) => rewrap(body, wrap.saturating_sub(layout.indent).max(1)),
+                None => body.lines().map(Cow::Borrowed).collect(),
+            };
+            for line in lines {
+                // Blank lines aren't indented, leaving no trailing whitespace
+                if line.is_empty() {
+                    writeln!(out)?;
+                } else {
+                    writeln!(out, "{}{}", indent, line)?;
+                }
+            }
+            if let Some(annotation) = &text.annotation {
+                writeln!(out)?;
+                writeln!(out, "{}({})", indent, annotation)?;
+            }
+        }
+    }
+    writeln!(out)?;
+    writeln!(out)
+}
+
+/// Re-wraps the paragraphs of a license text to `width` columns. Lines that
+/// are indented, quoted or list items are kept as they are, their layout is
+/// part of the text's structure.
+fn rewrap(text: &str, width: usize) -> Vec<Cow<'_, str>> {
+    fn preserved(line: &str) -> bool {
+        if line.starts_with(char::is_whitespace) || line.starts_with('>') {
+            return true;
+        }
+        let marker = line.split_whitespace().next().unwrap_or_default();
+        if marker.len() == line.len() {
+            return false;
+        }
+        // Bullets, and enumerations like `1.`, `a)` or `(iv)`
+        let label = marker.trim_start_matches('(').trim_end_matches(['.', ')']);
+        let enumeration = label.chars().all(|c| c.is_ascii_digit())
+            || label.len() == 1
+            || label.chars().all(|c| "ivxlc".contains(c));
+        matches!(marker, "-" | "*" | "+" | "•")
+            || (marker.len() > label.len() && !label.is_empty() && enumeration)
+    }
+
+    fn fill<'a>(words: &mut Vec<&str>, width: usize, out: &mut Vec<Cow<'a, str>>) {
+        let mut line = String::new();
+        for word in words.drain(..) {
+            if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+                out.push(Cow::Owned(mem::take(&mut line)));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        if !line.is_empty() {
+            out.push(Cow::Owned(line));
+        }
+    }
+
+    let mut out = Vec::new();
+    let mut words = Vec::new();
+    for line in text.lines() {
+        if line.trim().is_empty() || preserved(line) {
+            fill(&mut words, width, &mut out);
+            out.push(Cow::Borrowed(line));
+        } else {
+            words.extend(line.split_whitespace());
+        }
+    }
+    fill(&mut words, width, &mut out);
+    out
+}
+
+fn inline(
+    context: &Context,
+    layout: &Layout,
+    out: &mut dyn io::Write,
+) -> anyhow::Result<Vec<Entry>> {
+    write_preamble(context, out)?;
+    write_header(out, &context.roots_name)?;
+    let mut written = Vec::new();
+    for_each_lich(context, |lich| {
+        let package = lich.package;
+        let texts = lich
+            .texts
+            .iter()
+            .map(|chosen| InlineText {
+                license: chosen.license.to_string(),
+                note: chosen
+                    .text
+                    .as_ref()
+                    .and_then(|text| note(context, package, text)),
+                text: chosen.text.as_ref().map(|text| &*text.text),
+                annotation: chosen
+                    .provenance
+                    .as_ref()
+                    .filter(|_| context.print_hashes)
+                    .map(ToString::to_string),
+            })
+            .collect::<Vec<_>>();
+        write_entry(
+            out,
+            &package.name,
+            &format!("{}{}", package.version, SourceKind::of(package).label()),
+            &format!("{}{}", lich.license, declared(package, &lich.license)),
+            context
+                .attribution(package)
+                .and_then(|attribution| attribution.line())
+                .as_deref(),
+            Some(&texts),
+            layout,
+        )?;
+        written.push(entry(package));
+        Ok(())
+    })?;
+    write_postamble(context, out)?;
+    Ok(written)
+}
+
+fn name_only(context: &Context, out: &mut dyn io::Write) -> anyhow::Result<Vec<Entry>> {
+    write_preamble(context, out)?;
+    write_header(out, &context.roots_name)?;
+    let mut written = Vec::new();
+    for package in context.packages {
+        let license = context.license(package);
+        write_entry(
+            out,
+            &package.name,
+            &format!("{}{}", package.version, SourceKind::of(package).label()),
+            &format!(
+                "{}{}{}",
+                license,
+                license
+                    .spdx_url()
+                    .iter()
+                    .map(|url| format!(" <{}>", url))
+                    .collect::<String>(),
+                declared(package, &license)
+            ),
+            None,
+            None,
+            &Layout::default(),
+        )?;
+        written.push(entry(package));
+    }
+    write_postamble(context, out)?;
+    Ok(written)
+}
+
+fn source(context: &Context, out: &mut dyn io::Write) -> anyhow::Result<Vec<Entry>> {
+    out.write_all(
+        b"\
+//! Licenses of dependencies
+//!
+//! This file was generated by [`cargo-lichking`](https://github.com/Nemo157/cargo-lichking)
+
+pub struct License {
+    pub name: &'static str,
+    pub text: Option<&'static str>,
+}
+
+pub struct Licenses {
+    pub name: &'static str,
+    pub licenses: &'static [License],
+}
+
+pub struct LicensedCrate {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub licenses: Licenses,
+}
+
+pub const CRATES: &[LicensedCrate] = &[
+",
+    )?;
+    let mut written = Vec::new();
+    for_each_lich(context, |lich| {
+        let package = lich.package;
+        writeln!(
+            out,
+            "
+    LicensedCrate {{
+        name: {:?},
+        version: {:?},
+        licenses: Licenses {{
+            name: {:?},
+            licenses: &[",
+            package.name,
+            package.version.to_string(),
+            lich.license.to_string(),
+        )?;
+        // Alternatives of a multiple license are spaced out from each other
+        let spacing = if lich.texts.len() > 1 { "\n" } else { "" };
+        for chosen in &lich.texts {
+            let text = chosen
+                .text
+                .as_ref()
+                .map(|t| format!("Some({:?})", t.text))
+                .unwrap_or_else(|| "None".to_owned());
+            writeln!(
+                out,
+                "{}                License {{
+                    name: {:?},
+                    text: {},
+                }},",
+                spacing,
+                chosen.license.to_string(),
+                text
+            )?;
+        }
+        writeln!(
+            out,
+            "{}            ],
+        }},
+    }},",
+            spacing
+        )?;
+        writeln!(out)?;
+        written.push(entry(package));
+        Ok(())
+    })?;
+    out.write_all(b"];\n")?;
+    Ok(written)
+}
+
+fn split<P: AsRef<Path>>(
+    context: &Context,
+    out: &mut dyn io::Write,
+    dir: P,
+    verbatim: bool,
+) -> anyhow::Result<Vec<Entry>> {
+    writeln!(
+        out,
+        "The {} uses some third party libraries under their own license terms:",
+        context.roots_name
+    )?;
+    writeln!(out)?;
+    let mut written = Vec::new();
+    let mut targets = HashSet::new();
+    for_each_lich(context, |lich| {
+        let package = lich.package;
+        written.push(entry(package));
+        writeln!(
+            out,
+            " * {} {}{} under the terms of {}{}",
+            package.name,
+            package.version,
+            SourceKind::of(package).label(),
+            lich.license,
+            declared(package, &lich.license),
+        )?;
+        if context.print_hashes {
+            for provenance in lich
+                .texts
+                .iter()
+                .filter_map(|chosen| chosen.provenance.as_ref())
+            {
+                writeln!(out, "   ({})", provenance)?;
+            }
+        }
+        if verbatim {
+            // Segments of one file and README sections can't be copied on
+            // their own, the whole upstream file is shipped instead
+            let mut copied = HashSet::new();
+            for text in lich.texts.iter().filter_map(|chosen| chosen.text.as_ref()) {
+                if !copied.insert(&text.path) {
+                    continue;
+                }
+                let target = verbatim_target(dir.as_ref(), package, &text.path, &mut targets);
+                fs::copy(&text.path, &target).with_context(|| {
+                    format!(
+                        "Couldn't copy {} to {}",
+                        text.path.display(),
+                        target.display()
+                    )
+                })?;
+            }
+            return Ok(());
+        }
+        // Named by version too, several versions of a crate can be in use
+        let path = dir
+            .as_ref()
+            .join(format!("{}-{}", package.name, package.version));
+        let file = File::create(&path)
+            .with_context(|| format!("Couldn't create {}", absolute(&path).display()))?;
+        let mut file = textutil::with_line_endings(file, context.line_ending);
+        let mut first = true;
+        for chosen in &lich.texts {
+            if first {
+                first = false;
+            } else {
+                writeln!(file)?;
+                writeln!(file, "===============")?;
+                writeln!(file)?;
+            }
+            if let Some(text) = &chosen.text {
+                file.write_all(text.text.as_bytes())?;
+            }
+        }
+        Ok(())
+    })?;
+    Ok(written)
+}
+
+/// Where to copy a license file to verbatim, named after the package and the
+/// file. Files of the same name, e.g. from several directories of a package,
+/// are told apart by their path in the package and then by a counter.
+fn verbatim_target(
+    dir: &Path,
+    package: &Package,
+    path: &Path,
+    targets: &mut HashSet<PathBuf>,
+) -> PathBuf {
+    let prefix = format!("{}-{}", package.name, package.version);
+    let file_name = path.file_name().expect("license texts are files");
+    let in_package = package_dir(package)
+        .ok()
+        .and_then(|package_dir| path.strip_prefix(package_dir).ok())
+        .map(|relative| {
+            relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("-")
+        });
+    let names = IntoIterator::into_iter([
+        Some(format!("{}-{}", prefix, file_name.to_string_lossy())),
+        in_package.map(|relative| format!("{}-{}", prefix, relative)),
+    ])
+    .flatten()
+    .collect::<Vec<_>>();
+    let last = names.last().expect("the file name is always there").clone();
+    let numbered = (2..).map(|n| format!("{}-{}", last, n));
+    let target = names
+        .into_iter()
+        .chain(numbered)
+        .map(|name| dir.join(name))
+        .find(|target| !targets.contains(target))
+        .expect("some number is free");
+    if !target.ends_with(format!("{}-{}", prefix, file_name.to_string_lossy())) {
+        log::info!(
+            "Copying {} to {} to keep it apart from another file of the same name",
+            path.display(),
+            target.display()
+        );
+    }
+    targets.insert(target.clone());
+    target
+}
+
+/// Makes `path` relative to `base`, both must be absolute and canonical
+fn relative(path: &Path, base: &Path) -> PathBuf {
+    let path = path.components().collect::<Vec<_>>();
+    let base = base.components().collect::<Vec<_>>();
+    let common = path.iter().zip(&base).take_while(|(a, b)| a == b).count();
+    let mut relative = PathBuf::new();
+    for _ in common..base.len() {
+        relative.push("..");
+    }
+    for component in &path[common..] {
+        relative.push(component);
+    }
+    relative
+}
+
+fn paths(
+    context: &Context,
+    out: &mut dyn io::Write,
+    relative_to: Option<PathBuf>,
+) -> anyhow::Result<Vec<Entry>> {
+    let mut written = Vec::new();
+    for_each_lich(context, |lich| {
+        let package = lich.package;
+        written.push(entry(package));
+        if lich.texts.is_empty() {
+            writeln!(
+                out,
+                "{} {}\t{}\tMISSING",
+                package.name, package.version, lich.license
+            )?;
+        }
+        for chosen in &lich.texts {
+            let path = if let Some(text) = &chosen.text {
+                let path = fs::canonicalize(&text.path)?;
+                if let Some(base) = &relative_to {
+                    relative(&path, base).display().to_string()
+                } else {
+                    context.path(package, &path)
+                }
+            } else {
+                "MISSING".to_owned()
+            };
+            writeln!(
+                out,
+                "{} {}\t{}\t{}",
+                package.name, package.version, chosen.license, path
+            )?;
+        }
+        Ok(())
+    })?;
+    Ok(written)
+}
+
+/// Mentions the license as declared in the manifest when our normalization
+/// changed it
+fn declared(package: &Package, license: &License) -> String {
+    match package.declared_license_str() {
+        Some(declared) if declared != license.to_string() => {
+            format!(" (declared as {:?})", declared)
+        }
+        _ => String::new(),
+    }
+}
+
+/// The first source file whose `SPDX-License-Identifier` header names
+/// `license`, with `--scan-source-headers` and if the headers agree. The
+/// headers may name more than the license, e.g. every alternative the package
+/// declares.
+fn headers_naming(context: &Context, package: &Package, license: &License) -> Option<PathBuf> {
+    let (expression, path) = match context.licenses.source_headers(package)? {
+        SourceHeaders::Consistent(expression, path, _) => (expression, path),
+        SourceHeaders::Conflicting(_) => return None,
+    };
+    let named = match expression.parse().unwrap() {
+        License::Multiple(licenses) | License::All(licenses) => licenses.contains(license),
+        named => named == *license,
+    };
+    Some(path).filter(|_| named)
+}
+
+/// Describes where a text came from when it's not simply a license file in
+/// the package directory
+fn note(context: &Context, package: &Package, text: &LicenseText) -> Option<String> {
+    let name = text.path.file_name().unwrap_or_default().to_string_lossy();
+    if text.origin == Origin::Readme {
+        return Some(format!("extracted from {}", name));
+    }
+    if text.origin == Origin::Template {
+        let how = match package.declared_license_str() {
+            Some(_) => "named by",
+            None => "inferred from",
+        };
+        return Some(format!(
+            "standard license text, the license is {} the header of {}",
+            how,
+            context.path(package, &text.path)
+        ));
+    }
+    let dir = context.source_dir(package, &text.path)?;
+    let parent = text.path.parent()?;
+    let location = if parent != dir {
+        Some(relative(&text.path, dir).display().to_string())
+    } else {
+        None
+    };
+    match (&text.origin, location) {
+        (Origin::Segment { .. }, Some(location)) => Some(format!("part of {}", location)),
+        (Origin::Segment { .. }, None) => Some(format!("part of {}", name)),
+        (Origin::Html, Some(location)) => Some(format!("markup stripped from {}", location)),
+        (Origin::Html, None) => Some(format!("markup stripped from {}", name)),
+        (_, Some(location)) => Some(format!("found at {}", location)),
+        (_, None) => None,
+    }
+}
+
+/// Renders `license` with the name of each license on the SPDX list made a
+/// link to its text by `link`, given the escaped name and the URL, and the
+/// rest escaped by `escape`. Exceptions aren't linked, only their base.
+fn linked(license: &License, escape: fn(&str) -> String, link: fn(&str, &str) -> String) -> String {
+    let part = |license: &License, grouped: fn(&License) -> bool| {
+        if grouped(license) {
+            format!("({})", linked(license, escape, link))
+        } else {
+            linked(license, escape, link)
+        }
+    };
+    match license {
+        License::Multiple(licenses) => licenses
+            .iter()
+            .map(|license| part(license, |license| matches!(license, License::All(_))))
+            .collect::<Vec<_>>()
+            .join(" / "),
+        License::All(licenses) => licenses
+            .iter()
+            .map(|license| part(license, |license| matches!(license, License::Multiple(_))))
+            .collect::<Vec<_>>()
+            .join(" AND "),
+        License::WithException { base, exception } => format!(
+            "{} WITH {}",
+            part(base, |base| matches!(
+                base,
+                License::Multiple(_) | License::All(_)
+            )),
+            escape(exception)
+        ),
+        license => match license.spdx_url().as_slice() {
+            [url] => link(&escape(&license.to_string()), url),
+            _ => escape(&license.to_string()),
+        },
+    }
+}
+
+/// Returns a code fence long enough to not be closed by anything in `text`
+fn fence(text: &str) -> String {
+    let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    "`".repeat(std::cmp::max(3, longest + 1))
+}
+
+/// Escapes what Markdown would otherwise render from names and other inline
+/// text, joining lines so the text stays where it's put
+fn escape_markdown(text: &str) -> String {
+    let text = text.replace(['\r', '\n'], " ");
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\`*_[]<>|~&".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn markdown(context: &Context, out: &mut dyn io::Write) -> anyhow::Result<Vec<Entry>> {
+    write_preamble(context, out)?;
+    writeln!(out, "# Third party licenses")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "The {} uses some third party libraries under their own license terms.",
+        escape_markdown(&context.roots_name)
+    )?;
+    let mut written = Vec::new();
+    for_each_lich(context, |lich| {
+        let package = lich.package;
+        written.push(entry(package));
+        writeln!(out)?;
+        writeln!(
+            out,
+            "## {} {}{} — {}{}",
+            escape_markdown(&package.name),
+            escape_markdown(&package.version.to_string()),
+            escape_markdown(&SourceKind::of(package).label()),
+            linked(&lich.license, escape_markdown, |name, url| format!(
+                "[{}]({})",
+                name, url
+            )),
+            escape_markdown(&declared(package, &lich.license)),
+        )?;
+        if let Some(attribution) = context.attribution(package) {
+            if let Some(description) = &attribution.description {
+                writeln!(out)?;
+                writeln!(out, "{}", escape_markdown(description))?;
+            }
+            let link = attribution
+                .url
+                .as_ref()
+                .map(|url| format!("[{}]({})", escape_markdown(url), url));
+            let byline = attribution.byline().map(|byline| escape_markdown(&byline));
+            let line = IntoIterator::into_iter([link, byline])
+                .flatten()
+                .collect::<Vec<_>>();
+            if !line.is_empty() {
+                writeln!(out)?;
+                writeln!(out, "{}", line.join(" — "))?;
+            }
+        }
+        if lich.texts.is_empty() {
+            writeln!(out)?;
+            writeln!(out, "*License text not found.*")?;
+        }
+        for chosen in &lich.texts {
+            writeln!(out)?;
+            if lich.texts.len() > 1 {
+                writeln!(out, "### {}", escape_markdown(&chosen.license.to_string()))?;
+                writeln!(out)?;
+            }
+            if let Some(text) = &chosen.text {
+                if let Some(note) = note(context, package, text) {
+                    writeln!(out, "*({})*", escape_markdown(&note))?;
+                    writeln!(out)?;
+                }
+                let fence = fence(&text.text);
+                writeln!(out, "{}text", fence)?;
+                writeln!(out, "{}", text.text.trim_end())?;
+                writeln!(out, "{}", fence)?;
+            } else {
+                writeln!(out, "*License text not found.*")?;
+            }
+        }
+        Ok(())
+    })?;
+    write_postamble(context, out)?;
+    Ok(written)
+}
+
+/// The spdx.org page of a single license, or an exception's base license
+fn license_url(license: &License) -> Option<String> {
+    match license.spdx_url().as_slice() {
+        [url] if !matches!(license, License::Multiple(_) | License::All(_)) => Some(url.clone()),
+        _ => None,
+    }
+}
+
+#[derive(Serialize)]
+struct TemplateContext<'a> {
+    roots_name: &'a str,
+    generated: String,
+    packages: Vec<TemplatePackage<'a>>,
+}
+
+#[derive(Serialize)]
+struct TemplatePackage<'a> {
+    name: &'a str,
+    version: String,
+    license: String,
+    resolution: Resolution,
+    licenses: Vec<TemplateLicense>,
+    repository: Option<&'a str>,
+    description: Option<&'a str>,
+    authors: &'a [String],
+    /// Problems with the package's license files
+    issues: Vec<String>,
+}
+
+/// A license of a package, the text related fields are `None` if no text
+/// could be found
+#[derive(Serialize)]
+struct TemplateLicense {
+    name: String,
+    /// The license's page on spdx.org, if it's on the SPDX list
+    url: Option<String>,
+    text: Option<String>,
+    confidence: Option<&'static str>,
+    path: Option<String>,
+    provenance: Option<Provenance>,
+}
+
+/// The generation time as an RFC 3339 UTC timestamp, `SOURCE_DATE_EPOCH` is
+/// respected to allow reproducible bundles, otherwise when `reproducible` the
+/// Unix epoch is used
+fn generated(reproducible: bool) -> anyhow::Result<String> {
+    let secs = match env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) => epoch
+            .parse::<u64>()
+            .map_err(|err| anyhow!("Invalid SOURCE_DATE_EPOCH {:?}: {}", epoch, err))?,
+        Err(_) if reproducible => 0,
+        Err(_) => SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+    };
+    Ok(format!(
+        "{}T{:02}:{:02}:{:02}Z",
+        date::date((secs / 86400) as i64),
+        secs % 86400 / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    ))
+}
+
+/// Describes a template error, including all underlying causes and the
+/// location of the offending expression when Tera doesn't report one itself
+fn template_error(err: &tera::Error, path: &str, source: &str) -> anyhow::Error {
+    let mut message = err.to_string();
+    let mut cause = err.source();
+    while let Some(err) = cause {
+        message += "\n";
+        message += &err.to_string();
+        cause = err.source();
+    }
+    // Parse errors already point at the line and column, rendering errors only
+    // quote the expression so find where it's used
+    if !message.contains("-->") {
+        let location = ['`', '\'']
+            .iter()
+            .flat_map(|&quote| message.split(quote).skip(1).step_by(2))
+            .filter(|expression| !expression.is_empty() && *expression != path)
+            .find_map(|expression| source.find(expression).map(|offset| (expression, offset)));
+        if let Some((expression, offset)) = location {
+            let before = &source[..offset];
+            let line = before.matches('\n').count() + 1;
+            let column = before[before.rfind('\n').map_or(0, |i| i + 1)..]
+                .chars()
+                .count()
+                + 1;
+            message += &format!("\n  --> {}:{}:{} in `{}`", path, line, column, expression);
+        }
+    }
+    anyhow!("{}", message)
+}
+
+fn templated(context: &Context, out: &mut dyn io::Write, path: &str) -> anyhow::Result<Vec<Entry>> {
+    let source = fs::read_to_string(path)
+        .map_err(|err| anyhow!("Couldn't read template {}: {}", path, err))?;
+    let mut tera = Tera::default();
+    tera.add_raw_template(path, &source)
+        .map_err(|err| template_error(&err, path, &source))?;
+
+    let mut packages = Vec::new();
+    for_each_lich(context, |lich| {
+        let package = lich.package;
+        packages.push(TemplatePackage {
+            name: &package.name,
+            version: package.version.to_string(),
+            license: lich.license.to_string(),
+            resolution: context.licenses.resolve(package).resolution.clone(),
+            issues: lich.problems.issues(context, package),
+            licenses: lich
+                .texts
+                .into_iter()
+                .map(|chosen| TemplateLicense {
+                    name: chosen.license.to_string(),
+                    url: license_url(&chosen.license),
+                    confidence: chosen.text.as_ref().map(|text| match text.confidence {
+                        Confidence::Confident => "confident",
+                        Confidence::SemiConfident => "semi-confident",
+                        Confidence::Unsure => "unsure",
+                    }),
+                    path: chosen
+                        .text
+                        .as_ref()
+                        .map(|text| context.path(package, &text.path)),
+                    text: chosen.text.map(|text| text.text),
+                    provenance: chosen.provenance,
+                })
+                .collect(),
+            repository: package.repository.as_deref(),
+            description: package.description.as_deref(),
+            authors: &package.authors,
+        });
+        Ok(())
+    })?;
+
+    let data = TemplateContext {
+        roots_name: &context.roots_name,
+        generated: generated(context.reproducible)?,
+        packages,
+    };
+    let data = tera::Context::from_serialize(&data)?;
+    // Whatever the template does with them, each package was handed to it
+    // once
+    let written = written_entries(data.get("packages"))?;
+    let rendered = tera
+        .render(path, &data)
+        .map_err(|err| template_error(&err, path, &source))?;
+    out.write_all(rendered.as_bytes())?;
+    Ok(written)
+}
+
+#[derive(Serialize)]
+struct JsonPackage<'a> {
+    name: &'a str,
+    version: String,
+    license: String,
+    resolution: Resolution,
+    repository: Option<&'a str>,
+    /// Only with `--with-metadata`, and left out when the package has none
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authors: Option<&'a [String]>,
+    licenses: Vec<JsonLicense>,
+    /// Problems with the package's license files, left out when there are none
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    issues: Vec<String>,
 }
 
-pub struct LicensedCrate {
-    pub name: &'static str,
-    pub version: &'static str,
-    pub licenses: Licenses,
+/// A license of a package, `text`, `path` and `confidence` are `None` and
+/// `info` is `missing` if no text could be found
+#[derive(Serialize)]
+struct JsonLicense {
+    spdx_id_or_name: String,
+    /// The license's page on spdx.org, if it's on the SPDX list
+    license_url: Option<String>,
+    text: Option<String>,
+    path: Option<String>,
+    confidence: Option<Confidence>,
+    /// Where the text came from
+    info: &'static str,
+    provenance: Option<Provenance>,
 }
 
-pub const CRATES: &[LicensedCrate] = &[
-",
-    )?;
-    for package in context.packages {
-        source_package(context, package, out)?;
+fn json(context: &Context, out: &mut dyn io::Write) -> anyhow::Result<Vec<Entry>> {
+    let mut packages = Vec::new();
+    for_each_lich(context, |lich| {
+        let package = lich.package;
+        let attribution = context.attribution(package);
+        packages.push(JsonPackage {
+            name: &package.name,
+            version: package.version.to_string(),
+            license: lich.license.to_string(),
+            resolution: context.licenses.resolve(package).resolution.clone(),
+            repository: package.repository.as_deref(),
+            description: attribution
+                .as_ref()
+                .and_then(|attribution| attribution.description.clone()),
+            url: attribution
+                .as_ref()
+                .and_then(|attribution| attribution.url.clone()),
+            authors: attribution
+                .map(|attribution| attribution.authors)
+                .filter(|authors| !authors.is_empty()),
+            issues: lich.problems.issues(context, package),
+            licenses: lich
+                .texts
+                .into_iter()
+                .map(|chosen| JsonLicense {
+                    spdx_id_or_name: chosen.license.to_string(),
+                    license_url: license_url(&chosen.license),
+                    path: chosen
+                        .text
+                        .as_ref()
+                        .map(|text| context.path(package, &text.path)),
+                    confidence: chosen.text.as_ref().map(|text| text.confidence),
+                    info: match chosen.text.as_ref().map(|text| &text.origin) {
+                        Some(Origin::LicenseFile) => "license-file",
+                        Some(Origin::Readme) => "readme",
+                        Some(Origin::Segment { .. }) => "segment",
+                        Some(Origin::Html) => "html",
+                        Some(Origin::Template) => "template",
+                        None => "missing",
+                    },
+                    text: chosen.text.map(|text| text.text),
+                    provenance: chosen.provenance,
+                })
+                .collect(),
+        });
+        Ok(())
+    })?;
+    let serialized = serde_json::to_vec_pretty(&packages)?;
+    out.write_all(&serialized)?;
+    writeln!(out)?;
+    written_entries(Some(&serde_json::from_slice(&serialized)?))
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
     }
-    out.write_all(b"];\n")?;
-    Ok(())
+    escaped
 }
 
-fn split<P: AsRef<Path>>(
-    context: &mut Context,
-    out: &mut dyn io::Write,
-    dir: P,
-) -> anyhow::Result<()> {
-    fs::create_dir_all(dir.as_ref())?;
+fn html(context: &Context, out: &mut dyn io::Write) -> anyhow::Result<Vec<Entry>> {
+    let mut liches = Vec::new();
+    for_each_lich(context, |lich| {
+        liches.push(lich);
+        Ok(())
+    })?;
+
+    let anchor = |package: &Package| escape_html(&format!("{}-{}", package.name, package.version));
+
+    writeln!(out, "<!DOCTYPE html>")?;
+    writeln!(out, "<html>")?;
+    writeln!(out, "<head>")?;
+    writeln!(out, "<meta charset=\"utf-8\">")?;
+    writeln!(out, "<title>Third party licenses</title>")?;
+    writeln!(out, "</head>")?;
+    writeln!(out, "<body>")?;
+    if let Some(preamble) = &context.preamble {
+        write_html_block(context, out, preamble)?;
+    }
+    writeln!(out, "<h1>Third party licenses</h1>")?;
     writeln!(
         out,
-        "The {} uses some third party libraries under their own license terms:",
-        context.roots_name
+        "<p>The {} uses some third party libraries under their own license terms:</p>",
+        escape_html(&context.roots_name)
     )?;
-    writeln!(out)?;
-    for package in context.packages {
+    writeln!(out, "<ul>")?;
+    for lich in &liches {
         writeln!(
             out,
-            " * {} {} under the terms of {}",
-            package.name,
-            package.version,
-            package.license(),
+            "<li><a href=\"#{}\">{} {}</a> — {}</li>",
+            anchor(lich.package),
+            escape_html(&lich.package.name),
+            lich.package.version,
+            escape_html(&lich.license.to_string())
         )?;
-        split_package(context, package, dir.as_ref())?;
     }
-    Ok(())
-}
-
-fn inline_package(
-    context: &mut Context,
-    package: &Package,
-    out: &mut dyn io::Write,
-) -> anyhow::Result<()> {
-    let license = package.license();
-    if let Some(text) = find_generic_license_text(package, &license)? {
-        match text.confidence {
-            Confidence::Confident => (),
-            Confidence::SemiConfident => {
-                log::warn!(
-                    "{} has only a low-confidence candidate for license {}:",
-                    package.name,
-                    license
-                );
-                log::warn!("    {}", text.path.display());
+    writeln!(out, "</ul>")?;
+    let mut written = Vec::new();
+    for lich in &liches {
+        written.push(entry(lich.package));
+        writeln!(out, "<section id=\"{}\">", anchor(lich.package))?;
+        writeln!(
+            out,
+            "<h2>{} {}{} — {}{}</h2>",
+            escape_html(&lich.package.name),
+            lich.package.version,
+            escape_html(&SourceKind::of(lich.package).label()),
+            linked(&lich.license, escape_html, |name, url| format!(
+                "<a href=\"{}\">{}</a>",
+                escape_html(url),
+                name
+            )),
+            escape_html(&declared(lich.package, &lich.license)),
+        )?;
+        if let Some(attribution) = context.attribution(lich.package) {
+            if let Some(description) = &attribution.description {
+                writeln!(out, "<p>{}</p>", escape_html(description))?;
             }
-            Confidence::Unsure => {
-                log::error!(
-                    "{} has only a very low-confidence candidate for license {}:",
-                    package.name,
-                    license
-                );
-                log::error!("    {}", text.path.display());
+            let link = attribution.url.as_ref().map(|url| {
+                let url = escape_html(url);
+                format!("<a href=\"{}\">{}</a>", url, url)
+            });
+            let byline = attribution.byline().map(|byline| escape_html(&byline));
+            let line = IntoIterator::into_iter([link, byline])
+                .flatten()
+                .collect::<Vec<_>>();
+            if !line.is_empty() {
+                writeln!(out, "<p>{}</p>", line.join(" — "))?;
             }
         }
-        for line in text.text.lines() {
-            writeln!(out, "    {}", line)?;
+        if lich.texts.is_empty() {
+            writeln!(out, "<p><em>License text not found.</em></p>")?;
         }
-    } else {
-        match license {
-            License::Unspecified => {
-                log::error!("{} does not specify a license", package.name);
+        for chosen in &lich.texts {
+            if lich.texts.len() > 1 {
+                writeln!(out, "<h3>{}</h3>", escape_html(&chosen.license.to_string()))?;
             }
-            License::Multiple(licenses) => {
-                let mut first = true;
-                for license in licenses {
-                    if first {
-                        first = false;
-                    } else {
-                        writeln!(out)?;
-                        writeln!(out, "    ===============")?;
-                        writeln!(out)?;
-                    }
-                    inline_license(context, package, &license, out)?;
+            if let Some(text) = &chosen.text {
+                if let Some(note) = note(context, lich.package, text) {
+                    writeln!(out, "<p><em>({})</em></p>", escape_html(&note))?;
                 }
-            }
-            license => {
-                inline_license(context, package, &license, out)?;
+                writeln!(out, "<pre>{}</pre>", escape_html(text.text.trim_end()))?;
+            } else {
+                writeln!(out, "<p><em>License text not found.</em></p>")?;
             }
         }
+        writeln!(out, "</section>")?;
     }
-    writeln!(out)?;
-    Ok(())
+    if let Some(postamble) = &context.postamble {
+        write_html_block(context, out, postamble)?;
+    }
+    writeln!(out, "</body>")?;
+    writeln!(out, "</html>")?;
+    Ok(written)
 }
 
-fn source_package(
-    context: &mut Context,
-    package: &Package,
+/// One section of the grouped bundle, a license text shared by all members
+struct Group<'a, 'b> {
+    license: String,
+    members: Vec<(&'a Package, &'b Chosen)>,
+}
+
+/// Picks the single text a package is grouped under. For alternatives of a
+/// `License::Multiple` that is the one with the most confident text, ties
+/// broken by the order of the `License` enum which lists more permissive
+/// licenses first.
+fn group_choice<'b>(lich: &'b Lich) -> Option<&'b Chosen> {
+    if let License::Multiple(_) = lich.license {
+        lich.texts
+            .iter()
+            .filter(|chosen| chosen.text.is_some())
+            .min_by(|a, b| {
+                let rank = |chosen: &Chosen| match chosen.text.as_ref().map(|t| t.confidence) {
+                    Some(Confidence::Confident) => 0,
+                    Some(Confidence::SemiConfident) => 1,
+                    _ => 2,
+                };
+                rank(a)
+                    .cmp(&rank(b))
+                    .then_with(|| a.license.cmp(&b.license))
+            })
+    } else if lich.texts.len() == 1 {
+        lich.texts.first()
+    } else {
+        None
+    }
+}
+
+/// The attribution of a member of a grouped bundle, under its name
+fn write_attribution_line(
+    context: &Context,
     out: &mut dyn io::Write,
-) -> anyhow::Result<()> {
-    let license = package.license();
-    if let Some(text) = find_generic_license_text(package, &license)? {
-        match text.confidence {
-            Confidence::Confident => (),
-            Confidence::SemiConfident => {
-                log::warn!(
-                    "{} has only a low-confidence candidate for license {}:",
-                    package.name,
-                    license
-                );
-                log::warn!("    {}", text.path.display());
-            }
-            Confidence::Unsure => {
-                log::error!(
-                    "{} has only a very low-confidence candidate for license {}:",
-                    package.name,
-                    license
-                );
-                log::error!("    {}", text.path.display());
+    package: &Package,
+) -> io::Result<()> {
+    match context
+        .attribution(package)
+        .and_then(|attribution| attribution.line())
+    {
+        Some(line) => writeln!(out, "     ({})", line),
+        None => Ok(()),
+    }
+}
+
+fn grouped(context: &Context, out: &mut dyn io::Write) -> anyhow::Result<Vec<Entry>> {
+    let mut liches = Vec::new();
+    for_each_lich(context, |lich| {
+        liches.push(lich);
+        Ok(())
+    })?;
+
+    // Known licenses are shared by all packages using them, anything else
+    // gets a section of its own. Look-alikes such as MIT and X11 stay apart
+    // even when their texts are near-identical, the declared license decides.
+    let mut groups = BTreeMap::new();
+    let mut own = Vec::new();
+    for lich in &liches {
+        match group_choice(lich) {
+            Some(chosen)
+                if chosen.text.is_some()
+                    && !matches!(
+                        chosen.license,
+                        License::Custom(_) | License::File(_) | License::Unspecified
+                    ) =>
+            {
+                groups
+                    .entry(chosen.license.to_string())
+                    .or_insert_with(|| Group {
+                        license: chosen.license.to_string(),
+                        members: Vec::new(),
+                    })
+                    .members
+                    .push((lich.package, chosen));
             }
+            _ => own.push(lich),
         }
+    }
+
+    write_preamble(context, out)?;
+    writeln!(
+        out,
+        "The {} uses some third party libraries under their own license terms.",
+        context.roots_name
+    )?;
+
+    let mut written = Vec::new();
+    for group in groups.values() {
+        writeln!(out)?;
+        writeln!(out, "===============")?;
+        writeln!(out)?;
         writeln!(
             out,
-            "
-    LicensedCrate {{
-        name: {:?},
-        version: {:?},
-        licenses: Licenses {{
-            name: {:?},
-            licenses: &[
-                License {{
-                    name: {:?},
-                    text: Some({:?}),
-                }},
-            ],
-        }},
-    }},",
-            package.name,
-            package.version.to_string(),
-            license.to_string(),
-            license.to_string(),
-            text.text
+            "The following components are licensed under {}:",
+            group.license
         )?;
-    } else {
-        let license_name = license.to_string();
-        match license {
-            License::Unspecified => {
-                log::error!("{} does not specify a license", package.name);
-            }
-            License::Multiple(licenses) => {
-                writeln!(
-                    out,
-                    "
-    LicensedCrate {{
-        name: {:?},
-        version: {:?},
-        licenses: Licenses {{
-            name: {:?},
-            licenses: &[",
-                    package.name,
-                    package.version.to_string(),
-                    license_name
-                )?;
-                for license in licenses {
-                    let texts = find_license_text(package, &license)?;
-                    let text = (choose(context, package, &license, texts)?)
-                        .map(|t| format!("Some({:?})", t.text))
-                        .unwrap_or_else(|| "None".to_owned());
-                    writeln!(
-                        out,
-                        "
-                License {{
-                    name: {:?},
-                    text: {},
-                }},",
-                        license.to_string(),
-                        text
-                    )?;
+        writeln!(out)?;
+        for (package, chosen) in &group.members {
+            writeln!(out, " * {} {}", package.name, package.version)?;
+            written.push(entry(package));
+            write_attribution_line(context, out, package)?;
+            if let Some(text) = &chosen.text {
+                for line in copyright_lines(&text.text) {
+                    writeln!(out, "     {}", line)?;
                 }
-                writeln!(
-                    out,
-                    "
-            ],
-        }},
-    }},"
-                )?;
-            }
-            license => {
-                let texts = find_license_text(package, &license)?;
-                let text = (choose(context, package, &license, texts)?)
-                    .map(|t| format!("Some({:?})", t.text))
-                    .unwrap_or_else(|| "None".to_owned());
-                writeln!(
-                    out,
-                    "
-    LicensedCrate {{
-        name: {:?},
-        version: {:?},
-        licenses: Licenses {{
-            name: {:?},
-            licenses: &[
-                License {{
-                    name: {:?},
-                    text: {},
-                }},
-            ],
-        }},
-    }},",
-                    package.name,
-                    package.version.to_string(),
-                    license.to_string(),
-                    license.to_string(),
-                    text
-                )?;
             }
         }
-    }
-    writeln!(out)?;
-    Ok(())
-}
+        writeln!(out)?;
 
-fn split_package(context: &mut Context, package: &Package, dir: &Path) -> anyhow::Result<()> {
-    let license = package.license();
-    let mut file = File::create(dir.join(package.name.as_str()))?;
-    if let Some(text) = find_generic_license_text(package, &license)? {
-        match text.confidence {
-            Confidence::Confident => (),
-            Confidence::SemiConfident => {
-                log::warn!(
-                    "{} has only a low-confidence candidate for license {}:",
-                    package.name,
-                    license
-                );
-                log::warn!("    {}", text.path.display());
-            }
-            Confidence::Unsure => {
-                log::error!(
-                    "{} has only a very low-confidence candidate for license {}:",
-                    package.name,
-                    license
-                );
-                log::error!("    {}", text.path.display());
-            }
+        // The notices were listed with each package, what they share is the
+        // rest of the text, from one that's a confident match if possible
+        let text = group
+            .members
+            .iter()
+            .filter_map(|(_, chosen)| chosen.text.as_ref())
+            .max_by_key(|text| (text.confidence == Confidence::Confident, text.text.len()))
+            .map(|text| holders::without_notices(&text.text))
+            .unwrap_or_default();
+        for line in text.lines() {
+            writeln!(out, "    {}", line)?;
         }
-        file.write_all(text.text.as_bytes())?;
-    } else {
-        match license {
-            License::Unspecified => {
-                log::error!("{} does not specify a license", package.name);
-            }
-            License::Multiple(licenses) => {
-                let mut first = true;
-                for license in licenses {
-                    if first {
-                        first = false;
-                    } else {
-                        writeln!(file)?;
-                        writeln!(file, "===============")?;
-                        writeln!(file)?;
-                    }
-                    let texts = find_license_text(package, &license)?;
-                    if let Some(text) = choose(context, package, &license, texts)? {
-                        file.write_all(text.text.as_bytes())?;
-                    }
+    }
+
+    for lich in own {
+        writeln!(out)?;
+        writeln!(out, "===============")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "The following component is licensed under {}:",
+            lich.license
+        )?;
+        writeln!(out)?;
+        writeln!(out, " * {} {}", lich.package.name, lich.package.version)?;
+        written.push(entry(lich.package));
+        write_attribution_line(context, out, lich.package)?;
+        writeln!(out)?;
+        let mut first = true;
+        for chosen in &lich.texts {
+            if let Some(text) = &chosen.text {
+                if !first {
+                    writeln!(out)?;
                 }
-            }
-            license => {
-                let texts = find_license_text(package, &license)?;
-                if let Some(text) = choose(context, package, &license, texts)? {
-                    file.write_all(text.text.as_bytes())?;
+                first = false;
+                for line in text.text.lines() {
+                    writeln!(out, "    {}", line)?;
                 }
             }
         }
+        if first {
+            writeln!(out, "    License text not found.")?;
+        }
     }
-    Ok(())
+
+    write_postamble(context, out)?;
+    Ok(written)
 }
 
-fn inline_license(
-    context: &mut Context,
+fn candidate(
+    context: &Context,
     package: &Package,
+    text: &LicenseText,
     license: &License,
-    out: &mut dyn io::Write,
-) -> anyhow::Result<()> {
-    let texts = find_license_text(package, license)?;
-    if let Some(text) = choose(context, package, license, texts)? {
-        for line in text.text.lines() {
-            writeln!(out, "    {}", line)?;
-        }
+) -> String {
+    if let Some(score) = text.score {
+        format!(
+            "{} matched {} with score {:.2} (threshold {:.2})",
+            context.path(package, &text.path),
+            license,
+            score,
+            context.config.threshold(&text.confidence)
+        )
+    } else {
+        format!(
+            "{} (no template for {})",
+            context.path(package, &text.path),
+            license
+        )
     }
-    Ok(())
 }
 
 fn choose(
-    context: &mut Context,
+    context: &Context,
     package: &Package,
     license: &License,
-    texts: Vec<LicenseText>,
+    mut texts: Vec<LicenseText>,
+    problems: &mut Problems,
 ) -> anyhow::Result<Option<LicenseText>> {
+    // Best scoring first, then by path so that the choice between equally good
+    // candidates doesn't depend on the order they were found in
+    texts.sort_by(|a, b| {
+        let (a_score, b_score) = (a.score.unwrap_or(1.0), b.score.unwrap_or(1.0));
+        a_score
+            .total_cmp(&b_score)
+            .then_with(|| a.path.cmp(&b.path))
+    });
     let (mut confident, texts): (Vec<LicenseText>, Vec<LicenseText>) = texts
         .into_iter()
         .partition(|text| text.confidence == Confidence::Confident);
@@ -477,9 +2693,12 @@ fn choose(
         .into_iter()
         .partition(|text| text.confidence == Confidence::SemiConfident);
 
-    Ok(Some({
+    let (text, reason) = {
         if confident.len() == 1 {
-            confident.swap_remove(0)
+            (
+                confident.swap_remove(0),
+                "the only confident candidate".to_owned(),
+            )
         } else if confident.len() > 1 {
             log::error!(
                 "{} has multiple candidates for license {}:",
@@ -487,57 +2706,411 @@ fn choose(
                 license
             );
             for text in &confident {
-                log::error!("    {}", text.path.display());
+                log::error!("    {}", candidate(context, package, text, license));
             }
-            confident.swap_remove(0)
+            let reason = format!(
+                "the best scoring of {} confident candidates",
+                confident.len()
+            );
+            (confident.swap_remove(0), reason)
         } else if semi_confident.len() == 1 {
             log::warn!(
                 "{} has only a low-confidence candidate for license {}:\n    {}",
                 package.name,
                 license,
-                semi_confident[0].path.display(),
+                candidate(context, package, &semi_confident[0], license),
             );
-            semi_confident.swap_remove(0)
+            (
+                semi_confident.swap_remove(0),
+                "the only low-confidence candidate".to_owned(),
+            )
         } else if semi_confident.len() > 1 {
-            context.low_quality_license = true;
+            problems.low_quality_license = true;
             log::error!(
                 "{} has multiple low-confidence candidates for license {}:",
                 package.name,
                 license
             );
             for text in &semi_confident {
-                log::error!("    {}", text.path.display());
+                log::error!("    {}", candidate(context, package, text, license));
             }
-            semi_confident.swap_remove(0)
+            let reason = format!(
+                "the best scoring of {} low-confidence candidates",
+                semi_confident.len()
+            );
+            (semi_confident.swap_remove(0), reason)
         } else if unconfident.len() == 1 {
-            context.low_quality_license = true;
+            problems.low_quality_license = true;
             log::warn!(
                 "{} has only a very low-confidence candidate for license {}:\n    {}",
                 package.name,
                 license,
-                unconfident[0].path.display(),
+                candidate(context, package, &unconfident[0], license),
             );
-            unconfident.swap_remove(0)
+            (
+                unconfident.swap_remove(0),
+                "the only very low-confidence candidate".to_owned(),
+            )
         } else if unconfident.len() > 1 {
-            context.low_quality_license = true;
+            problems.low_quality_license = true;
             log::error!(
                 "{} has multiple very low-confidence candidates for license {}:",
                 package.name,
                 license
             );
             for text in &unconfident {
-                log::error!("    {}", text.path.display());
+                log::error!("    {}", candidate(context, package, text, license));
+            }
+            let reason = format!(
+                "the best scoring of {} very low-confidence candidates",
+                unconfident.len()
+            );
+            (unconfident.swap_remove(0), reason)
+        } else if let Some(text) = readme_license_text(context, package, license)? {
+            log::warn!(
+                "{} has no license file, using text for license {} extracted from its README:\n    {}",
+                package.name,
+                license,
+                candidate(context, package, &text, license),
+            );
+            (
+                text,
+                "no license files, extracted from the README".to_owned(),
+            )
+        } else if let Some((template, path)) = context
+            .config
+            .template(license)
+            .and_then(|template| Some((template, headers_naming(context, package, license)?)))
+        {
+            if package.declared_license_str().is_some() {
+                log::warn!(
+                    "{} has no license text, using the standard text for license {} which its \
+                     source headers also name",
+                    package.name,
+                    license
+                );
+            } else {
+                log::warn!(
+                    "{} has no license text, using the standard text for license {} inferred \
+                     from its source headers",
+                    package.name,
+                    license
+                );
             }
-            unconfident.swap_remove(0)
+            let text = LicenseText {
+                path,
+                origin: Origin::Template,
+                text: template.to_owned(),
+                confidence: Confidence::SemiConfident,
+                score: None,
+                bytes: None,
+            };
+            (
+                text,
+                "no license files, the license is named by source headers".to_owned(),
+            )
         } else {
             log::error!(
                 "{} has no candidate texts for license {} in {}",
                 package.name,
                 license,
-                package.manifest_path.parent().unwrap().display()
+                context.path(
+                    package,
+                    package_dir(package).unwrap_or(&package.manifest_path)
+                )
             );
-            context.missing_license = true;
+            problems.missing_license = true;
+            context.choice(license, None, || "no candidate texts".to_owned());
             return Ok(None);
         }
-    }))
+    };
+    context.choice(license, Some(&text), || reason);
+    Ok(Some(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::{self, path_package, TempDir};
+
+    #[test]
+    fn relative_paths() {
+        let cases = [
+            ("/work/vendor/foo/LICENSE", "/work", "vendor/foo/LICENSE"),
+            (
+                "/work/vendor/foo/LICENSE",
+                "/work/app",
+                "../vendor/foo/LICENSE",
+            ),
+            (
+                "/registry/foo/LICENSE",
+                "/work/app",
+                "../../registry/foo/LICENSE",
+            ),
+            ("/work/LICENSE", "/work", "LICENSE"),
+        ];
+        for (path, base, expected) in &cases {
+            assert_eq!(
+                relative(Path::new(path), Path::new(base)),
+                Path::new(expected),
+                "{} from {}",
+                path,
+                base
+            );
+        }
+    }
+
+    #[test]
+    fn verbatim_targets_are_kept_apart() {
+        let dir = TempDir::new();
+        let package = path_package("nested", "0.3.0", None, &dir.path().join("nested"));
+        let out = Path::new("/out");
+        let mut targets = HashSet::new();
+        let mut target =
+            |path: &str| verbatim_target(out, &package, &dir.path().join(path), &mut targets);
+        assert_eq!(target("nested/LICENSE"), out.join("nested-0.3.0-LICENSE"));
+        assert_eq!(
+            target("nested/licenses/mit/LICENSE"),
+            out.join("nested-0.3.0-licenses-mit-LICENSE")
+        );
+        assert_eq!(
+            target("nested/licenses/apache/LICENSE"),
+            out.join("nested-0.3.0-licenses-apache-LICENSE")
+        );
+        // Only the counter is left when the path in the package is taken too
+        assert_eq!(
+            target("nested/licenses/mit/LICENSE"),
+            out.join("nested-0.3.0-licenses-mit-LICENSE-2")
+        );
+        assert_eq!(
+            target("nested/licenses/mit/LICENSE"),
+            out.join("nested-0.3.0-licenses-mit-LICENSE-3")
+        );
+    }
+
+    #[test]
+    fn markdown_is_escaped() {
+        for (text, expected) in &[
+            ("serde_json", "serde\\_json"),
+            ("*not* [a](link) <b>", "\\*not\\* \\[a\\](link) \\<b\\>"),
+            ("a `code` | table", "a \\`code\\` \\| table"),
+            ("1.0.0-alpha", "1.0.0-alpha"),
+            ("one\n# two", "one # two"),
+            ("&copy;", "\\&copy;"),
+        ] {
+            assert_eq!(&escape_markdown(text), expected, "{}", text);
+        }
+    }
+
+    #[test]
+    fn missing_sources_are_reported_per_package() {
+        let dir = TempDir::new();
+        dir.write("fetched/LICENSE", testutil::MIT_TEXT);
+        let fetched = path_package("fetched", "0.1.0", Some("MIT"), &dir.path().join("fetched"));
+        // As if removed from a pruned cache after resolving
+        let unfetched = path_package(
+            "unfetched",
+            "0.1.0",
+            Some("MIT"),
+            &dir.path().join("unfetched"),
+        );
+        dir.write("workspace/Cargo.toml", "[workspace]\nmembers = []\n");
+        let workspace = path_package(
+            "workspace",
+            "0.1.0",
+            Some("MIT"),
+            &dir.path().join("workspace"),
+        );
+        let packages = [&fetched, &unfetched, &workspace];
+        let licenses = LicensedCache::new(false, false);
+        let context = |require_sources| Context {
+            roots_name: "app".to_owned(),
+            packages: &packages,
+            duplicates: HashMap::new(),
+            workspace_root: dir.path(),
+            licenses: &licenses,
+            config: DiscoveryConfig::default(),
+            cache: None,
+            collected: None,
+            require_sources,
+            cross_check: false,
+            reproducible: false,
+            preamble: None,
+            postamble: None,
+            raw_html: false,
+            print_hashes: false,
+            with_metadata: false,
+            check_holders: false,
+            election: None,
+            normalize: false,
+            line_ending: LineEnding::Lf,
+            missing_license: AtomicBool::new(false),
+            contradicted_license: AtomicBool::new(false),
+            missing_sources: AtomicBool::new(false),
+            virtual_manifests: AtomicBool::new(false),
+            low_quality_license: AtomicBool::new(false),
+            skipped_files: AtomicBool::new(false),
+            summary: Mutex::new(Summary::default()),
+            trace: None,
+        };
+
+        let lenient = context(false);
+        let missing = || lenient.missing_sources.load(Ordering::SeqCst);
+        assert_eq!(collect(&lenient, &fetched).unwrap().texts.len(), 1);
+        assert!(!missing());
+        assert!(collect(&lenient, &unfetched).unwrap().texts.is_empty());
+        assert!(missing());
+        let lich = collect(&lenient, &workspace).unwrap();
+        assert!(lich.texts.is_empty());
+        assert_eq!(
+            lich.problems.issues(&lenient, &workspace),
+            [format!(
+                "manifest {} is a workspace virtual manifest, there are no package sources",
+                dir.path().join("workspace/Cargo.toml").display()
+            )]
+        );
+        assert!(lenient.virtual_manifests.load(Ordering::SeqCst));
+
+        let err = collect(&context(true), &unfetched).err().unwrap();
+        assert!(
+            err.to_string()
+                .contains("Couldn't read source directory of unfetched 0.1.0"),
+            "{}",
+            err
+        );
+    }
+
+    const LAYOUT_TEXT: &str = "                         THE EXAMPLE LICENSE
+
+Redistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:
+
+- Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.
+* Redistributions in binary form must reproduce the above copyright notice.
+1. Numbered conditions are kept too.
+(iv) So are roman ones.
+    An indented line that is much longer than the width it would be wrapped to.
+> A quoted line that is much longer than the width it would be wrapped to.
+
+A short
+paragraph.
+";
+
+    #[test]
+    fn rewrapping() {
+        let lines = rewrap(LAYOUT_TEXT, 40);
+        assert_eq!(
+            lines,
+            [
+                "                         THE EXAMPLE LICENSE",
+                "",
+                "Redistribution and use in source and",
+                "binary forms, with or without",
+                "modification, are permitted provided",
+                "that the following conditions are met:",
+                "",
+                "- Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.",
+                "* Redistributions in binary form must reproduce the above copyright notice.",
+                "1. Numbered conditions are kept too.",
+                "(iv) So are roman ones.",
+                "    An indented line that is much longer than the width it would be wrapped to.",
+                "> A quoted line that is much longer than the width it would be wrapped to.",
+                "",
+                "A short paragraph.",
+            ]
+        );
+
+        // Words longer than the width get a line of their own
+        assert_eq!(rewrap("a verylongword b", 5), ["a", "verylongword", "b"]);
+    }
+
+    #[test]
+    fn entry_layout() {
+        let render = |layout: &Layout| {
+            let texts = [InlineText {
+                license: "MIT".to_owned(),
+                note: None,
+                text: Some(LAYOUT_TEXT),
+                annotation: None,
+            }];
+            let mut out = Vec::new();
+            write_entry(&mut out, "foo", "1.0.0", "MIT", None, Some(&texts), layout).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        // The default passes the text through, only indenting lines that
+        // aren't blank
+        let default = render(&Layout::default());
+        let mut expected = " * foo 1.0.0 under the terms of MIT:\n\n".to_owned();
+        for line in LAYOUT_TEXT.lines() {
+            if !line.is_empty() {
+                expected.push_str("    ");
+            }
+            expected.push_str(line);
+            expected.push('\n');
+        }
+        expected.push_str("\n\n");
+        assert_eq!(default, expected);
+
+        // The width includes the indentation
+        let wrapped = render(&Layout {
+            wrap: Some(42),
+            indent: 2,
+        });
+        assert!(wrapped.contains("\n  Redistribution and use in source and\n  binary forms,"));
+        assert!(wrapped.contains("\n  - Redistributions of source code must retain the above"));
+        assert!(wrapped.contains("\n  A short paragraph.\n"));
+        assert!(!wrapped.contains("\n    Redistribution"));
+    }
+
+    #[test]
+    fn missing_and_repeated_entries_fail_verification() {
+        let dir = TempDir::new();
+        let one = path_package("one", "0.1.0", Some("MIT"), dir.path());
+        let two = path_package("two", "0.2.0", Some("MIT"), dir.path());
+        let packages = [&one, &two];
+
+        assert!(verify(&packages, vec![entry(&one), entry(&two)]).is_ok());
+        let err = verify(&packages, vec![entry(&one), entry(&one)])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("\n  one 0.1.0 is written 2 times instead of 1\n"),
+            "{}",
+            err
+        );
+        assert!(err.contains("\n  two 0.2.0 is missing\n"), "{}", err);
+        assert!(err.contains("CARGO_LICHKING_NO_VERIFY=1"), "{}", err);
+    }
+
+    #[test]
+    fn packages_dropped_from_serialized_bundles_fail_verification() {
+        let dir = TempDir::new();
+        let one = path_package("one", "0.1.0", Some("MIT"), dir.path());
+        let two = path_package("two", "0.2.0", Some("MIT"), dir.path());
+        let packages = [&one, &two];
+
+        let json = serde_json::json!([
+            { "name": "one", "version": "0.1.0", "license": "MIT" },
+            { "name": "two", "version": "0.2.0", "license": "MIT" },
+        ]);
+        let written = written_entries(Some(&json)).unwrap();
+        assert!(verify(&packages, written).is_ok());
+
+        // A package lost between collecting and serializing is caught
+        let dropped = serde_json::json!([{ "name": "one", "version": "0.1.0" }]);
+        let err = verify(&packages, written_entries(Some(&dropped)).unwrap())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("\n  two 0.2.0 is missing\n"), "{}", err);
+
+        let mut context = tera::Context::new();
+        context.insert("packages", &dropped);
+        let err = verify(&packages, written_entries(context.get("packages")).unwrap())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("\n  two 0.2.0 is missing\n"), "{}", err);
+
+        assert!(written_entries(None).is_err());
+        assert!(written_entries(Some(&serde_json::json!([{ "name": "one" }]))).is_err());
+    }
 }
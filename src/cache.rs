@@ -0,0 +1,385 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+use cargo_metadata::Package;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::discovery::{
+    find_generic_license_text_traced, find_license_text_traced, Confidence, DiscoveryConfig,
+    DiscoveryTrace, LicenseText, Origin, SkippedFile,
+};
+use crate::license::License;
+
+/// Bump whenever the format or the discovery logic changes in a way that
+/// makes old entries invalid, e.g. how texts are extracted or scored
+const VERSION: u32 = 6;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CachedText {
+    path: PathBuf,
+    origin: Origin,
+    /// The size, modification time and hash of the whole file, the text may
+    /// only be part of it or have been decoded lossily
+    size: u64,
+    modified: Option<u128>,
+    hash: String,
+    text: String,
+    confidence: Confidence,
+    score: Option<f32>,
+}
+
+/// The texts a search found along with the files it skipped
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CachedEntry {
+    texts: Vec<CachedText>,
+    skipped: Vec<SkippedFile>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    entries: HashMap<String, CachedEntry>,
+}
+
+/// Remembers the results of discovery for immutable (registry and git)
+/// packages across runs, path dependencies are always rescanned. Can be shared
+/// between threads, the lock is only held while accessing the entries, not
+/// during discovery.
+pub struct Cache {
+    path: PathBuf,
+    file: Mutex<CacheFile>,
+    dirty: AtomicBool,
+}
+
+fn modified(path: &Path) -> Option<u128> {
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    Some(modified.duration_since(UNIX_EPOCH).ok()?.as_nanos())
+}
+
+fn hash(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+impl CachedText {
+    /// `None` for texts not read from a file
+    fn new(text: &LicenseText) -> Option<CachedText> {
+        let bytes = text.bytes.as_ref()?;
+        Some(CachedText {
+            path: text.path.clone(),
+            origin: text.origin.clone(),
+            size: bytes.len() as u64,
+            modified: modified(&text.path),
+            hash: hash(bytes),
+            text: text.text.clone(),
+            confidence: text.confidence,
+            score: text.score,
+        })
+    }
+
+    /// Reloads the text, returning `None` if the file has changed since it was
+    /// cached
+    fn load(&self) -> Option<LicenseText> {
+        if modified(&self.path) != self.modified {
+            return None;
+        }
+        let bytes = fs::read(&self.path).ok()?;
+        if bytes.len() as u64 != self.size || hash(&bytes) != self.hash {
+            return None;
+        }
+        Some(LicenseText {
+            path: self.path.clone(),
+            origin: self.origin.clone(),
+            text: self.text.clone(),
+            confidence: self.confidence,
+            score: self.score,
+            bytes: Some(bytes.into()),
+        })
+    }
+}
+
+/// A hash of the custom templates, empty without any
+fn templates(config: &DiscoveryConfig) -> String {
+    config.templates.as_ref().map_or_else(String::new, |store| {
+        let mut hasher = Sha256::new();
+        for (slug, template) in store.iter() {
+            hasher.update(slug);
+            hasher.update([0]);
+            hasher.update(template);
+            hasher.update([0]);
+        }
+        format!("{:x}", hasher.finalize())
+    })
+}
+
+impl Cache {
+    pub fn default_path() -> Option<PathBuf> {
+        let cargo_home = env::var_os("CARGO_HOME").map(PathBuf::from).or_else(|| {
+            env::var_os("HOME")
+                .or_else(|| env::var_os("USERPROFILE"))
+                .map(|home| PathBuf::from(home).join(".cargo"))
+        })?;
+        Some(cargo_home.join("lichking-cache.json"))
+    }
+
+    /// Loads the cache, a missing, corrupted or outdated cache file is
+    /// treated as empty and will be overwritten on save
+    pub fn load(path: PathBuf) -> Cache {
+        let file = File::open(&path)
+            .ok()
+            .and_then(|file| serde_json::from_reader::<_, CacheFile>(file).ok())
+            .filter(|file| file.version == VERSION)
+            .unwrap_or_else(|| CacheFile {
+                version: VERSION,
+                entries: HashMap::new(),
+            });
+        Cache {
+            path,
+            file: Mutex::new(file),
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if self.dirty.load(Ordering::SeqCst) {
+            if let Some(parent) = self.path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let file = self.file.lock().unwrap();
+            serde_json::to_writer(File::create(&self.path)?, &*file)?;
+        }
+        Ok(())
+    }
+
+    /// Includes where the package is, the same package can also be in a
+    /// vendor directory whose copy of it may differ, and the custom templates
+    /// texts were scored against
+    fn key(kind: &str, package: &Package, license: &License, config: &DiscoveryConfig) -> String {
+        format!(
+            "{} {} {} {} {} {} {} {} {}",
+            kind,
+            package.id,
+            package.manifest_path.display(),
+            license,
+            config.confident,
+            config.semi_confident,
+            config.max_file_size,
+            config.max_entries,
+            templates(config)
+        )
+    }
+
+    fn get(&self, key: &str) -> Option<(Vec<LicenseText>, Vec<SkippedFile>)> {
+        let cached = self.file.lock().unwrap().entries.get(key)?.clone();
+        let texts = cached
+            .texts
+            .iter()
+            .map(CachedText::load)
+            .collect::<Option<_>>()?;
+        Some((texts, cached.skipped))
+    }
+
+    fn insert(&self, key: String, texts: &[LicenseText], skipped: &[SkippedFile]) {
+        // Texts from files that can't be read again can't be checked later
+        let texts = match texts.iter().map(CachedText::new).collect() {
+            Some(texts) => texts,
+            None => return,
+        };
+        let skipped = skipped.to_vec();
+        let entry = CachedEntry { texts, skipped };
+        self.file.lock().unwrap().entries.insert(key, entry);
+        self.dirty.store(true, Ordering::SeqCst);
+    }
+
+    /// Along with the text, the files skipped looking for it
+    pub fn find_generic_license_text(
+        &self,
+        package: &Package,
+        license: &License,
+        config: &DiscoveryConfig,
+    ) -> anyhow::Result<(Option<LicenseText>, Vec<SkippedFile>)> {
+        let mut trace = DiscoveryTrace::default();
+        if package.source.is_none() {
+            let text = find_generic_license_text_traced(package, license, config, &mut trace)?;
+            return Ok((text, trace.skipped));
+        }
+        let key = Cache::key("generic", package, license, config);
+        if let Some((mut texts, skipped)) = self.get(&key) {
+            log::debug!("using cached generic license texts for {}", package.name);
+            return Ok((texts.pop(), skipped));
+        }
+        let text = find_generic_license_text_traced(package, license, config, &mut trace)?;
+        self.insert(key, text.as_slice(), &trace.skipped);
+        Ok((text, trace.skipped))
+    }
+
+    /// Along with the texts, the files skipped looking for them
+    pub fn find_license_text(
+        &self,
+        package: &Package,
+        license: &License,
+        config: &DiscoveryConfig,
+    ) -> anyhow::Result<(Vec<LicenseText>, Vec<SkippedFile>)> {
+        let mut trace = DiscoveryTrace::default();
+        if package.source.is_none() {
+            let texts = find_license_text_traced(package, license, config, &mut trace)?;
+            return Ok((texts, trace.skipped));
+        }
+        let key = Cache::key("specific", package, license, config);
+        if let Some(cached) = self.get(&key) {
+            log::debug!(
+                "using cached {} license texts for {}",
+                license,
+                package.name
+            );
+            return Ok(cached);
+        }
+        let texts = find_license_text_traced(package, license, config, &mut trace)?;
+        self.insert(key, &texts, &trace.skipped);
+        Ok((texts, trace.skipped))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::{self, TempDir, MIT_TEXT};
+
+    fn cached(dir: &TempDir) -> Cache {
+        Cache::load(dir.path().join("cache.json"))
+    }
+
+    #[test]
+    fn hits_until_the_file_changes() {
+        let dir = TempDir::new();
+        let license = dir.write("foo/LICENSE-MIT", MIT_TEXT);
+        let package = testutil::package("foo", "1.0.0", Some("MIT"), &dir.path().join("foo"));
+        let config = DiscoveryConfig::default();
+        let key = Cache::key("specific", &package, &License::MIT, &config);
+
+        let cache = cached(&dir);
+        assert!(cache.get(&key).is_none());
+        let (texts, _) = cache
+            .find_license_text(&package, &License::MIT, &config)
+            .unwrap();
+        assert_eq!(texts.len(), 1);
+        cache.save().unwrap();
+
+        let cache = cached(&dir);
+        let (hit, _) = cache.get(&key).expect("cached");
+        assert_eq!(hit[0].text, texts[0].text);
+        assert_eq!(hit[0].origin, Origin::LicenseFile);
+
+        fs::write(&license, MIT_TEXT.replace("2020", "2021")).unwrap();
+        assert!(cached(&dir).get(&key).is_none());
+    }
+
+    #[test]
+    fn extracted_texts_hit() {
+        let dir = TempDir::new();
+        let path = dir.write(
+            "foo/LICENSE",
+            &format!("{}\n---\n\nOther terms\n", MIT_TEXT),
+        );
+        let text = LicenseText {
+            bytes: Some(fs::read(&path).unwrap().into()),
+            path,
+            origin: Origin::Segment {
+                start: 0,
+                end: MIT_TEXT.len(),
+            },
+            text: MIT_TEXT.to_owned(),
+            confidence: Confidence::Confident,
+            score: Some(0.0),
+        };
+        let cache = cached(&dir);
+        cache.insert("segment".to_owned(), std::slice::from_ref(&text), &[]);
+        cache.save().unwrap();
+
+        let (hit, _) = cached(&dir).get("segment").expect("cached");
+        assert_eq!(hit[0].origin, text.origin);
+        assert_eq!(hit[0].text, text.text);
+    }
+
+    #[test]
+    fn outdated_versions_are_ignored() {
+        let dir = TempDir::new();
+        fs::write(
+            dir.path().join("cache.json"),
+            r#"{"version":1,"entries":{"key":[]}}"#,
+        )
+        .unwrap();
+        assert!(cached(&dir).get("key").is_none());
+    }
+
+    #[test]
+    fn copies_elsewhere_miss() {
+        let dir = TempDir::new();
+        dir.write("registry/foo/LICENSE-MIT", MIT_TEXT);
+        dir.write(
+            "vendor/foo/LICENSE-MIT",
+            &MIT_TEXT.replace("2020 The Authors", "2021 Vendored"),
+        );
+        let registry = testutil::package(
+            "foo",
+            "1.0.0",
+            Some("MIT"),
+            &dir.path().join("registry/foo"),
+        );
+        let vendored =
+            testutil::package("foo", "1.0.0", Some("MIT"), &dir.path().join("vendor/foo"));
+        let config = DiscoveryConfig::default();
+        let cache = cached(&dir);
+        cache
+            .find_license_text(&registry, &License::MIT, &config)
+            .unwrap();
+        cache.save().unwrap();
+
+        let (texts, _) = cached(&dir)
+            .find_license_text(&vendored, &License::MIT, &config)
+            .unwrap();
+        assert_eq!(texts.len(), 1);
+        assert!(texts[0].path.starts_with(dir.path().join("vendor")));
+        assert!(texts[0].text.contains("2021 Vendored"));
+    }
+
+    #[test]
+    fn skipped_files_are_remembered() {
+        let dir = TempDir::new();
+        dir.write("foo/LICENSE-MIT", MIT_TEXT);
+        dir.write("foo/MIT", "%PDF-1.4\n\0\0\0\n");
+        let package = testutil::package("foo", "1.0.0", Some("MIT"), &dir.path().join("foo"));
+        let config = DiscoveryConfig::default();
+        let cache = cached(&dir);
+        let (_, skipped) = cache
+            .find_license_text(&package, &License::MIT, &config)
+            .unwrap();
+        assert_eq!(skipped.len(), 1);
+        cache.save().unwrap();
+
+        let (texts, hit) = cached(&dir)
+            .find_license_text(&package, &License::MIT, &config)
+            .unwrap();
+        assert_eq!(texts.len(), 1);
+        assert_eq!(hit, skipped);
+    }
+
+    #[test]
+    fn path_dependencies_are_not_saved() {
+        let dir = TempDir::new();
+        dir.write("foo/LICENSE-MIT", MIT_TEXT);
+        let package = testutil::path_package("foo", "1.0.0", Some("MIT"), &dir.path().join("foo"));
+        let config = DiscoveryConfig::default();
+        let cache = cached(&dir);
+        cache
+            .find_license_text(&package, &License::MIT, &config)
+            .unwrap();
+        cache.save().unwrap();
+        assert!(!dir.path().join("cache.json").exists());
+    }
+}
@@ -1,36 +1,604 @@
-use anyhow::anyhow;
 use cargo_metadata::Package;
 
-use crate::licensed::Licensed;
+use crate::analysis;
+use crate::decisions::{Decisions, Verdict};
+use crate::discovery::{self, DiscoveryTrace};
+use crate::license::{CheckContext, CopyleftScope, Family, License, Linkage};
+use crate::licensed::{LicenseSource, Licensed, LicensedCache, Resolution, SourceHeaders};
+use crate::load::{self, DepGraph, ResolvedPackage};
+use crate::manifest;
+use crate::options::{CheckOptions, FailOn, TargetKind};
+use crate::outcome::Outcome;
+use crate::output;
+use crate::policy::{Policy, Waived};
+use crate::progress::Progress;
+use crate::yanked;
 
-pub fn run(root: &Package, packages: &[&Package]) -> anyhow::Result<()> {
+/// Describes how `root` depends on `package`, via one of the shortest paths or
+/// every path when verbose
+fn via<'a>(
+    graph: &DepGraph<'a>,
+    root: &'a Package,
+    package: &Package,
+    options: &CheckOptions,
+) -> anyhow::Result<String> {
+    let paths = graph.paths(root, package, options.verbose);
+    if paths.is_empty() {
+        return Ok(String::new());
+    }
+    let paths = paths
+        .iter()
+        .map(|path| load::format_path(path))
+        .collect::<Vec<_>>();
+    Ok(format!(" (via {})", paths.join("; ")))
+}
+
+/// Points at the line of a workspace member's manifest declaring the
+/// dependency that brings in `package`, like cargo-deny does, and which
+/// direct dependency of the member it comes through when it's not a direct
+/// dependency itself. Empty if it can't be found.
+fn declared_at<'a>(
+    graph: &DepGraph<'a>,
+    root: &'a Package,
+    package: &Package,
+) -> anyhow::Result<String> {
+    let path = match graph.paths(root, package, false).pop() {
+        Some(path) if path.len() > 1 => path,
+        _ => return Ok(String::new()),
+    };
+    // The dependency leaves the workspace after the last member on the path
+    let metadata = graph.metadata();
+    let member = match path[..path.len() - 1]
+        .iter()
+        .rposition(|package| metadata.workspace_members.contains(&package.id))
+    {
+        Some(member) => member,
+        None => return Ok(String::new()),
+    };
+    let (declarer, declared) = (path[member], path[member + 1]);
+    let location = match manifest::dependency_location(declarer, declared) {
+        Some(location) => location,
+        None => return Ok(String::new()),
+    };
+    let manifest_path = &declarer.manifest_path;
+    let introduced = if declared.id == package.id {
+        String::new()
+    } else {
+        format!(" (introduced via {} {})", declared.name, declared.version)
+    };
+    Ok(format!(
+        "\n  --> {}:{}:{}{}",
+        manifest_path
+            .strip_prefix(&metadata.workspace_root)
+            .unwrap_or(manifest_path)
+            .display(),
+        location.line,
+        location.column,
+        introduced
+    ))
+}
+
+const LIB_KINDS: &[&str] = &["lib", "rlib", "dylib", "cdylib", "staticlib", "proc-macro"];
+
+/// The kinds of distributable targets `package` builds, ignoring examples,
+/// tests, benches and build scripts
+fn target_kinds(package: &Package) -> Vec<&'static str> {
+    let has = |kinds: &[&str]| {
+        package
+            .targets
+            .iter()
+            .flat_map(|target| &target.kind)
+            .any(|kind| kinds.contains(&kind.as_str()))
+    };
+    let mut target_kinds = Vec::new();
+    if has(&["bin"]) {
+        target_kinds.push("bin");
+    }
+    if has(LIB_KINDS) {
+        target_kinds.push("lib");
+    }
+    target_kinds
+}
+
+/// Whether `root` should be checked for the selected target kind
+pub fn selected(root: &Package, kind: TargetKind) -> bool {
+    match kind {
+        TargetKind::All => true,
+        TargetKind::Bin => target_kinds(root).contains(&"bin"),
+        TargetKind::Lib => target_kinds(root).contains(&"lib"),
+    }
+}
+
+/// Whether `--fail-on fail_on` rejects a dependency of `family`
+fn matches(fail_on: FailOn, family: Family) -> bool {
+    match fail_on {
+        FailOn::Copyleft => matches!(
+            family,
+            Family::WeakCopyleft | Family::StrongCopyleft | Family::NetworkCopyleft
+        ),
+        FailOn::StrongCopyleft => {
+            matches!(family, Family::StrongCopyleft | Family::NetworkCopyleft)
+        }
+        FailOn::NetworkCopyleft => family == Family::NetworkCopyleft,
+        FailOn::Unknown => family == Family::Unknown,
+    }
+}
+
+/// What including a copyleft licensed package obliges, even when compatible
+fn copyleft_obligation(package: &Package, license: &License) -> Option<String> {
+    Some(match license.obligations()?.copyleft_scope {
+        CopyleftScope::None => return None,
+        CopyleftScope::FileLevel => format!(
+            "{} is file-level copyleft, compatible but modified {} files must be published",
+            license, license
+        ),
+        CopyleftScope::Library => format!(
+            "{} is library copyleft, compatible but modifications to {} must be published \
+             and users must be able to replace it",
+            license, package.name
+        ),
+        CopyleftScope::Strong => format!(
+            "{} is strong copyleft, compatible but the combined work must be distributed \
+             under its terms with source",
+            license
+        ),
+    })
+}
+
+/// What every root's dependencies are checked with
+pub struct Checker<'a, 'b> {
+    pub graph: &'b DepGraph<'a>,
+    pub licenses: &'b LicensedCache,
+    pub options: &'b CheckOptions,
+    pub policy: Option<&'b Policy>,
+}
+
+/// Checks that `root` can include the `resolved` packages, under the
+/// hypothetical license `against` instead of its own when given
+pub fn run<'a>(
+    checker: &Checker<'a, '_>,
+    root: &'a Package,
+    against: Option<&License>,
+    resolved: &[ResolvedPackage],
+    mut decisions: Option<&mut Decisions>,
+) -> anyhow::Result<Outcome> {
+    let Checker {
+        graph,
+        licenses,
+        options,
+        policy,
+    } = *checker;
+    let packages = &load::packages(resolved);
     let mut fail = 0;
-    let license = root.license();
+    let mut undecided = 0;
+    let mut unknown = 0;
+    let mut contradicted = 0;
+    let mut failed_families = 0;
+    let mut lgpl_static = 0;
+    let mut skipped = 0;
+    let mut virtual_manifests = 0;
+    let own;
+    let (subject, license) = match against {
+        Some(license) => {
+            output::note(&format!(
+                "checking as if {} were licensed {}",
+                root.name, license
+            ));
+            (format!("{} (as {})", root.name, license), license)
+        }
+        None => {
+            own = licenses.resolve(root);
+            (root.name.clone(), &own.license)
+        }
+    };
+    // Only those who can't be granted anything need the assumption that the
+    // root isn't distributed, unpublishable ones most likely aren't
+    let unpublished = root.publish.as_ref().is_some_and(Vec::is_empty);
+    let private = *license == License::Unspecified && (options.assume_private || unpublished);
+    let subject = if private {
+        output::note(&format!(
+            "{} declares no license and {}, checking it as all rights reserved and not \
+             distributed",
+            root.name,
+            if unpublished {
+                "has `publish = false`"
+            } else {
+                "--assume-private was passed"
+            }
+        ));
+        format!("{} (assumed private)", subject)
+    } else {
+        subject
+    };
+    // When selecting by target kind mention which targets findings affect
+    let root_name = match options.target_kind {
+        TargetKind::All => subject.clone(),
+        _ => format!("{} [{}]", subject, target_kinds(root).join(", ")),
+    };
+    let context = CheckContext {
+        linkage: options.linkage,
+        private,
+    };
+
+    let invalid = licenses.report_diagnostics(packages) && options.strict_spdx;
+    // Already reported along with the diagnostics, inferred licenses are
+    // checked like declared ones but only as trustworthy as the headers
+    let headers = resolved
+        .iter()
+        .filter_map(|resolved| resolved.license.headers.as_ref())
+        .collect::<Vec<_>>();
+    let inferred = headers
+        .iter()
+        .filter(|headers| matches!(headers, SourceHeaders::Consistent(..)))
+        .count();
+    let conflicting = headers.len() - inferred;
+    let changed =
+        analysis::report_license_changes(packages, licenses, options.deny_license_changes)
+            && options.deny_license_changes;
+    let (rejected, mut expiring) =
+        policy.map_or((false, false), |policy| policy.report(root, resolved));
+    let mut yanked = 0;
+    if options.check_yanked {
+        for id in yanked::yanked(packages, options.allow_network) {
+            yanked += 1;
+            let package = packages.iter().find(|package| &package.id == id).unwrap();
+            output::warning(&format!(
+                "{} {} has been yanked from crates.io{}",
+                package.name,
+                package.version,
+                via(graph, root, package, options)?
+            ));
+        }
+    }
 
-    for package in packages {
+    let progress = Progress::new("Checking licenses", packages.len());
+    for resolved in resolved {
+        let package = resolved.package;
+        progress.inc(&package.name);
+        if package.license_source() == LicenseSource::Both {
+            output::warning(&format!(
+                "{} {} declares both license {:?} and license-file {}, only the former is checked",
+                package.name,
+                package.version,
+                package.declared_license_str().unwrap_or_default(),
+                package
+                    .declared_license_file()
+                    .unwrap_or_default()
+                    .display()
+            ));
+        }
         if package.id == root.id {
             continue;
         }
-        let can_include = license.can_include(&package.license());
-        if let Some(can_include) = can_include {
+        let package_license = &resolved.license.license;
+        // How the license was arrived at unless simply as declared
+        let resolution = &resolved.license.resolution;
+        if options.verbose && !matches!(resolution, Resolution::Declared | Resolution::Unspecified)
+        {
+            output::note(&format!(
+                "{} {} is checked under license {}, {}",
+                package.name, package.version, package_license, resolution
+            ));
+        }
+        let waive = |finding: &str| {
+            policy.map_or(Waived::No, |policy| {
+                policy.waive(package, package_license, finding)
+            })
+        };
+        // The vendored copy is what's built when there is one
+        let sources = licenses.sources(package);
+        if options.cross_check && discovery::virtual_manifest(sources) {
+            output::warning(&format!(
+                "{} {} manifest {} is a workspace virtual manifest, there are no package sources \
+                 to cross-check",
+                package.name,
+                package.version,
+                sources.manifest_path.display()
+            ));
+            virtual_manifests += 1;
+        } else if options.cross_check {
+            let mut trace = DiscoveryTrace::default();
+            let contradiction = discovery::contradicting_license_text(
+                sources,
+                package_license,
+                &options.discovery,
+                &mut trace,
+            )?;
+            for file in &trace.skipped {
+                output::warning(&format!(
+                    "{} {} license file {} was skipped, {}",
+                    package.name,
+                    package.version,
+                    file.path.display(),
+                    file.reason
+                ));
+                skipped += 1;
+            }
+            if let Some((text, found)) = contradiction {
+                // The hash of what was matched, so it can be checked against
+                // the published package
+                let sha256 = text
+                    .digest()
+                    .map(|(_, sha256)| format!(" (sha256: {})", sha256))
+                    .unwrap_or_default();
+                let finding = format!(
+                    "{} {} declares license {} but {}{} matches {} with high confidence{}",
+                    package.name,
+                    package.version,
+                    package_license,
+                    text.path.display(),
+                    sha256,
+                    found,
+                    via(graph, root, package, options)?
+                );
+                match waive(&finding) {
+                    Waived::No => {
+                        output::error(&(finding + &declared_at(graph, root, package)?));
+                        contradicted += 1;
+                    }
+                    Waived::Yes => (),
+                    Waived::Expiring => expiring = true,
+                }
+            }
+        }
+        let family = package_license.family();
+        if let Some(fail_on) = options
+            .fail_on
+            .iter()
+            .find(|fail_on| matches(**fail_on, family))
+        {
+            let finding = format!(
+                "{} {} has license {} of family {}, rejected by --fail-on {}{}",
+                package.name,
+                package.version,
+                package_license,
+                family,
+                fail_on,
+                via(graph, root, package, options)?
+            );
+            match waive(&finding) {
+                Waived::No => {
+                    output::error(&(finding + &declared_at(graph, root, package)?));
+                    failed_families += 1;
+                }
+                Waived::Yes => (),
+                Waived::Expiring => expiring = true,
+            }
+        }
+        let (can_include, explanation) = license.can_include_explain(package_license, &context);
+        let dynamic = CheckContext {
+            linkage: Linkage::Dynamic,
+            ..context
+        };
+        if can_include.is_none()
+            && context.linkage == Linkage::Static
+            && license.can_include(package_license, &dynamic) == Some(true)
+        {
+            let finding = format!(
+                "{} statically links package {} {} under {}, distributing it requires letting users \
+                 relink against a modified {}, e.g. by providing the object files or source of \
+                 the rest of the work, pass `--linkage dynamic` if it's a shared library{}",
+                root_name,
+                package.name,
+                package.version,
+                package_license,
+                package.name,
+                via(graph, root, package, options)?
+            );
+            match waive(&finding) {
+                Waived::No if options.deny_lgpl_static => {
+                    output::error(&(finding + &declared_at(graph, root, package)?));
+                    lgpl_static += 1;
+                }
+                Waived::No => {
+                    output::warning(&(finding + &declared_at(graph, root, package)?));
+                    lgpl_static += 1;
+                }
+                Waived::Yes => (),
+                Waived::Expiring => expiring = true,
+            }
+        } else if let Some(can_include) = can_include {
             if !can_include {
-                log::error!(
-                    "{} cannot include package {}, license {} is incompatible with {}",
-                    root.name,
+                let finding = format!(
+                    "{} cannot include package {}, license {} is incompatible with {}: {}{}",
+                    root_name,
                     package.name,
-                    package.license(),
-                    license
+                    package_license,
+                    license,
+                    explanation,
+                    via(graph, root, package, options)?
                 );
-                fail += 1;
+                match waive(&finding) {
+                    Waived::No => {
+                        output::error(&(finding + &declared_at(graph, root, package)?));
+                        fail += 1;
+                    }
+                    Waived::Yes => (),
+                    Waived::Expiring => expiring = true,
+                }
+            } else if private {
+                if package_license.family() == Family::WeakCopyleft {
+                    output::note(&format!(
+                        "{} {}: {} is weak copyleft, its obligations only apply if {} is ever distributed{}",
+                        package.name,
+                        package.version,
+                        package_license,
+                        root.name,
+                        via(graph, root, package, options)?
+                    ));
+                }
+            } else if let Some(obligation) = copyleft_obligation(package, package_license) {
+                output::warning(&format!(
+                    "{} {}: {}{}",
+                    package.name,
+                    package.version,
+                    obligation,
+                    via(graph, root, package, options)?
+                ));
+            }
+        } else if let Some(decisions) = decisions.as_deref_mut() {
+            let verdict = match decisions.find(package) {
+                Some(decision) => Some(decision.verdict),
+                None => decisions.prompt(root, package, package_license)?,
+            };
+            match verdict {
+                Some(Verdict::Allow) => {
+                    log::info!(
+                        "{} may include package {}, license {} was allowed by a recorded decision",
+                        root_name,
+                        package.name,
+                        package_license
+                    );
+                }
+                Some(Verdict::Deny) => {
+                    output::error(&format!(
+                        "{} cannot include package {}, license {} was denied by a recorded decision{}{}",
+                        root_name,
+                        package.name,
+                        package_license,
+                        via(graph, root, package, options)?,
+                        declared_at(graph, root, package)?
+                    ));
+                    fail += 1;
+                }
+                None => {
+                    output::warning(&format!("{} might not be able to include package {}, license {} is not known to be compatible with {} and there is no recorded decision: {}{}", root_name, package.name, package_license, license, explanation, via(graph, root, package, options)?));
+                    undecided += 1;
+                }
             }
         } else {
-            log::warn!("{} might not be able to include package {}, license {} is not known to be compatible with {}", root.name, package.name, package.license(), license);
+            let finding = format!("{} might not be able to include package {}, license {} is not known to be compatible with {}: {}{}", root_name, package.name, package_license, license, explanation, via(graph, root, package, options)?);
+            match waive(&finding) {
+                Waived::No => {
+                    output::warning(&(finding + &declared_at(graph, root, package)?));
+                    unknown += 1;
+                }
+                Waived::Yes => (),
+                Waived::Expiring => expiring = true,
+            }
         }
     }
 
+    let mut outcome = Outcome::default();
     if fail > 0 {
-        Err(anyhow!("Incompatible license"))
-    } else {
-        Ok(())
+        outcome.fail(format!("{}: Incompatible license", subject));
+    }
+    if contradicted > 0 {
+        outcome.fail(format!(
+            "{}: License files contradict the declared licenses",
+            subject
+        ));
+    }
+    if failed_families > 0 {
+        outcome.fail(format!(
+            "{}: Dependencies with rejected license families",
+            subject
+        ));
+    }
+    if rejected {
+        outcome.fail(format!("{}: Licenses not accepted by policy", subject));
+    }
+    if undecided > 0 {
+        let reason = format!("{}: Undecided license compatibility", subject);
+        if options.deny_undecided {
+            outcome.fail(reason);
+        } else {
+            outcome.warn(reason);
+        }
+    }
+    if lgpl_static > 0 {
+        let reason = format!("{}: Statically linked LGPL dependencies", subject);
+        if options.deny_lgpl_static {
+            outcome.fail(reason);
+        } else {
+            outcome.warn(reason);
+        }
+    }
+    if expiring {
+        outcome.warn(format!("{}: Waivers expiring soon", subject));
+    }
+    if unknown > 0 {
+        outcome.warn(format!("{}: Unknown license compatibility", subject));
+    }
+    if invalid {
+        outcome.fail(format!("{}: Invalid SPDX license identifiers", subject));
+    }
+    if changed {
+        outcome.fail(format!("{}: License changed across versions", subject));
+    }
+    if yanked > 0 {
+        outcome.warn(format!("{}: Yanked dependencies", subject));
+    }
+    if inferred > 0 {
+        outcome.warn(format!(
+            "{}: Licenses inferred from source headers",
+            subject
+        ));
+    }
+    if conflicting > 0 {
+        outcome.warn(format!(
+            "{}: Conflicting license headers in sources",
+            subject
+        ));
+    }
+    if virtual_manifests > 0 {
+        outcome.warn(format!(
+            "{}: Packages pointing at a workspace virtual manifest",
+            subject
+        ));
+    }
+    if skipped > 0 {
+        outcome.warn(format!(
+            "{}: License files skipped as too large or binary",
+            subject
+        ));
+    }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::testutil::package;
+
+    fn with_targets(name: &str, kinds: &[&[&str]]) -> Package {
+        let mut package = package(name, "0.1.0", Some("MIT"), Path::new("/nonexistent"));
+        package.targets = kinds
+            .iter()
+            .map(|kind| {
+                serde_json::from_value(serde_json::json!({
+                    "name": name,
+                    "kind": kind,
+                    "src_path": "/nonexistent/src/lib.rs",
+                }))
+                .unwrap()
+            })
+            .collect();
+        package
+    }
+
+    #[test]
+    fn target_kinds_ignore_auxiliary_targets() {
+        let tool = with_targets("tool", &[&["bin"], &["test"], &["custom-build"]]);
+        let library = with_targets("library", &[&["cdylib", "rlib"], &["example"]]);
+        let both = with_targets("both", &[&["lib"], &["bin"]]);
+        let macros = with_targets("macros", &[&["proc-macro"], &["bench"]]);
+        assert_eq!(target_kinds(&tool), ["bin"]);
+        assert_eq!(target_kinds(&library), ["lib"]);
+        assert_eq!(target_kinds(&both), ["bin", "lib"]);
+        assert_eq!(target_kinds(&macros), ["lib"]);
+
+        for (root, bin, lib) in &[
+            (&tool, true, false),
+            (&library, false, true),
+            (&both, true, true),
+            (&macros, false, true),
+        ] {
+            assert!(selected(root, TargetKind::All));
+            assert_eq!(selected(root, TargetKind::Bin), *bin, "{}", root.name);
+            assert_eq!(selected(root, TargetKind::Lib), *lib, "{}", root.name);
+        }
     }
 }
@@ -0,0 +1,272 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use cargo_metadata::Package;
+use serde::Serialize;
+
+use crate::license::{CheckContext, Explanation, License, Linkage};
+use crate::licensed::LicensedCache;
+use crate::load::ResolvedPackage;
+use crate::options::Format;
+use crate::outcome::Summary;
+
+#[derive(Debug, Serialize)]
+struct Cell {
+    root: String,
+    /// `None` when compatibility is unknown
+    compatible: Option<bool>,
+    /// The rule that decided it
+    reason: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Row {
+    license: String,
+    packages: usize,
+    cells: Vec<Cell>,
+}
+
+#[derive(Debug, Serialize)]
+struct Matrix {
+    roots: Vec<String>,
+    rows: Vec<Row>,
+    summary: Summary,
+    /// Some cells depend on how LGPL dependencies are linked, which is
+    /// assumed to be statically
+    #[serde(skip)]
+    assumed_static: bool,
+}
+
+/// Shows whether each license used by the dependencies can be included by the
+/// roots' licenses and any `as_licenses` being considered instead
+pub fn run(
+    roots: &[&Package],
+    resolved: &[ResolvedPackage],
+    licenses: &LicensedCache,
+    as_licenses: &[String],
+    format: Format,
+) -> anyhow::Result<()> {
+    let matrix = matrix(roots, resolved, licenses, as_licenses);
+    match format {
+        Format::Text => write_text(&matrix, &mut io::stdout())?,
+        Format::Json => {
+            serde_json::to_writer_pretty(io::stdout(), &matrix)?;
+            println!();
+        }
+        Format::CycloneDx => unreachable!("not an allowed format"),
+    }
+    Ok(())
+}
+
+fn matrix(
+    roots: &[&Package],
+    resolved: &[ResolvedPackage],
+    licenses: &LicensedCache,
+    as_licenses: &[String],
+) -> Matrix {
+    let mut columns = Vec::<License>::new();
+    let candidates = roots
+        .iter()
+        .map(|root| licenses.resolve(root).license.clone())
+        .chain(as_licenses.iter().map(|license| license.parse().unwrap()));
+    for license in candidates {
+        if !columns.contains(&license) {
+            columns.push(license);
+        }
+    }
+
+    let mut assumed_static = false;
+    let mut counts = BTreeMap::<License, usize>::new();
+    for resolved in resolved {
+        if roots.iter().any(|root| root.id == resolved.package.id) {
+            continue;
+        }
+        *counts.entry(resolved.license.license.clone()).or_default() += 1;
+    }
+
+    Matrix {
+        roots: columns.iter().map(ToString::to_string).collect(),
+        rows: counts
+            .iter()
+            .map(|(license, &count)| Row {
+                license: license.to_string(),
+                packages: count,
+                cells: columns
+                    .iter()
+                    .map(|root| {
+                        let (compatible, explanation) =
+                            root.can_include_explain(license, &CheckContext::default());
+                        let mut reason = explanation.to_string();
+                        if let Explanation::LgplLinkage {
+                            linkage: Linkage::Static,
+                            ..
+                        } = explanation
+                        {
+                            reason += ", dependencies are assumed to be linked statically";
+                            assumed_static = true;
+                        }
+                        Cell {
+                            root: root.to_string(),
+                            compatible,
+                            reason,
+                        }
+                    })
+                    .collect(),
+            })
+            .collect(),
+        summary: Summary::of(resolved),
+        assumed_static,
+    }
+}
+
+fn write_text(matrix: &Matrix, out: &mut dyn Write) -> io::Result<()> {
+    let cell = |compatible: Option<bool>| match compatible {
+        Some(true) => "yes",
+        Some(false) => "no",
+        None => "unknown",
+    };
+
+    let license_width = matrix
+        .rows
+        .iter()
+        .map(|row| row.license.len())
+        .chain(Some("License".len()))
+        .max()
+        .unwrap_or_default();
+    let widths = matrix
+        .roots
+        .iter()
+        .map(|root| root.len().max("unknown".len()))
+        .collect::<Vec<_>>();
+
+    let mut line = format!("{:<width$}  Packages", "License", width = license_width);
+    for (root, width) in matrix.roots.iter().zip(&widths) {
+        line += &format!("  {:<width$}", root, width = width);
+    }
+    writeln!(out, "{}", line.trim_end())?;
+    for row in &matrix.rows {
+        let mut line = format!(
+            "{:<width$}  {:>8}",
+            row.license,
+            row.packages,
+            width = license_width
+        );
+        for (entry, width) in row.cells.iter().zip(&widths) {
+            line += &format!("  {:<width$}", cell(entry.compatible), width = width);
+        }
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    if matrix.assumed_static {
+        writeln!(
+            out,
+            "\nLGPL dependencies are assumed to be linked statically, as cargo does"
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::testutil::{self, package};
+
+    fn compat(as_licenses: &[&str]) -> Matrix {
+        let dir = Path::new("/nonexistent");
+        let packages = [
+            package("compat-root", "0.1.0", Some("MIT"), dir),
+            package("compat-apache", "1.0.0", Some("Apache-2.0"), dir),
+            package("compat-mit", "1.0.0", Some("MIT"), dir),
+            package("compat-mit-too", "2.0.0", Some("MIT"), dir),
+            package("compat-gpl", "1.0.0", Some("GPL-3.0-only"), dir),
+            package("compat-gpl-2", "1.0.0", Some("GPL-2.0-only"), dir),
+            package("compat-mpl", "1.0.0", Some("MPL-2.0"), dir),
+            package("compat-custom", "1.0.0", Some("LicenseRef-Custom"), dir),
+        ];
+        let resolved = packages.iter().map(testutil::resolved).collect::<Vec<_>>();
+        let as_licenses = as_licenses
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let licenses = LicensedCache::new(false, false);
+        matrix(&[&packages[0]], &resolved, &licenses, &as_licenses)
+    }
+
+    fn cell(matrix: &Matrix, root: &str, license: &str) -> Option<bool> {
+        let column = matrix.roots.iter().position(|name| name == root).unwrap();
+        let row = matrix
+            .rows
+            .iter()
+            .find(|row| row.license == license)
+            .unwrap_or_else(|| panic!("no row for {}", license));
+        row.cells[column].compatible
+    }
+
+    #[test]
+    fn cells() {
+        let matrix = compat(&["MPL-2.0", "GPL-3.0-only", "MIT"]);
+        // The root's own license comes first and isn't repeated
+        assert_eq!(matrix.roots, ["MIT", "MPL-2.0", "GPL-3.0-only"]);
+
+        // The Apache-2.0's patent terms aren't in the MIT
+        assert_eq!(cell(&matrix, "MIT", "Apache-2.0"), Some(false));
+        assert_eq!(cell(&matrix, "MIT", "GPL-3.0-only"), Some(false));
+        assert_eq!(cell(&matrix, "MIT", "MPL-2.0"), Some(false));
+        assert_eq!(cell(&matrix, "MPL-2.0", "MIT"), Some(true));
+        assert_eq!(cell(&matrix, "MPL-2.0", "GPL-3.0-only"), Some(false));
+        assert_eq!(cell(&matrix, "GPL-3.0-only", "MIT"), Some(true));
+        assert_eq!(cell(&matrix, "GPL-3.0-only", "Apache-2.0"), Some(true));
+        assert_eq!(cell(&matrix, "GPL-3.0-only", "MPL-2.0"), Some(true));
+        assert_eq!(cell(&matrix, "GPL-3.0-only", "GPL-2.0-only"), Some(false));
+        for root in &matrix.roots {
+            assert_eq!(cell(&matrix, root, "LicenseRef-Custom"), None, "{}", root);
+        }
+    }
+
+    #[test]
+    fn text() {
+        let mut out = Vec::new();
+        write_text(&compat(&["GPL-3.0-only"]), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\
+License            Packages  MIT      GPL-3.0-only
+MIT                       2  yes      yes
+Apache-2.0                1  no       yes
+MPL-2.0                   1  no       yes
+GPL-2.0-only              1  no       no
+GPL-3.0-only              1  no       yes
+LicenseRef-Custom         1  unknown  unknown
+"
+        );
+    }
+
+    #[test]
+    fn static_linkage_is_assumed() {
+        let dir = Path::new("/nonexistent");
+        let packages = [
+            package("compat-root", "0.1.0", Some("MIT"), dir),
+            package("compat-lgpl", "1.0.0", Some("LGPL-3.0-or-later"), dir),
+        ];
+        let resolved = packages.iter().map(testutil::resolved).collect::<Vec<_>>();
+        let licenses = LicensedCache::new(false, false);
+        let matrix = matrix(&[&packages[0]], &resolved, &licenses, &[]);
+        assert_eq!(cell(&matrix, "MIT", "LGPL-3.0-or-later"), None);
+        assert!(
+            matrix.rows[0].cells[0]
+                .reason
+                .ends_with("dependencies are assumed to be linked statically"),
+            "{}",
+            matrix.rows[0].cells[0].reason
+        );
+        let mut out = Vec::new();
+        write_text(&matrix, &mut out).unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .ends_with("\nLGPL dependencies are assumed to be linked statically, as cargo does\n"));
+
+        // Nothing to say without LGPL dependencies
+        assert!(!compat(&[]).assumed_static);
+    }
+}
@@ -0,0 +1,243 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use serde::Deserialize;
+
+/// The name of the config file searched for in the workspace
+pub const FILE_NAME: &str = "lichking.toml";
+
+/// Persistent defaults for command line options, read from a `lichking.toml`.
+/// Each section holds the options of the subcommand it's named after, or
+/// `defaults` the options given before the subcommand.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub defaults: Defaults,
+    pub check: Check,
+    pub bundle: Bundle,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Defaults {
+    pub color: Option<String>,
+    pub frozen: bool,
+    pub locked: bool,
+    pub offline: bool,
+    pub features: Vec<String>,
+    pub all_features: bool,
+    pub no_default_features: bool,
+    pub warnings_as_errors: bool,
+    pub scan_source_headers: bool,
+    pub license_templates: Option<PathBuf>,
+    pub vendor_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Check {
+    pub deny: Vec<String>,
+    pub fail_on: Vec<String>,
+    pub linkage: Option<String>,
+    pub policy: Option<PathBuf>,
+    pub strict_spdx: bool,
+    pub assume_private: bool,
+    pub distribution_only: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Bundle {
+    pub variant: Option<String>,
+    pub file: Option<PathBuf>,
+    pub distribution_only: bool,
+    pub preamble: Option<PathBuf>,
+    pub postamble: Option<PathBuf>,
+    pub with_metadata: bool,
+}
+
+/// The value of a command line option from a config file, `None` for a flag
+pub type Arg = (&'static str, Option<String>);
+
+impl Config {
+    /// The nearest `lichking.toml` in `dir` or any of its parents
+    pub fn find(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .map(|dir| dir.join(FILE_NAME))
+            .find(|path| path.is_file())
+    }
+
+    /// Reads the config file at `path`, paths in it are relative to its
+    /// directory
+    pub fn load(path: &Path) -> anyhow::Result<Config> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| anyhow!("Couldn't read {}: {}", path.display(), err))?;
+        let mut config: Config = toml::from_str(&text).map_err(|err| {
+            anyhow!(
+                "Couldn't parse {}: {}",
+                path.display(),
+                locate_unknown_field(&text, err.to_string())
+            )
+        })?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let paths = [
+            &mut config.defaults.license_templates,
+            &mut config.defaults.vendor_dir,
+            &mut config.check.policy,
+            &mut config.bundle.file,
+            &mut config.bundle.preamble,
+            &mut config.bundle.postamble,
+        ];
+        for path in IntoIterator::into_iter(paths).flatten() {
+            *path = dir.join(&*path);
+        }
+        Ok(config)
+    }
+
+    /// The options given before the subcommand
+    pub fn defaults(&self) -> Vec<Arg> {
+        let defaults = &self.defaults;
+        let mut args = flags(&[
+            ("frozen", defaults.frozen),
+            ("locked", defaults.locked),
+            ("offline", defaults.offline),
+            ("all-features", defaults.all_features),
+            ("no-default-features", defaults.no_default_features),
+            ("warnings-as-errors", defaults.warnings_as_errors),
+            ("scan-source-headers", defaults.scan_source_headers),
+        ]);
+        args.extend(value("color", &defaults.color));
+        args.extend(values("features", &defaults.features));
+        args.extend(path("license-templates", &defaults.license_templates));
+        args.extend(path("vendor-dir", &defaults.vendor_dir));
+        args
+    }
+
+    /// The options of `subcommand`
+    pub fn subcommand(&self, subcommand: &str) -> Vec<Arg> {
+        match subcommand {
+            "check" => {
+                let check = &self.check;
+                let mut args = flags(&[
+                    ("strict-spdx", check.strict_spdx),
+                    ("assume-private", check.assume_private),
+                    ("distribution-only", check.distribution_only),
+                ]);
+                args.extend(values("deny", &check.deny));
+                args.extend(values("fail-on", &check.fail_on));
+                args.extend(value("linkage", &check.linkage));
+                args.extend(path("policy", &check.policy));
+                args
+            }
+            "bundle" => {
+                let bundle = &self.bundle;
+                let mut args = flags(&[
+                    ("distribution-only", bundle.distribution_only),
+                    ("with-metadata", bundle.with_metadata),
+                ]);
+                args.extend(value("variant", &bundle.variant));
+                args.extend(path("file", &bundle.file));
+                args.extend(path("preamble", &bundle.preamble));
+                args.extend(path("postamble", &bundle.postamble));
+                args
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// toml points unknown fields at the start of their table, point at the
+/// line of the key itself instead where it can be found
+fn locate_unknown_field(text: &str, message: String) -> String {
+    let key = match message
+        .strip_prefix("unknown field `")
+        .and_then(|rest| rest.split('`').next())
+    {
+        Some(key) => key,
+        None => return message,
+    };
+    let line = text.lines().position(|line| {
+        line.trim_start()
+            .strip_prefix(key)
+            .is_some_and(|rest| rest.trim_start().starts_with('='))
+    });
+    match (line, message.rfind(" at line ")) {
+        (Some(line), Some(at)) => format!("{} at line {}", &message[..at], line + 1),
+        _ => message,
+    }
+}
+
+fn flags(flags: &[(&'static str, bool)]) -> Vec<Arg> {
+    flags
+        .iter()
+        .filter(|(_, set)| *set)
+        .map(|&(name, _)| (name, None))
+        .collect()
+}
+
+fn value(name: &'static str, value: &Option<String>) -> Vec<Arg> {
+    value
+        .iter()
+        .map(|value| (name, Some(value.clone())))
+        .collect()
+}
+
+fn values(name: &'static str, values: &[String]) -> Vec<Arg> {
+    values
+        .iter()
+        .map(|value| (name, Some(value.clone())))
+        .collect()
+}
+
+fn path(name: &'static str, path: &Option<PathBuf>) -> Vec<Arg> {
+    path.iter()
+        .map(|path| (name, Some(path.display().to_string())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TempDir;
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let dir = TempDir::new();
+        let path = dir.write(
+            FILE_NAME,
+            "[check]\nlinkage = \"dynamic\"\nfail-onn = [\"copyleft\"]\n",
+        );
+        let err = Config::load(&path).unwrap_err().to_string();
+        assert!(
+            err.starts_with(&format!("Couldn't parse {}: ", path.display())),
+            "{}",
+            err
+        );
+        assert!(err.contains("unknown field `fail-onn`"), "{}", err);
+        assert!(err.contains("line 3"), "{}", err);
+    }
+
+    #[test]
+    fn paths_are_relative_to_the_file() {
+        let dir = TempDir::new();
+        let path = dir.write(
+            "ci/lichking.toml",
+            "[defaults]\noffline = true\n\n[bundle]\nvariant = \"markdown\"\nfile = \"THIRDPARTY.md\"\n",
+        );
+        let config = Config::load(&path).unwrap();
+        assert_eq!(Config::find(&dir.path().join("ci/nested")), Some(path));
+        assert_eq!(config.defaults(), [("offline", None)]);
+        assert_eq!(
+            config.subcommand("bundle"),
+            [
+                ("variant", Some("markdown".to_owned())),
+                (
+                    "file",
+                    Some(dir.path().join("ci/THIRDPARTY.md").display().to_string())
+                ),
+            ]
+        );
+        assert!(config.subcommand("list").is_empty());
+    }
+}
@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cargo_metadata::{DependencyKind, Package};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::discovery::{self, Confidence, DiscoveryConfig, LicenseText};
+use crate::license::License;
+use crate::load::ResolvedPackage;
+use crate::query::MetadataIndex;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Bom {
+    bom_format: &'static str,
+    spec_version: &'static str,
+    serial_number: String,
+    version: u32,
+    components: Vec<Component>,
+    dependencies: Vec<Dependency>,
+}
+
+#[derive(Serialize)]
+struct Component {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    name: String,
+    version: String,
+    purl: String,
+    licenses: Vec<LicenseChoice>,
+    /// Where the license texts are in the package's sources
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    properties: Vec<Property>,
+}
+
+#[derive(Serialize)]
+struct Property {
+    name: &'static str,
+    value: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum LicenseChoice {
+    License(LicenseEntry),
+    Expression(String),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct LicenseEntry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Dependency {
+    #[serde(rename = "ref")]
+    reference: String,
+    depends_on: Vec<String>,
+}
+
+fn purl(package: &Package) -> String {
+    format!("pkg:cargo/{}@{}", package.name, package.version)
+}
+
+/// An SPDX `LicenseRef-` for a license that isn't on the SPDX list, keeping
+/// only the characters allowed in an SPDX identifier
+fn license_ref(name: &str) -> String {
+    let name = name.strip_prefix("LicenseRef-").unwrap_or(name);
+    let name = name
+        .chars()
+        .map(|c| match c {
+            c if c.is_ascii_alphanumeric() || c == '.' || c == '-' => c,
+            _ => '-',
+        })
+        .collect::<String>();
+    format!("LicenseRef-{}", name.trim_matches('-'))
+}
+
+/// Renders a license as an SPDX expression, licenses without an SPDX
+/// identifier are referred to by a `LicenseRef-`
+fn expression(license: &License) -> String {
+    let operand = |license: &License| match license {
+        License::Multiple(_) | License::All(_) => format!("({})", expression(license)),
+        license => expression(license),
+    };
+    match license {
+        License::Multiple(licenses) => licenses
+            .iter()
+            .map(|license| match license {
+                License::Multiple(_) => expression(license),
+                license => operand(license),
+            })
+            .collect::<Vec<_>>()
+            .join(" OR "),
+        License::All(licenses) => licenses
+            .iter()
+            .map(|license| match license {
+                License::All(_) => expression(license),
+                license => operand(license),
+            })
+            .collect::<Vec<_>>()
+            .join(" AND "),
+        License::WithException { base, exception } => {
+            format!("{} WITH {}", operand(base), exception)
+        }
+        License::Custom(name) => license_ref(name),
+        License::File(path) => license_ref(
+            &path
+                .file_name()
+                .unwrap_or_else(|| path.as_os_str())
+                .to_string_lossy(),
+        ),
+        License::Unspecified => "NOASSERTION".to_owned(),
+        license => license.spdx_id().expect("on the SPDX list").to_owned(),
+    }
+}
+
+fn licenses(license: &License) -> Vec<LicenseChoice> {
+    match license {
+        License::Unspecified => Vec::new(),
+        License::Multiple(_) | License::All(_) | License::WithException { .. } => {
+            vec![LicenseChoice::Expression(expression(license))]
+        }
+        License::Custom(_) | License::File(_) => vec![LicenseChoice::License(LicenseEntry {
+            id: None,
+            name: Some(license.to_string()),
+        })],
+        license => vec![LicenseChoice::License(LicenseEntry {
+            id: license.spdx_id().map(ToOwned::to_owned),
+            name: None,
+        })],
+    }
+}
+
+/// The best matching text found for each of the package's licenses that has
+/// a template to match against
+fn license_texts(package: &Package, license: &License) -> anyhow::Result<Vec<LicenseText>> {
+    if !discovery::source_available(package) {
+        return Ok(Vec::new());
+    }
+    let licenses = match license {
+        License::Multiple(licenses) | License::All(licenses) => licenses.iter().collect(),
+        license => vec![license],
+    };
+    let config = DiscoveryConfig::default();
+    let mut texts = Vec::<LicenseText>::new();
+    for license in licenses {
+        if license.template().is_none() {
+            continue;
+        }
+        let mut candidates = discovery::find_license_text(package, license, &config)?;
+        candidates.extend(discovery::find_generic_license_text(
+            package, license, &config,
+        )?);
+        let best = [Confidence::Confident, Confidence::SemiConfident]
+            .iter()
+            .find_map(|confidence| {
+                candidates
+                    .iter()
+                    .position(|text| text.confidence == *confidence)
+            });
+        if let Some(best) = best {
+            let text = candidates.swap_remove(best);
+            if !texts.iter().any(|other| other.path == text.path) {
+                texts.push(text);
+            }
+        }
+    }
+    Ok(texts)
+}
+
+/// A `cargo-lichking:license-text` property for each of the package's license
+/// texts, its path relative to the package and the SHA-256 of the file
+fn properties(package: &Package, license: &License) -> anyhow::Result<Vec<Property>> {
+    let dir = discovery::package_dir(package)?;
+    Ok(license_texts(package, license)?
+        .into_iter()
+        .filter_map(|text| {
+            let (_, sha256) = text.digest()?;
+            let path = text.path.strip_prefix(dir).unwrap_or(&text.path);
+            Some(Property {
+                name: "cargo-lichking:license-text",
+                value: format!("{} sha256:{}", path.display(), sha256),
+            })
+        })
+        .collect())
+}
+
+/// Formats the first 16 bytes of a digest as a version 4 variant 1 UUID URN
+fn serial_number(digest: &[u8]) -> String {
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    format!(
+        "urn:uuid:{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+pub fn run(
+    index: &MetadataIndex,
+    resolved: &[ResolvedPackage],
+    reproducible: bool,
+) -> anyhow::Result<()> {
+    let resolved = {
+        let mut resolved = resolved.iter().collect::<Vec<_>>();
+        resolved.sort_by_key(|r| (&r.package.name, &r.package.version));
+        resolved
+    };
+    let packages = resolved.iter().map(|r| r.package).collect::<Vec<_>>();
+
+    let included = packages
+        .iter()
+        .map(|p| (&p.id, *p))
+        .collect::<HashMap<_, _>>();
+
+    let components = resolved
+        .iter()
+        .map(|resolved| {
+            Ok(Component {
+                kind: "library",
+                bom_ref: purl(resolved.package),
+                name: resolved.package.name.clone(),
+                version: resolved.package.version.to_string(),
+                purl: purl(resolved.package),
+                licenses: licenses(&resolved.license.license),
+                properties: properties(resolved.package, &resolved.license.license)?,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let dependencies = packages
+        .iter()
+        .map(|package| {
+            let mut depends_on = index
+                .deps(&package.id)?
+                .iter()
+                .filter(|dep| {
+                    dep.dep_kinds
+                        .iter()
+                        .any(|info| info.kind == DependencyKind::Normal)
+                })
+                .filter_map(|dep| included.get(&dep.pkg))
+                .map(|p| purl(p))
+                .collect::<Vec<_>>();
+            depends_on.sort();
+            Ok(Dependency {
+                reference: purl(package),
+                depends_on,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(serde_json::to_vec(&(&components, &dependencies))?);
+    if !reproducible {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        hasher.update(now.as_nanos().to_le_bytes());
+        hasher.update(process::id().to_le_bytes());
+    }
+
+    let bom = Bom {
+        bom_format: "CycloneDX",
+        spec_version: "1.5",
+        serial_number: serial_number(&hasher.finalize()),
+        version: 1,
+        components,
+        dependencies,
+    };
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    serde_json::to_writer_pretty(&mut out, &bom)?;
+    writeln!(out)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn choices(license: &str) -> Vec<LicenseChoice> {
+        licenses(&license.parse().unwrap())
+    }
+
+    fn expressions(license: &str) -> String {
+        match choices(license).as_slice() {
+            [LicenseChoice::Expression(expression)] => expression.clone(),
+            choices => panic!("{} gave {:?}", license, choices),
+        }
+    }
+
+    #[test]
+    fn single_licenses() {
+        assert_eq!(
+            choices("MIT"),
+            [LicenseChoice::License(LicenseEntry {
+                id: Some("MIT".to_owned()),
+                name: None,
+            })]
+        );
+        assert_eq!(
+            choices("My Own License"),
+            [LicenseChoice::License(LicenseEntry {
+                id: None,
+                name: Some("My Own License".to_owned()),
+            })]
+        );
+        assert_eq!(choices(""), []);
+    }
+
+    #[test]
+    fn expressions_are_valid_spdx() {
+        for (license, expected) in &[
+            ("MIT OR Apache-2.0", "MIT OR Apache-2.0"),
+            ("MIT/Apache-2.0", "MIT OR Apache-2.0"),
+            ("MIT AND CC-BY-4.0", "MIT AND CC-BY-4.0"),
+            (
+                "(MIT OR Apache-2.0) AND GPL-3.0",
+                "GPL-3.0-only AND (MIT OR Apache-2.0)",
+            ),
+            (
+                "MIT AND CC-BY-4.0 OR Apache-2.0",
+                "Apache-2.0 OR (MIT AND CC-BY-4.0)",
+            ),
+            (
+                "Apache-2.0 WITH LLVM-exception OR MIT",
+                "MIT OR Apache-2.0 WITH LLVM-exception",
+            ),
+            (
+                "(MIT OR Apache-2.0) WITH LLVM-exception",
+                "(MIT OR Apache-2.0) WITH LLVM-exception",
+            ),
+            ("MIT OR My Own License", "MIT OR LicenseRef-My-Own-License"),
+            (
+                "MIT AND LicenseRef-Proprietary",
+                "MIT AND LicenseRef-Proprietary",
+            ),
+        ] {
+            assert_eq!(&expressions(license), expected, "{}", license);
+        }
+    }
+
+    #[test]
+    fn files_are_license_refs() {
+        let license = License::all(vec![
+            License::MIT,
+            License::File("vendor/LICENSE (old).txt".into()),
+        ]);
+        assert_eq!(
+            licenses(&license),
+            [LicenseChoice::Expression(
+                "MIT AND LicenseRef-LICENSE--old-.txt".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        for license in &[
+            "MIT",
+            "My Own License",
+            "MIT OR Apache-2.0",
+            "(MIT OR Apache-2.0) AND GPL-3.0",
+            "Apache-2.0 WITH LLVM-exception OR My Own License",
+        ] {
+            let choices = choices(license);
+            let json = serde_json::to_string(&choices).unwrap();
+            let parsed: Vec<LicenseChoice> = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, choices, "{}", json);
+
+            // Reading the expression back gives the same license
+            if let [LicenseChoice::Expression(expression)] = parsed.as_slice() {
+                assert_eq!(&expressions(expression), expression);
+            }
+        }
+    }
+}
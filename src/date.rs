@@ -0,0 +1,116 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A calendar date in UTC, as the number of days since the Unix epoch
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date(i64);
+
+impl Date {
+    pub fn today() -> Date {
+        Date(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|now| now.as_secs() / 86400)
+                .unwrap_or(0) as i64,
+        )
+    }
+
+    /// How many days after `self` `other` is, negative if it's before
+    pub fn days_until(self, other: Date) -> i64 {
+        other.0 - self.0
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    /// Parses a `YYYY-MM-DD` date
+    fn from_str(s: &str) -> Result<Date, String> {
+        let invalid = || format!("'{}' is not a valid date in YYYY-MM-DD format", s);
+        let mut parts = s.splitn(3, '-');
+        let mut part = |len: usize| {
+            parts
+                .next()
+                .filter(|part| part.len() == len)
+                .and_then(|part| part.parse::<i64>().ok())
+                .ok_or_else(invalid)
+        };
+        let (year, month, day) = (part(4)?, part(2)?, part(2)?);
+        // Howard Hinnant's days_from_civil
+        let y = if month <= 2 { year - 1 } else { year };
+        let era = y.div_euclid(400);
+        let yoe = y.rem_euclid(400);
+        let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146_097 + doe - 719_468;
+        // Months and days out of range end up as a different date
+        if date(days) != s {
+            return Err(invalid());
+        }
+        Ok(Date(days))
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&date(self.0))
+    }
+}
+
+/// Formats a number of days since the Unix epoch as `YYYY-MM-DD`
+pub fn date(days: i64) -> String {
+    // Howard Hinnant's civil_from_days
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates() {
+        for valid in &["1970-01-01", "2024-02-29", "2000-02-29", "2025-12-31"] {
+            assert_eq!(valid.parse::<Date>().unwrap().to_string(), *valid);
+        }
+        for invalid in &[
+            "2023-02-29",
+            "1900-02-29",
+            "2025-04-31",
+            "2025-13-01",
+            "2025-00-10",
+            "2025-6-30",
+            "25-06-30",
+            "2025-06-30T00:00",
+            "soon",
+            "",
+        ] {
+            assert!(invalid.parse::<Date>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn days_between_dates() {
+        let days = |from: &str, to: &str| {
+            from.parse::<Date>()
+                .unwrap()
+                .days_until(to.parse().unwrap())
+        };
+        assert_eq!(days("2025-06-30", "2025-06-30"), 0);
+        assert_eq!(days("2025-06-30", "2025-07-01"), 1);
+        assert_eq!(days("2025-07-01", "2025-06-30"), -1);
+        assert_eq!(days("2024-02-28", "2024-03-01"), 2);
+        assert_eq!(days("2024-12-31", "2025-01-01"), 1);
+        assert_eq!(days("2025-01-01", "2026-01-01"), 365);
+        assert_eq!(days("1970-01-01", "2000-01-01"), 10_957);
+    }
+}
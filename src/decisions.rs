@@ -0,0 +1,239 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use cargo_metadata::Package;
+use semver::VersionReq;
+use serde::{Deserialize, Serialize};
+
+use crate::date::Date;
+use crate::license::License;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Allow,
+    Deny,
+}
+
+/// A recorded human decision about including a package whose license is not
+/// known to be compatible
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Decision {
+    pub package: String,
+    /// Which versions of the package this applies to, recording decisions
+    /// against a caret requirement means a major bump needs a new review
+    pub version_req: String,
+    pub verdict: Verdict,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decided_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DecisionsFile {
+    #[serde(default)]
+    decision: Vec<Decision>,
+}
+
+pub struct Decisions {
+    path: PathBuf,
+    decisions: Vec<(VersionReq, Decision)>,
+    interactive: bool,
+}
+
+impl Decisions {
+    /// Loads the decisions file, a missing file is treated as having no
+    /// decisions yet
+    pub fn load(path: &str, non_interactive: bool) -> anyhow::Result<Decisions> {
+        let file: DecisionsFile = match fs::read(path) {
+            Ok(bytes) => toml::from_slice(&bytes)
+                .map_err(|err| anyhow!("Couldn't parse decisions file {}: {}", path, err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => DecisionsFile::default(),
+            Err(err) => return Err(anyhow!("Couldn't read decisions file {}: {}", path, err)),
+        };
+        let decisions = file
+            .decision
+            .into_iter()
+            .map(|decision| {
+                let req = VersionReq::parse(&decision.version_req).map_err(|err| {
+                    anyhow!(
+                        "Invalid version_req {:?} for {} in {}: {}",
+                        decision.version_req,
+                        decision.package,
+                        path,
+                        err
+                    )
+                })?;
+                Ok((req, decision))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Decisions {
+            path: PathBuf::from(path),
+            decisions,
+            interactive: !non_interactive && io::stdin().is_terminal(),
+        })
+    }
+
+    /// Finds the most recent decision matching this package
+    pub fn find(&self, package: &Package) -> Option<&Decision> {
+        self.decisions
+            .iter()
+            .rev()
+            .find(|(req, decision)| {
+                decision.package == package.name && req.matches(&package.version)
+            })
+            .map(|(_, decision)| decision)
+    }
+
+    /// Asks the user for a decision when running interactively, recording the
+    /// answer to the decisions file, returns `None` if they skipped or we
+    /// couldn't ask
+    pub fn prompt(
+        &mut self,
+        root: &Package,
+        package: &Package,
+        license: &License,
+    ) -> anyhow::Result<Option<Verdict>> {
+        if !self.interactive {
+            return Ok(None);
+        }
+
+        let verdict = loop {
+            eprint!(
+                "Allow {} to include {} {} licensed under {}? [y/n/skip] ",
+                root.name, package.name, package.version, license
+            );
+            io::stderr().flush()?;
+            let mut answer = String::new();
+            if io::stdin().lock().read_line(&mut answer)? == 0 {
+                return Ok(None);
+            }
+            match answer.trim() {
+                "y" | "yes" => break Verdict::Allow,
+                "n" | "no" => break Verdict::Deny,
+                "s" | "skip" | "" => return Ok(None),
+                _ => continue,
+            }
+        };
+
+        let version = &package.version;
+        let decision = Decision {
+            package: package.name.clone(),
+            version_req: format!("^{}.{}.{}", version.major, version.minor, version.patch),
+            verdict,
+            reason: Some(format!("{} is licensed under {}", package.name, license)),
+            decided_by: std::env::var("USER").ok(),
+            date: Some(Date::today().to_string()),
+        };
+        let record = toml::to_string(&DecisionsFile {
+            decision: vec![decision.clone()],
+        })?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file)?;
+        file.write_all(record.as_bytes())?;
+
+        let req = VersionReq::parse(&decision.version_req)?;
+        self.decisions.push((req, decision));
+        Ok(Some(verdict))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::testutil::{package, TempDir};
+
+    const DECISIONS: &str = r#"
+[[decision]]
+package = "ring"
+version_req = "^0.16"
+verdict = "allow"
+reason = "Reviewed by legal"
+decided_by = "someone"
+date = "2025-01-01"
+
+[[decision]]
+package = "ring"
+version_req = "=0.16.20"
+verdict = "deny"
+
+[[decision]]
+package = "openssl"
+version_req = "*"
+verdict = "deny"
+"#;
+
+    #[test]
+    fn loading() {
+        let dir = TempDir::new();
+        let path = dir.write("decisions.toml", DECISIONS);
+        let decisions = Decisions::load(path.to_str().unwrap(), true).unwrap();
+        assert_eq!(decisions.decisions.len(), 3);
+        let (_, ring) = &decisions.decisions[0];
+        assert_eq!(ring.verdict, Verdict::Allow);
+        assert_eq!(ring.reason.as_deref(), Some("Reviewed by legal"));
+        assert_eq!(ring.decided_by.as_deref(), Some("someone"));
+        assert!(!decisions.interactive);
+
+        // Nothing has been decided before the first run
+        let missing = dir.path().join("missing.toml");
+        let decisions = Decisions::load(missing.to_str().unwrap(), true).unwrap();
+        assert!(decisions.decisions.is_empty());
+
+        let path = dir.write(
+            "invalid.toml",
+            "[[decision]]\npackage = \"ring\"\nversion_req = \"one\"\nverdict = \"allow\"\n",
+        );
+        let err = Decisions::load(path.to_str().unwrap(), true).err().unwrap();
+        assert!(err.to_string().contains("Invalid version_req"), "{}", err);
+
+        let path = dir.write(
+            "unknown.toml",
+            "[[decision]]\npackage = \"ring\"\nversion_req = \"*\"\nverdict = \"maybe\"\n",
+        );
+        let err = Decisions::load(path.to_str().unwrap(), true).err().unwrap();
+        assert!(err.to_string().contains("Couldn't parse"), "{}", err);
+    }
+
+    #[test]
+    fn decisions_match_semver_requirements() {
+        let dir = TempDir::new();
+        let path = dir.write("decisions.toml", DECISIONS);
+        let mut decisions = Decisions::load(path.to_str().unwrap(), true).unwrap();
+        let nowhere = Path::new("/nonexistent");
+        let verdict = |name: &str, version: &str| {
+            decisions
+                .find(&package(name, version, Some("ISC"), nowhere))
+                .map(|decision| decision.verdict)
+        };
+        assert_eq!(verdict("ring", "0.16.0"), Some(Verdict::Allow));
+        assert_eq!(verdict("ring", "0.16.19"), Some(Verdict::Allow));
+        // Later decisions take precedence
+        assert_eq!(verdict("ring", "0.16.20"), Some(Verdict::Deny));
+        // A major bump needs a new review
+        assert_eq!(verdict("ring", "0.17.0"), None);
+        assert_eq!(verdict("openssl", "10.0.0"), Some(Verdict::Deny));
+        assert_eq!(verdict("rustls", "0.16.0"), None);
+
+        // Non-interactive runs never ask, leaving the package undecided
+        let root = package("root", "1.0.0", Some("MIT"), nowhere);
+        let ring = package("ring", "0.17.0", Some("ISC"), nowhere);
+        assert_eq!(
+            decisions
+                .prompt(&root, &ring, &License::Custom("ISC".to_owned()))
+                .unwrap(),
+            None
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), DECISIONS);
+    }
+}
@@ -1,26 +1,363 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 
+use anyhow::{anyhow, Context};
 use cargo_metadata::Package;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
-use crate::license::License;
+use crate::license::{License, TemplateStore, KNOWN};
 
-const HIGH_CONFIDENCE_LIMIT: f32 = 0.10;
-const LOW_CONFIDENCE_LIMIT: f32 = 0.15;
+#[derive(Clone, Debug)]
+pub struct DiscoveryConfig {
+    /// Texts scoring below this are considered a confident match
+    pub confident: f32,
+    /// Texts scoring below this (but not below `confident`) are considered a
+    /// semi-confident match, anything else is unsure
+    pub semi_confident: f32,
+    /// How many packages to discover license texts for in parallel, defaults
+    /// to the number of cores
+    pub jobs: Option<usize>,
+    /// Candidate files larger than this many bytes are skipped, no license
+    /// text comes close but generated aggregates can be huge
+    pub max_file_size: u64,
+    /// How many directory entries to look at per package when searching the
+    /// subdirectories of path and git packages, their directories may hold
+    /// a huge `target`
+    pub max_entries: usize,
+    /// Custom templates from `--license-templates`, taking precedence over
+    /// the bundled ones
+    pub templates: Option<Arc<TemplateStore>>,
+}
 
-#[derive(Debug, Eq, PartialEq)]
+impl Default for DiscoveryConfig {
+    fn default() -> DiscoveryConfig {
+        DiscoveryConfig {
+            confident: 0.10,
+            semi_confident: 0.15,
+            jobs: None,
+            max_file_size: 4 * 1024 * 1024,
+            max_entries: 5000,
+            templates: None,
+        }
+    }
+}
+
+impl DiscoveryConfig {
+    /// The threshold that was relevant in deciding on `confidence`: the one it
+    /// beat if confident, otherwise the one it missed
+    pub fn threshold(&self, confidence: &Confidence) -> f32 {
+        match confidence {
+            Confidence::Confident | Confidence::SemiConfident => self.confident,
+            Confidence::Unsure => self.semi_confident,
+        }
+    }
+
+    /// The text that texts of the license are scored against, a custom
+    /// template takes precedence over the bundled one
+    pub fn template(&self, license: &License) -> Option<&str> {
+        if let License::WithException { base, .. } = license {
+            return self.template(base);
+        }
+        self.templates
+            .as_ref()
+            .and_then(|store| store.get(license))
+            .or_else(|| license.template())
+    }
+
+    fn confidence(&self, score: f32) -> Confidence {
+        if score < self.confident {
+            Confidence::Confident
+        } else if score < self.semi_confident {
+            Confidence::SemiConfident
+        } else {
+            Confidence::Unsure
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Confidence {
     Confident,
     SemiConfident,
     Unsure,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Origin {
+    /// The whole file is the license text
+    LicenseFile,
+    /// The text was extracted from a section of a README
+    Readme,
+    /// The text is the given byte range of a file containing several licenses
+    Segment { start: usize, end: usize },
+    /// The text was extracted from an HTML file by stripping its markup
+    Html,
+    /// The standard text of a license inferred from the source file's
+    /// `SPDX-License-Identifier` header, the package has no text of its own
+    Template,
+}
+
+#[derive(Clone)]
 pub struct LicenseText {
     pub path: PathBuf,
+    pub origin: Origin,
     pub text: String,
     pub confidence: Confidence,
+    /// Proportion of words differing from the template (capped in length for
+    /// long templates), `None` if there was no template to compare against
+    pub score: Option<f32>,
+    /// The whole file the text was read from, exactly as it was read, `None`
+    /// for standard texts which aren't read from the package
+    pub bytes: Option<Arc<[u8]>>,
+}
+
+impl LicenseText {
+    /// The size and SHA-256 of the whole file the text was read from, of the
+    /// bytes discovery read rather than whatever the file holds by now
+    pub fn digest(&self) -> Option<(u64, String)> {
+        let bytes = self.bytes.as_ref()?;
+        Some((bytes.len() as u64, format!("{:x}", Sha256::digest(bytes))))
+    }
+}
+
+/// What discovery made of a single file it looked at
+#[derive(Debug)]
+pub enum Decision {
+    /// Not named like a generic license file, e.g. `LICENSE` or `COPYING`
+    NotGeneric,
+    /// Not named after the license being looked for
+    WrongName(String),
+    /// A symlink to, or otherwise the same file as, an earlier candidate
+    SameFile,
+    Unreadable(String),
+    /// Can't be a useful license text
+    Skipped(SkipReason),
+    /// A directory that isn't searched, e.g. build output
+    Pruned(&'static str),
+    /// Compared against the license's template
+    Scored {
+        /// How the file was found, e.g. by a generic name
+        search: &'static str,
+        license: String,
+        confidence: Confidence,
+        score: Option<f32>,
+        /// Not valid UTF-8, so read lossily
+        lossy: bool,
+    },
+}
+
+impl std::fmt::Display for Decision {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Decision::NotGeneric => write!(f, "skipped, not a generic license file name"),
+            Decision::WrongName(license) => write!(f, "skipped, not named after {}", license),
+            Decision::SameFile => write!(f, "skipped, the same file as another candidate"),
+            Decision::Unreadable(err) => write!(f, "skipped, unreadable: {}", err),
+            Decision::Skipped(reason) => write!(f, "skipped, {}", reason),
+            Decision::Pruned(reason) => write!(f, "not searched, {}", reason),
+            Decision::Scored {
+                search,
+                license,
+                confidence,
+                score,
+                lossy,
+            } => {
+                write!(f, "{} candidate for {}, {:?}", search, license, confidence)?;
+                match score {
+                    Some(score) => write!(f, " with score {:.2}", score)?,
+                    None => write!(f, " as there is no template to compare against")?,
+                }
+                if *lossy {
+                    write!(f, " (not valid UTF-8, read lossily)")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Why a license file was passed over without being compared to a template
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum SkipReason {
+    TooLarge {
+        size: u64,
+        limit: u64,
+    },
+    /// Contains NUL bytes, e.g. a PDF
+    Binary,
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SkipReason::TooLarge { size, limit } => {
+                write!(f, "{} bytes is over the {} byte limit", size, limit)
+            }
+            SkipReason::Binary => write!(f, "binary content"),
+        }
+    }
+}
+
+/// A license file the package ships that couldn't be used as its text
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SkippedFile {
+    pub path: PathBuf,
+    pub reason: SkipReason,
+}
+
+/// A record of every file discovery considered and which texts were chosen,
+/// only kept when created with `DiscoveryTrace::new` so that normal discovery
+/// doesn't pay for it. Skipped files are always kept, they are reported as
+/// issues with the package.
+#[derive(Debug, Default)]
+pub struct DiscoveryTrace {
+    enabled: bool,
+    pub skipped: Vec<SkippedFile>,
+    pub files: Vec<(PathBuf, Decision)>,
+    /// Each license part along with the chosen file, if any, and why
+    pub choices: Vec<(String, Option<PathBuf>, String)>,
+    /// The most directory entries a single search looked at
+    pub entries: usize,
+    /// Whether a search stopped at `DiscoveryConfig::max_entries`
+    pub capped: bool,
+}
+
+impl DiscoveryTrace {
+    pub fn new() -> DiscoveryTrace {
+        DiscoveryTrace {
+            enabled: true,
+            ..DiscoveryTrace::default()
+        }
+    }
+
+    fn file(&mut self, path: &Path, decision: impl FnOnce() -> Decision) {
+        if self.enabled {
+            self.files.push((path.to_owned(), decision()));
+        }
+    }
+
+    fn skip(&mut self, path: &Path, reason: SkipReason) {
+        log::debug!("skipping {}, {}", path.display(), reason);
+        self.file(path, || Decision::Skipped(reason));
+        let skipped = SkippedFile {
+            path: path.to_owned(),
+            reason,
+        };
+        if !self.skipped.contains(&skipped) {
+            self.skipped.push(skipped);
+        }
+    }
+
+    fn examined(&mut self, entries: usize, capped: bool) {
+        self.entries = self.entries.max(entries);
+        self.capped |= capped;
+    }
+
+    fn scored(&mut self, search: &'static str, text: &LicenseText, license: &License, lossy: bool) {
+        self.file(&text.path, || Decision::Scored {
+            search,
+            license: license.to_string(),
+            confidence: text.confidence,
+            score: text.score,
+            lossy,
+        });
+    }
+
+    pub fn choice(
+        &mut self,
+        license: &License,
+        text: Option<&LicenseText>,
+        reason: impl FnOnce() -> String,
+    ) {
+        if self.enabled {
+            self.choices.push((
+                license.to_string(),
+                text.map(|text| text.path.clone()),
+                reason(),
+            ));
+        }
+    }
+}
+
+/// Problems with a license text that a good template score doesn't rule out
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TextIssue {
+    /// Much shorter than the license template, likely truncated or just the
+    /// license name
+    Short { length: usize, expected: usize },
+    /// Still contains a placeholder from the license template
+    Placeholder(&'static str),
+    /// Only refers to the license text elsewhere rather than including it
+    Pointer,
+}
+
+impl std::fmt::Display for TextIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TextIssue::Short { length, expected } => write!(
+                f,
+                "is only {} bytes long, expected around {}",
+                length, expected
+            ),
+            TextIssue::Placeholder(placeholder) => {
+                write!(f, "contains the unreplaced placeholder {}", placeholder)
+            }
+            TextIssue::Pointer => write!(f, "only refers to a license elsewhere"),
+        }
+    }
+}
+
+/// Sanity checks a chosen license text
+pub fn text_issues(text: &str, license: &License, config: &DiscoveryConfig) -> Vec<TextIssue> {
+    const PLACEHOLDERS: &[&str] = &[
+        "[yyyy]",
+        "[name of copyright owner]",
+        "<year>",
+        "<copyright holder>",
+        "<copyright holders>",
+        "{{",
+    ];
+
+    let mut issues = Vec::new();
+
+    let template_len = templates(license, config)
+        .and_then(|templates| templates.into_iter().map(|t| required_terms(t).len()).min());
+    if let Some(expected) = template_len {
+        if (text.len() as f32) < (expected as f32) * 0.3 {
+            issues.push(TextIssue::Short {
+                length: text.len(),
+                expected,
+            });
+        }
+    }
+
+    // The Apache appendix explaining how to apply the license legitimately
+    // contains placeholders, so only look at the terms themselves
+    let lower = text.to_lowercase();
+    let terms = lower
+        .find("end of terms and conditions")
+        .map_or(&*lower, |end| &lower[..end]);
+    if let Some(placeholder) = PLACEHOLDERS
+        .iter()
+        .find(|placeholder| terms.contains(*placeholder))
+    {
+        issues.push(TextIssue::Placeholder(placeholder));
+    }
+
+    static POINTER: OnceLock<Regex> = OnceLock::new();
+    let pointer = POINTER
+        .get_or_init(|| Regex::new(r"(?is)\b(see|refer to|found in)\b.*\blicen[cs]e").unwrap());
+    if text.trim().len() < 300 && pointer.is_match(text) {
+        issues.push(TextIssue::Pointer);
+    }
+
+    issues
 }
 
 fn add_frequencies(freq: &mut HashMap<String, u32>, text: &str) {
@@ -37,118 +374,2054 @@ fn calculate_frequency(text: &str) -> HashMap<String, u32> {
     freq
 }
 
-fn compare(mut text_freq: HashMap<String, u32>, template_freq: &HashMap<String, u32>) -> u32 {
+fn compare(text_freq: &HashMap<String, u32>, template_freq: &HashMap<String, u32>) -> u32 {
     let mut errors = 0;
 
     for (word, &count) in template_freq {
-        let text_count = text_freq.remove(word).unwrap_or(0);
-        let diff = ((text_count as i32) - (count as i32)).abs() as u32;
+        let text_count = text_freq.get(word).copied().unwrap_or(0);
+        let diff = ((text_count as i32) - (count as i32)).unsigned_abs();
         errors += diff;
     }
 
-    for (_, count) in text_freq {
-        errors += count;
+    for (word, &count) in text_freq {
+        if !template_freq.contains_key(word) {
+            errors += count;
+        }
     }
 
     errors
 }
 
-fn check_against_template(text: &str, license: &License) -> Confidence {
-    let text_freq = calculate_frequency(text);
+/// Differences are counted relative to at most this many words, otherwise a
+/// few percent of a GPL sized text is enough to hide another license's extra
+/// sections, e.g. the AGPL's network clause
+const LONG_TEMPLATE_WORDS: u32 = 2000;
+
+/// The templates a text for `license` is made up of, `None` if any are
+/// missing
+fn templates<'a>(license: &License, config: &'a DiscoveryConfig) -> Option<Vec<&'a str>> {
+    match license {
+        License::Multiple(licenses) | License::All(licenses) => licenses
+            .iter()
+            .map(|license| config.template(license))
+            .collect(),
+        license => config.template(license).map(|template| vec![template]),
+    }
+}
 
-    let template_freq = if let License::Multiple(ref licenses) = *license {
+/// The part of a license template that's always included, the appendices
+/// after the terms explaining how to apply the license are often omitted
+fn required_terms(template: &str) -> &str {
+    template
+        .find("END OF TERMS AND CONDITIONS")
+        .map_or(template, |end| &template[..end])
+}
+
+/// The word frequencies of the templates both with and without their
+/// optional appendices
+fn template_frequencies(templates: &[&str]) -> [HashMap<String, u32>; 2] {
+    [false, true].map(|strip| {
         let mut template_freq = HashMap::new();
-        for license in licenses {
-            if let Some(template) = license.template() {
-                add_frequencies(&mut template_freq, template)
+        for template in templates {
+            let template = if strip {
+                required_terms(template)
             } else {
-                return Confidence::Unsure;
-            }
+                template
+            };
+            add_frequencies(&mut template_freq, template);
         }
         template_freq
-    } else if let Some(template) = license.template() {
-        calculate_frequency(template)
-    } else {
-        return Confidence::Unsure;
+    })
+}
+
+/// Scores a text against the template frequencies, going with whichever of
+/// them it is closest to
+fn score(text_freq: &HashMap<String, u32>, template_freqs: &[HashMap<String, u32>]) -> f32 {
+    template_freqs
+        .iter()
+        .map(|template_freq| {
+            let total: u32 = template_freq.values().sum();
+            let errors = compare(text_freq, template_freq);
+            (errors as f32) / (total.min(LONG_TEMPLATE_WORDS) as f32)
+        })
+        .fold(f32::INFINITY, f32::min)
+}
+
+pub fn check_against_template(
+    text: &str,
+    license: &License,
+    config: &DiscoveryConfig,
+) -> (Confidence, Option<f32>) {
+    let templates = match templates(license, config) {
+        Some(templates) => templates,
+        None => return (Confidence::Unsure, None),
+    };
+    let text_freq = calculate_frequency(text);
+    let mut best = score(&text_freq, &template_frequencies(&templates));
+    // A look-alike's text is as good as the license's own, e.g. the MIT text
+    // for a package declaring X11
+    for similar in license.similarity_group().into_iter().flatten() {
+        if let Some(template) = config.template(similar) {
+            best = best.min(score(&text_freq, &template_frequencies(&[template])));
+        }
+    }
+    (config.confidence(best), Some(best))
+}
+
+/// Whether texts of `found` are expected for a package licensed under
+/// `declared`, either they share a template, are in the same similarity group
+/// or the LGPL-3.0 which is an addition to the GPL-3.0 and commonly comes
+/// with its text
+pub fn same_text(declared: &License, found: &License) -> bool {
+    let lgpl_3 = matches!(declared, License::LGPL_3_0 | License::LGPL_3_0Plus);
+    declared.template() == found.template()
+        || declared
+            .similarity_group()
+            .is_some_and(|group| group.contains(found))
+        || lgpl_3 && found.template() == License::GPL_3_0.template()
+}
+
+/// Scores the text against the template of every known license that has one,
+/// without their look-alikes
+pub fn template_scores(text: &str, config: &DiscoveryConfig) -> Vec<(&'static License, f32)> {
+    static FREQUENCIES: OnceLock<Vec<[HashMap<String, u32>; 2]>> = OnceLock::new();
+    let frequencies = FREQUENCIES.get_or_init(|| {
+        KNOWN
+            .iter()
+            .map(|license| template_frequencies(license.template().as_slice()))
+            .collect()
+    });
+    let text_freq = calculate_frequency(text);
+    let custom = |license| config.templates.as_ref()?.get(license);
+    KNOWN
+        .iter()
+        .zip(frequencies)
+        .filter_map(|(license, template_freqs)| {
+            let score = match custom(license) {
+                Some(template) => score(&text_freq, &template_frequencies(&[template])),
+                None if license.template().is_some() => score(&text_freq, template_freqs),
+                None => return None,
+            };
+            Some((license, score))
+        })
+        .collect()
+}
+
+/// Finds the known license whose template best matches the text along with
+/// its score
+fn best_match(text: &str, config: &DiscoveryConfig) -> Option<(&'static License, f32)> {
+    template_scores(text, config)
+        .into_iter()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+}
+
+/// Looks for a generic license file in the package that doesn't match its
+/// declared license but confidently matches a different known license,
+/// returning the file and the license it matches
+pub fn contradicting_license_text(
+    package: &Package,
+    license: &License,
+    config: &DiscoveryConfig,
+    trace: &mut DiscoveryTrace,
+) -> anyhow::Result<Option<(LicenseText, &'static License)>> {
+    let declared = match license {
+        License::Multiple(licenses) | License::All(licenses) => licenses.iter().collect(),
+        License::WithException { base, .. } => vec![&**base],
+        license => vec![license],
     };
+    // Without a template we can't tell whether a text belongs to the license
+    if !source_available(package)
+        || declared
+            .iter()
+            .all(|license| config.template(license).is_none())
+    {
+        return Ok(None);
+    }
+    let text = match find_generic_license_text_traced(package, license, config, trace)? {
+        Some(text) if text.confidence == Confidence::Unsure => text,
+        _ => return Ok(None),
+    };
+    match best_match(&text.text, config) {
+        Some((found, score))
+            if config.confidence(score) == Confidence::Confident
+                && !declared.iter().any(|declared| same_text(declared, found)) =>
+        {
+            log::debug!(
+                "{} matched {} with score {:.2} instead of {}",
+                text.path.display(),
+                found,
+                score,
+                license
+            );
+            Ok(Some((text, found)))
+        }
+        _ => Ok(None),
+    }
+}
 
-    let total: u32 = template_freq.values().sum();
-    let errors = compare(text_freq, &template_freq);
-    let score = (errors as f32) / (total as f32);
+/// Splits a file containing several license texts one after another, e.g. the
+/// Apache-2.0 and MIT texts concatenated into a single `LICENSE`, into a text
+/// for each of the licenses in a `License::Multiple` or `License::All`. Returns
+/// `None` unless every license confidently matches its own part of the file.
+pub fn split_license_text(
+    text: &LicenseText,
+    license: &License,
+    config: &DiscoveryConfig,
+) -> Option<Vec<LicenseText>> {
+    let licenses = match license {
+        License::Multiple(licenses) | License::All(licenses) => licenses,
+        _ => return None,
+    };
+
+    // Each license starts at some paragraph, they're separated by blank lines
+    // or rules made of `=`, `-` etc.
+    let mut starts = vec![0];
+    let mut offset = 0;
+    for line in text.text.split_inclusive('\n') {
+        offset += line.len();
+        let line = line.trim();
+        if line.is_empty() || line.len() >= 3 && line.chars().all(|c| "=-*_#~".contains(c)) {
+            starts.push(offset);
+        }
+    }
+    starts.push(text.text.len());
+    starts.dedup();
+    let paragraphs = starts
+        .windows(2)
+        .map(|range| calculate_frequency(&text.text[range[0]..range[1]]))
+        .collect::<Vec<_>>();
+
+    let mut segments = Vec::new();
+    for license in licenses {
+        let template_freqs = template_frequencies(&templates(license, config)?);
+        let mut best: Option<(f32, usize, usize)> = None;
+        for first in 0..paragraphs.len() {
+            let mut freq = HashMap::new();
+            for (last, paragraph) in paragraphs.iter().enumerate().skip(first) {
+                for (word, count) in paragraph {
+                    *freq.entry(word.clone()).or_insert(0) += count;
+                }
+                let score = score(&freq, &template_freqs);
+                // On a tie take in the optional appendices, but not rules
+                // or other paragraphs without any words
+                let better = |(best, _, _): (f32, usize, usize)| {
+                    score < best || score == best && !paragraph.is_empty()
+                };
+                if best.is_none_or(better) {
+                    best = Some((score, first, last + 1));
+                }
+            }
+        }
+        let (score, first, end) = best?;
+        log::debug!(
+            "{} best matched {} in bytes {}..{} with score {:.2}",
+            text.path.display(),
+            license,
+            starts[first],
+            starts[end],
+            score
+        );
+        if config.confidence(score) != Confidence::Confident {
+            return None;
+        }
+        segments.push((first, end, score));
+    }
 
-    if score < HIGH_CONFIDENCE_LIMIT {
-        Confidence::Confident
-    } else if score < LOW_CONFIDENCE_LIMIT {
-        Confidence::SemiConfident
+    let mut ranges = segments
+        .iter()
+        .map(|&(first, end, _)| (first, end))
+        .collect::<Vec<_>>();
+    ranges.sort_unstable();
+    if ranges.windows(2).any(|pair| pair[0].1 > pair[1].0) {
+        log::debug!(
+            "{} licenses matched overlapping parts, not splitting it",
+            text.path.display()
+        );
+        return None;
+    }
+
+    // The copyright notice heading a text isn't part of its template, keep it
+    // with the license unless it belongs to the text before
+    let mentions_copyright = |paragraph: usize| {
+        text.text[starts[paragraph]..starts[paragraph + 1]]
+            .to_lowercase()
+            .contains("copyright")
+    };
+    for (first, _, _) in &mut segments {
+        let previous = ranges
+            .iter()
+            .map(|&(_, end)| end)
+            .filter(|&end| end <= *first)
+            .max()
+            .unwrap_or(0);
+        while *first > previous && mentions_copyright(*first - 1) {
+            *first -= 1;
+        }
+    }
+
+    Some(
+        segments
+            .into_iter()
+            .map(|(first, end, score)| {
+                let segment = &text.text[starts[first]..starts[end]];
+                let start = starts[first] + (segment.len() - segment.trim_start().len());
+                (start, start + segment.trim().len(), score)
+            })
+            .map(|(start, end, score)| LicenseText {
+                path: text.path.clone(),
+                origin: Origin::Segment { start, end },
+                text: text.text[start..end].to_owned(),
+                confidence: Confidence::Confident,
+                score: Some(score),
+                bytes: text.bytes.clone(),
+            })
+            .collect(),
+    )
+}
+
+fn check_file(
+    path: PathBuf,
+    text: String,
+    bytes: Arc<[u8]>,
+    license: &License,
+    config: &DiscoveryConfig,
+) -> LicenseText {
+    let (confidence, score) = check_against_template(&text, license, config);
+    if let Some(score) = score {
+        log::debug!(
+            "{} matched {} with score {:.2} (threshold {:.2})",
+            path.display(),
+            license,
+            score,
+            config.threshold(&confidence)
+        );
+    } else {
+        log::debug!(
+            "{} has no template for {} to compare against",
+            path.display(),
+            license
+        );
+    }
+    LicenseText {
+        path,
+        origin: Origin::LicenseFile,
+        text,
+        confidence,
+        score,
+        bytes: Some(bytes),
+    }
+}
+
+fn check_candidate(
+    path: PathBuf,
+    candidate: Candidate,
+    license: &License,
+    config: &DiscoveryConfig,
+    search: &'static str,
+    trace: &mut DiscoveryTrace,
+) -> LicenseText {
+    let mut text = check_file(path, candidate.text, candidate.bytes, license, config);
+    if candidate.html {
+        text.origin = Origin::Html;
+    }
+    trace.scored(search, &text, license, candidate.lossy);
+    text
+}
+
+/// The entries of `dir` sorted by name, so that which candidates are found
+/// doesn't depend on the order the filesystem lists them in. Only an
+/// unreadable directory is an error, entries that can't be listed are skipped
+/// with a warning.
+fn read_dir(
+    package: &Package,
+    dir: &Path,
+    trace: &mut DiscoveryTrace,
+) -> anyhow::Result<Vec<fs::DirEntry>> {
+    let mut entries = fs::read_dir(dir)
+        .with_context(|| {
+            format!(
+                "Couldn't read source directory of {} {} at {}",
+                package.name,
+                package.version,
+                dir.display()
+            )
+        })?
+        .filter_map(|entry| match entry {
+            Ok(entry) => Some(entry),
+            Err(err) => {
+                log::warn!(
+                    "Couldn't list an entry of {}, skipping it: {}",
+                    dir.display(),
+                    err
+                );
+                trace.file(dir, || Decision::Unreadable(err.to_string()));
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by_key(|entry| entry.file_name());
+    Ok(entries)
+}
+
+/// Directories below a package's own that are never searched: build output,
+/// where `cargo package` leaves copies of the package's files, version control
+/// and other projects' dependencies. A package vendored into a `vendor`
+/// directory is still searched, only its subdirectories are matched by name.
+const PRUNED_DIRS: &[&str] = &["target", ".git", "node_modules", "vendor"];
+
+/// Why a subdirectory of a package isn't searched, if it isn't
+fn pruned(entry: &fs::DirEntry) -> Option<&'static str> {
+    // Symlinked directories aren't followed, they may form cycles
+    if !entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+        return None;
+    }
+    let name = entry.file_name();
+    if PRUNED_DIRS.iter().any(|pruned| name == **pruned) {
+        Some("build output, version control or dependencies")
+    } else if entry.path().join("Cargo.toml").exists() {
+        Some("a nested package")
     } else {
-        Confidence::Unsure
+        None
     }
 }
 
+/// The entries of the subdirectories of `dir` for path and git packages,
+/// grouped by depth, nearest first, `top` being the entries of `dir` itself.
+/// Registry packages are small and have their license files at the top, so
+/// only their directory itself is read. At most
+/// `DiscoveryConfig::max_entries` entries are examined including the top
+/// ones, with a warning when there are more.
+fn read_subdirs(
+    package: &Package,
+    dir: &Path,
+    top: &[fs::DirEntry],
+    config: &DiscoveryConfig,
+    trace: &mut DiscoveryTrace,
+) -> anyhow::Result<Vec<Vec<fs::DirEntry>>> {
+    let mut levels = Vec::new();
+    let mut examined = top.len();
+    let registry = package
+        .source
+        .as_ref()
+        .is_some_and(|source| !source.to_string().starts_with("git+"));
+    // Only the package's own directory, not the checkout root of a git
+    // dependency which may hold many other packages
+    if registry || dir != package_dir(package)? {
+        return Ok(levels);
+    }
+
+    let mut capped = false;
+    while !capped {
+        let mut next = Vec::new();
+        for entry in levels.last().map_or(top, Vec::as_slice) {
+            if !entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+                continue;
+            }
+            if let Some(reason) = pruned(entry) {
+                log::trace!("not searching {}, {}", entry.path().display(), reason);
+                trace.file(&entry.path(), || Decision::Pruned(reason));
+                continue;
+            }
+            let mut entries = match read_dir(package, &entry.path(), trace) {
+                Ok(entries) => entries,
+                Err(err) => {
+                    log::warn!("{:#}, skipping it", err);
+                    continue;
+                }
+            };
+            if examined + entries.len() > config.max_entries {
+                entries.truncate(config.max_entries.saturating_sub(examined));
+                capped = true;
+            }
+            examined += entries.len();
+            next.extend(entries);
+            if capped {
+                log::warn!(
+                    "Stopped searching {} {} for license files after {} entries in {}, some \
+                     subdirectories weren't searched",
+                    package.name,
+                    package.version,
+                    config.max_entries,
+                    dir.display()
+                );
+                break;
+            }
+        }
+        if next.is_empty() {
+            break;
+        }
+        levels.push(next);
+    }
+    trace.examined(examined, capped);
+    Ok(levels)
+}
+
+/// The directory containing the package's manifest, failing for a manifest
+/// path without one, e.g. a bare or drive relative (`C:Cargo.toml`) path from
+/// rewritten metadata
+pub fn package_dir(package: &Package) -> anyhow::Result<&Path> {
+    package
+        .manifest_path
+        .parent()
+        .filter(|dir| {
+            dir.components()
+                .any(|component| !matches!(component, Component::Prefix(_)))
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "{} {} manifest path {} has no parent directory to find its sources in",
+                package.name,
+                package.version,
+                package.manifest_path.display()
+            )
+        })
+}
+
+/// Whether the package's sources are available locally, they may not be if
+/// the registry cache was pruned or the dependencies haven't been fetched yet
+pub fn source_available(package: &Package) -> bool {
+    package_dir(package).is_ok_and(Path::is_dir)
+}
+
+/// Whether the package's manifest is a workspace's virtual manifest rather
+/// than its own, e.g. from a path dependency pointing at a workspace root, so
+/// its directory has no package sources to search
+pub fn virtual_manifest(package: &Package) -> bool {
+    let manifest = match fs::read_to_string(&package.manifest_path) {
+        Ok(manifest) => manifest,
+        Err(_) => return false,
+    };
+    match toml::from_str::<toml::Value>(&manifest) {
+        Ok(toml::Value::Table(table)) => {
+            table.contains_key("workspace") && !table.contains_key("package")
+        }
+        _ => false,
+    }
+}
+
+/// The directories to search for license files in, nearest first. Git
+/// dependencies living in a subdirectory of their repository often only have
+/// a license at the repository root, so for those every directory up to the
+/// root of the checkout is included when the package directory has no
+/// license files of its own. If the checkout root can't be found only the
+/// package directory is searched, to avoid escaping into unrelated parent
+/// directories.
+pub fn search_dirs(package: &Package) -> anyhow::Result<Vec<&Path>> {
+    fn has_license_files(dir: &Path) -> bool {
+        fs::read_dir(dir)
+            .map(|entries| {
+                entries.filter_map(Result::ok).any(|entry| {
+                    let name = entry.file_name().to_string_lossy().into_owned();
+                    let upper = name.to_uppercase();
+                    upper.starts_with("LICENSE")
+                        || upper.starts_with("LICENCE")
+                        || generic_license_name(&name).is_some()
+                })
+            })
+            .unwrap_or(true)
+    }
+
+    let dir = package_dir(package)?;
+    let git = package
+        .source
+        .as_ref()
+        .is_some_and(|source| source.to_string().starts_with("git+"));
+    if !git || has_license_files(dir) {
+        return Ok(vec![dir]);
+    }
+
+    let mut dirs = Vec::new();
+    for ancestor in dir.ancestors() {
+        dirs.push(ancestor);
+        if ancestor.join(".git").exists() || ancestor.join(".cargo-ok").exists() {
+            return Ok(dirs);
+        }
+    }
+    Ok(vec![dir])
+}
+
+/// The names of license files not named after a specific license, in order of
+/// preference, matched case-insensitively with any of `GENERIC_EXTENSIONS`
+const GENERIC_NAMES: &[&str] = &["LICENSE", "LICENCE", "COPYING", "UNLICENSE", "COPYRIGHT"];
+/// Generic names of files that often only point at the actual license files,
+/// e.g. of a dual licensed crate, or hold more than the license, these are only
+/// taken when they confidently match it
+const POINTER_NAMES: &[&str] = &["COPYING", "COPYRIGHT"];
+const GENERIC_EXTENSIONS: &[&str] = &["", ".MD", ".TXT", ".RST", ".MARKDOWN", ".HTML", ".HTM"];
+
+/// The preference of a generic license file name, lower is better, `None` if
+/// it isn't one
+fn generic_license_name(name: &str) -> Option<usize> {
+    let name = name.to_uppercase();
+    GENERIC_NAMES.iter().position(|generic| {
+        name.strip_prefix(generic)
+            .is_some_and(|extension| GENERIC_EXTENSIONS.contains(&extension))
+    })
+}
+
 pub fn find_generic_license_text(
     package: &Package,
     license: &License,
+    config: &DiscoveryConfig,
+) -> anyhow::Result<Option<LicenseText>> {
+    find_generic_license_text_traced(package, license, config, &mut DiscoveryTrace::default())
+}
+
+pub fn find_generic_license_text_traced(
+    package: &Package,
+    license: &License,
+    config: &DiscoveryConfig,
+    trace: &mut DiscoveryTrace,
 ) -> anyhow::Result<Option<LicenseText>> {
-    fn generic_license_name(name: &str) -> bool {
-        name.to_uppercase() == "LICENSE"
-            || name.to_uppercase() == "LICENCE"
-            || name.to_uppercase() == "LICENSE.MD"
-            || name.to_uppercase() == "LICENSE.TXT"
+    for dir in search_dirs(package)? {
+        let top = read_dir(package, dir, trace)?;
+        trace.examined(top.len(), false);
+        if let Some(text) = generic_text(&top, license, config, trace) {
+            return Ok(Some(text));
+        }
+        // Subdirectories hold test fixtures and bundled code under licenses of
+        // their own, so they're left for when the top has nothing at all
+        if top
+            .iter()
+            .any(|entry| named_after_any(&entry.file_name().to_string_lossy(), license))
+        {
+            log::trace!(
+                "{} has files named after {}, not searching its subdirectories",
+                dir.display(),
+                license
+            );
+            return Ok(None);
+        }
+        for entries in read_subdirs(package, dir, &top, config, trace)? {
+            if let Some(text) = generic_text(&entries, license, config, trace) {
+                return Ok(Some(text));
+            }
+        }
     }
 
-    for entry in fs::read_dir(package.manifest_path.parent().unwrap())? {
-        let entry = entry?;
-        let path = entry.path().to_owned();
+    Ok(None)
+}
+
+/// The most preferred generic license file of `entries` that can be used for
+/// `license`
+fn generic_text(
+    entries: &[fs::DirEntry],
+    license: &License,
+    config: &DiscoveryConfig,
+    trace: &mut DiscoveryTrace,
+) -> Option<LicenseText> {
+    let mut candidates = entries
+        .iter()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let priority = generic_license_name(&entry.file_name().to_string_lossy());
+            if priority.is_none() {
+                log::trace!("{} is not a generic license file", path.display());
+                trace.file(&path, || Decision::NotGeneric);
+            }
+            Some((priority?, path))
+        })
+        .collect::<Vec<_>>();
+    // Stable, so names with the same priority stay sorted
+    candidates.sort_by_key(|&(priority, _)| priority);
+    for (priority, path) in candidates {
+        let name = GENERIC_NAMES[priority];
+        // Only ever holds The Unlicense
+        if name == "UNLICENSE" && *license != License::Unlicense {
+            log::trace!("{} is not named after {}", path.display(), license);
+            trace.file(&path, || Decision::WrongName(license.to_string()));
+            continue;
+        }
+        if let Some(candidate) = read_candidate(&path, config, trace) {
+            let text = check_candidate(path, candidate, license, config, "generic", trace);
+            if POINTER_NAMES.contains(&name) && text.confidence != Confidence::Confident {
+                log::debug!(
+                    "{} doesn't confidently match {}, it may only point at the license files",
+                    text.path.display(),
+                    license
+                );
+                continue;
+            }
+            return Some(text);
+        }
+    }
+    None
+}
+
+/// Whether a file is named after `license`, or after one of the licenses of a
+/// choice or combination of them
+fn named_after_any(name: &str, license: &License) -> bool {
+    match license {
+        License::Multiple(licenses) | License::All(licenses) => {
+            licenses.iter().any(|license| named_after(name, license))
+        }
+        license => named_after(name, license),
+    }
+}
+
+fn named_after(name: &str, license: &License) -> bool {
+    let name = name.to_uppercase();
+    match *license {
+        License::Apache_2_0 => name == "LICENSE-APACHE",
+        License::WithException {
+            ref base,
+            ref exception,
+        } => {
+            let with = format!("{}_WITH_{}", base, exception).to_uppercase();
+            named_after(&name, base) || name == with || name == format!("LICENSE-{}", with)
+        }
+        License::Custom(ref custom) => custom_name_matches(&name, custom),
+        ref license => {
+            let license = license.to_string().to_uppercase();
+            name == license || name == format!("LICENSE-{}", license)
+        }
+    }
+}
+
+pub fn find_license_text(
+    package: &Package,
+    license: &License,
+    config: &DiscoveryConfig,
+) -> anyhow::Result<Vec<LicenseText>> {
+    find_license_text_traced(package, license, config, &mut DiscoveryTrace::default())
+}
+
+pub fn find_license_text_traced(
+    package: &Package,
+    license: &License,
+    config: &DiscoveryConfig,
+    trace: &mut DiscoveryTrace,
+) -> anyhow::Result<Vec<LicenseText>> {
+    if let License::Custom(custom) = license {
+        if distinctive_words(custom).is_empty() {
+            log::debug!(
+                "custom license {:?} has no distinctive words, only a generic license file can \
+                 hold its text",
+                custom
+            );
+        }
+    }
+
+    let mut texts = Vec::new();
+    // Files symlinked under several names are only a single text
+    let mut seen = HashSet::new();
+    // The nearest texts, deeper ones are only searched without any
+    for dir in search_dirs(package)? {
+        let top = read_dir(package, dir, trace)?;
+        trace.examined(top.len(), false);
+        texts.extend(named_texts(&top, license, config, trace, &mut seen));
+        if !texts.is_empty() {
+            break;
+        }
+        // As in the generic search, subdirectories are left for when the top
+        // has nothing at all
+        if generic_text(&top, license, config, &mut DiscoveryTrace::default()).is_some() {
+            log::trace!(
+                "{} has a generic license file, not searching its subdirectories",
+                dir.display()
+            );
+            break;
+        }
+        for entries in read_subdirs(package, dir, &top, config, trace)? {
+            texts.extend(named_texts(&entries, license, config, trace, &mut seen));
+            if !texts.is_empty() {
+                return Ok(texts);
+            }
+        }
+    }
+
+    Ok(texts)
+}
+
+/// The texts of the files of `entries` named after `license`, leaving out
+/// those `seen` before
+fn named_texts(
+    entries: &[fs::DirEntry],
+    license: &License,
+    config: &DiscoveryConfig,
+    trace: &mut DiscoveryTrace,
+    seen: &mut HashSet<PathBuf>,
+) -> Vec<LicenseText> {
+    let mut texts = Vec::new();
+    for entry in entries {
+        let path = entry.path();
         let name = entry.file_name().to_string_lossy().into_owned();
 
-        if generic_license_name(&name) {
-            if let Ok(text) = fs::read_to_string(&path) {
-                let confidence = check_against_template(&text, license);
-                return Ok(Some(LicenseText {
+        if named_after(&name, license) {
+            let canonical = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+            if !seen.insert(canonical) {
+                log::debug!("{} is the same file as another candidate", path.display());
+                trace.file(&path, || Decision::SameFile);
+                continue;
+            }
+            if let Some(candidate) = read_candidate(&path, config, trace) {
+                texts.push(check_candidate(
                     path,
-                    text,
-                    confidence,
-                }));
+                    candidate,
+                    license,
+                    config,
+                    "name-matched",
+                    trace,
+                ));
             }
+        } else {
+            log::trace!("{} is not named after {}", path.display(), license);
+            trace.file(&path, || Decision::WrongName(license.to_string()));
         }
     }
+    texts
+}
 
-    Ok(None)
+/// Words of custom licenses that say nothing about which license it is, e.g.
+/// "see LICENSE" or "BSD-like"
+const GENERIC_WORDS: &[&str] = &[
+    "LICENSE",
+    "LICENCE",
+    "LICENSED",
+    "SEE",
+    "THE",
+    "FILE",
+    "IN",
+    "UNDER",
+    "TERMS",
+    "OF",
+    "LIKE",
+    "STYLE",
+    "BASED",
+    "ON",
+    "DERIVATIVE",
+];
+
+/// Words that may appear in a license file's name next to the license's own
+const FILE_NAME_WORDS: &[&str] = &["LICENSE", "LICENCE", "COPYING", "TXT", "MD", "HTML", "HTM"];
+
+/// The uppercased words of `text`, split at anything not alphanumeric
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_uppercase)
+        .collect()
 }
 
-pub fn find_license_text(package: &Package, license: &License) -> anyhow::Result<Vec<LicenseText>> {
-    fn name_matches(name: &str, license: &License) -> bool {
-        let name = name.to_uppercase();
-        match *license {
-            License::Apache_2_0 => name == "LICENSE-APACHE",
-            License::Custom(ref custom) => {
-                let custom = custom.to_uppercase();
-                name == custom || name == format!("LICENSE-{}", custom)
+/// The words of a custom license that set it apart from any other license
+fn distinctive_words(custom: &str) -> Vec<String> {
+    words(custom)
+        .into_iter()
+        .filter(|word| !GENERIC_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Whether a file is named after a custom license: every distinctive word of
+/// the license is a word of the name, and the name has nothing besides them
+/// but words like `LICENSE` or an extension. A license with no distinctive
+/// words matches no name, only generic license files are searched for it.
+fn custom_name_matches(name: &str, custom: &str) -> bool {
+    let distinctive = distinctive_words(custom);
+    if distinctive.is_empty() {
+        return false;
+    }
+    let name_words = words(name);
+    let matches = distinctive.iter().all(|word| name_words.contains(word))
+        && name_words
+            .iter()
+            .all(|word| distinctive.contains(word) || FILE_NAME_WORDS.contains(&word.as_str()));
+    if matches {
+        log::debug!(
+            "{} matches custom license {:?} by its words {}",
+            name,
+            custom,
+            distinctive.join(", ")
+        );
+    }
+    matches
+}
+
+/// Reads a possibly non-UTF-8 file (e.g. Latin-1 copyright symbols) lossily,
+/// good enough for scoring, the original is kept alongside. Also returns
+/// whether it had to be read lossily.
+fn read_text(path: &Path) -> io::Result<(String, bool, Arc<[u8]>)> {
+    let bytes = Arc::<[u8]>::from(read_stable(path)?);
+    let (text, lossy) = decode(path, &bytes);
+    Ok((text, lossy, bytes))
+}
+
+/// Reads a whole file, retrying once if its size changes while it's read,
+/// e.g. when a registry source is still being extracted
+fn read_stable(path: &Path) -> io::Result<Vec<u8>> {
+    let mut retried = false;
+    loop {
+        let before = fs::metadata(path)?.len();
+        let bytes = fs::read(path)?;
+        let after = fs::metadata(path)?.len();
+        if before == after && bytes.len() as u64 == after {
+            return Ok(bytes);
+        }
+        if retried {
+            return Err(io::Error::other(format!(
+                "changed while being read, from {} to {} bytes",
+                before, after
+            )));
+        }
+        log::debug!("{} changed while being read, retrying", path.display());
+        retried = true;
+    }
+}
+
+fn decode(path: &Path, bytes: &[u8]) -> (String, bool) {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => (text.to_owned(), false),
+        Err(_) => {
+            log::debug!("{} is not valid UTF-8, reading it lossily", path.display());
+            (String::from_utf8_lossy(bytes).into_owned(), true)
+        }
+    }
+}
+
+/// The text of a candidate license file
+struct Candidate {
+    text: String,
+    /// The whole file as read
+    bytes: Arc<[u8]>,
+    /// Not valid UTF-8, so read lossily
+    lossy: bool,
+    /// Stripped of its HTML markup
+    html: bool,
+}
+
+/// Reduces an HTML document to its text, only as well as needed for scoring
+/// it and reading it in a bundle
+fn strip_html(html: &str) -> String {
+    static PATTERNS: OnceLock<[Regex; 6]> = OnceLock::new();
+    let [hidden, whitespace, line_breaks, paragraphs, tags, blank_lines] =
+        PATTERNS.get_or_init(|| {
+            [
+                r"(?is)<script\b.*?</script\s*>|<style\b.*?</style\s*>|<!--.*?-->",
+                r"\s+",
+                r"(?i)<br\b[^>]*>",
+                r"(?i)</?(p|div|h[1-6]|li|tr|pre|blockquote)\b[^>]*>",
+                r"(?s)<[^>]*>",
+                r"\n\s*\n(\s*\n)+",
+            ]
+            .map(|pattern| Regex::new(pattern).unwrap())
+        });
+
+    let text = hidden.replace_all(html, "");
+    // Outside of preformatted text line breaks in the source are only spaces
+    let text = if text.to_lowercase().contains("<pre") {
+        text
+    } else {
+        whitespace.replace_all(&text, " ")
+    };
+    let text = line_breaks.replace_all(&text, "\n");
+    let text = paragraphs.replace_all(&text, "\n\n");
+    let text = tags.replace_all(&text, "");
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&copy;", "\u{a9}")
+        .replace("&amp;", "&");
+    let text = text.lines().map(str::trim).collect::<Vec<_>>().join("\n");
+    blank_lines.replace_all(&text, "\n\n").trim().to_owned() + "\n"
+}
+
+/// Reads a candidate license file, following symlinks. Unreadable files are
+/// skipped with a warning, dangling symlinks are likely a packaging mistake
+/// hiding the license. Oversized and binary files which can't be a useful
+/// license text are skipped too, and recorded on the trace for the caller to
+/// report.
+fn read_candidate(
+    path: &Path,
+    config: &DiscoveryConfig,
+    trace: &mut DiscoveryTrace,
+) -> Option<Candidate> {
+    let size = fs::metadata(path).map_or(0, |meta| meta.len());
+    if size > config.max_file_size {
+        let limit = config.max_file_size;
+        trace.skip(path, SkipReason::TooLarge { size, limit });
+        return None;
+    }
+    let bytes = read_stable(path);
+    if let Ok(bytes) = &bytes {
+        if bytes.iter().take(8192).any(|&byte| byte == 0) {
+            trace.skip(path, SkipReason::Binary);
+            return None;
+        }
+    }
+    match bytes.map(Arc::<[u8]>::from) {
+        Ok(bytes) => {
+            let (text, lossy) = decode(path, &bytes);
+            let html = path.extension().is_some_and(|extension| {
+                extension.eq_ignore_ascii_case("html") || extension.eq_ignore_ascii_case("htm")
+            });
+            let text = if html { strip_html(&text) } else { text };
+            Some(Candidate {
+                text,
+                bytes,
+                lossy,
+                html,
+            })
+        }
+        Err(err) => {
+            trace.file(path, || Decision::Unreadable(err.to_string()));
+            let symlink =
+                fs::symlink_metadata(path).is_ok_and(|meta| meta.file_type().is_symlink());
+            if symlink && !path.exists() {
+                log::warn!(
+                    "{} is a symlink to a missing file, skipping it",
+                    path.display()
+                );
+            } else {
+                log::warn!("Couldn't read {}, skipping it: {}", path.display(), err);
+            }
+            None
+        }
+    }
+}
+
+/// Finds the body of a `# License` style heading, up until the next heading of
+/// the same or a higher level
+fn readme_license_section(text: &str) -> Option<String> {
+    let heading = Regex::new(r"(?i)^licen[cs]es?:?$").unwrap();
+    let mut level = None;
+    let mut section = Vec::new();
+    for line in text.lines() {
+        let trimmed = line.trim();
+        let depth = trimmed.chars().take_while(|&c| c == '#').count();
+        match level {
+            None => {
+                if depth > 0
+                    && heading.is_match(trimmed[depth..].trim_matches(|c| c == '#' || c == ' '))
+                {
+                    level = Some(depth);
+                }
             }
-            ref license => {
-                let license = license.to_string().to_uppercase();
-                name == license || name == format!("LICENSE-{}", license)
+            Some(level) => {
+                if depth > 0 && depth <= level {
+                    break;
+                }
+                section.push(line);
             }
         }
     }
+    level.map(|_| section.join("\n").trim().to_owned())
+}
 
-    let mut texts = Vec::new();
-    for entry in fs::read_dir(package.manifest_path.parent().unwrap())? {
-        let entry = entry?;
+/// Finds the first known license opening phrase, and takes everything from
+/// there until the next heading
+fn readme_license_opening(text: &str) -> Option<String> {
+    const OPENINGS: &[&str] = &[
+        "Permission is hereby granted",
+        "This is free and unencumbered software",
+        "Redistribution and use in source and binary forms",
+        "Apache License",
+    ];
+
+    let start = OPENINGS
+        .iter()
+        .filter_map(|opening| text.find(opening))
+        .min()?;
+    Some(
+        text[start..]
+            .lines()
+            .take_while(|line| !line.trim_start().starts_with('#'))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// Fallback for packages that ship their license text only as part of their
+/// README, only returns texts that are at least a semi-confident match
+pub fn find_readme_license_text(
+    package: &Package,
+    license: &License,
+    config: &DiscoveryConfig,
+) -> anyhow::Result<Option<LicenseText>> {
+    find_readme_license_text_traced(package, license, config, &mut DiscoveryTrace::default())
+}
+
+pub fn find_readme_license_text_traced(
+    package: &Package,
+    license: &License,
+    config: &DiscoveryConfig,
+    trace: &mut DiscoveryTrace,
+) -> anyhow::Result<Option<LicenseText>> {
+    fn readme_name(name: &str) -> bool {
+        name.to_uppercase() == "README"
+            || name.to_uppercase() == "README.MD"
+            || name.to_uppercase() == "README.TXT"
+    }
+
+    for entry in read_dir(package, package_dir(package)?, trace)? {
         let path = entry.path().to_owned();
         let name = entry.file_name().to_string_lossy().into_owned();
 
-        if name_matches(&name, license) {
-            if let Ok(text) = fs::read_to_string(&path) {
-                let confidence = check_against_template(&text, license);
-                texts.push(LicenseText {
-                    path,
-                    text,
-                    confidence,
-                });
+        if !readme_name(&name) {
+            continue;
+        }
+        let (text, lossy, bytes) = match read_text(&path) {
+            Ok(read) => read,
+            Err(err) => {
+                log::warn!("Couldn't read {}, skipping it: {}", path.display(), err);
+                trace.file(&path, || Decision::Unreadable(err.to_string()));
+                continue;
+            }
+        };
+        let sections = readme_license_section(&text)
+            .into_iter()
+            .chain(readme_license_opening(&text));
+        for section in sections {
+            let mut text = check_file(path.clone(), section, bytes.clone(), license, config);
+            trace.scored("README section", &text, license, lossy);
+            if text.confidence != Confidence::Unsure {
+                text.origin = Origin::Readme;
+                return Ok(Some(text));
             }
         }
     }
 
-    Ok(texts)
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::license::KNOWN;
+    use crate::testutil::{self, TempDir, MIT_TEXT};
+
+    fn config(confident: f32, semi_confident: f32) -> DiscoveryConfig {
+        DiscoveryConfig {
+            confident,
+            semi_confident,
+            ..DiscoveryConfig::default()
+        }
+    }
+
+    #[test]
+    fn parentless_manifest_paths() {
+        let package = testutil::package("parentless", "1.0.0", Some("MIT"), Path::new(""));
+        let config = DiscoveryConfig::default();
+        let err = find_license_text(&package, &License::MIT, &config)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "parentless 1.0.0 manifest path Cargo.toml has no parent directory to find its \
+             sources in"
+        );
+        assert!(find_generic_license_text(&package, &License::MIT, &config).is_err());
+        assert!(find_readme_license_text(&package, &License::MIT, &config).is_err());
+        assert!(!source_available(&package));
+    }
+
+    #[test]
+    fn custom_templates_only_apply_to_their_config() {
+        let dir = TempDir::new();
+        let internal = "Internal use only, not to be distributed outside the company.\n";
+        dir.write("templates/Proprietary-InternalUse-1.0.txt", internal);
+        dir.write("templates/MIT.txt", "Patched MIT.\n");
+        let custom = DiscoveryConfig {
+            templates: Some(Arc::new(
+                TemplateStore::load(&dir.path().join("templates")).unwrap(),
+            )),
+            ..DiscoveryConfig::default()
+        };
+        let bundled = DiscoveryConfig::default();
+
+        let license = License::Custom("Proprietary-InternalUse-1.0".to_owned());
+        assert_eq!(
+            check_against_template(internal, &license, &custom),
+            (Confidence::Confident, Some(0.0))
+        );
+        assert_eq!(
+            check_against_template(internal, &license, &bundled),
+            (Confidence::Unsure, None)
+        );
+
+        assert_eq!(custom.template(&License::MIT), Some("Patched MIT.\n"));
+        assert_eq!(bundled.template(&License::MIT), License::MIT.template());
+        let exception = "MIT WITH LLVM-exception".parse::<License>().unwrap();
+        assert_eq!(custom.template(&exception), Some("Patched MIT.\n"));
+        assert_eq!(
+            custom.template(&License::Apache_2_0),
+            License::Apache_2_0.template()
+        );
+    }
+
+    #[test]
+    fn thresholds_only_change_the_classification() {
+        // Enough words changed to be a worse match than the pristine text
+        let text = MIT_TEXT
+            .replace("free of charge", "for a modest fee")
+            .replace("without restriction", "with some restrictions");
+        let (confidence, score) = check_against_template(&text, &License::MIT, &config(0.0, 0.0));
+        let score = score.expect("MIT has a template");
+        assert!(score > 0.0);
+        assert_eq!(confidence, Confidence::Unsure);
+        assert_eq!(
+            check_against_template(&text, &License::MIT, &config(score + 0.01, 1.0)),
+            (Confidence::Confident, Some(score))
+        );
+        assert_eq!(
+            check_against_template(&text, &License::MIT, &config(score, score + 0.01)),
+            (Confidence::SemiConfident, Some(score))
+        );
+
+        let dir = TempDir::new();
+        dir.write("foo/LICENSE-MIT", &text);
+        let package = testutil::path_package("foo", "1.0.0", Some("MIT"), &dir.path().join("foo"));
+        let strict = find_license_text(&package, &License::MIT, &config(0.0, 0.0)).unwrap();
+        let loose = find_license_text(&package, &License::MIT, &config(1.0, 1.0)).unwrap();
+        assert_eq!(strict.len(), 1);
+        assert_eq!(loose.len(), 1);
+        assert_eq!(strict[0].path, loose[0].path);
+        assert_eq!(strict[0].confidence, Confidence::Unsure);
+        assert_eq!(loose[0].confidence, Confidence::Confident);
+        assert_eq!(strict[0].score, Some(score));
+    }
+
+    #[test]
+    fn generic_names() {
+        for name in &[
+            "LICENSE",
+            "License.md",
+            "licence.txt",
+            "COPYING",
+            "Copying.rst",
+            "UNLICENSE",
+            "COPYRIGHT.markdown",
+        ] {
+            assert!(generic_license_name(name).is_some(), "{}", name);
+        }
+        for name in &["LICENSE-MIT", "COPYING.LESSER", "LICENSE.rs", "README.md"] {
+            assert_eq!(generic_license_name(name), None, "{}", name);
+        }
+        assert!(generic_license_name("LICENSE") < generic_license_name("COPYING"));
+    }
+
+    #[test]
+    fn pointer_files() {
+        let dir = TempDir::new();
+        let pointer = "This project is licensed under either of MIT or Apache-2.0 at your \
+                       option, see LICENSE-MIT and LICENSE-APACHE.\n";
+        dir.write("dual/COPYING", pointer);
+        dir.write("dual/LICENSE-MIT", MIT_TEXT);
+        dir.write(
+            "unicode/COPYRIGHT",
+            "Copyright 2020 The Authors, see LICENSE-MIT.\n",
+        );
+        dir.write("unicode/LICENSE-MIT", MIT_TEXT);
+        dir.write("unlicense/UNLICENSE", include_str!("licenses/Unlicense"));
+        let package = |name: &str, license: &str| {
+            testutil::path_package(name, "1.0.0", Some(license), &dir.path().join(name))
+        };
+        let generic = |name: &str, license: License| {
+            find_generic_license_text(
+                &package(name, &license.to_string()),
+                &license,
+                &DiscoveryConfig::default(),
+            )
+            .unwrap()
+            .map(|text| text.path)
+        };
+
+        // Left for the name-matched search to find the actual texts
+        let dual = "MIT OR Apache-2.0".parse().unwrap();
+        assert_eq!(generic("dual", dual), None);
+        assert_eq!(generic("dual", License::MIT), None);
+        assert_eq!(generic("unicode", License::MIT), None);
+        assert_eq!(
+            generic("unlicense", License::Unlicense),
+            Some(dir.path().join("unlicense/UNLICENSE"))
+        );
+        assert_eq!(generic("unlicense", License::MIT), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn digests_are_of_the_bytes_read() {
+        let dir = TempDir::new();
+        let path = dir.write("foo/LICENSE-MIT", MIT_TEXT);
+        let package = testutil::path_package("foo", "1.0.0", Some("MIT"), &dir.path().join("foo"));
+        let texts =
+            find_license_text(&package, &License::MIT, &DiscoveryConfig::default()).unwrap();
+        // Changing the file afterwards doesn't change what was found
+        fs::write(&path, "Something else entirely\n").unwrap();
+        assert_eq!(
+            texts[0].digest(),
+            Some((
+                MIT_TEXT.len() as u64,
+                format!("{:x}", Sha256::digest(MIT_TEXT.as_bytes()))
+            ))
+        );
+    }
+
+    #[test]
+    fn symlinks_and_copying() {
+        use std::os::unix::fs::symlink;
+
+        let dir = TempDir::new();
+        dir.write("copying/COPYING", MIT_TEXT);
+        let package =
+            testutil::path_package("copying", "1.0.0", Some("MIT"), &dir.path().join("copying"));
+        let text = find_generic_license_text(&package, &License::MIT, &DiscoveryConfig::default())
+            .unwrap()
+            .unwrap();
+        assert!(text.path.ends_with("COPYING"));
+        assert_eq!(text.confidence, Confidence::Confident);
+
+        // A vendored crate linking to its workspace's license under two names,
+        // and to a license that's missing
+        let mit = dir.write("vendored/LICENSE-MIT", MIT_TEXT);
+        dir.write("vendored/linked/Cargo.toml", "");
+        let linked = dir.path().join("vendored/linked");
+        symlink(&mit, linked.join("LICENSE-MIT")).unwrap();
+        symlink(&mit, linked.join("MIT-LICENSE.txt")).unwrap();
+        symlink(
+            dir.path().join("vendored/LICENSE-APACHE"),
+            linked.join("LICENSE-APACHE"),
+        )
+        .unwrap();
+        let package = testutil::path_package("linked", "1.0.0", Some("MIT OR Apache-2.0"), &linked);
+        let texts =
+            find_license_text(&package, &License::MIT, &DiscoveryConfig::default()).unwrap();
+        assert_eq!(texts.len(), 1);
+        assert_eq!(texts[0].text, MIT_TEXT);
+        assert_eq!(texts[0].confidence, Confidence::Confident);
+        let texts =
+            find_license_text(&package, &License::Apache_2_0, &DiscoveryConfig::default()).unwrap();
+        assert!(texts.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unreadable_files_are_skipped() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new();
+        let license = dir.write("locked/LICENSE", MIT_TEXT);
+        dir.write("locked/COPYING", MIT_TEXT);
+        fs::set_permissions(&license, fs::Permissions::from_mode(0o000)).unwrap();
+        // Permissions don't stop root, e.g. in a container
+        if fs::read(&license).is_ok() {
+            eprintln!(
+                "skipping unreadable_files_are_skipped, permissions aren't enforced for root"
+            );
+            return;
+        }
+        let package =
+            testutil::path_package("locked", "1.0.0", Some("MIT"), &dir.path().join("locked"));
+        let mut trace = DiscoveryTrace::new();
+        let text = find_generic_license_text_traced(
+            &package,
+            &License::MIT,
+            &DiscoveryConfig::default(),
+            &mut trace,
+        )
+        .unwrap()
+        .unwrap();
+        assert!(text.path.ends_with("COPYING"));
+        let (path, decision) = &trace.files[0];
+        assert_eq!(path, &license);
+        assert!(
+            decision.to_string().starts_with("skipped, unreadable: "),
+            "{}",
+            decision
+        );
+        fs::set_permissions(&license, fs::Permissions::from_mode(0o644)).unwrap();
+    }
+
+    #[test]
+    fn files_disappearing_after_listing_are_skipped() {
+        let dir = TempDir::new();
+        let license = dir.write("vanishing/LICENSE", MIT_TEXT);
+        let package = testutil::path_package(
+            "vanishing",
+            "1.0.0",
+            Some("MIT"),
+            &dir.path().join("vanishing"),
+        );
+        let mut trace = DiscoveryTrace::new();
+        let entries = read_dir(&package, license.parent().unwrap(), &mut trace).unwrap();
+        assert_eq!(entries.len(), 1);
+        fs::remove_file(&license).unwrap();
+        assert!(
+            read_candidate(&entries[0].path(), &DiscoveryConfig::default(), &mut trace).is_none()
+        );
+        let (path, decision) = &trace.files[0];
+        assert_eq!(path, &license);
+        assert!(
+            decision.to_string().starts_with("skipped, unreadable: "),
+            "{}",
+            decision
+        );
+
+        // Only the directory itself being unreadable is an error
+        let config = DiscoveryConfig::default();
+        assert_eq!(
+            find_generic_license_text(&package, &License::MIT, &config)
+                .unwrap()
+                .map(|text| text.path),
+            None
+        );
+        fs::remove_dir(license.parent().unwrap()).unwrap();
+        let err = find_generic_license_text(&package, &License::MIT, &config)
+            .map(|_| ())
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Couldn't read source directory of vanishing 1.0.0"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn virtual_manifests_are_detected() {
+        let dir = TempDir::new();
+        dir.write(
+            "workspace/Cargo.toml",
+            "[workspace]\nmembers = [\"member\"]\n",
+        );
+        dir.write("workspace/LICENSE", MIT_TEXT);
+        dir.write(
+            "member/Cargo.toml",
+            "[package]\nname = \"member\"\nversion = \"1.0.0\"\n\n[workspace]\n",
+        );
+        let package =
+            |name: &str| testutil::path_package(name, "1.0.0", Some("MIT"), &dir.path().join(name));
+        assert!(virtual_manifest(&package("workspace")));
+        assert!(!virtual_manifest(&package("member")));
+        assert!(!virtual_manifest(&package("missing")));
+    }
+
+    #[test]
+    fn subdirectories_are_searched_without_build_output() {
+        let dir = TempDir::new();
+        dir.write("path/Cargo.toml", "");
+        dir.write("path/target/package/foo-0.1.0/Cargo.toml", "");
+        dir.write("path/target/package/foo-0.1.0/LICENSE", MIT_TEXT);
+        dir.write("path/target/package/foo-0.1.0/LICENSE-MIT", MIT_TEXT);
+        dir.write("path/nested/Cargo.toml", "");
+        dir.write("path/nested/LICENSE", MIT_TEXT);
+        let package = testutil::path_package("foo", "0.1.0", Some("MIT"), &dir.path().join("path"));
+        let config = DiscoveryConfig::default();
+        let mut trace = DiscoveryTrace::new();
+        let text =
+            find_generic_license_text_traced(&package, &License::MIT, &config, &mut trace).unwrap();
+        assert!(text.is_none());
+        assert!(find_license_text(&package, &License::MIT, &config)
+            .unwrap()
+            .is_empty());
+        let pruned = trace
+            .files
+            .iter()
+            .filter(|(_, decision)| matches!(decision, Decision::Pruned(_)))
+            .map(|(path, _)| path.strip_prefix(dir.path().join("path")).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(pruned, [Path::new("nested"), Path::new("target")]);
+        assert_eq!(trace.entries, 3);
+
+        // The package's own documentation is found, though only if there's
+        // nothing at the top
+        dir.write("path/docs/LICENSE-MIT", MIT_TEXT);
+        let texts = find_license_text(&package, &License::MIT, &config).unwrap();
+        assert_eq!(texts.len(), 1);
+        assert!(texts[0].path.ends_with("docs/LICENSE-MIT"));
+        dir.write("path/LICENSE-MIT", MIT_TEXT);
+        let texts = find_license_text(&package, &License::MIT, &config).unwrap();
+        assert_eq!(texts.len(), 1);
+        assert_eq!(texts[0].path, dir.path().join("path/LICENSE-MIT"));
+
+        // Registry packages only have their top level searched
+        fs::remove_file(dir.path().join("path/LICENSE-MIT")).unwrap();
+        let registry = testutil::package("foo", "0.1.0", Some("MIT"), &dir.path().join("path"));
+        assert!(find_license_text(&registry, &License::MIT, &config)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn subdirectories_are_only_searched_without_license_files_at_the_top() {
+        let dir = TempDir::new();
+        dir.write("path/Cargo.toml", "");
+        dir.write("path/LICENSE-MIT", MIT_TEXT);
+        dir.write(
+            "path/tests/fixtures/LICENSE",
+            include_str!("licenses/GPL-3.0"),
+        );
+        let package = testutil::path_package("foo", "0.1.0", Some("MIT"), &dir.path().join("path"));
+        let config = DiscoveryConfig::default();
+        assert!(find_generic_license_text(&package, &License::MIT, &config)
+            .unwrap()
+            .is_none());
+        let texts = find_license_text(&package, &License::MIT, &config).unwrap();
+        assert_eq!(texts.len(), 1);
+        assert_eq!(texts[0].path, dir.path().join("path/LICENSE-MIT"));
+
+        // Nor for names when there's a generic license file at the top
+        fs::remove_file(dir.path().join("path/LICENSE-MIT")).unwrap();
+        dir.write("path/LICENSE", MIT_TEXT);
+        dir.write("path/tests/fixtures/LICENSE-MIT", MIT_TEXT);
+        let text = find_generic_license_text(&package, &License::MIT, &config)
+            .unwrap()
+            .unwrap();
+        assert_eq!(text.path, dir.path().join("path/LICENSE"));
+        assert!(find_license_text(&package, &License::MIT, &config)
+            .unwrap()
+            .is_empty());
+
+        // Without either the fixtures are all there is
+        fs::remove_file(dir.path().join("path/LICENSE")).unwrap();
+        let text = find_generic_license_text(&package, &License::MIT, &config)
+            .unwrap()
+            .unwrap();
+        assert_eq!(text.path, dir.path().join("path/tests/fixtures/LICENSE"));
+    }
+
+    #[test]
+    fn searches_stop_after_max_entries() {
+        let dir = TempDir::new();
+        dir.write("many/Cargo.toml", "");
+        for i in 0..10 {
+            dir.write(&format!("many/generated/{}.rs", i), "");
+        }
+        dir.write("many/later/LICENSE", MIT_TEXT);
+        let package =
+            testutil::path_package("many", "1.0.0", Some("MIT"), &dir.path().join("many"));
+        let config = DiscoveryConfig {
+            max_entries: 8,
+            ..DiscoveryConfig::default()
+        };
+        let mut trace = DiscoveryTrace::new();
+        let text =
+            find_generic_license_text_traced(&package, &License::MIT, &config, &mut trace).unwrap();
+        assert!(text.is_none());
+        assert_eq!(trace.entries, 8);
+        assert!(trace.capped);
+
+        let mut trace = DiscoveryTrace::new();
+        let text = find_generic_license_text_traced(
+            &package,
+            &License::MIT,
+            &DiscoveryConfig::default(),
+            &mut trace,
+        )
+        .unwrap();
+        assert!(text.unwrap().path.ends_with("later/LICENSE"));
+        assert_eq!(trace.entries, 14);
+        assert!(!trace.capped);
+
+        // A limit below the number of entries at the top searches no further
+        let config = DiscoveryConfig {
+            max_entries: 2,
+            ..DiscoveryConfig::default()
+        };
+        let mut trace = DiscoveryTrace::new();
+        let text =
+            find_generic_license_text_traced(&package, &License::MIT, &config, &mut trace).unwrap();
+        assert!(text.is_none());
+        assert_eq!(trace.entries, 3);
+        assert!(trace.capped);
+    }
+
+    #[test]
+    fn oversized_and_binary_files_are_skipped() {
+        let dir = TempDir::new();
+        dir.write("large/LICENSE", MIT_TEXT);
+        dir.write(
+            "binary/LICENSE",
+            "%PDF-1.4\n\0\0\0 Permission is hereby granted\n",
+        );
+        let find = |name: &str, config: &DiscoveryConfig| {
+            let package =
+                testutil::path_package(name, "1.0.0", Some("MIT"), &dir.path().join(name));
+            let mut trace = DiscoveryTrace::new();
+            let text =
+                find_generic_license_text_traced(&package, &License::MIT, config, &mut trace)
+                    .unwrap();
+            let decision = trace
+                .files
+                .into_iter()
+                .find(|(path, _)| path.ends_with("LICENSE"))
+                .map(|(_, decision)| decision.to_string());
+            (text.map(|text| text.confidence), decision)
+        };
+
+        let limited = DiscoveryConfig {
+            max_file_size: 100,
+            ..DiscoveryConfig::default()
+        };
+        assert_eq!(
+            find("large", &limited),
+            (
+                None,
+                Some(format!(
+                    "skipped, {} bytes is over the 100 byte limit",
+                    MIT_TEXT.len()
+                ))
+            )
+        );
+        assert_eq!(
+            find("large", &DiscoveryConfig::default()).0,
+            Some(Confidence::Confident)
+        );
+        assert_eq!(
+            find("binary", &DiscoveryConfig::default()),
+            (None, Some("skipped, binary content".to_owned()))
+        );
+
+        // Kept without tracing, to report as issues with the package
+        let package =
+            testutil::path_package("binary", "1.0.0", Some("MIT"), &dir.path().join("binary"));
+        let mut trace = DiscoveryTrace::default();
+        find_generic_license_text_traced(&package, &License::MIT, &limited, &mut trace).unwrap();
+        assert!(trace.files.is_empty());
+        assert_eq!(
+            trace.skipped,
+            vec![SkippedFile {
+                path: dir.path().join("binary/LICENSE"),
+                reason: SkipReason::Binary,
+            }]
+        );
+    }
+
+    #[test]
+    fn html_texts_are_stripped() {
+        let paragraphs = MIT_TEXT
+            .split("\n\n")
+            .map(|paragraph| format!("<p>{}</p>", paragraph.replace('"', "&quot;")))
+            .collect::<String>();
+        let html = format!(
+            "<!DOCTYPE html>\n<html><head><title>License</title>\
+             <style>p {{ margin: 0 }}</style></head>\n<body>\n{}\n</body></html>\n",
+            paragraphs
+        );
+        let dir = TempDir::new();
+        dir.write("html/LICENSE.html", &html);
+        let package =
+            testutil::path_package("html", "1.0.0", Some("MIT"), &dir.path().join("html"));
+        let text = find_generic_license_text(&package, &License::MIT, &DiscoveryConfig::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(text.origin, Origin::Html);
+        assert_eq!(text.confidence, Confidence::Confident);
+        assert!(!text.text.contains('<'), "{}", text.text);
+        assert!(!text.text.contains("margin"), "{}", text.text);
+        assert!(text.text.contains("\"AS IS\""), "{}", text.text);
+        assert!(text
+            .text
+            .contains("Copyright (c) 2020 The Authors\n\nPermission"));
+
+        assert_eq!(
+            strip_html("<pre>a\n  b</pre><br>c &amp; d&nbsp;e"),
+            "a\nb\n\nc & d e\n"
+        );
+    }
+
+    #[test]
+    fn exception_files_are_found() {
+        let dir = TempDir::new();
+        let apache = include_str!("../LICENSE-APACHE");
+        dir.write("llvm/LICENSE-Apache-2.0_WITH_LLVM-exception", apache);
+        dir.write("llvm/LICENSE-MIT", MIT_TEXT);
+        let package = testutil::path_package(
+            "llvm",
+            "1.0.0",
+            Some("Apache-2.0 WITH LLVM-exception"),
+            &dir.path().join("llvm"),
+        );
+        let license = "Apache-2.0 WITH LLVM-exception".parse().unwrap();
+        let texts = find_license_text(&package, &license, &DiscoveryConfig::default()).unwrap();
+        assert_eq!(texts.len(), 1);
+        assert!(texts[0]
+            .path
+            .ends_with("LICENSE-Apache-2.0_WITH_LLVM-exception"));
+        assert_eq!(texts[0].confidence, Confidence::Confident);
+    }
+
+    #[test]
+    fn missing_sources_name_the_package() {
+        let dir = TempDir::new();
+        let package = testutil::package(
+            "unfetched",
+            "1.0.0",
+            Some("MIT"),
+            &dir.path().join("unfetched"),
+        );
+        assert!(!source_available(&package));
+        let err = find_license_text(&package, &License::MIT, &DiscoveryConfig::default())
+            .err()
+            .unwrap();
+        let message = format!("{:#}", err);
+        assert!(
+            message.contains("unfetched 1.0.0")
+                && message.contains(&dir.path().join("unfetched").display().to_string()),
+            "{}",
+            message
+        );
+
+        dir.write("unfetched/LICENSE", MIT_TEXT);
+        assert!(source_available(&package));
+    }
+
+    #[test]
+    fn text_sanity_checks() {
+        let config = DiscoveryConfig::default();
+        let apache = include_str!("../LICENSE-APACHE");
+        // The appendix's placeholders are part of the license
+        assert!(apache.contains("[yyyy]"));
+        assert_eq!(text_issues(apache, &License::Apache_2_0, &config), []);
+        assert_eq!(text_issues(MIT_TEXT, &License::MIT, &config), []);
+
+        assert!(matches!(
+            text_issues("MIT\n", &License::MIT, &config)[..],
+            [TextIssue::Short { length: 4, .. }]
+        ));
+
+        let unfilled = MIT_TEXT.replace("2020 The Authors", "<year> <copyright holders>");
+        assert_eq!(
+            text_issues(&unfilled, &License::MIT, &config),
+            [TextIssue::Placeholder("<year>")]
+        );
+        let end = apache.find("END OF TERMS AND CONDITIONS").unwrap();
+        let terms = format!(
+            "Copyright [yyyy] [name of copyright owner]\n\n{}",
+            &apache[..end]
+        );
+        assert_eq!(
+            text_issues(&terms, &License::Apache_2_0, &config),
+            [TextIssue::Placeholder("[yyyy]")]
+        );
+        let templated = MIT_TEXT.replace("2020 The Authors", "{{ year }} {{ authors }}");
+        assert_eq!(
+            text_issues(&templated, &License::MIT, &config),
+            [TextIssue::Placeholder("{{")]
+        );
+
+        let pointer = "Licensed under the Apache License, Version 2.0, see LICENSE-APACHE \
+                       for the full license text.\n";
+        let issues = text_issues(pointer, &License::Apache_2_0, &config);
+        assert!(issues.contains(&TextIssue::Pointer), "{:?}", issues);
+        // Without a template only the pointer check applies
+        assert_eq!(
+            text_issues(pointer, &License::Custom("Custom".to_owned()), &config),
+            [TextIssue::Pointer]
+        );
+    }
+
+    #[test]
+    fn concatenated_texts_are_split() {
+        let apache = include_str!("../LICENSE-APACHE");
+        let mit = include_str!("../LICENSE-MIT");
+        let combined = format!(
+            "This project is licensed under either of the following, at your option.\n\n\
+             {}\n==========\n\n{}",
+            apache, mit
+        );
+        let config = DiscoveryConfig::default();
+        let text = LicenseText {
+            path: PathBuf::from("LICENSE"),
+            origin: Origin::LicenseFile,
+            text: combined.clone(),
+            confidence: Confidence::Unsure,
+            score: None,
+            bytes: Some(combined.as_bytes().into()),
+        };
+        let either = License::multiple(vec![License::MIT, License::Apache_2_0]);
+        assert_eq!(
+            check_against_template(&combined, &License::MIT, &config).0,
+            Confidence::Unsure
+        );
+
+        let segments = split_license_text(&text, &either, &config).unwrap();
+        assert_eq!(segments.len(), 2);
+        for (segment, expected) in segments.iter().zip(&[mit, apache]) {
+            assert_eq!(segment.text, expected.trim());
+            assert_eq!(segment.path, text.path);
+            assert_eq!(segment.confidence, Confidence::Confident);
+            // Provenance is of the whole file
+            assert_eq!(segment.digest(), text.digest());
+            match segment.origin {
+                Origin::Segment { start, end } => assert_eq!(&combined[start..end], segment.text),
+                ref origin => panic!("{:?}", origin),
+            }
+        }
+
+        // Only combinations are split, and only when every part is found
+        assert!(split_license_text(&text, &License::MIT, &config).is_none());
+        let text = LicenseText {
+            text: mit.to_owned(),
+            ..text
+        };
+        assert!(split_license_text(&text, &either, &config).is_none());
+    }
+
+    #[test]
+    fn contradicting_texts() {
+        let gpl = include_str!("licenses/GPL-3.0");
+        let config = DiscoveryConfig::default();
+        assert_eq!(best_match(gpl, &config).unwrap().0, &License::GPL_3_0);
+        assert_eq!(best_match(MIT_TEXT, &config).unwrap().0, &License::MIT);
+
+        let dir = TempDir::new();
+        dir.write("mislabeled/LICENSE", gpl);
+        dir.write("honest/LICENSE", MIT_TEXT);
+        dir.write("lesser/COPYING", gpl);
+        let package = |name: &str, license: &str| {
+            testutil::path_package(name, "1.0.0", Some(license), &dir.path().join(name))
+        };
+        let contradiction = |name: &str, license: License| {
+            let package = package(name, &license.to_string());
+            contradicting_license_text(&package, &license, &config, &mut DiscoveryTrace::default())
+                .unwrap()
+                .map(|(text, found)| (text.path, found))
+        };
+        assert_eq!(
+            contradiction("mislabeled", License::MIT),
+            Some((dir.path().join("mislabeled/LICENSE"), &License::GPL_3_0))
+        );
+        assert_eq!(contradiction("honest", License::MIT), None);
+        // The MIT and X11 texts are too alike to tell apart
+        assert_eq!(contradiction("honest", License::X11), None);
+        dir.write("x11/LICENSE", include_str!("licenses/X11"));
+        assert_eq!(contradiction("x11", License::MIT), None);
+        assert_eq!(
+            check_against_template(MIT_TEXT, &License::X11, &config).0,
+            Confidence::Confident
+        );
+        // The GPL-3.0 text is expected with the LGPL-3.0
+        assert_eq!(contradiction("lesser", License::LGPL_3_0), None);
+        // Without a template nothing can be told about the text
+        assert_eq!(
+            contradiction("mislabeled", License::Custom("Custom".to_owned())),
+            None
+        );
+    }
+
+    #[test]
+    fn latin_1_texts_are_found() {
+        let dir = TempDir::new();
+        let path = dir.path().join("latin/LICENSE");
+        let mut latin_1 = MIT_TEXT.replace("(c)", "\u{a9}").into_bytes();
+        // U+00A9 is a single byte in ISO-8859-1
+        let at = latin_1
+            .windows(2)
+            .position(|pair| pair == [0xc2, 0xa9])
+            .unwrap();
+        latin_1.remove(at);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &latin_1).unwrap();
+        assert!(String::from_utf8(latin_1).is_err());
+
+        let package =
+            testutil::path_package("latin", "1.0.0", Some("MIT"), &dir.path().join("latin"));
+        let text = find_generic_license_text(&package, &License::MIT, &DiscoveryConfig::default())
+            .unwrap()
+            .expect("the text is found");
+        assert_eq!(text.path, path);
+        assert_eq!(text.confidence, Confidence::Confident);
+        assert!(text.text.contains("Copyright \u{fffd}"), "{}", text.text);
+    }
+
+    #[test]
+    fn readme_license_sections() {
+        let dir = TempDir::new();
+        dir.write(
+            "section/README.md",
+            &format!(
+                "# section\n\nDoes things.\n\n## License\n\n{}\n## Contributing\n\nPRs welcome\n",
+                MIT_TEXT
+            ),
+        );
+        let package =
+            testutil::path_package("section", "1.0.0", Some("MIT"), &dir.path().join("section"));
+        let text = find_readme_license_text(&package, &License::MIT, &DiscoveryConfig::default())
+            .unwrap()
+            .expect("the section is found");
+        assert_eq!(text.origin, Origin::Readme);
+        assert_eq!(text.confidence, Confidence::Confident);
+        assert!(text.text.starts_with("MIT License"), "{}", text.text);
+        assert!(!text.text.contains("PRs welcome"), "{}", text.text);
+    }
+
+    #[test]
+    fn readmes_mentioning_a_license_have_no_text() {
+        let dir = TempDir::new();
+        dir.write(
+            "mention/README.md",
+            "# mention\n\nLike the MIT License, but faster.\n\n## License\n\n\
+             Licensed under the MIT License, see the LICENSE file for details.\n",
+        );
+        let package =
+            testutil::path_package("mention", "1.0.0", Some("MIT"), &dir.path().join("mention"));
+        let text =
+            find_readme_license_text(&package, &License::MIT, &DiscoveryConfig::default()).unwrap();
+        assert!(text.is_none(), "{:?}", text.map(|text| text.text));
+    }
+
+    #[test]
+    fn git_checkouts_inside_another_checkout() {
+        let dir = TempDir::new();
+        dir.write("outer/.git/HEAD", "ref: refs/heads/main\n");
+        dir.write(
+            "outer/LICENSE",
+            "All rights reserved by the outer project\n",
+        );
+        dir.write("outer/deps/checkout/.git/HEAD", "ref: refs/heads/main\n");
+        dir.write("outer/deps/checkout/LICENSE-MIT", MIT_TEXT);
+        dir.write("outer/deps/checkout/crates/inner/Cargo.toml", "");
+        dir.write("outer/deps/bare/.git/HEAD", "ref: refs/heads/main\n");
+        dir.write("outer/deps/bare/lone/Cargo.toml", "");
+        let checkout = dir.path().join("outer/deps/checkout");
+        let bare = dir.path().join("outer/deps/bare");
+        let config = DiscoveryConfig::default();
+
+        // The search stops at the nearest checkout root
+        let package = testutil::git_package("inner", "0.1.0", &checkout.join("crates/inner"));
+        assert_eq!(
+            search_dirs(&package).unwrap(),
+            [
+                checkout.join("crates/inner"),
+                checkout.join("crates"),
+                checkout.clone()
+            ]
+        );
+        let texts = find_license_text(&package, &License::MIT, &config).unwrap();
+        assert_eq!(
+            texts.iter().map(|text| &text.path).collect::<Vec<_>>(),
+            [&checkout.join("LICENSE-MIT")]
+        );
+
+        // Never into the checkout it's in, even without a license of its own
+        let package = testutil::git_package("lone", "0.1.0", &bare.join("lone"));
+        assert_eq!(
+            search_dirs(&package).unwrap(),
+            [bare.join("lone"), bare.clone()]
+        );
+        assert!(find_generic_license_text(&package, &License::MIT, &config)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn empty_custom_licenses_match_no_files() {
+        let dir = TempDir::new();
+        dir.write(
+            "blank/CHANGELOG.md",
+            "# Changelog\n\n## 0.1.0\n\n- Initial release\n",
+        );
+        dir.write("blank/README.md", "# blank\n");
+        dir.write("blank/LICENSE-", "Not a license\n");
+        let package = testutil::path_package("blank", "0.1.0", None, &dir.path().join("blank"));
+        let texts = find_license_text(
+            &package,
+            &License::Custom(String::new()),
+            &DiscoveryConfig::default(),
+        )
+        .unwrap();
+        assert!(
+            texts.is_empty(),
+            "{:?}",
+            texts.iter().map(|text| &text.path).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn custom_licenses_match_by_distinctive_words() {
+        let dir = TempDir::new();
+        dir.write("custom/CHANGELOG.md", "# Changelog\n");
+        dir.write("custom/LICENSE", "Some license\n");
+        dir.write("custom/LICENSE-BSD", "Some BSD-like license\n");
+        let package = testutil::path_package("custom", "0.1.0", None, &dir.path().join("custom"));
+        let matched = |custom: &str| {
+            find_license_text(
+                &package,
+                &License::Custom(custom.to_owned()),
+                &DiscoveryConfig::default(),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|text| {
+                text.path
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect::<Vec<_>>()
+        };
+
+        // Nothing distinctive, left to generic license file discovery
+        assert_eq!(matched("see LICENSE"), Vec::<String>::new());
+        assert_eq!(matched("BSD-like"), ["LICENSE-BSD"]);
+        assert_eq!(matched("MIT/X derivative"), Vec::<String>::new());
+
+        assert!(custom_name_matches("LICENSE-MIT-X.txt", "MIT/X derivative"));
+        assert!(custom_name_matches("PROPRIETARY", "Proprietary"));
+        assert!(!custom_name_matches("proprietary.rs", "Proprietary"));
+    }
+
+    #[test]
+    fn texts_without_a_template_have_no_score() {
+        let (confidence, score) = check_against_template(
+            "Do what you like",
+            &License::Custom("Custom".to_owned()),
+            &DiscoveryConfig::default(),
+        );
+        assert_eq!((confidence, score), (Confidence::Unsure, None));
+    }
+
+    #[test]
+    fn templates_match_their_own_texts() {
+        let config = DiscoveryConfig::default();
+        for license in KNOWN.iter() {
+            if let Some(template) = license.template() {
+                assert_eq!(
+                    check_against_template(template, license, &config).0,
+                    Confidence::Confident,
+                    "{}",
+                    license
+                );
+            }
+        }
+        for license in &[
+            License::CC0_1_0,
+            License::BSD_2_Clause,
+            License::X11,
+            License::MPL_1_1,
+            License::MPL_2_0,
+            License::LGPL_2_0,
+            License::LGPL_2_1,
+            License::LGPL_3_0,
+            License::GPL_2_0,
+            License::GPL_3_0,
+            License::AGPL_3_0,
+        ] {
+            assert!(license.template().is_some(), "{}", license);
+        }
+
+        let mpl = License::MPL_2_0.template().unwrap();
+        let (confidence, _) = check_against_template(mpl, &License::GPL_3_0, &config);
+        assert_ne!(confidence, Confidence::Confident);
+        let (confidence, _) = check_against_template(
+            License::GPL_3_0.template().unwrap(),
+            &License::MPL_2_0,
+            &config,
+        );
+        assert_ne!(confidence, Confidence::Confident);
+
+        // The GPL is often distributed without its "How to Apply" appendix
+        let gpl = required_terms(License::GPL_3_0.template().unwrap());
+        assert!(gpl.len() < License::GPL_3_0.template().unwrap().len());
+        assert_eq!(
+            check_against_template(gpl, &License::GPL_3_0, &config).0,
+            Confidence::Confident
+        );
+
+        let multiple = License::multiple(vec![License::MIT, License::Apache_2_0]);
+        assert_eq!(multiple.template(), None);
+    }
 }
@@ -0,0 +1,260 @@
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use cargo_metadata::Package;
+
+use crate::date::Date;
+use crate::discovery::{self, DiscoveryConfig};
+use crate::license::License;
+use crate::licensed::LicensedCache;
+use crate::outcome::Outcome;
+
+/// The name to write a license's text to, named so that discovery finds it
+/// again for that license
+fn file_name(license: &License, single: bool) -> String {
+    if single {
+        return "LICENSE".to_owned();
+    }
+    match license {
+        License::Apache_2_0 => "LICENSE-APACHE".to_owned(),
+        License::WithException { base, .. } => file_name(base, false),
+        license => format!("LICENSE-{}", license),
+    }
+}
+
+/// Fills in the copyright line of the MIT and BSD templates, other templates'
+/// placeholders are part of their instructions on how to apply them
+fn fill(template: &str, license: &License, package: &Package, year: &str) -> String {
+    match license {
+        License::MIT | License::BSD_0_Clause | License::BSD_2_Clause | License::BSD_3_Clause => (),
+        _ => return template.to_owned(),
+    }
+    let holders = if package.authors.is_empty() {
+        format!("The {} authors", package.name)
+    } else {
+        package
+            .authors
+            .iter()
+            .map(|author| author.split(" <").next().unwrap_or(author).trim())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    template
+        .replace("<YEAR>", year)
+        .replace("<year>", year)
+        .replace("<COPYRIGHT HOLDER>", &holders)
+        .replace("<owner>", &holders)
+        .replace("YEAR by AUTHOR EMAIL", &format!("{} by {}", year, holders))
+}
+
+/// Writes the template of each part of a local package's license that it has
+/// no license file for into its directory, or only lists them when `dry_run`
+pub fn run(
+    packages: &[&Package],
+    licenses: &LicensedCache,
+    dry_run: bool,
+) -> anyhow::Result<Outcome> {
+    let mut outcome = Outcome::default();
+    let config = DiscoveryConfig::default();
+    let year = Date::today().to_string()[..4].to_owned();
+
+    for package in packages {
+        if let Some(source) = &package.source {
+            log::error!(
+                "{} {} comes from {}, only local packages can be fixed",
+                package.name,
+                package.version,
+                source
+            );
+            outcome.fail("Some packages aren't local");
+            continue;
+        }
+
+        let resolved = licenses.resolve(package);
+        let license = &resolved.license;
+        if *license == License::Unspecified {
+            log::warn!(
+                "{} has no license, there's nothing to write a license file for",
+                package.name
+            );
+            outcome.warn("Some packages have no license");
+            continue;
+        }
+        if let Some(text) = discovery::find_generic_license_text(package, license, &config)? {
+            log::info!(
+                "{} already has a license file at {}",
+                package.name,
+                text.path.display()
+            );
+            continue;
+        }
+
+        let parts = match license {
+            License::Multiple(licenses) | License::All(licenses) => licenses.iter().collect(),
+            license => vec![license],
+        };
+        let dir = discovery::package_dir(package)?;
+        for part in &parts {
+            if let Some(text) = discovery::find_license_text(package, part, &config)?.first() {
+                log::info!(
+                    "{} already has a {} license file at {}",
+                    package.name,
+                    part,
+                    text.path.display()
+                );
+                continue;
+            }
+            let template = match part.template() {
+                Some(template) => template,
+                None => {
+                    log::warn!(
+                        "{} is licensed under {} which has no bundled license text, add its \
+                         license file by hand",
+                        package.name,
+                        part
+                    );
+                    outcome.warn("Some licenses have no bundled text");
+                    continue;
+                }
+            };
+
+            let path: PathBuf = dir.join(file_name(part, parts.len() == 1));
+            if dry_run {
+                println!("Would create {}", path.display());
+                continue;
+            }
+            // Never overwrite, a file with this name that didn't match the
+            // license may still be something the package relies on
+            let mut file = match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => file,
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    log::error!(
+                        "{} already exists but doesn't look like the {} license, not overwriting it",
+                        path.display(),
+                        part
+                    );
+                    outcome.fail("Some license files already exist");
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+            file.write_all(fill(template, part, package, &year).as_bytes())?;
+            println!("Created {}", path.display());
+        }
+    }
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::testutil::{package, path_package, TempDir};
+
+    #[test]
+    fn placeholders() {
+        let dir = TempDir::new();
+        let mut package = path_package("authored", "1.0.0", Some("MIT"), dir.path());
+        let mit = License::MIT.template().unwrap();
+        let filled = fill(mit, &License::MIT, &package, "2024");
+        assert!(filled.starts_with("Copyright 2024 The authored authors\n"));
+
+        package.authors = vec!["Jane Doe <jane@example.com>".to_owned(), "Bob".to_owned()];
+        let filled = fill(mit, &License::MIT, &package, "2024");
+        assert!(filled.starts_with("Copyright 2024 Jane Doe, Bob\n"));
+        assert!(!filled.contains('<'));
+
+        // The Apache appendix explains how to fill in its placeholders
+        let apache = License::Apache_2_0.template().unwrap();
+        assert_eq!(fill(apache, &License::Apache_2_0, &package, "2024"), apache);
+    }
+
+    #[test]
+    fn file_names() {
+        assert_eq!(file_name(&License::MIT, true), "LICENSE");
+        assert_eq!(file_name(&License::MIT, false), "LICENSE-MIT");
+        assert_eq!(file_name(&License::Apache_2_0, false), "LICENSE-APACHE");
+        let llvm = "Apache-2.0 WITH LLVM-exception".parse().unwrap();
+        assert_eq!(file_name(&llvm, false), "LICENSE-APACHE");
+    }
+
+    #[test]
+    fn writes_missing_files() {
+        let dir = TempDir::new();
+        let dual = path_package(
+            "fix-dual",
+            "1.0.0",
+            Some("MIT OR Apache-2.0"),
+            &dir.path().join("dual"),
+        );
+        dir.write("dual/Cargo.toml", "");
+        let dry = run(&[&dual], &LicensedCache::new(false, false), true).unwrap();
+        assert!(dry.failures.is_empty() && dry.warnings.is_empty());
+        assert!(!dir.path().join("dual/LICENSE-MIT").exists());
+
+        let outcome = run(&[&dual], &LicensedCache::new(false, false), false).unwrap();
+        assert!(outcome.failures.is_empty() && outcome.warnings.is_empty());
+        let mit = fs::read_to_string(dir.path().join("dual/LICENSE-MIT")).unwrap();
+        assert!(mit.starts_with("Copyright "));
+        assert!(mit.contains("The fix-dual authors"));
+        let apache = fs::read_to_string(dir.path().join("dual/LICENSE-APACHE")).unwrap();
+        assert_eq!(apache, License::Apache_2_0.template().unwrap());
+
+        // Found again, so there's nothing left to do
+        let again = run(&[&dual], &LicensedCache::new(false, false), false).unwrap();
+        assert!(again.failures.is_empty() && again.warnings.is_empty());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("dual/LICENSE-MIT")).unwrap(),
+            mit
+        );
+    }
+
+    #[test]
+    fn refusals() {
+        let dir = TempDir::new();
+
+        let registry = package(
+            "fix-registry",
+            "1.0.0",
+            Some("MIT"),
+            &dir.path().join("registry"),
+        );
+        dir.write("registry/Cargo.toml", "");
+        let outcome = run(&[&registry], &LicensedCache::new(false, false), false).unwrap();
+        assert_eq!(outcome.failures, ["Some packages aren't local"]);
+        assert!(!dir.path().join("registry/LICENSE").exists());
+
+        // Not recognisably MIT, but a license file all the same, it's left
+        // for the package's authors to sort out
+        let existing = path_package(
+            "fix-existing",
+            "1.0.0",
+            Some("MIT"),
+            &dir.path().join("existing"),
+        );
+        dir.write("existing/LICENSE", "All rights reserved\n");
+        run(&[&existing], &LicensedCache::new(false, false), false).unwrap();
+        assert_eq!(
+            fs::read_dir(dir.path().join("existing")).unwrap().count(),
+            1
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("existing/LICENSE")).unwrap(),
+            "All rights reserved\n"
+        );
+
+        let custom = path_package(
+            "fix-custom",
+            "1.0.0",
+            Some("LicenseRef-Internal"),
+            &dir.path().join("custom"),
+        );
+        dir.write("custom/Cargo.toml", "");
+        let outcome = run(&[&custom], &LicensedCache::new(false, false), false).unwrap();
+        assert_eq!(outcome.warnings, ["Some licenses have no bundled text"]);
+        assert_eq!(fs::read_dir(dir.path().join("custom")).unwrap().count(), 1);
+    }
+}
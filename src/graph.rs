@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::io;
+
+use cargo_metadata::{Package, PackageId};
+
+use crate::license::{Family, License};
+use crate::load::{self, ResolvedPackage};
+use crate::options::GraphFormat;
+use crate::query::MetadataIndex;
+
+struct Node<'a> {
+    id: String,
+    package: &'a Package,
+    license: &'a License,
+    family: Family,
+    member: bool,
+}
+
+impl Node<'_> {
+    fn label(&self) -> [String; 3] {
+        [
+            self.package.name.clone(),
+            self.package.version.to_string(),
+            self.license.to_string(),
+        ]
+    }
+}
+
+fn color(family: Family) -> &'static str {
+    match family {
+        Family::PublicDomain => "#bbeebb",
+        Family::Permissive => "#99dd99",
+        Family::WeakCopyleft => "#ffbb66",
+        Family::StrongCopyleft => "#ee7777",
+        Family::NetworkCopyleft => "#cc5555",
+        Family::Unknown => "#cccccc",
+    }
+}
+
+fn class(family: Family) -> &'static str {
+    match family {
+        Family::PublicDomain => "public_domain",
+        Family::Permissive => "permissive",
+        Family::WeakCopyleft => "weak_copyleft",
+        Family::StrongCopyleft => "strong_copyleft",
+        Family::NetworkCopyleft => "network_copyleft",
+        Family::Unknown => "unknown",
+    }
+}
+
+pub fn run(
+    index: &MetadataIndex,
+    resolved: &[ResolvedPackage],
+    format: GraphFormat,
+    max_depth: Option<u32>,
+    out: &mut dyn io::Write,
+) -> anyhow::Result<()> {
+    let members = &index.metadata().workspace_members;
+    let nodes = resolved
+        .iter()
+        .filter(|resolved| max_depth.is_none_or(|max_depth| resolved.depth <= max_depth))
+        .enumerate()
+        .map(|(index, resolved)| Node {
+            id: format!("n{}", index),
+            package: resolved.package,
+            license: &resolved.license.license,
+            family: resolved.license.license.family(),
+            member: members.contains(&resolved.package.id),
+        })
+        .collect::<Vec<_>>();
+
+    // Dependencies on other copies of a package from a different source are
+    // drawn to the single node for it
+    let duplicates = load::duplicates(resolved);
+    let by_id = nodes
+        .iter()
+        .flat_map(|node| {
+            let copies = duplicates.get(&node.package.id).into_iter().flatten();
+            Some(&node.package.id)
+                .into_iter()
+                .chain(copies.map(|package| &package.id))
+                .map(move |id| (id, node))
+        })
+        .collect::<HashMap<&PackageId, &Node>>();
+    let mut edges = Vec::new();
+    for node in &nodes {
+        for dep in load::normal_deps(index, &node.package.id)? {
+            if let Some(dep) = by_id.get(dep) {
+                edges.push((node, *dep));
+            }
+        }
+    }
+
+    match format {
+        GraphFormat::Dot => dot(&nodes, &edges, out)?,
+        GraphFormat::Mermaid => mermaid(&nodes, &edges, out)?,
+    }
+    Ok(())
+}
+
+fn dot(nodes: &[Node], edges: &[(&Node, &Node)], out: &mut dyn io::Write) -> io::Result<()> {
+    fn escape(s: &str) -> String {
+        s.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    writeln!(out, "digraph dependencies {{")?;
+    writeln!(out, "    node [style=filled];")?;
+    for node in nodes {
+        writeln!(
+            out,
+            "    {} [label=\"{}\", shape={}, fillcolor=\"{}\"];",
+            node.id,
+            node.label()
+                .iter()
+                .map(|line| escape(line))
+                .collect::<Vec<_>>()
+                .join("\\n"),
+            if node.member { "box" } else { "ellipse" },
+            color(node.family)
+        )?;
+    }
+    for (from, to) in edges {
+        writeln!(out, "    {} -> {};", from.id, to.id)?;
+    }
+    writeln!(out, "}}")?;
+    Ok(())
+}
+
+fn mermaid(nodes: &[Node], edges: &[(&Node, &Node)], out: &mut dyn io::Write) -> io::Result<()> {
+    // Mermaid labels can't contain a literal quote, but do support HTML
+    // entity codes in its own `#code;` syntax
+    fn escape(s: &str) -> String {
+        s.replace('#', "#35;")
+            .replace('"', "#quot;")
+            .replace('<', "#lt;")
+            .replace('>', "#gt;")
+    }
+
+    writeln!(out, "graph TD")?;
+    for family in [
+        Family::PublicDomain,
+        Family::Permissive,
+        Family::WeakCopyleft,
+        Family::StrongCopyleft,
+        Family::NetworkCopyleft,
+        Family::Unknown,
+    ] {
+        writeln!(out, "    classDef {} fill:{}", class(family), color(family))?;
+    }
+    for node in nodes {
+        let label = node
+            .label()
+            .iter()
+            .map(|line| escape(line))
+            .collect::<Vec<_>>()
+            .join("<br>");
+        if node.member {
+            writeln!(
+                out,
+                "    {}[[\"{}\"]]:::{}",
+                node.id,
+                label,
+                class(node.family)
+            )?;
+        } else {
+            writeln!(
+                out,
+                "    {}(\"{}\"):::{}",
+                node.id,
+                label,
+                class(node.family)
+            )?;
+        }
+    }
+    for (from, to) in edges {
+        writeln!(out, "    {} --> {}", from.id, to.id)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::licensed::LicensedCache;
+    use crate::options::Filter;
+    use crate::testutil::{self, package};
+
+    fn render(format: GraphFormat, max_depth: Option<u32>) -> String {
+        let dir = Path::new("/nonexistent");
+        let packages = [
+            package("viewer", "0.1.0", Some("MIT"), dir),
+            package("plot \"fancy\"", "2.0.0", Some("LGPL-3.0-only"), dir),
+            package("gnu", "1.0.0", Some("GPL-3.0-only"), dir),
+            package("mystery", "0.0.1", None, dir),
+            package("devtool", "1.0.0", Some("AGPL-3.0-only"), dir),
+        ];
+        // viewer -> plot -> gnu and viewer -> mystery, devtool is only a dev
+        // dependency
+        let deps = [
+            (0, 1, None),
+            (0, 3, None),
+            (1, 2, None),
+            (0, 4, Some("dev")),
+        ];
+        let metadata = testutil::metadata(&packages, &deps);
+        let index = MetadataIndex::new(&metadata);
+        let licenses = LicensedCache::new(false, false);
+        let resolved =
+            load::resolve_packages(&index, &[&packages[0]], &Filter::default(), &licenses).unwrap();
+        let mut out = Vec::new();
+        run(&index, &resolved, format, max_depth, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn dot() {
+        assert_eq!(
+            render(GraphFormat::Dot, None),
+            r##"digraph dependencies {
+    node [style=filled];
+    n0 [label="viewer\n0.1.0\nMIT", shape=box, fillcolor="#99dd99"];
+    n1 [label="plot \"fancy\"\n2.0.0\nLGPL-3.0-only", shape=ellipse, fillcolor="#ffbb66"];
+    n2 [label="mystery\n0.0.1\nNo license specified", shape=ellipse, fillcolor="#cccccc"];
+    n3 [label="gnu\n1.0.0\nGPL-3.0-only", shape=ellipse, fillcolor="#ee7777"];
+    n0 -> n1;
+    n0 -> n2;
+    n1 -> n3;
+}
+"##
+        );
+    }
+
+    #[test]
+    fn mermaid() {
+        assert_eq!(
+            render(GraphFormat::Mermaid, None),
+            r##"graph TD
+    classDef public_domain fill:#bbeebb
+    classDef permissive fill:#99dd99
+    classDef weak_copyleft fill:#ffbb66
+    classDef strong_copyleft fill:#ee7777
+    classDef network_copyleft fill:#cc5555
+    classDef unknown fill:#cccccc
+    n0[["viewer<br>0.1.0<br>MIT"]]:::permissive
+    n1("plot #quot;fancy#quot;<br>2.0.0<br>LGPL-3.0-only"):::weak_copyleft
+    n2("mystery<br>0.0.1<br>No license specified"):::unknown
+    n3("gnu<br>1.0.0<br>GPL-3.0-only"):::strong_copyleft
+    n0 --> n1
+    n0 --> n2
+    n1 --> n3
+"##
+        );
+    }
+
+    #[test]
+    fn max_depth() {
+        let graph = render(GraphFormat::Dot, Some(1));
+        assert!(graph.contains("n2 [label=\"mystery"), "{}", graph);
+        assert!(!graph.contains("gnu"), "{}", graph);
+        assert!(!graph.contains("n3"), "{}", graph);
+    }
+}
@@ -0,0 +1,250 @@
+use cargo_metadata::Package;
+
+/// Holders of crates other than their own, which match no package metadata
+const UMBRELLA_HOLDERS: &[&str] = &[
+    "the rust project developers",
+    "the servo project developers",
+    "the rustcrypto project developers",
+    "the tokio contributors",
+];
+
+/// Words too common in holders and metadata to tell projects apart
+const IGNORED_WORDS: &[&str] = &[
+    "the",
+    "and",
+    "of",
+    "by",
+    "inc",
+    "llc",
+    "ltd",
+    "gmbh",
+    "co",
+    "corp",
+    "corporation",
+    "company",
+    "authors",
+    "contributors",
+    "developers",
+    "project",
+    "team",
+    "rs",
+    "rust",
+    "com",
+    "org",
+    "net",
+    "io",
+    "www",
+    "https",
+    "http",
+    "github",
+    "gitlab",
+];
+
+/// Copyright notices from a license text, skipping template placeholders
+pub fn copyright_lines(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| line.to_lowercase().starts_with("copyright "))
+        .filter(|line| !line.contains("[yyyy]") && !line.to_lowercase().contains("<year>"))
+        .collect()
+}
+
+/// Whether a line is a copyright notice or a template's placeholder for one,
+/// rather than license terms mentioning copyright
+fn is_notice(line: &str) -> bool {
+    let line = line.trim().to_lowercase();
+    let next = match line.strip_prefix("copyright ") {
+        Some(rest) => rest.trim_start(),
+        None => return false,
+    };
+    ["(c)", "©", "[yyyy]", "<year>", "{yyyy}"]
+        .iter()
+        .any(|start| next.starts_with(start))
+        || next.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// A license text without its copyright notices, for sharing between
+/// packages whose notices are listed separately
+pub fn without_notices(text: &str) -> String {
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        if is_notice(line) {
+            continue;
+        }
+        // Don't leave a gap twice as wide where a notice was
+        let blank = line.trim().is_empty();
+        if blank
+            && lines
+                .last()
+                .is_none_or(|last: &&str| last.trim().is_empty())
+        {
+            continue;
+        }
+        lines.push(line);
+    }
+    lines.join("\n").trim_end().to_owned()
+}
+
+/// The holder of a copyright notice, e.g. `Foo Corp` of `Copyright (c)
+/// 2019-2020 Foo Corp <foo@example.com>. All rights reserved.`
+fn holder(line: &str) -> Option<String> {
+    let line = &line["copyright".len()..];
+    let lower = line.to_lowercase();
+    let line = match lower.find("all rights reserved") {
+        Some(end) => &line[..end],
+        None => line,
+    };
+    let words = line
+        .split_whitespace()
+        .filter(|word| !["(c)", "©", "copyright"].contains(&&*word.to_lowercase()))
+        .filter(|word| !word.contains('@'))
+        .filter(|word| {
+            !word
+                .chars()
+                .all(|c| c.is_ascii_digit() || ['-', ',', '–'].contains(&c))
+        })
+        .collect::<Vec<_>>();
+    let holder = words.join(" ");
+    let holder = holder.trim_matches(|c: char| c.is_ascii_punctuation() || c.is_whitespace());
+    Some(holder.to_owned()).filter(|holder| !holder.is_empty())
+}
+
+/// The distinctive words of `text`, lowercased
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|word| word.chars().count() > 1 && !word.chars().all(|c| c.is_ascii_digit()))
+        .filter(|word| !IGNORED_WORDS.contains(&&**word))
+        .collect()
+}
+
+/// The owner of a repository hosted at e.g. `https://github.com/OWNER/REPO`
+fn repository_owner(url: &str) -> Option<&str> {
+    let path = url.split_once("://").map_or(url, |(_, rest)| rest);
+    path.split('/').nth(1).filter(|owner| !owner.is_empty())
+}
+
+/// The copyright holder of a license text that shares no word with the
+/// package's name, authors or repository owner, a sign that the text was
+/// copied from another project. Packages without authors and umbrella
+/// holders of many projects' crates are never reported.
+pub fn mismatched_holder(text: &str, package: &Package) -> Option<String> {
+    if package.authors.is_empty() {
+        return None;
+    }
+    let mut known = words(&package.name);
+    for author in &package.authors {
+        known.extend(words(author));
+    }
+    if let Some(owner) = package.repository.as_deref().and_then(repository_owner) {
+        known.extend(words(owner));
+    }
+
+    let mut mismatched = None;
+    for holder in copyright_lines(text).into_iter().filter_map(holder) {
+        if UMBRELLA_HOLDERS.contains(&&*holder.to_lowercase()) {
+            return None;
+        }
+        let words = words(&holder);
+        // Nothing to compare for generic holders like `The Authors`
+        if words.is_empty() || words.iter().any(|word| known.contains(word)) {
+            return None;
+        }
+        mismatched.get_or_insert(holder);
+    }
+    mismatched
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::testutil::package;
+
+    fn authored(name: &str, authors: &[&str], repository: Option<&str>) -> Package {
+        let mut package = package(name, "1.0.0", Some("MIT"), Path::new("/nonexistent"));
+        package.authors = authors.iter().map(|&author| author.to_owned()).collect();
+        package.repository = repository.map(ToOwned::to_owned);
+        package
+    }
+
+    #[test]
+    fn holders() {
+        assert_eq!(
+            holder("Copyright (c) 2019-2020 Foo Corp <foo@example.com>. All rights reserved."),
+            Some("Foo Corp".to_owned())
+        );
+        assert_eq!(
+            holder("Copyright © 2015, 2016 Jane Doe"),
+            Some("Jane Doe".to_owned())
+        );
+        assert_eq!(holder("Copyright 2020"), None);
+    }
+
+    #[test]
+    fn texts_without_notices() {
+        let text = "MIT License\n\nCopyright (c) 2020 Jane Doe\nCopyright 2021 Foo Corp\n\n\
+                    Permission is hereby granted\n\nThe above copyright notice shall be included\n";
+        assert_eq!(
+            without_notices(text),
+            "MIT License\n\nPermission is hereby granted\n\nThe above copyright notice shall be \
+             included"
+        );
+        assert_eq!(
+            without_notices("Copyright <YEAR> <COPYRIGHT HOLDER>\n\nPermission is granted\n"),
+            "Permission is granted"
+        );
+        let apache = "copyright notice that is included in or attached to the work\n";
+        assert_eq!(without_notices(apache), apache.trim_end());
+    }
+
+    #[test]
+    fn matching_holders() {
+        let package = authored(
+            "bar-rs",
+            &["Jane Doe <jane@example.com>"],
+            Some("https://github.com/acme/bar"),
+        );
+        for text in [
+            "Copyright (c) 2020 Jane Doe\n",
+            "Copyright (c) 2020 The bar developers\n",
+            "Copyright (c) 2020 Acme Inc.\n",
+            // Any of several holders is enough
+            "Copyright (c) 2018 Foo Corp\nCopyright (c) 2020 Jane Doe\n",
+            "Copyright (c) 2020 The Authors\n",
+            "MIT License\n",
+        ] {
+            assert_eq!(mismatched_holder(text, &package), None, "{}", text);
+        }
+    }
+
+    #[test]
+    fn mismatched_holders() {
+        let package = authored(
+            "bar-rs",
+            &["Jane Doe <jane@example.com>"],
+            Some("https://github.com/acme/bar"),
+        );
+        assert_eq!(
+            mismatched_holder("MIT License\n\nCopyright (c) 2017 Foo Corp\n", &package),
+            Some("Foo Corp".to_owned())
+        );
+
+        // Without authors there's too little to go on
+        let anonymous = authored("bar-rs", &[], None);
+        assert_eq!(
+            mismatched_holder("Copyright (c) 2017 Foo Corp\n", &anonymous),
+            None
+        );
+    }
+
+    #[test]
+    fn umbrella_holders() {
+        let package = authored("bar-rs", &["Jane Doe"], None);
+        assert_eq!(
+            mismatched_holder("Copyright (c) 2014 The Rust Project Developers\n", &package),
+            None
+        );
+    }
+}
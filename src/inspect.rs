@@ -0,0 +1,95 @@
+use std::io::Write;
+use std::path::Path;
+
+use cargo_metadata::Package;
+
+use crate::bundle;
+use crate::discovery::{package_dir, source_available, virtual_manifest, DiscoveryConfig};
+use crate::license::License;
+use crate::licensed::{Licensed, LicensedCache};
+
+/// Shows how a package's license was parsed and every decision license
+/// discovery made for it, for debugging why the wrong text or none was found
+pub fn run(
+    package: &Package,
+    workspace_root: &Path,
+    config: &DiscoveryConfig,
+    licenses: &LicensedCache,
+    out: &mut dyn Write,
+) -> anyhow::Result<()> {
+    let dir = package_dir(package)?;
+    let relative = |path: &Path| path.strip_prefix(dir).unwrap_or(path).display().to_string();
+    let resolved = licenses.resolve(package);
+    let license = &resolved.license;
+
+    writeln!(out, "{} {}", package.name, package.version)?;
+    writeln!(out, "  directory:         {}", dir.display())?;
+    match package.declared_license_str() {
+        Some(declared) => writeln!(out, "  declared license:  {:?}", declared)?,
+        None => writeln!(out, "  declared license:  none")?,
+    }
+    if let Some(file) = package.declared_license_file() {
+        writeln!(out, "  license file:      {}", file.display())?;
+    }
+    writeln!(out, "  parsed license:    {}", license)?;
+    match license {
+        License::Multiple(licenses) => writeln!(
+            out,
+            "  any one of:        {}",
+            licenses
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?,
+        License::All(licenses) => writeln!(
+            out,
+            "  all of:            {}",
+            licenses
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?,
+        _ => (),
+    }
+    if !source_available(package) {
+        writeln!(out, "  sources are not available locally")?;
+        return Ok(());
+    }
+    if virtual_manifest(package) {
+        writeln!(
+            out,
+            "  manifest is a workspace virtual manifest, there are no package sources"
+        )?;
+        return Ok(());
+    }
+
+    let trace = bundle::inspect(package, workspace_root, config, licenses)?;
+    write!(out, "  entries examined:  {}", trace.entries)?;
+    if trace.capped {
+        write!(
+            out,
+            ", stopped at --max-search-entries {}",
+            config.max_entries
+        )?;
+    }
+    writeln!(out)?;
+    writeln!(out)?;
+    writeln!(out, "Files considered:")?;
+    if trace.files.is_empty() {
+        writeln!(out, "  none")?;
+    }
+    for (path, decision) in &trace.files {
+        writeln!(out, "  {}: {}", relative(path), decision)?;
+    }
+    writeln!(out)?;
+    writeln!(out, "Chosen:")?;
+    for (license, path, reason) in &trace.choices {
+        match path {
+            Some(path) => writeln!(out, "  {}: {}, {}", license, relative(path), reason)?,
+            None => writeln!(out, "  {}: nothing, {}", license, reason)?,
+        }
+    }
+    Ok(())
+}
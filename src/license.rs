@@ -1,9 +1,13 @@
+use std::collections::BTreeMap;
 use std::fmt;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
-#[allow(non_camel_case_types)]
+use anyhow::anyhow;
+
+#[derive(Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Default)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
 pub enum License {
     // Licenses specified in the [SPDX License List](https://spdx.org/licenses/)
     Unlicense,
@@ -27,17 +31,157 @@ pub enum License {
     GPL_3_0Plus,
     AGPL_3_0,
     AGPL_3_0Plus,
+    CC_BY_3_0,
+    CC_BY_4_0,
+    CC_BY_SA_3_0,
+    CC_BY_SA_4_0,
+    GFDL_1_2,
+    GFDL_1_2Plus,
+    GFDL_1_3,
+    GFDL_1_3Plus,
 
     // Special cases
     Custom(String),
     File(PathBuf),
+    /// A choice of any of these licenses, e.g. `MIT OR Apache-2.0`. Build it
+    /// with `License::multiple`, which keeps the alternatives sorted,
+    /// distinct and flat.
     Multiple(Vec<License>),
+    /// All of these licenses apply at once, e.g. `MIT AND CC-BY-SA-4.0`.
+    /// Build it with `License::all`, like `Multiple`.
+    All(Vec<License>),
+    /// A license with an additional SPDX exception, e.g. `Apache-2.0 WITH LLVM-exception`
+    WithException {
+        base: Box<License>,
+        exception: String,
+    },
+    #[default]
     Unspecified,
 }
 
-impl Default for License {
-    fn default() -> License {
-        License::Unspecified
+/// Rough grouping of licenses by how much they require of their users,
+/// ordered from least to most restrictive with unknown licenses last
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Family {
+    /// Dedications to the public domain or the closest to it, e.g. CC0
+    PublicDomain,
+    Permissive,
+    /// Copyleft limited to the licensed files or library, e.g. LGPL and MPL
+    WeakCopyleft,
+    /// Copyleft extending to the whole combined work, e.g. GPL
+    StrongCopyleft,
+    /// Strong copyleft also covering use over a network, e.g. AGPL
+    NetworkCopyleft,
+    Unknown,
+}
+
+/// How far the copyleft of a license reaches into works using it
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum CopyleftScope {
+    None,
+    /// Only the licensed files themselves, e.g. MPL
+    FileLevel,
+    /// The licensed library, which users must be able to replace, e.g. LGPL
+    Library,
+    /// The whole combined work, e.g. GPL
+    Strong,
+}
+
+/// How dependencies end up in the distributed binaries
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Linkage {
+    /// The Rust default, all crates are compiled into one binary
+    #[default]
+    Static,
+    /// Dependencies are shipped as separate shared libraries
+    Dynamic,
+}
+
+/// What else compatibility depends on beyond the two licenses
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CheckContext {
+    pub linkage: Linkage,
+    /// An including work declaring no license is all rights reserved and not
+    /// distributed, e.g. a private application, rather than unusable with
+    /// anything but the most permissive licenses
+    pub private: bool,
+}
+
+/// What a license requires of those distributing the licensed work, roughly
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Obligations {
+    pub requires_source_disclosure_for_modifications: bool,
+    pub requires_license_text_distribution: bool,
+    pub requires_notice_preservation: bool,
+    pub patent_grant: bool,
+    pub copyleft_scope: CopyleftScope,
+}
+
+impl Obligations {
+    const NONE: Obligations = Obligations {
+        requires_source_disclosure_for_modifications: false,
+        requires_license_text_distribution: false,
+        requires_notice_preservation: false,
+        patent_grant: false,
+        copyleft_scope: CopyleftScope::None,
+    };
+
+    const PERMISSIVE: Obligations = Obligations {
+        requires_license_text_distribution: true,
+        requires_notice_preservation: true,
+        ..Obligations::NONE
+    };
+
+    const fn copyleft(copyleft_scope: CopyleftScope, patent_grant: bool) -> Obligations {
+        Obligations {
+            requires_source_disclosure_for_modifications: true,
+            requires_license_text_distribution: true,
+            requires_notice_preservation: true,
+            patent_grant,
+            copyleft_scope,
+        }
+    }
+
+    /// Both sets of obligations apply at once
+    fn union(self, other: Obligations) -> Obligations {
+        Obligations {
+            requires_source_disclosure_for_modifications: self
+                .requires_source_disclosure_for_modifications
+                || other.requires_source_disclosure_for_modifications,
+            requires_license_text_distribution: self.requires_license_text_distribution
+                || other.requires_license_text_distribution,
+            requires_notice_preservation: self.requires_notice_preservation
+                || other.requires_notice_preservation,
+            patent_grant: self.patent_grant || other.patent_grant,
+            copyleft_scope: self.copyleft_scope.max(other.copyleft_scope),
+        }
+    }
+
+    /// Short descriptions of each obligation that applies
+    pub fn describe(&self) -> Vec<&'static str> {
+        let mut descriptions = Vec::new();
+        match self.copyleft_scope {
+            CopyleftScope::None => (),
+            CopyleftScope::FileLevel => descriptions.push("file-level copyleft"),
+            CopyleftScope::Library => descriptions.push("library copyleft"),
+            CopyleftScope::Strong => descriptions.push("strong copyleft"),
+        }
+        if self.requires_source_disclosure_for_modifications {
+            descriptions.push("publish source of modifications");
+        }
+        if self.requires_license_text_distribution {
+            descriptions.push("distribute license text");
+        }
+        if self.requires_notice_preservation {
+            descriptions.push("preserve notices");
+        }
+        if self.patent_grant {
+            descriptions.push("patent grant");
+        }
+        if descriptions.is_empty() {
+            descriptions.push("no obligations");
+        }
+        descriptions
     }
 }
 
@@ -45,193 +189,2087 @@ macro_rules! compatibility {
   ($s:expr, $o:expr, { $($a:pat => [$($b:pat),+])+ }) => {
     match $s {
       $(
-        $a => if let $($b)|+ = $o {
-          return Some(true);
-        }
-      ),*
+        $a => matches!($o, $($b)|+),
+      )*
     }
   };
 }
 
+/// Why a license can or can't include another, the rule of
+/// `License::can_include` that decided it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Explanation {
+    /// The included work declares no license, so nothing grants using it
+    Unlicensed,
+    /// Custom and file licenses can't be evaluated
+    Unevaluable(License),
+    /// There's no compatibility data for this license
+    NotEncoded(License),
+    /// A permissive work including an LGPL library, compatible when users can
+    /// replace the library
+    LgplLinkage { lgpl: License, linkage: Linkage },
+    /// The included license only asks for what the including one carries
+    /// over, e.g. attribution
+    Permissive {
+        including: License,
+        included: License,
+    },
+    /// The included copyleft requires the combined work to be licensed
+    /// compatibly, which the including license is
+    CopyleftSatisfied {
+        including: License,
+        included: License,
+    },
+    /// The included copyleft requires the combined work to be licensed
+    /// compatibly, which the including license isn't
+    CopyleftViolated {
+        including: License,
+        included: License,
+    },
+    /// The included license has terms the including one can't carry over,
+    /// e.g. the Apache-2.0 patent terms under GPL-2.0
+    ConflictingTerms {
+        including: License,
+        included: License,
+    },
+    /// None of the alternatives of a choice of licenses can be included
+    NoAlternative(License),
+    /// A private work that isn't distributed can use anything short of
+    /// strong copyleft without taking on its obligations
+    Private(License),
+    /// A private work can use strong copyleft, but would have to be released
+    /// under it if it were ever distributed
+    PrivateCopyleft(License),
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Explanation::Unlicensed => {
+                write!(
+                    w,
+                    "it declares no license, so nothing grants the right to include it"
+                )
+            }
+            Explanation::Unevaluable(license) => write!(
+                w,
+                "unknown because custom licenses like {} cannot be evaluated",
+                license
+            ),
+            Explanation::NotEncoded(license) => {
+                write!(w, "unknown: {} compatibility data not encoded", license)
+            }
+            Explanation::LgplLinkage {
+                lgpl,
+                linkage: Linkage::Static,
+            } => write!(
+                w,
+                "unknown: {} requires users to be able to relink against a modified library, \
+                 which static linking makes hard",
+                lgpl
+            ),
+            Explanation::LgplLinkage {
+                lgpl,
+                linkage: Linkage::Dynamic,
+            } => write!(
+                w,
+                "{} only requires users to be able to replace the library, which a shared \
+                 library allows",
+                lgpl
+            ),
+            Explanation::Permissive {
+                including,
+                included,
+            } => write!(
+                w,
+                "{} is permissive, its terms can be met by a work under {}",
+                included, including
+            ),
+            Explanation::CopyleftSatisfied {
+                including,
+                included,
+            } => write!(
+                w,
+                "{} is copyleft, it requires the combined work to be licensed compatibly, \
+                 which {} is",
+                included, including
+            ),
+            Explanation::CopyleftViolated {
+                including,
+                included,
+            } => write!(
+                w,
+                "{} is copyleft, it requires the combined work to be licensed compatibly and \
+                 {} is not compatible with it in that direction",
+                included, including
+            ),
+            Explanation::ConflictingTerms {
+                including,
+                included,
+            } => write!(
+                w,
+                "{} has terms that {} cannot carry over",
+                included, including
+            ),
+            Explanation::NoAlternative(license) => {
+                write!(w, "none of the alternatives of {} can be included", license)
+            }
+            Explanation::Private(license) => write!(
+                w,
+                "{} only places obligations on distributing the work, which a private work \
+                 doesn't do",
+                license
+            ),
+            Explanation::PrivateCopyleft(license) => write!(
+                w,
+                "needs review: {} is strong copyleft, fine while the work stays private but \
+                 distributing it would require releasing the whole work under {}",
+                license, license
+            ),
+        }
+    }
+}
+
 impl License {
-    pub fn can_include(&self, other: &License) -> Option<bool> {
+    pub fn can_include(&self, other: &License, context: &CheckContext) -> Option<bool> {
+        self.can_include_explain(other, context).0
+    }
+
+    /// Whether this license can include `other` along with the rule that
+    /// decided it, for a choice or combination of licenses the rule deciding
+    /// the part that decided the whole
+    pub fn can_include_explain(
+        &self,
+        other: &License,
+        context: &CheckContext,
+    ) -> (Option<bool>, Explanation) {
         use self::License::*;
 
         if let Unspecified = *other {
-            return Some(false);
+            return (Some(false), Explanation::Unlicensed);
         }
 
-        if let Custom(_) = *self {
-            return None;
+        if let Custom(_) | File(_) = *self {
+            return (None, Explanation::Unevaluable(self.clone()));
         }
-        if let Custom(_) = *other {
-            return None;
+        if let Custom(_) | File(_) = *other {
+            return (None, Explanation::Unevaluable(other.clone()));
         }
-        if let File(_) = *self {
-            return None;
+
+        // Exceptions only ever grant additional permissions, so are at least
+        // as compatible as their base license
+        if let WithException { ref base, .. } = *self {
+            return base.can_include_explain(other, context);
         }
-        if let File(_) = *other {
-            return None;
+        if let WithException { ref base, .. } = *other {
+            return self.can_include_explain(base, context);
         }
 
-        if let Multiple(ref licenses) = *self {
+        if let All(ref licenses) = *other {
+            let mut unknown = None;
+            let mut last = None;
             for license in licenses {
-                if let Some(can_include) = license.can_include(other) {
-                    if !can_include {
-                        return Some(false);
-                    }
-                } else {
-                    return None;
+                match self.can_include_explain(license, context) {
+                    (Some(false), explanation) => return (Some(false), explanation),
+                    (None, explanation) => unknown = unknown.or(Some(explanation)),
+                    (Some(true), explanation) => last = Some(explanation),
                 }
             }
-            return Some(true);
+            return match unknown {
+                Some(explanation) => (None, explanation),
+                None => (Some(true), last.expect("combinations aren't empty")),
+            };
+        }
+
+        if let Multiple(ref licenses) | All(ref licenses) = *self {
+            let mut last = None;
+            for license in licenses {
+                match license.can_include_explain(other, context) {
+                    (Some(true), explanation) => last = Some(explanation),
+                    decided => return decided,
+                }
+            }
+            return (Some(true), last.expect("choices aren't empty"));
         }
 
         if let Multiple(ref licenses) = *other {
-            let mut seen_none = false;
+            let mut unknown = None;
             for license in licenses {
-                if let Some(can_include) = self.can_include(license) {
-                    if can_include {
-                        return Some(true);
-                    }
-                } else {
-                    seen_none = true;
+                match self.can_include_explain(license, context) {
+                    (Some(true), explanation) => return (Some(true), explanation),
+                    (None, explanation) => unknown = unknown.or(Some(explanation)),
+                    (Some(false), _) => (),
                 }
             }
-            return if seen_none { None } else { Some(false) };
+            return match unknown {
+                Some(explanation) => (None, explanation),
+                None => (Some(false), Explanation::NoAlternative(other.clone())),
+            };
+        }
+
+        if let (Unspecified, true) = (self, context.private) {
+            return match other.family() {
+                Family::StrongCopyleft | Family::NetworkCopyleft => {
+                    (None, Explanation::PrivateCopyleft(other.clone()))
+                }
+                _ => (Some(true), Explanation::Private(other.clone())),
+            };
+        }
+
+        // A permissively licensed work can use an LGPL library as long as
+        // users can replace it, which is easy when it's a separate shared
+        // library but needs relinkable object files when statically linked
+        if matches!(self.family(), Family::PublicDomain | Family::Permissive)
+            && matches!(
+                other,
+                LGPL_2_0 | LGPL_2_1 | LGPL_2_1Plus | LGPL_3_0 | LGPL_3_0Plus
+            )
+        {
+            let explanation = Explanation::LgplLinkage {
+                lgpl: other.clone(),
+                linkage: context.linkage,
+            };
+            return match context.linkage {
+                Linkage::Dynamic => (Some(true), explanation),
+                Linkage::Static => (None, explanation),
+            };
         }
 
         if let LGPL_2_0 = *self {
-            return None; /* TODO: unknown */
+            return (None, Explanation::NotEncoded(LGPL_2_0)); /* TODO: unknown */
         }
         if let LGPL_2_0 = *other {
-            return None; /* TODO: unknown */
+            return (None, Explanation::NotEncoded(LGPL_2_0)); /* TODO: unknown */
         }
 
-        compatibility!(*self, *other, {
+        let compatible = compatibility!(*self, *other, {
             Unspecified         => [Unlicense, MIT, X11, BSD_2_Clause, BSD_3_Clause]
 
             LGPL_2_0     => [LGPL_2_0] // TODO: probably allows more
 
-            Unlicense    => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11]
-            BSD_0_Clause => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11]
-            CC0_1_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11]
-            MIT          => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11]
-            X11          => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11]
-            BSD_2_Clause => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause]
-            BSD_3_Clause => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause]
-            Apache_2_0   => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, Apache_2_0]
-            MPL_1_1      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_1_1]
-            MPL_2_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, Apache_2_0, MPL_2_0]
-            LGPL_2_1Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus]
-            LGPL_2_1     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus, LGPL_2_1]
-            LGPL_3_0Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_3_0Plus]
-            LGPL_3_0     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_3_0Plus, LGPL_3_0]
-            GPL_2_0Plus  => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus]
-            GPL_2_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_2_0]
-            GPL_3_0Plus  => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus]
-            GPL_3_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus, GPL_3_0]
-            AGPL_3_0Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus, GPL_3_0, AGPL_3_0Plus]
-            AGPL_3_0     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus, GPL_3_0, AGPL_3_0Plus, AGPL_3_0]
+            Unlicense    => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0]
+            BSD_0_Clause => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0]
+            CC0_1_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0]
+            MIT          => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0]
+            X11          => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0]
+            BSD_2_Clause => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause]
+            BSD_3_Clause => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause]
+            Apache_2_0   => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, Apache_2_0]
+            MPL_1_1      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_1_1]
+            MPL_2_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, Apache_2_0, MPL_2_0]
+            LGPL_2_1Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus]
+            LGPL_2_1     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus, LGPL_2_1]
+            LGPL_3_0Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_3_0Plus]
+            LGPL_3_0     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_3_0Plus, LGPL_3_0]
+            GPL_2_0Plus  => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus]
+            GPL_2_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_2_0]
+            GPL_3_0Plus  => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus]
+            GPL_3_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus, GPL_3_0]
+            AGPL_3_0Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus, GPL_3_0, AGPL_3_0Plus]
+            AGPL_3_0     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus, GPL_3_0, AGPL_3_0Plus, AGPL_3_0]
+
+            CC_BY_3_0    => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0]
+            CC_BY_4_0    => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0]
+            CC_BY_SA_3_0 => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, CC_BY_SA_3_0]
+            CC_BY_SA_4_0 => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, CC_BY_SA_3_0, CC_BY_SA_4_0]
+            GFDL_1_2Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, GFDL_1_2Plus]
+            GFDL_1_2     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, GFDL_1_2Plus, GFDL_1_2]
+            GFDL_1_3Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, GFDL_1_2Plus, GFDL_1_3Plus]
+            GFDL_1_3     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, CC_BY_3_0, CC_BY_4_0, GFDL_1_2Plus, GFDL_1_3Plus, GFDL_1_3]
 
             // TODO: These are `unreachable!()`, can't figure out a nice way to allow this in the macro...
             Custom(_)    => [MIT]
             File(_)      => [MIT]
             Multiple(_)  => [MIT]
+            All(_)       => [MIT]
+            WithException { .. } => [MIT]
         });
 
-        Some(false)
+        let (including, included) = (self.clone(), other.clone());
+        let permissive = matches!(other.family(), Family::PublicDomain | Family::Permissive);
+        let explanation = match (compatible, permissive) {
+            (true, true) => Explanation::Permissive {
+                including,
+                included,
+            },
+            (true, false) => Explanation::CopyleftSatisfied {
+                including,
+                included,
+            },
+            (false, true) => Explanation::ConflictingTerms {
+                including,
+                included,
+            },
+            (false, false) => Explanation::CopyleftViolated {
+                including,
+                included,
+            },
+        };
+        (Some(compatible), explanation)
+    }
+
+    /// What distributing a work under this license obliges, `None` if
+    /// unknown. For a choice of licenses those of the least restrictive one.
+    pub fn obligations(&self) -> Option<Obligations> {
+        use self::License::*;
+        Some(match self {
+            Unlicense | BSD_0_Clause | CC0_1_0 => Obligations::NONE,
+            MIT | X11 | BSD_2_Clause | BSD_3_Clause => Obligations::PERMISSIVE,
+            Apache_2_0 => Obligations {
+                patent_grant: true,
+                ..Obligations::PERMISSIVE
+            },
+            CC_BY_3_0 | CC_BY_4_0 => Obligations {
+                requires_notice_preservation: true,
+                ..Obligations::NONE
+            },
+            LGPL_2_0 | LGPL_2_1 | LGPL_2_1Plus => {
+                Obligations::copyleft(CopyleftScope::Library, false)
+            }
+            LGPL_3_0 | LGPL_3_0Plus => Obligations::copyleft(CopyleftScope::Library, true),
+            MPL_1_1 | MPL_2_0 => Obligations::copyleft(CopyleftScope::FileLevel, true),
+            GPL_2_0 | GPL_2_0Plus => Obligations::copyleft(CopyleftScope::Strong, false),
+            GPL_3_0 | GPL_3_0Plus | AGPL_3_0 | AGPL_3_0Plus => {
+                Obligations::copyleft(CopyleftScope::Strong, true)
+            }
+            CC_BY_SA_3_0 | CC_BY_SA_4_0 => Obligations {
+                requires_notice_preservation: true,
+                copyleft_scope: CopyleftScope::Strong,
+                ..Obligations::NONE
+            },
+            GFDL_1_2 | GFDL_1_2Plus | GFDL_1_3 | GFDL_1_3Plus => {
+                Obligations::copyleft(CopyleftScope::Strong, false)
+            }
+            Multiple(licenses) => {
+                return licenses
+                    .iter()
+                    .filter(|license| license.obligations().is_some())
+                    .min_by_key(|license| license.family())?
+                    .obligations()
+            }
+            All(licenses) => {
+                let mut obligations = Obligations::NONE;
+                for license in licenses {
+                    obligations = obligations.union(license.obligations()?);
+                }
+                obligations
+            }
+            WithException { base, .. } => return base.obligations(),
+            Custom(_) | File(_) | Unspecified => return None,
+        })
+    }
+
+    pub fn family(&self) -> Family {
+        use self::License::*;
+        match self {
+            Unlicense | CC0_1_0 => Family::PublicDomain,
+            BSD_0_Clause | MIT | X11 | BSD_2_Clause | BSD_3_Clause | Apache_2_0 | CC_BY_3_0
+            | CC_BY_4_0 => Family::Permissive,
+            LGPL_2_0 | LGPL_2_1 | LGPL_2_1Plus | LGPL_3_0 | LGPL_3_0Plus | MPL_1_1 | MPL_2_0 => {
+                Family::WeakCopyleft
+            }
+            GPL_2_0 | GPL_2_0Plus | GPL_3_0 | GPL_3_0Plus | CC_BY_SA_3_0 | CC_BY_SA_4_0
+            | GFDL_1_2 | GFDL_1_2Plus | GFDL_1_3 | GFDL_1_3Plus => Family::StrongCopyleft,
+            AGPL_3_0 | AGPL_3_0Plus => Family::NetworkCopyleft,
+            // Any one alternative can be chosen, so the least restrictive
+            Multiple(licenses) => licenses
+                .iter()
+                .map(License::family)
+                .min()
+                .unwrap_or(Family::Unknown),
+            // All of them apply, so the most restrictive
+            All(licenses) => licenses
+                .iter()
+                .map(License::family)
+                .max()
+                .unwrap_or(Family::Unknown),
+            WithException { base, .. } => base.family(),
+            Custom(_) | File(_) | Unspecified => Family::Unknown,
+        }
+    }
+
+    /// The licenses whose texts are too alike to be told apart by comparing
+    /// word frequencies, including this one, `None` if it has no look-alikes
+    pub fn similarity_group(&self) -> Option<&'static [License]> {
+        match self {
+            License::WithException { base, .. } => base.similarity_group(),
+            license => SIMILARITY_GROUPS
+                .iter()
+                .copied()
+                .find(|group| group.contains(license)),
+        }
+    }
+
+    /// The identifier of this license on the SPDX license list, spelled as
+    /// there, `None` for custom licenses and expressions
+    pub fn spdx_id(&self) -> Option<&'static str> {
+        Some(match *self {
+            License::Unlicense => "Unlicense",
+            License::BSD_0_Clause => "0BSD",
+            License::CC0_1_0 => "CC0-1.0",
+            License::MIT => "MIT",
+            License::X11 => "X11",
+            License::BSD_2_Clause => "BSD-2-Clause",
+            License::BSD_3_Clause => "BSD-3-Clause",
+            License::Apache_2_0 => "Apache-2.0",
+            License::LGPL_2_0 => "LGPL-2.0-only",
+            License::LGPL_2_1 => "LGPL-2.1-only",
+            License::LGPL_2_1Plus => "LGPL-2.1-or-later",
+            License::LGPL_3_0 => "LGPL-3.0-only",
+            License::LGPL_3_0Plus => "LGPL-3.0-or-later",
+            License::MPL_1_1 => "MPL-1.1",
+            License::MPL_2_0 => "MPL-2.0",
+            License::GPL_2_0 => "GPL-2.0-only",
+            License::GPL_2_0Plus => "GPL-2.0-or-later",
+            License::GPL_3_0 => "GPL-3.0-only",
+            License::GPL_3_0Plus => "GPL-3.0-or-later",
+            License::AGPL_3_0 => "AGPL-3.0-only",
+            License::AGPL_3_0Plus => "AGPL-3.0-or-later",
+            License::CC_BY_3_0 => "CC-BY-3.0",
+            License::CC_BY_4_0 => "CC-BY-4.0",
+            License::CC_BY_SA_3_0 => "CC-BY-SA-3.0",
+            License::CC_BY_SA_4_0 => "CC-BY-SA-4.0",
+            License::GFDL_1_2 => "GFDL-1.2-only",
+            License::GFDL_1_2Plus => "GFDL-1.2-or-later",
+            License::GFDL_1_3 => "GFDL-1.3-only",
+            License::GFDL_1_3Plus => "GFDL-1.3-or-later",
+            License::Custom(_)
+            | License::File(_)
+            | License::Multiple(_)
+            | License::All(_)
+            | License::WithException { .. }
+            | License::Unspecified => return None,
+        })
+    }
+
+    /// The spdx.org pages with the text of this license, one per alternative
+    /// or part of an expression and the base license of an exception, empty
+    /// for licenses that aren't on the SPDX license list
+    pub fn spdx_url(&self) -> Vec<String> {
+        match self {
+            License::Multiple(licenses) | License::All(licenses) => {
+                let mut urls = Vec::new();
+                for url in licenses.iter().flat_map(License::spdx_url) {
+                    if !urls.contains(&url) {
+                        urls.push(url);
+                    }
+                }
+                urls
+            }
+            License::WithException { base, .. } => base.spdx_url(),
+            license => license
+                .spdx_id()
+                .map(|id| format!("https://spdx.org/licenses/{}.html", id))
+                .into_iter()
+                .collect(),
+        }
     }
 
+    /// The bundled text that texts of the license are scored against, see
+    /// `DiscoveryConfig::template` for the one custom templates take
+    /// precedence over
     pub fn template(&self) -> Option<&'static str> {
         Some(match *self {
             License::Unlicense => include_str!("licenses/Unlicense"),
             License::MIT => include_str!("licenses/MIT"),
             License::Apache_2_0 => include_str!("licenses/Apache-2.0"),
             License::BSD_3_Clause => include_str!("licenses/BSD-3-Clause"),
-            License::Multiple(_) => panic!("TODO: Refactor multiple handling"),
+            License::BSD_0_Clause => include_str!("licenses/0BSD"),
+            License::CC0_1_0 => include_str!("licenses/CC0-1.0"),
+            License::X11 => include_str!("licenses/X11"),
+            License::BSD_2_Clause => include_str!("licenses/BSD-2-Clause"),
+            License::LGPL_2_0 => include_str!("licenses/LGPL-2.0"),
+            License::LGPL_2_1 | License::LGPL_2_1Plus => include_str!("licenses/LGPL-2.1"),
+            License::LGPL_3_0 | License::LGPL_3_0Plus => include_str!("licenses/LGPL-3.0"),
+            License::MPL_1_1 => include_str!("licenses/MPL-1.1"),
+            License::MPL_2_0 => include_str!("licenses/MPL-2.0"),
+            License::GPL_2_0 | License::GPL_2_0Plus => include_str!("licenses/GPL-2.0"),
+            License::GPL_3_0 | License::GPL_3_0Plus => include_str!("licenses/GPL-3.0"),
+            License::AGPL_3_0 | License::AGPL_3_0Plus => include_str!("licenses/AGPL-3.0"),
+            License::CC_BY_3_0 => include_str!("licenses/CC-BY-3.0"),
+            License::CC_BY_4_0 => include_str!("licenses/CC-BY-4.0"),
+            License::CC_BY_SA_3_0 => include_str!("licenses/CC-BY-SA-3.0"),
+            License::CC_BY_SA_4_0 => include_str!("licenses/CC-BY-SA-4.0"),
+            License::GFDL_1_2 | License::GFDL_1_2Plus => include_str!("licenses/GFDL-1.2"),
+            License::GFDL_1_3 | License::GFDL_1_3Plus => include_str!("licenses/GFDL-1.3"),
+            // There's no single text for a combination of licenses, callers
+            // need to look at the templates of each part
+            License::Multiple(_) | License::All(_) => return None,
+            License::WithException { ref base, .. } => return base.template(),
             _ => return None,
         })
     }
 }
 
+/// License templates read from a `--license-templates` directory, each
+/// `<identifier>.txt` is the template of the license whose name slugifies to
+/// the same as `<identifier>`
+#[derive(Debug, Default)]
+pub struct TemplateStore {
+    templates: BTreeMap<String, String>,
+    /// The templates replacing bundled ones and the licenses they replace
+    overridden: Vec<(PathBuf, &'static License)>,
+}
+
+impl TemplateStore {
+    pub fn load(dir: &Path) -> anyhow::Result<TemplateStore> {
+        let entries = fs::read_dir(dir).map_err(|err| {
+            anyhow!(
+                "Couldn't read license templates directory {}: {}",
+                dir.display(),
+                err
+            )
+        })?;
+        let mut paths = entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        paths.sort();
+        let mut templates = BTreeMap::new();
+        let mut sources = BTreeMap::new();
+        let mut overridden = Vec::new();
+        for path in paths {
+            let identifier = match path.file_stem() {
+                Some(stem) if path.extension().is_some_and(|ext| ext == "txt") => {
+                    stem.to_string_lossy().into_owned()
+                }
+                _ => continue,
+            };
+            let text = fs::read_to_string(&path)
+                .map_err(|err| anyhow!("Couldn't read {}: {}", path.display(), err))?;
+            let slug = slugify(&identifier);
+            if let Some(known) = KNOWN
+                .iter()
+                .find(|known| slugify(&known.to_string()) == slug)
+                .filter(|known| known.template().is_some())
+            {
+                overridden.push((path.clone(), known));
+            }
+            if let Some(other) = sources.insert(slug.clone(), path.clone()) {
+                return Err(anyhow!(
+                    "{} and {} are templates of the same license",
+                    other.display(),
+                    path.display()
+                ));
+            }
+            templates.insert(slug, text);
+        }
+        Ok(TemplateStore {
+            templates,
+            overridden,
+        })
+    }
+
+    /// The templates that replace the bundled template of a known license
+    pub fn overridden(&self) -> &[(PathBuf, &'static License)] {
+        &self.overridden
+    }
+
+    /// The slugs of the licenses and their templates
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.templates
+            .iter()
+            .map(|(slug, template)| (slug.as_str(), template.as_str()))
+    }
+
+    /// Combinations have no template of their own and exceptions use their
+    /// base license's
+    pub fn get(&self, license: &License) -> Option<&str> {
+        match license {
+            License::Multiple(_) | License::All(_) | License::WithException { .. } => None,
+            license => self
+                .templates
+                .get(&slugify(&license.to_string()))
+                .map(String::as_str),
+        }
+    }
+}
+
+/// Lowercase with each run of other characters than letters and digits
+/// replaced by a `-`, so `Proprietary-InternalUse-1.0` and
+/// `proprietary_internaluse_1_0` are the same license
+fn slugify(name: &str) -> String {
+    let mut slug = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
+/// Something noteworthy found while parsing a license field
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseDiagnostic {
+    /// `from` is not a valid SPDX identifier but was recognised as `to`
+    Normalized { from: String, to: String },
+    /// `from` would have been recognised as `to` if normalization were enabled
+    NotNormalized { from: String, to: String },
+    /// The deprecated `/` separator was used instead of ` OR `
+    SlashSeparator,
+    /// `operator` was written in lowercase, SPDX operators are uppercase
+    LowercaseOperator { operator: String },
+    /// `id` could refer to multiple licenses
+    Ambiguous {
+        id: String,
+        candidates: &'static str,
+    },
+    /// `id` is not a recognised license identifier
+    Unknown { id: String },
+    /// The license field is present but empty, e.g. left over from a template
+    Empty,
+    /// `expression` isn't a well formed license expression for `reason`
+    Malformed {
+        expression: String,
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for Family {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        w.write_str(match self {
+            Family::PublicDomain => "public domain",
+            Family::Permissive => "permissive",
+            Family::WeakCopyleft => "weak copyleft",
+            Family::StrongCopyleft => "strong copyleft",
+            Family::NetworkCopyleft => "network copyleft",
+            Family::Unknown => "unknown",
+        })
+    }
+}
+
+impl fmt::Display for ParseDiagnostic {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseDiagnostic::Normalized { from, to } => {
+                write!(
+                    w,
+                    "'{}' is not an SPDX identifier, treating it as {}",
+                    from, to
+                )
+            }
+            ParseDiagnostic::NotNormalized { from, to } => {
+                write!(
+                    w,
+                    "'{}' is not an SPDX identifier, did you mean {}?",
+                    from, to
+                )
+            }
+            ParseDiagnostic::SlashSeparator => {
+                write!(
+                    w,
+                    "'/' is a deprecated license separator, use ' OR ' instead"
+                )
+            }
+            ParseDiagnostic::LowercaseOperator { operator } => write!(
+                w,
+                "'{}' is not an SPDX operator, use '{}' instead",
+                operator,
+                operator.to_uppercase()
+            ),
+            ParseDiagnostic::Ambiguous { id, candidates } => write!(
+                w,
+                "'{}' is ambiguous, it could mean any of {}",
+                id, candidates
+            ),
+            ParseDiagnostic::Unknown { id } => {
+                write!(w, "'{}' is not a recognised license identifier", id)
+            }
+            ParseDiagnostic::Empty => {
+                write!(w, "the license field is empty, treating it as unspecified")
+            }
+
+            ParseDiagnostic::Malformed { expression, reason } => write!(
+                w,
+                "'{}' is not a valid license expression, {}",
+                expression, reason
+            ),
+        }
+    }
+}
+
+/// Licenses whose texts differ by only a sentence or two, X11 is the MIT
+/// license with an extra paragraph about using the copyright holders' names
+static SIMILARITY_GROUPS: &[&[License]] = &[&[License::MIT, License::X11]];
+
+/// Every license with a known SPDX identifier
+pub static KNOWN: &[License] = &[
+    License::Unlicense,
+    License::BSD_0_Clause,
+    License::CC0_1_0,
+    License::MIT,
+    License::X11,
+    License::BSD_2_Clause,
+    License::BSD_3_Clause,
+    License::Apache_2_0,
+    License::LGPL_2_0,
+    License::LGPL_2_1,
+    License::LGPL_2_1Plus,
+    License::LGPL_3_0,
+    License::LGPL_3_0Plus,
+    License::MPL_1_1,
+    License::MPL_2_0,
+    License::GPL_2_0,
+    License::GPL_2_0Plus,
+    License::GPL_3_0,
+    License::GPL_3_0Plus,
+    License::AGPL_3_0,
+    License::AGPL_3_0Plus,
+    License::CC_BY_3_0,
+    License::CC_BY_4_0,
+    License::CC_BY_SA_3_0,
+    License::CC_BY_SA_4_0,
+    License::GFDL_1_2,
+    License::GFDL_1_2Plus,
+    License::GFDL_1_3,
+    License::GFDL_1_3Plus,
+];
+
+const AMBIGUOUS: &[(&str, &str)] = &[
+    ("bsd", "0BSD, BSD-2-Clause, BSD-3-Clause"),
+    ("apache", "Apache-1.1, Apache-2.0"),
+    (
+        "gpl",
+        "GPL-2.0-only, GPL-2.0-or-later, GPL-3.0-only, GPL-3.0-or-later",
+    ),
+    (
+        "lgpl",
+        "LGPL-2.1-only, LGPL-2.1-or-later, LGPL-3.0-only, LGPL-3.0-or-later",
+    ),
+    ("agpl", "AGPL-3.0-only, AGPL-3.0-or-later"),
+    ("mpl", "MPL-1.1, MPL-2.0"),
+    (
+        "gfdl",
+        "GFDL-1.2-only, GFDL-1.2-or-later, GFDL-1.3-only, GFDL-1.3-or-later",
+    ),
+    ("ccby", "CC-BY-3.0, CC-BY-4.0"),
+    ("ccbysa", "CC-BY-SA-3.0, CC-BY-SA-4.0"),
+];
+
+/// Reduces a license identifier to a form where common variations in writing
+/// it compare equal, e.g. `Apache License, Version 2.0`, `Apache 2` and
+/// `apache-2.0` all become `apache2`
+fn fuzzy_key(id: &str) -> String {
+    let id = id
+        .to_lowercase()
+        .replace("or-later", "+")
+        .replace("or later", "+");
+    let mut key = String::new();
+    for word in id.split(|c: char| c.is_whitespace() || "-_,".contains(c)) {
+        match word {
+            "" | "the" | "license" | "licence" | "version" | "clause" | "only" => {}
+            word => key.push_str(word),
+        }
+    }
+
+    // GPLv3 -> gpl3
+    let chars = key.chars().collect::<Vec<_>>();
+    let mut key = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let prev_alpha = i > 0 && chars[i - 1].is_alphabetic();
+        let next_digit = chars.get(i + 1).is_some_and(|c| c.is_ascii_digit());
+        if !(c == 'v' && prev_alpha && next_digit) {
+            key.push(c);
+        }
+    }
+
+    // 2.0 -> 2
+    let plus = key.ends_with('+');
+    let mut key = key.trim_end_matches('+').to_owned();
+    while key.ends_with(".0") {
+        key.truncate(key.len() - 2);
+    }
+    if plus {
+        key.push('+');
+    }
+    key
+}
+
+fn parse_id(id: &str) -> Option<License> {
+    Some(match id {
+        "Unlicense" => License::Unlicense,
+        "0BSD" => License::BSD_0_Clause,
+        "CC0-1.0" => License::CC0_1_0,
+        "MIT" => License::MIT,
+        "X11" => License::X11,
+        "BSD-2-Clause" => License::BSD_2_Clause,
+        "BSD-3-Clause" => License::BSD_3_Clause,
+        "Apache-2.0" => License::Apache_2_0,
+        "LGPL-2.0-only" | "LGPL-2.0" => License::LGPL_2_0,
+        "LGPL-2.1-only" | "LGPL-2.1" => License::LGPL_2_1,
+        "LGPL-2.1-or-later" | "LGPL-2.1+" => License::LGPL_2_1Plus,
+        "LGPL-3.0-only" | "LGPL-3.0" => License::LGPL_3_0,
+        "LGPL-3.0-or-later" | "LGPL-3.0+" => License::LGPL_3_0Plus,
+        "MPL-1.1" => License::MPL_1_1,
+        "MPL-2.0" => License::MPL_2_0,
+        "GPL-2.0-only" | "GPL-2.0" => License::GPL_2_0,
+        "GPL-2.0-or-later" | "GPL-2.0+" => License::GPL_2_0Plus,
+        "GPL-3.0-only" | "GPL-3.0" => License::GPL_3_0,
+        "GPL-3.0-or-later" | "GPL-3.0+" => License::GPL_3_0Plus,
+        "AGPL-3.0-only" | "AGPL-3.0" => License::AGPL_3_0,
+        "AGPL-3.0-or-later" | "AGPL-3.0+" => License::AGPL_3_0Plus,
+        "CC-BY-3.0" => License::CC_BY_3_0,
+        "CC-BY-4.0" => License::CC_BY_4_0,
+        "CC-BY-SA-3.0" => License::CC_BY_SA_3_0,
+        "CC-BY-SA-4.0" => License::CC_BY_SA_4_0,
+        "GFDL-1.2-only" | "GFDL-1.2" => License::GFDL_1_2,
+        "GFDL-1.2-or-later" | "GFDL-1.2+" => License::GFDL_1_2Plus,
+        "GFDL-1.3-only" | "GFDL-1.3" => License::GFDL_1_3,
+        "GFDL-1.3-or-later" | "GFDL-1.3+" => License::GFDL_1_3Plus,
+        _ => return None,
+    })
+}
+
+fn parse_fuzzy_id(id: &str) -> Result<License, Option<&'static str>> {
+    let key = fuzzy_key(id);
+    if key == "cc0" {
+        return Ok(License::CC0_1_0);
+    }
+    if let Some(&(_, candidates)) = AMBIGUOUS.iter().find(|&&(ambiguous, _)| ambiguous == key) {
+        return Err(Some(candidates));
+    }
+    KNOWN
+        .iter()
+        .find(|license| fuzzy_key(&license.to_string()) == key)
+        .map(|license| parse_id(&license.to_string()).expect("known license"))
+        .ok_or(None)
+}
+
+impl License {
+    /// A choice of any of `licenses`, with nested choices flattened into it
+    /// and duplicates removed. The alternatives are sorted so that the same
+    /// choice compares and hashes equal however it was written, a single one
+    /// is just that license and none at all is `Unspecified`.
+    pub fn multiple(licenses: Vec<License>) -> License {
+        let mut alternatives = Vec::with_capacity(licenses.len());
+        for license in licenses {
+            match license {
+                License::Multiple(nested) => alternatives.extend(nested),
+                license => alternatives.push(license),
+            }
+        }
+        License::combined(alternatives, License::Multiple)
+    }
+
+    /// All of `licenses` at once, canonical like `License::multiple`
+    pub fn all(licenses: Vec<License>) -> License {
+        let mut parts = Vec::with_capacity(licenses.len());
+        for license in licenses {
+            match license {
+                License::All(nested) => parts.extend(nested),
+                license => parts.push(license),
+            }
+        }
+        License::combined(parts, License::All)
+    }
+
+    fn combined(mut licenses: Vec<License>, combine: fn(Vec<License>) -> License) -> License {
+        licenses.sort();
+        licenses.dedup();
+        match licenses.len() {
+            0 => License::Unspecified,
+            1 => licenses.remove(0),
+            _ => combine(licenses),
+        }
+    }
+
+    /// Rebuilds combinations of licenses bottom up through `License::multiple`
+    /// and `License::all`, e.g. `MIT OR (MIT OR Apache-2.0)` becomes
+    /// `MIT OR Apache-2.0`
+    pub fn canonicalize(self) -> License {
+        match self {
+            License::Multiple(licenses) => {
+                License::multiple(licenses.into_iter().map(License::canonicalize).collect())
+            }
+            License::All(licenses) => {
+                License::all(licenses.into_iter().map(License::canonicalize).collect())
+            }
+            License::WithException { base, exception } => License::WithException {
+                base: Box::new(base.canonicalize()),
+                exception,
+            },
+            license => license,
+        }
+    }
+
+    /// Parses a license field, normalizing common mistakes in writing SPDX
+    /// identifiers unless `strict` is set, along with diagnostics about what
+    /// was wrong with it. `WITH` binds tighter than `AND`, which binds
+    /// tighter than `OR` and the deprecated `/`, with parentheses to group
+    /// them otherwise.
+    pub fn parse_with_diagnostics(s: &str, strict: bool) -> (License, Vec<ParseDiagnostic>) {
+        let s = s.trim();
+        if s.is_empty() {
+            return (License::Unspecified, vec![ParseDiagnostic::Empty]);
+        }
+        let (tokens, lowercase) = tokenize(s);
+        let mut parser = Parser {
+            source: s,
+            tokens,
+            position: 0,
+            strict,
+            slash: false,
+            diagnostics: Vec::new(),
+        };
+        match parser.expression() {
+            Ok(license) => {
+                let mut diagnostics = parser.diagnostics;
+                let lowercase =
+                    lowercase
+                        .into_iter()
+                        .map(|operator| ParseDiagnostic::LowercaseOperator {
+                            operator: operator.to_owned(),
+                        });
+                diagnostics.splice(0..0, lowercase);
+                if parser.slash {
+                    diagnostics.insert(0, ParseDiagnostic::SlashSeparator);
+                }
+                (license.canonicalize(), diagnostics)
+            }
+            Err(reason) => (
+                License::Custom(s.to_owned()),
+                vec![ParseDiagnostic::Malformed {
+                    expression: s.to_owned(),
+                    reason,
+                }],
+            ),
+        }
+    }
+
+    /// Parses a single license identifier, recognising common variations of
+    /// writing it unless `strict` is set
+    fn parse_id_with_diagnostics(
+        id: &str,
+        strict: bool,
+        diagnostics: &mut Vec<ParseDiagnostic>,
+    ) -> License {
+        if let Some(license) = parse_id(id) {
+            return license;
+        }
+        match parse_fuzzy_id(id) {
+            Ok(license) => {
+                let from = id.to_owned();
+                let to = license.to_string();
+                if strict {
+                    diagnostics.push(ParseDiagnostic::NotNormalized { from, to });
+                    License::Custom(id.to_owned())
+                } else {
+                    diagnostics.push(ParseDiagnostic::Normalized { from, to });
+                    license
+                }
+            }
+            Err(Some(candidates)) => {
+                diagnostics.push(ParseDiagnostic::Ambiguous {
+                    id: id.to_owned(),
+                    candidates,
+                });
+                License::Custom(id.to_owned())
+            }
+            Err(None) => {
+                diagnostics.push(ParseDiagnostic::Unknown { id: id.to_owned() });
+                License::Custom(id.to_owned())
+            }
+        }
+    }
+}
+
+/// A token of a license expression, words are byte ranges of the expression
+/// so that identifiers made of several words keep their spacing
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Or { slash: bool },
+    And,
+    With,
+    Word(usize, usize),
+}
+
+/// Splits `s` into tokens, along with the operators that were written in
+/// lowercase. A lowercase `or` before `later` or `any` is part of a name like
+/// `GPL-2.0 or later` rather than an operator.
+fn tokenize(s: &str) -> (Vec<Token>, Vec<&str>) {
+    let mut tokens = Vec::new();
+    let mut word = None;
+    let end_word = |tokens: &mut Vec<Token>, word: &mut Option<usize>, end: usize| {
+        if let Some(start) = word.take() {
+            tokens.push(match &s[start..end] {
+                "OR" => Token::Or { slash: false },
+                "AND" => Token::And,
+                "WITH" => Token::With,
+                _ => Token::Word(start, end),
+            });
+        }
+    };
+    for (i, c) in s.char_indices() {
+        let token = match c {
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '/' => Token::Or { slash: true },
+            c if c.is_whitespace() => {
+                end_word(&mut tokens, &mut word, i);
+                continue;
+            }
+            _ => {
+                word.get_or_insert(i);
+                continue;
+            }
+        };
+        end_word(&mut tokens, &mut word, i);
+        tokens.push(token);
+    }
+    end_word(&mut tokens, &mut word, s.len());
+
+    let word = |token: Option<&Token>| match token {
+        Some(&Token::Word(start, end)) => Some(&s[start..end]),
+        _ => None,
+    };
+    let mut lowercase = Vec::new();
+    for i in 0..tokens.len() {
+        let operator = match word(tokens.get(i)) {
+            Some(operator @ "and") => (operator, Token::And),
+            Some(operator @ "with") => (operator, Token::With),
+            Some(operator @ "or") => {
+                let next = word(tokens.get(i + 1)).unwrap_or_default();
+                if next.eq_ignore_ascii_case("later") || next.eq_ignore_ascii_case("any") {
+                    continue;
+                }
+                (operator, Token::Or { slash: false })
+            }
+            _ => continue,
+        };
+        tokens[i] = operator.1;
+        if !lowercase.contains(&operator.0) {
+            lowercase.push(operator.0);
+        }
+    }
+    (tokens, lowercase)
+}
+
+/// A recursive descent parser of license expressions, the grammar being
+///
+/// ```text
+/// expression = conjunction { ("OR" | "or" | "/") conjunction }
+/// conjunction = exception { ("AND" | "and") exception }
+/// exception = primary [ ("WITH" | "with") words ]
+/// primary = "(" expression ")" | words
+/// ```
+///
+/// where consecutive words make up a single identifier, so the names
+/// normalization recognises like `Apache License, Version 2.0` can be used.
+/// The lowercase operators and `/` aren't SPDX and are reported as such, and
+/// `or` is a word when followed by `later` or `any`.
+struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+    strict: bool,
+    /// Whether the deprecated `/` separator was used
+    slash: bool,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn expression(&mut self) -> Result<License, &'static str> {
+        let license = self.disjunction()?;
+        match self.peek() {
+            None => Ok(license),
+            Some(Token::Close) => Err("it has unbalanced parentheses"),
+            Some(_) => Err("it is missing an operator between licenses"),
+        }
+    }
+
+    fn disjunction(&mut self) -> Result<License, &'static str> {
+        let mut alternatives = vec![self.conjunction()?];
+        while let Some(&Token::Or { slash }) = self.peek() {
+            self.slash |= slash;
+            self.position += 1;
+            alternatives.push(self.conjunction()?);
+        }
+        // Made canonical once the whole expression is parsed
+        Ok(match alternatives.len() {
+            1 => alternatives.remove(0),
+            _ => License::Multiple(alternatives),
+        })
+    }
+
+    fn conjunction(&mut self) -> Result<License, &'static str> {
+        let mut licenses = vec![self.exception()?];
+        while let Some(Token::And) = self.peek() {
+            self.position += 1;
+            licenses.push(self.exception()?);
+        }
+        Ok(match licenses.len() {
+            1 => licenses.remove(0),
+            _ => License::All(licenses),
+        })
+    }
+
+    fn exception(&mut self) -> Result<License, &'static str> {
+        let base = self.primary()?;
+        if let Some(Token::With) = self.peek() {
+            self.position += 1;
+            let exception = self
+                .words()
+                .ok_or("WITH must be followed by an exception")?;
+            return Ok(License::WithException {
+                base: Box::new(base),
+                exception: exception.to_owned(),
+            });
+        }
+        Ok(base)
+    }
+
+    fn primary(&mut self) -> Result<License, &'static str> {
+        if let Some(Token::Open) = self.peek() {
+            self.position += 1;
+            let license = self.disjunction()?;
+            if self.peek() != Some(&Token::Close) {
+                return Err("it has unbalanced parentheses");
+            }
+            self.position += 1;
+            return Ok(license);
+        }
+        match self.words() {
+            Some(id) => Ok(License::parse_id_with_diagnostics(
+                id,
+                self.strict,
+                &mut self.diagnostics,
+            )),
+            None if self.peek() == Some(&Token::Close) => Err("it has unbalanced parentheses"),
+            None => Err("an operator is missing a license on one side"),
+        }
+    }
+
+    /// The identifier made of the words at the current position, if any
+    fn words(&mut self) -> Option<&'a str> {
+        let (start, mut end) = match self.peek() {
+            Some(&Token::Word(start, end)) => (start, end),
+            _ => return None,
+        };
+        self.position += 1;
+        while let Some(&Token::Word(_, next)) = self.peek() {
+            end = next;
+            self.position += 1;
+        }
+        Some(&self.source[start..end])
+    }
+}
+
 impl FromStr for License {
     type Err = core::convert::Infallible;
 
     fn from_str(s: &str) -> Result<License, core::convert::Infallible> {
-        Ok(match s.trim() {
-            "Unlicense" => License::Unlicense,
-            "0BSD" => License::BSD_0_Clause,
-            "CC0-1.0" => License::CC0_1_0,
-            "MIT" => License::MIT,
-            "X11" => License::X11,
-            "BSD-2-Clause" => License::BSD_2_Clause,
-            "BSD-3-Clause" => License::BSD_3_Clause,
-            "Apache-2.0" => License::Apache_2_0,
-            "LGPL-2.0-only" | "LGPL-2.0" => License::LGPL_2_0,
-            "LGPL-2.1-only" | "LGPL-2.1" => License::LGPL_2_1,
-            "LGPL-2.1-or-later" | "LGPL-2.1+" => License::LGPL_2_1Plus,
-            "LGPL-3.0-only" | "LGPL-3.0" => License::LGPL_3_0,
-            "LGPL-3.0-or-later" | "LGPL-3.0+" => License::LGPL_3_0Plus,
-            "MPL-1.1" => License::MPL_1_1,
-            "MPL-2.0" => License::MPL_2_0,
-            "GPL-2.0-only" | "GPL-2.0" => License::GPL_2_0,
-            "GPL-2.0-or-later" | "GPL-2.0+" => License::GPL_2_0Plus,
-            "GPL-3.0-only" | "GPL-3.0" => License::GPL_3_0,
-            "GPL-3.0-or-later" | "GPL-3.0+" => License::GPL_3_0Plus,
-            "AGPL-3.0-only" | "AGPL-3.0" => License::AGPL_3_0,
-            "AGPL-3.0-or-later" | "AGPL-3.0+" => License::AGPL_3_0Plus,
-            s if s.contains('/') || s.contains(" OR ") => {
-                let mut licenses = s
-                    .split('/')
-                    .flat_map(|s| s.split(" OR "))
-                    .map(str::parse)
-                    .map(Result::unwrap)
-                    .collect::<Vec<License>>();
-                licenses.sort();
-                License::Multiple(licenses)
-            }
-            s => License::Custom(s.to_owned()),
-        })
+        Ok(License::parse_with_diagnostics(s, false).0)
     }
 }
 
 impl fmt::Display for License {
     fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(id) = self.spdx_id() {
+            return w.write_str(id);
+        }
         match *self {
-            License::Unlicense => write!(w, "Unlicense"),
-            License::BSD_0_Clause => write!(w, "0BSD"),
-            License::CC0_1_0 => write!(w, "CC0-1.0"),
-            License::MIT => write!(w, "MIT"),
-            License::X11 => write!(w, "X11"),
-            License::BSD_2_Clause => write!(w, "BSD-2-Clause"),
-            License::BSD_3_Clause => write!(w, "BSD-3-Clause"),
-            License::Apache_2_0 => write!(w, "Apache-2.0"),
-            License::LGPL_2_0 => write!(w, "LGPL-2.0-only"),
-            License::LGPL_2_1 => write!(w, "LGPL-2.1-only"),
-            License::LGPL_2_1Plus => write!(w, "LGPL-2.1-or-later"),
-            License::LGPL_3_0 => write!(w, "LGPL-3.0-only"),
-            License::LGPL_3_0Plus => write!(w, "LGPL-3.0-or-later"),
-            License::MPL_1_1 => write!(w, "MPL-1.1"),
-            License::MPL_2_0 => write!(w, "MPL-2.0"),
-            License::GPL_2_0 => write!(w, "GPL-2.0-only"),
-            License::GPL_2_0Plus => write!(w, "GPL-2.0-or-later"),
-            License::GPL_3_0 => write!(w, "GPL-3.0-only"),
-            License::GPL_3_0Plus => write!(w, "GPL-3.0-or-later"),
-            License::AGPL_3_0 => write!(w, "AGPL-3.0-only"),
-            License::AGPL_3_0Plus => write!(w, "AGPL-3.0-or-later"),
             License::Custom(ref s) => write!(w, "{}", s),
             License::File(ref f) => {
                 write!(w, "License specified in file ({})", f.to_string_lossy())
             }
             License::Multiple(ref ls) => {
-                write!(w, "{}", ls[0])?;
+                let alternative = |w: &mut fmt::Formatter, l: &License| match l {
+                    License::All(_) => write!(w, "({})", l),
+                    l => write!(w, "{}", l),
+                };
+                alternative(w, &ls[0])?;
+                for l in ls.iter().skip(1) {
+                    write!(w, " / ")?;
+                    alternative(w, l)?;
+                }
+                Ok(())
+            }
+            License::All(ref ls) => {
+                let part = |w: &mut fmt::Formatter, l: &License| match l {
+                    License::Multiple(_) => write!(w, "({})", l),
+                    l => write!(w, "{}", l),
+                };
+                part(w, &ls[0])?;
                 for l in ls.iter().skip(1) {
-                    write!(w, " / {}", l)?;
+                    write!(w, " AND ")?;
+                    part(w, l)?;
                 }
                 Ok(())
             }
+            License::WithException {
+                ref base,
+                ref exception,
+            } => match **base {
+                License::Multiple(_) | License::All(_) => {
+                    write!(w, "({}) WITH {}", base, exception)
+                }
+                _ => write!(w, "{} WITH {}", base, exception),
+            },
             License::Unspecified => write!(w, "No license specified"),
+            _ => unreachable!("has an SPDX identifier"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::License::*;
+    use super::*;
+
+    fn parse(s: &str) -> License {
+        let (license, diagnostics) = License::parse_with_diagnostics(s, true);
+        assert!(
+            diagnostics
+                .iter()
+                .all(|diagnostic| *diagnostic == ParseDiagnostic::SlashSeparator),
+            "{}: {:?}",
+            s,
+            diagnostics
+        );
+        license
+    }
+
+    fn any(licenses: Vec<License>) -> License {
+        License::multiple(licenses)
+    }
+
+    fn all(licenses: Vec<License>) -> License {
+        License::all(licenses)
+    }
+
+    fn with(base: License, exception: &str) -> License {
+        WithException {
+            base: Box::new(base),
+            exception: exception.to_owned(),
         }
     }
+
+    #[test]
+    fn precedence() {
+        let cases = vec![
+            ("MIT", MIT),
+            ("MIT OR Apache-2.0", any(vec![MIT, Apache_2_0])),
+            ("MIT/Apache-2.0", any(vec![MIT, Apache_2_0])),
+            ("MIT AND Apache-2.0", all(vec![MIT, Apache_2_0])),
+            (
+                "MIT AND Apache-2.0 OR GPL-3.0-only",
+                any(vec![all(vec![MIT, Apache_2_0]), GPL_3_0]),
+            ),
+            (
+                "MIT OR Apache-2.0 AND GPL-3.0-only",
+                any(vec![MIT, all(vec![Apache_2_0, GPL_3_0])]),
+            ),
+            (
+                "MIT/Apache-2.0 AND GPL-3.0-only",
+                any(vec![MIT, all(vec![Apache_2_0, GPL_3_0])]),
+            ),
+            (
+                "Apache-2.0 WITH LLVM-exception OR MIT",
+                any(vec![with(Apache_2_0, "LLVM-exception"), MIT]),
+            ),
+            (
+                "MIT AND Apache-2.0 WITH LLVM-exception",
+                all(vec![MIT, with(Apache_2_0, "LLVM-exception")]),
+            ),
+        ];
+        for (expression, expected) in cases {
+            assert_eq!(parse(expression), expected, "{}", expression);
+        }
+    }
+
+    #[test]
+    fn duplicate_and_nested_alternatives() {
+        let cases = vec![
+            ("MIT OR MIT OR Apache-2.0", any(vec![MIT, Apache_2_0])),
+            (
+                "MIT/Apache-2.0 OR BSD-3-Clause",
+                any(vec![MIT, Apache_2_0, BSD_3_Clause]),
+            ),
+            (
+                "(MIT OR Apache-2.0) OR (Apache-2.0 OR BSD-3-Clause)",
+                any(vec![MIT, Apache_2_0, BSD_3_Clause]),
+            ),
+            ("MIT AND (Apache-2.0 AND MIT)", all(vec![MIT, Apache_2_0])),
+            ("MIT OR (MIT AND MIT)", MIT),
+            (
+                "(MIT / MIT) WITH LLVM-exception",
+                with(MIT, "LLVM-exception"),
+            ),
+        ];
+        for (expression, expected) in cases {
+            let license = parse(expression);
+            assert_eq!(license, expected, "{}", expression);
+            assert_eq!(license.to_string(), expected.to_string(), "{}", expression);
+        }
+        assert_eq!(
+            parse("MIT OR MIT OR Apache-2.0").to_string(),
+            "MIT / Apache-2.0"
+        );
+    }
+
+    #[test]
+    fn canonical_combinations() {
+        // Already canonical parts are kept as they are
+        assert_eq!(
+            License::multiple(vec![Apache_2_0, MIT, MIT]),
+            Multiple(vec![MIT, Apache_2_0])
+        );
+        assert_eq!(License::multiple(vec![MIT]), MIT);
+        assert_eq!(License::all(vec![]), Unspecified);
+        assert_eq!(
+            License::multiple(vec![BSD_3_Clause, any(vec![Apache_2_0, MIT])]),
+            License::multiple(vec![MIT, any(vec![BSD_3_Clause, Apache_2_0])])
+        );
+
+        // Anything built by hand can be fixed up afterwards
+        let nested = Multiple(vec![
+            Multiple(vec![MIT]),
+            All(vec![Apache_2_0, Apache_2_0]),
+            MIT,
+        ]);
+        assert_eq!(nested.canonicalize(), any(vec![MIT, Apache_2_0]));
+        assert_eq!(Multiple(vec![All(vec![MIT])]).canonicalize(), MIT);
+    }
+
+    #[test]
+    fn parentheses() {
+        let cases = vec![
+            ("(MIT)", MIT),
+            ("((MIT))", MIT),
+            (
+                "(MIT OR Apache-2.0) AND GPL-3.0-only",
+                all(vec![any(vec![MIT, Apache_2_0]), GPL_3_0]),
+            ),
+            (
+                "GPL-3.0-only AND (MIT OR Apache-2.0)",
+                all(vec![GPL_3_0, any(vec![MIT, Apache_2_0])]),
+            ),
+            (
+                "MIT AND (Apache-2.0 OR GPL-3.0-only)",
+                all(vec![MIT, any(vec![Apache_2_0, GPL_3_0])]),
+            ),
+            (
+                "(MIT OR Apache-2.0) AND (BSD-2-Clause OR BSD-3-Clause)",
+                all(vec![
+                    any(vec![MIT, Apache_2_0]),
+                    any(vec![BSD_2_Clause, BSD_3_Clause]),
+                ]),
+            ),
+            (
+                "(Apache-2.0 OR MIT) WITH LLVM-exception",
+                with(any(vec![Apache_2_0, MIT]), "LLVM-exception"),
+            ),
+            (
+                "(MIT OR Apache-2.0) AND Unicode-3.0",
+                all(vec![
+                    any(vec![MIT, Apache_2_0]),
+                    Custom("Unicode-3.0".to_owned()),
+                ]),
+            ),
+        ];
+        for (expression, expected) in cases {
+            let license = License::parse_with_diagnostics(expression, false).0;
+            assert_eq!(license, expected, "{}", expression);
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for expression in [
+            "(MIT OR Apache-2.0) AND GPL-3.0-only",
+            "MIT OR Apache-2.0 AND GPL-3.0-only",
+            "(MIT OR Apache-2.0) WITH LLVM-exception",
+            "MIT AND Apache-2.0 WITH LLVM-exception",
+        ] {
+            let license = parse(expression);
+            assert_eq!(parse(&license.to_string()), license, "{}", license);
+        }
+    }
+
+    #[test]
+    fn spdx_urls() {
+        let url = |id: &str| format!("https://spdx.org/licenses/{}.html", id);
+        for (license, id) in [
+            (MIT, "MIT"),
+            (BSD_0_Clause, "0BSD"),
+            (Apache_2_0, "Apache-2.0"),
+            (LGPL_2_1Plus, "LGPL-2.1-or-later"),
+            (GPL_3_0, "GPL-3.0-only"),
+            (CC_BY_SA_4_0, "CC-BY-SA-4.0"),
+        ] {
+            assert_eq!(license.spdx_id(), Some(id));
+            assert_eq!(license.spdx_url(), [url(id)]);
+        }
+        // Every known license has an identifier
+        for license in KNOWN {
+            assert_eq!(license.spdx_id(), Some(&*license.to_string()));
+        }
+
+        assert_eq!(
+            parse("MIT OR Apache-2.0").spdx_url(),
+            [url("MIT"), url("Apache-2.0")]
+        );
+        assert_eq!(
+            parse("(MIT OR Apache-2.0) AND MIT").spdx_url(),
+            [url("MIT"), url("Apache-2.0")]
+        );
+        assert_eq!(
+            parse("Apache-2.0 WITH LLVM-exception").spdx_url(),
+            [url("Apache-2.0")]
+        );
+        assert_eq!(parse("MIT OR Apache-2.0").spdx_id(), None);
+
+        for license in [
+            Custom("LicenseRef-Odd".to_owned()),
+            File("LICENSE".into()),
+            Unspecified,
+        ] {
+            assert_eq!(license.spdx_id(), None);
+            assert_eq!(license.spdx_url(), Vec::<String>::new());
+        }
+    }
+
+    #[test]
+    fn equivalent_spellings_are_equal() {
+        let expected = any(vec![Apache_2_0, MIT]);
+        for expression in [
+            "MIT OR Apache-2.0",
+            "Apache-2.0 OR MIT",
+            "MIT/Apache-2.0",
+            "Apache-2.0/MIT",
+            "MIT OR Apache-2.0 OR MIT",
+            " mit  OR  apache-2.0 ",
+        ] {
+            let license = expression.parse::<License>().unwrap();
+            assert_eq!(license, expected, "{}", expression);
+            assert_eq!(license.to_string(), expected.to_string(), "{}", expression);
+        }
+        assert_eq!("MIT OR MIT".parse::<License>().unwrap(), MIT);
+        assert_eq!(
+            "Apache-2.0 AND MIT AND Apache-2.0"
+                .parse::<License>()
+                .unwrap(),
+            all(vec![MIT, Apache_2_0])
+        );
+    }
+
+    #[test]
+    fn exceptions_are_structural() {
+        let cases = vec![
+            (
+                "Apache-2.0 WITH LLVM-exception",
+                with(Apache_2_0, "LLVM-exception"),
+            ),
+            (
+                "Apache-2.0  WITH   LLVM-exception",
+                with(Apache_2_0, "LLVM-exception"),
+            ),
+            (
+                "GPL-2.0-only WITH Classpath-exception-2.0",
+                with(GPL_2_0, "Classpath-exception-2.0"),
+            ),
+        ];
+        for (expression, expected) in cases {
+            let license = parse(expression);
+            assert_eq!(license, expected, "{}", expression);
+            assert_eq!(parse(&license.to_string()), license, "{}", license);
+        }
+        assert_eq!(
+            parse("Apache-2.0  WITH LLVM-exception").to_string(),
+            "Apache-2.0 WITH LLVM-exception"
+        );
+    }
+
+    #[test]
+    fn exceptions_delegate_to_their_base() {
+        let context = CheckContext::default();
+        let llvm = with(Apache_2_0, "LLVM-exception");
+        for root in [MIT, Apache_2_0, GPL_3_0, MPL_2_0] {
+            assert_eq!(
+                root.can_include(&llvm, &context),
+                root.can_include(&Apache_2_0, &context),
+                "{} including {}",
+                root,
+                llvm
+            );
+            assert_eq!(
+                llvm.can_include(&root, &context),
+                Apache_2_0.can_include(&root, &context),
+                "{} including {}",
+                llvm,
+                root
+            );
+        }
+        assert_eq!(llvm.template(), Apache_2_0.template());
+        assert!(llvm.template().is_some());
+    }
+
+    #[test]
+    fn empty_licenses_are_unspecified() {
+        for empty in &["", "   ", "\t\n"] {
+            assert_eq!(
+                empty.parse::<License>().unwrap(),
+                Unspecified,
+                "{:?}",
+                empty
+            );
+            assert_eq!(
+                License::parse_with_diagnostics(empty, false),
+                (Unspecified, vec![ParseDiagnostic::Empty]),
+                "{:?}",
+                empty
+            );
+        }
+        assert_ne!(Unspecified.to_string(), "");
+    }
+
+    #[test]
+    fn similarity_groups() {
+        let group = |license: &str| parse(license).similarity_group();
+        assert_eq!(group("MIT"), Some(&[MIT, X11][..]));
+        assert_eq!(group("X11"), group("MIT"));
+        let excepted = WithException {
+            base: Box::new(X11),
+            exception: "Some-exception".to_owned(),
+        };
+        assert_eq!(excepted.similarity_group(), group("MIT"));
+        assert_eq!(group("Apache-2.0"), None);
+        assert_eq!(group("BSD-3-Clause"), None);
+        assert_eq!(Custom("Custom".to_owned()).similarity_group(), None);
+    }
+
+    #[test]
+    fn families() {
+        let family = |license: &str| parse(license).family();
+        assert_eq!(family("CC0-1.0"), Family::PublicDomain);
+        assert_eq!(family("MIT"), Family::Permissive);
+        assert_eq!(family("MPL-2.0"), Family::WeakCopyleft);
+        assert_eq!(family("LGPL-2.1-or-later"), Family::WeakCopyleft);
+        assert_eq!(family("GPL-2.0-only"), Family::StrongCopyleft);
+        assert_eq!(family("AGPL-3.0-or-later"), Family::NetworkCopyleft);
+        let custom = Custom("Mine".to_owned());
+        assert_eq!(custom.family(), Family::Unknown);
+        assert_eq!(
+            family("GPL-3.0-only WITH GCC-exception-3.1"),
+            Family::StrongCopyleft
+        );
+        // Any alternative can be chosen, but every one of a combination applies
+        assert_eq!(family("MIT OR GPL-3.0-only"), Family::Permissive);
+        assert_eq!(family("MIT AND LGPL-3.0-only"), Family::WeakCopyleft);
+        assert_eq!(family("Unlicense OR MIT"), Family::PublicDomain);
+        assert_eq!(All(vec![MIT, custom]).family(), Family::Unknown);
+        assert_eq!(File("LICENSE".into()).family(), Family::Unknown);
+        assert_eq!(Unspecified.family(), Family::Unknown);
+    }
+
+    #[test]
+    fn family_of_every_license() {
+        use super::Family::*;
+        // In the order of `KNOWN`, a new license has to be classified here
+        let families = [
+            PublicDomain,
+            Permissive,
+            PublicDomain,
+            Permissive,
+            Permissive,
+            Permissive,
+            Permissive,
+            Permissive,
+            WeakCopyleft,
+            WeakCopyleft,
+            WeakCopyleft,
+            WeakCopyleft,
+            WeakCopyleft,
+            WeakCopyleft,
+            WeakCopyleft,
+            StrongCopyleft,
+            StrongCopyleft,
+            StrongCopyleft,
+            StrongCopyleft,
+            NetworkCopyleft,
+            NetworkCopyleft,
+            Permissive,
+            Permissive,
+            StrongCopyleft,
+            StrongCopyleft,
+            StrongCopyleft,
+            StrongCopyleft,
+            StrongCopyleft,
+            StrongCopyleft,
+        ];
+        assert_eq!(KNOWN.len(), families.len());
+        for (license, family) in KNOWN.iter().zip(&families) {
+            assert_eq!(license.family(), *family, "{}", license);
+        }
+    }
+
+    #[test]
+    fn grouped_gpl_is_not_hidden() {
+        let license = parse("(MIT OR Apache-2.0) AND GPL-3.0-only");
+        assert_eq!(
+            MIT.can_include(&license, &CheckContext::default()),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        for expression in [
+            "(MIT OR Apache-2.0",
+            "MIT OR Apache-2.0)",
+            "MIT OR",
+            "AND MIT",
+            "MIT OR () ",
+            "Apache-2.0 WITH",
+            "(MIT) (Apache-2.0)",
+        ] {
+            let (license, diagnostics) = License::parse_with_diagnostics(expression, false);
+            assert_eq!(license, Custom(expression.trim().to_owned()));
+            assert!(
+                matches!(diagnostics[..], [ParseDiagnostic::Malformed { .. }]),
+                "{}: {:?}",
+                expression,
+                diagnostics
+            );
+        }
+    }
+
+    #[test]
+    fn normalization() {
+        let cases = vec![
+            ("Apache 2.0", Apache_2_0),
+            ("apache-2.0", Apache_2_0),
+            ("Apache License 2.0", Apache_2_0),
+            ("Apache License, Version 2.0", Apache_2_0),
+            ("mit", MIT),
+            ("BSD3", BSD_3_Clause),
+            ("BSD 2-Clause", BSD_2_Clause),
+            ("GPLv3", GPL_3_0),
+            ("GPL v2+", GPL_2_0Plus),
+            ("LGPL-2.1 or later", LGPL_2_1Plus),
+            ("MPL 2", MPL_2_0),
+            ("CC0", CC0_1_0),
+        ];
+        for (id, expected) in cases {
+            let (license, diagnostics) = License::parse_with_diagnostics(id, false);
+            assert_eq!(license, expected, "{}", id);
+            assert_eq!(
+                diagnostics,
+                vec![ParseDiagnostic::Normalized {
+                    from: id.to_owned(),
+                    to: expected.to_string(),
+                }]
+            );
+
+            let (license, diagnostics) = License::parse_with_diagnostics(id, true);
+            assert_eq!(license, Custom(id.to_owned()), "{}", id);
+            assert_eq!(
+                diagnostics,
+                vec![ParseDiagnostic::NotNormalized {
+                    from: id.to_owned(),
+                    to: expected.to_string(),
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn normalization_diagnostics() {
+        let (license, diagnostics) = License::parse_with_diagnostics("MIT OR Apache 2.0", false);
+        assert_eq!(license, any(vec![MIT, Apache_2_0]));
+        assert_eq!(diagnostics.len(), 1);
+
+        let (license, diagnostics) = License::parse_with_diagnostics("BSD", false);
+        assert_eq!(license, Custom("BSD".to_owned()));
+        assert!(matches!(
+            diagnostics[..],
+            [ParseDiagnostic::Ambiguous { .. }]
+        ));
+
+        let (license, diagnostics) = License::parse_with_diagnostics("Frobnicate-1.0", false);
+        assert_eq!(license, Custom("Frobnicate-1.0".to_owned()));
+        assert!(matches!(diagnostics[..], [ParseDiagnostic::Unknown { .. }]));
+
+        let (_, diagnostics) = License::parse_with_diagnostics("", false);
+        assert_eq!(diagnostics, vec![ParseDiagnostic::Empty]);
+    }
+
+    #[test]
+    fn parentheses_are_not_fuzzy_matched() {
+        assert_eq!(parse_fuzzy_id("(MIT"), Err(None));
+        assert_eq!(parse_fuzzy_id("Apache-2.0)"), Err(None));
+    }
+
+    #[test]
+    fn words_make_up_one_identifier() {
+        let (license, diagnostics) =
+            License::parse_with_diagnostics("Apache License, Version 2.0 OR MIT", false);
+        assert_eq!(license, any(vec![Apache_2_0, MIT]));
+        assert_eq!(
+            diagnostics,
+            vec![ParseDiagnostic::Normalized {
+                from: "Apache License, Version 2.0".to_owned(),
+                to: "Apache-2.0".to_owned(),
+            }]
+        );
+        assert_eq!(
+            License::parse_with_diagnostics("GPL-2.0 or later", false).0,
+            GPL_2_0Plus
+        );
+    }
+
+    #[test]
+    fn lowercase_operators() {
+        let lowercase = |operators: &[&str]| {
+            operators
+                .iter()
+                .map(|operator| ParseDiagnostic::LowercaseOperator {
+                    operator: (*operator).to_owned(),
+                })
+                .collect::<Vec<_>>()
+        };
+        let cases = vec![
+            ("MIT or Apache-2.0", any(vec![MIT, Apache_2_0]), vec!["or"]),
+            (
+                "MIT and Apache-2.0",
+                all(vec![MIT, Apache_2_0]),
+                vec!["and"],
+            ),
+            (
+                "Apache-2.0 with LLVM-exception",
+                with(Apache_2_0, "LLVM-exception"),
+                vec!["with"],
+            ),
+            (
+                "(MIT or Apache-2.0) and Unlicense or MIT",
+                any(vec![all(vec![any(vec![MIT, Apache_2_0]), Unlicense]), MIT]),
+                vec!["or", "and"],
+            ),
+            (
+                "MIT OR Apache-2.0 and BSD-3-Clause",
+                any(vec![MIT, all(vec![Apache_2_0, BSD_3_Clause])]),
+                vec!["and"],
+            ),
+        ];
+        for (expression, expected, operators) in cases {
+            let (license, diagnostics) = License::parse_with_diagnostics(expression, false);
+            assert_eq!(license, expected, "{}", expression);
+            assert_eq!(diagnostics, lowercase(&operators), "{}", expression);
+        }
+        assert_eq!(
+            lowercase(&["or"])[0].to_string(),
+            "'or' is not an SPDX operator, use 'OR' instead"
+        );
+
+        // Part of a name rather than an operator
+        for expression in ["GPL-2.0 or later", "GPL-2.0 or Later"] {
+            let (license, diagnostics) = License::parse_with_diagnostics(expression, false);
+            assert_eq!(license, GPL_2_0Plus, "{}", expression);
+            assert!(
+                !diagnostics.iter().any(|diagnostic| matches!(
+                    diagnostic,
+                    ParseDiagnostic::LowercaseOperator { .. }
+                )),
+                "{}: {:?}",
+                expression,
+                diagnostics
+            );
+        }
+        let (license, _) = License::parse_with_diagnostics("GPL-2.0 or later or MIT", false);
+        assert_eq!(license, any(vec![GPL_2_0Plus, MIT]));
+
+        // Mixed case isn't an operator at all
+        assert_eq!(
+            License::parse_with_diagnostics("MIT Or Apache-2.0", false).0,
+            Custom("MIT Or Apache-2.0".to_owned())
+        );
+    }
+
+    #[test]
+    fn obligations() {
+        let obligations = |license: &str| parse(license).obligations();
+        let flags = |source, text, notice, patent, copyleft_scope| {
+            Some(Obligations {
+                requires_source_disclosure_for_modifications: source,
+                requires_license_text_distribution: text,
+                requires_notice_preservation: notice,
+                patent_grant: patent,
+                copyleft_scope,
+            })
+        };
+        use super::CopyleftScope::{FileLevel, Library, Strong};
+        let none = super::CopyleftScope::None;
+
+        assert_eq!(
+            obligations("Unlicense"),
+            flags(false, false, false, false, none)
+        );
+        assert_eq!(obligations("MIT"), flags(false, true, true, false, none));
+        assert_eq!(
+            obligations("BSD-3-Clause"),
+            flags(false, true, true, false, none)
+        );
+        assert_eq!(
+            obligations("Apache-2.0"),
+            flags(false, true, true, true, none)
+        );
+        assert_eq!(
+            obligations("MPL-2.0"),
+            flags(true, true, true, true, FileLevel)
+        );
+        assert_eq!(
+            obligations("LGPL-2.1-only"),
+            flags(true, true, true, false, Library)
+        );
+        assert_eq!(
+            obligations("LGPL-3.0-or-later"),
+            flags(true, true, true, true, Library)
+        );
+        assert_eq!(
+            obligations("GPL-2.0-only"),
+            flags(true, true, true, false, Strong)
+        );
+        assert_eq!(
+            obligations("AGPL-3.0-only"),
+            flags(true, true, true, true, Strong)
+        );
+        assert_eq!(
+            obligations("CC-BY-SA-4.0"),
+            flags(false, false, true, false, Strong)
+        );
+        assert_eq!(
+            obligations("GPL-2.0-or-later WITH Classpath-exception-2.0"),
+            obligations("GPL-2.0-or-later")
+        );
+
+        // A choice is used under its least restrictive alternative, a
+        // conjunction obliges everything its parts do
+        assert_eq!(obligations("MIT OR GPL-3.0-only"), obligations("MIT"));
+        assert_eq!(obligations("MIT AND Apache-2.0"), obligations("Apache-2.0"));
+        assert_eq!(
+            obligations("Apache-2.0 AND LGPL-2.1-only"),
+            flags(true, true, true, true, Library)
+        );
+
+        assert_eq!(Custom("Proprietary".to_owned()).obligations(), None);
+        assert_eq!(File("LICENSE".into()).obligations(), None);
+        assert_eq!(Unspecified.obligations(), None);
+        assert_eq!(
+            all(vec![MIT, Custom("Proprietary".to_owned())]).obligations(),
+            None
+        );
+        assert_eq!(
+            any(vec![Custom("Proprietary".to_owned()), MIT]).obligations(),
+            obligations("MIT")
+        );
+
+        assert_eq!(
+            obligations("MPL-2.0").unwrap().describe(),
+            vec![
+                "file-level copyleft",
+                "publish source of modifications",
+                "distribute license text",
+                "preserve notices",
+                "patent grant"
+            ]
+        );
+        assert_eq!(
+            obligations("Unlicense").unwrap().describe(),
+            vec!["no obligations"]
+        );
+    }
+
+    #[test]
+    fn explanations() {
+        let explain = |root: &str, dependency: &str| {
+            parse(root).can_include_explain(&parse(dependency), &CheckContext::default())
+        };
+        assert_eq!(
+            explain("GPL-3.0-only", "MIT"),
+            (
+                Some(true),
+                Explanation::Permissive {
+                    including: License::GPL_3_0,
+                    included: License::MIT,
+                }
+            )
+        );
+        assert_eq!(
+            explain("MIT", "GPL-3.0-only"),
+            (
+                Some(false),
+                Explanation::CopyleftViolated {
+                    including: License::MIT,
+                    included: License::GPL_3_0,
+                }
+            )
+        );
+        assert_eq!(
+            explain("GPL-3.0-or-later", "GPL-2.0-or-later"),
+            (
+                Some(true),
+                Explanation::CopyleftSatisfied {
+                    including: License::GPL_3_0Plus,
+                    included: License::GPL_2_0Plus,
+                }
+            )
+        );
+        assert_eq!(
+            explain("GPL-2.0-only", "Apache-2.0"),
+            (
+                Some(false),
+                Explanation::ConflictingTerms {
+                    including: License::GPL_2_0,
+                    included: License::Apache_2_0,
+                }
+            )
+        );
+        let odd = License::Custom("LicenseRef-Odd".to_owned());
+        assert_eq!(
+            License::MIT.can_include_explain(&odd, &CheckContext::default()),
+            (None, Explanation::Unevaluable(odd))
+        );
+        assert_eq!(
+            explain("GPL-3.0-only", "LGPL-2.0-only"),
+            (None, Explanation::NotEncoded(License::LGPL_2_0))
+        );
+        assert_eq!(
+            License::MIT.can_include_explain(&License::Unspecified, &CheckContext::default()),
+            (Some(false), Explanation::Unlicensed)
+        );
+
+        // Choices are explained by the alternative that decided them
+        assert_eq!(
+            explain("MIT", "MIT OR Apache-2.0").1,
+            explain("MIT", "MIT").1
+        );
+        assert_eq!(
+            explain("MIT", "GPL-3.0-only OR AGPL-3.0-only"),
+            (
+                Some(false),
+                Explanation::NoAlternative(parse("GPL-3.0-only OR AGPL-3.0-only"))
+            )
+        );
+        assert_eq!(
+            explain("Apache-2.0", "MIT AND GPL-2.0-only").1,
+            explain("Apache-2.0", "GPL-2.0-only").1
+        );
+        assert_eq!(
+            explain("MIT", "GPL-3.0-only").1.to_string(),
+            "GPL-3.0-only is copyleft, it requires the combined work to be licensed compatibly \
+             and MIT is not compatible with it in that direction"
+        );
+    }
+
+    #[test]
+    fn lgpl_linkage() {
+        let static_ = CheckContext::default();
+        let dynamic = CheckContext {
+            linkage: Linkage::Dynamic,
+            ..CheckContext::default()
+        };
+        for lgpl in &[
+            "LGPL-2.1-only",
+            "LGPL-2.1-or-later",
+            "LGPL-3.0-only",
+            "LGPL-3.0-or-later",
+        ] {
+            let lgpl = parse(lgpl);
+            for root in &["MIT", "Apache-2.0", "BSD-3-Clause"] {
+                let root = parse(root);
+                assert_eq!(root.can_include(&lgpl, &static_), None, "{} {}", root, lgpl);
+                assert_eq!(
+                    root.can_include(&lgpl, &dynamic),
+                    Some(true),
+                    "{} {}",
+                    root,
+                    lgpl
+                );
+            }
+
+            // Copyleft roots don't depend on how it's linked
+            for root in &["GPL-3.0-only", "LGPL-3.0-or-later"] {
+                let root = parse(root);
+                assert_eq!(
+                    root.can_include(&lgpl, &static_),
+                    root.can_include(&lgpl, &dynamic),
+                    "{} {}",
+                    root,
+                    lgpl
+                );
+            }
+        }
+        assert_eq!(
+            parse("GPL-3.0-only").can_include(&LGPL_2_1Plus, &static_),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn private_roots() {
+        let private = CheckContext {
+            private: true,
+            ..CheckContext::default()
+        };
+        for (license, can_include) in [
+            ("MIT", Some(true)),
+            ("Apache-2.0", Some(true)),
+            ("MPL-2.0", Some(true)),
+            ("LGPL-3.0-only", Some(true)),
+            ("GPL-3.0-only", None),
+            ("AGPL-3.0-only", None),
+            ("MIT OR GPL-3.0-only", Some(true)),
+            ("MIT AND GPL-3.0-only", None),
+        ] {
+            assert_eq!(
+                Unspecified.can_include(&parse(license), &private),
+                can_include,
+                "{}",
+                license
+            );
+        }
+        // Nothing grants using unlicensed packages, private or not
+        assert_eq!(Unspecified.can_include(&Unspecified, &private), Some(false));
+        // Only roots without a license are assumed to be private
+        assert_eq!(
+            MIT.can_include(&parse("GPL-3.0-only"), &private),
+            Some(false)
+        );
+        assert_eq!(
+            Unspecified.can_include(&Apache_2_0, &CheckContext::default()),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn custom_templates_are_found_by_slug() {
+        let dir = crate::testutil::TempDir::new();
+        dir.write("Proprietary-InternalUse-1.0.txt", "Internal use only.\n");
+        dir.write("MIT.txt", "Patched MIT.\n");
+        dir.write("README.md", "Not a template\n");
+        let store = TemplateStore::load(dir.path()).unwrap();
+        assert_eq!(
+            store.get(&Custom("proprietary_internaluse 1.0".to_owned())),
+            Some("Internal use only.\n")
+        );
+        assert_eq!(store.get(&MIT), Some("Patched MIT.\n"));
+        assert_eq!(store.get(&Apache_2_0), None);
+        assert_eq!(store.get(&parse("MIT OR Apache-2.0")), None);
+        assert_eq!(store.iter().count(), 2);
+        assert_eq!(store.overridden(), [(dir.path().join("MIT.txt"), &MIT)]);
+
+        dir.write("mit.txt", "Another MIT.\n");
+        let err = TemplateStore::load(dir.path()).unwrap_err().to_string();
+        assert!(err.contains("are templates of the same license"), "{}", err);
+    }
 }
@@ -1,17 +1,778 @@
-use cargo_metadata::Package;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
-use crate::license::License;
+use cargo_metadata::{Package, PackageId};
+use serde::{Deserialize, Serialize};
+
+use crate::license::{License, ParseDiagnostic};
+
+/// Which of the manifest's license fields were set
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LicenseSource {
+    /// Only `license`
+    Field,
+    /// Only `license-file`
+    File,
+    /// Both `license` and `license-file`, cargo accepts this but the two may
+    /// disagree
+    Both,
+    Neither,
+}
+
+/// What the `SPDX-License-Identifier` headers in a package's sources say
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SourceHeaders {
+    /// Every header names this license, given as the first file naming it
+    /// and the percentage of the files scanned that have a header
+    Consistent(String, PathBuf, u8),
+    /// The headers name different licenses, each with the first file naming
+    /// it
+    Conflicting(Vec<(String, PathBuf)>),
+}
+
+/// How a package's license was arrived at, so that consumers of the JSON
+/// output can tell what the package declared from what was worked out for it
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum Resolution {
+    /// The `license` or `license-file` field, the package's own or inherited
+    /// from its workspace, as written
+    Declared,
+    /// The `license` field after fixing up what isn't valid SPDX, e.g. a `/`
+    /// separator or a license name instead of its identifier
+    Normalized { original: String },
+    /// Declared nothing, the `SPDX-License-Identifier` headers of its sources
+    /// agree on the license, `path` is the first file naming it and
+    /// `confidence` the percentage of the files scanned that have a header
+    InferredHeaders {
+        path: PathBuf,
+        #[serde(default)]
+        confidence: u8,
+    },
+    /// Replaced by the `clarify.license` of the policy at `source`
+    Overridden { source: String },
+    /// Declared nothing and nothing could be inferred
+    Unspecified,
+}
+
+impl fmt::Display for Resolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Resolution::Declared => f.write_str("declared"),
+            Resolution::Normalized { original } => write!(f, "normalized from {:?}", original),
+            Resolution::InferredHeaders { path, confidence } => write!(
+                f,
+                "inferred from the source headers of {} and {}% of the files scanned",
+                path.display(),
+                confidence
+            ),
+            Resolution::Overridden { source } => write!(f, "overridden by {}", source),
+            Resolution::Unspecified => f.write_str("unspecified"),
+        }
+    }
+}
+
+/// At most this many source files are scanned for headers
+const MAX_HEADER_FILES: usize = 50;
+/// Headers are only looked for in the first lines of a file
+const HEADER_LINES: usize = 20;
+
+/// A package's license and how it was arrived at, worked out once by
+/// `LicensedCache::resolve`
+#[derive(Clone, Debug)]
+pub struct ResolvedLicense {
+    pub license: License,
+    pub resolution: Resolution,
+    /// Problems found parsing the declared expression
+    pub diagnostics: Vec<ParseDiagnostic>,
+    /// The headers of the sources of a package declaring no license, if
+    /// `--scan-source-headers` was given and there were any
+    pub headers: Option<SourceHeaders>,
+}
 
 pub trait Licensed {
-    fn license(&self) -> License;
+    /// The `license` field exactly as declared, before any normalization
+    fn declared_license_str(&self) -> Option<&str>;
+
+    /// The `license-file` field, resolved relative to the manifest directory
+    fn declared_license_file(&self) -> Option<PathBuf>;
+
+    fn license_source(&self) -> LicenseSource {
+        match (self.declared_license_str(), self.declared_license_file()) {
+            (Some(_), Some(_)) => LicenseSource::Both,
+            (Some(_), None) => LicenseSource::Field,
+            (None, Some(_)) => LicenseSource::File,
+            (None, None) => LicenseSource::Neither,
+        }
+    }
+}
+
+/// Licenses of packages already worked out, so that each package's
+/// expression is parsed, its inherited license looked up and any problems
+/// reported once however many commands and roots ask for it. One is created
+/// for each run with the options licenses are worked out under.
+pub struct LicensedCache {
+    /// Whether expressions are taken as written, see `--strict-spdx`
+    strict: bool,
+    /// Whether packages declaring no license get one from their sources'
+    /// `SPDX-License-Identifier` headers
+    scan_source_headers: bool,
+    /// Licenses used in place of the declared ones by crate name, and the
+    /// policy they come from
+    overrides: HashMap<String, License>,
+    overrides_source: String,
+    licenses: Mutex<HashMap<PackageId, Arc<ResolvedLicense>>>,
+    /// The `[workspace]` table of the manifest in each directory looked in
+    /// for a workspace root, `None` if there's no such manifest or table, so
+    /// that each is only read and parsed once however many members inherit
+    workspaces: Mutex<HashMap<PathBuf, Option<Arc<toml::Value>>>>,
+    /// Copies of packages in a `cargo vendor` directory, read in place of
+    /// their sources
+    vendored: HashMap<PackageId, Package>,
+    /// The headers of the sources of each package scanned so far
+    headers: Mutex<HashMap<PackageId, Option<SourceHeaders>>>,
+    /// Packages whose problems were already reported
+    reported: Mutex<HashSet<PackageId>>,
+    /// How many licenses were actually worked out rather than reused
+    evaluations: AtomicUsize,
+}
+
+impl LicensedCache {
+    pub fn new(strict: bool, scan_source_headers: bool) -> LicensedCache {
+        LicensedCache {
+            strict,
+            scan_source_headers,
+            overrides: HashMap::new(),
+            overrides_source: String::new(),
+            licenses: Mutex::new(HashMap::new()),
+            workspaces: Mutex::new(HashMap::new()),
+            vendored: HashMap::new(),
+            headers: Mutex::new(HashMap::new()),
+            reported: Mutex::new(HashSet::new()),
+            evaluations: AtomicUsize::new(0),
+        }
+    }
+
+    /// Uses `overrides` in place of the licenses the crates they name
+    /// declare, `source` is the policy they come from
+    pub fn with_overrides(
+        mut self,
+        overrides: HashMap<String, License>,
+        source: &str,
+    ) -> LicensedCache {
+        self.overrides = overrides;
+        self.overrides_source = source.to_owned();
+        self
+    }
+
+    /// Reads the sources of the packages in `vendored`, copies in a `cargo
+    /// vendor` directory by the id of the package they're of, from there
+    pub fn with_vendored(mut self, vendored: HashMap<PackageId, Package>) -> LicensedCache {
+        self.vendored = vendored;
+        self
+    }
+
+    /// The package's copy in the `cargo vendor` directory, if it's there
+    pub fn vendored(&self, package: &Package) -> Option<&Package> {
+        self.vendored.get(&package.id)
+    }
+
+    /// The copy of the package whose sources are read, the vendored one when
+    /// there is one
+    pub fn sources<'a>(&'a self, package: &'a Package) -> &'a Package {
+        self.vendored(package).unwrap_or(package)
+    }
+
+    /// The package's license, worked out the first time it's asked for
+    pub fn resolve(&self, package: &Package) -> Arc<ResolvedLicense> {
+        if let Some(resolved) = self.licenses.lock().unwrap().get(&package.id) {
+            return Arc::clone(resolved);
+        }
+        // Worked out without the lock held as it reads files, if another
+        // thread got there in the meantime its result is kept
+        let resolved = Arc::new(self.evaluate(package));
+        Arc::clone(
+            self.licenses
+                .lock()
+                .unwrap()
+                .entry(package.id.clone())
+                .or_insert(resolved),
+        )
+    }
+
+    fn evaluate(&self, package: &Package) -> ResolvedLicense {
+        self.evaluations.fetch_add(1, Ordering::SeqCst);
+        if let Some(license) = self.overrides.get(&package.name) {
+            // Whatever the package declares doesn't matter anymore
+            return ResolvedLicense {
+                license: license.clone(),
+                resolution: Resolution::Overridden {
+                    source: self.overrides_source.clone(),
+                },
+                diagnostics: Vec::new(),
+                headers: None,
+            };
+        }
+        let (mut license, diagnostics, mut resolution) = self.declared(package);
+        let mut headers = None;
+        if license == License::Unspecified && package.declared_license_str().is_none() {
+            headers = self.source_headers(package);
+            if let Some(SourceHeaders::Consistent(expression, path, confidence)) = &headers {
+                license = expression.parse().unwrap();
+                resolution = Resolution::InferredHeaders {
+                    path: path.clone(),
+                    confidence: *confidence,
+                };
+            }
+        }
+        ResolvedLicense {
+            license,
+            resolution,
+            diagnostics,
+            headers,
+        }
+    }
+
+    /// Works out a package's license from its manifest fields
+    fn declared(&self, package: &Package) -> (License, Vec<ParseDiagnostic>, Resolution) {
+        if let Some(license) = package.declared_license_str() {
+            parse(license, self.strict)
+        } else {
+            // An empty license field is treated as missing, but still
+            // reported so that it gets fixed
+            let diagnostics = match &package.license {
+                Some(_) => vec![ParseDiagnostic::Empty],
+                None => Vec::new(),
+            };
+            match package.declared_license_file() {
+                Some(file) => (License::File(file), diagnostics, Resolution::Declared),
+                None => match self.inherited_license(package) {
+                    Some(Inherited::License(license)) => parse(&license, self.strict),
+                    Some(Inherited::File(file)) => {
+                        (License::File(file), diagnostics, Resolution::Declared)
+                    }
+                    None => (License::default(), diagnostics, Resolution::Unspecified),
+                },
+            }
+        }
+    }
+
+    /// The license a package inherits from its workspace with
+    /// `license.workspace = true`, cargo normally resolves this for us but
+    /// metadata from older cargo versions leaves the fields empty
+    fn inherited_license(&self, package: &Package) -> Option<Inherited> {
+        fn inherits(manifest: &toml::Value, field: &str) -> bool {
+            manifest
+                .get("package")
+                .and_then(|package| package.get(field))
+                .and_then(|field| field.get("workspace"))
+                .and_then(toml::Value::as_bool)
+                .unwrap_or(false)
+        }
+
+        let manifest = read_manifest(&package.manifest_path)?;
+        let (license, file) = (
+            inherits(&manifest, "license"),
+            inherits(&manifest, "license-file"),
+        );
+        if !license && !file {
+            return None;
+        }
+
+        // The workspace root is the nearest manifest with a `[workspace]`, which
+        // may be the package's own
+        let (root, workspace) = package
+            .manifest_path
+            .ancestors()
+            .skip(1)
+            .find_map(|dir| Some((dir, self.workspace(dir)?)))?;
+        let fields = workspace.get("package")?;
+        if license {
+            if let Some(license) = fields.get("license").and_then(toml::Value::as_str) {
+                return Some(Inherited::License(license.to_owned()));
+            }
+        }
+        if file {
+            if let Some(file) = fields.get("license-file").and_then(toml::Value::as_str) {
+                return Some(Inherited::File(root.join(file)));
+            }
+        }
+        log::warn!(
+            "{} inherits its license from the workspace at {} which doesn't set one",
+            package.name,
+            root.display()
+        );
+        None
+    }
+
+    /// The `[workspace]` table of the manifest in `dir`, read the first time
+    /// it's asked for
+    fn workspace(&self, dir: &Path) -> Option<Arc<toml::Value>> {
+        if let Some(workspace) = self.workspaces.lock().unwrap().get(dir) {
+            return workspace.clone();
+        }
+        let workspace = read_manifest(&dir.join("Cargo.toml"))
+            .and_then(|manifest| manifest.get("workspace").cloned())
+            .map(Arc::new);
+        self.workspaces
+            .lock()
+            .unwrap()
+            .entry(dir.to_owned())
+            .or_insert(workspace)
+            .clone()
+    }
+
+    /// The `SPDX-License-Identifier` headers of the package's sources with
+    /// `--scan-source-headers`, scanned the first time they're asked for.
+    /// Packages declaring a license are only scanned when no text can be found
+    /// for it.
+    pub fn source_headers(&self, package: &Package) -> Option<SourceHeaders> {
+        if !self.scan_source_headers {
+            return None;
+        }
+        if let Some(headers) = self.headers.lock().unwrap().get(&package.id) {
+            return headers.clone();
+        }
+        let headers = read_source_headers(self.sources(package));
+        self.headers
+            .lock()
+            .unwrap()
+            .entry(package.id.clone())
+            .or_insert(headers)
+            .clone()
+    }
+
+    /// Logs any problems found parsing the license fields, as errors with
+    /// `--strict-spdx`, returns whether any were found. Each package's
+    /// problems are only logged the first time it's reported on.
+    pub fn report_diagnostics(&self, packages: &[&Package]) -> bool {
+        let mut found = false;
+        for package in packages {
+            let resolved = self.resolve(package);
+            found |= !resolved.diagnostics.is_empty();
+            if !self.reported.lock().unwrap().insert(package.id.clone()) {
+                continue;
+            }
+            match &resolved.headers {
+                Some(SourceHeaders::Consistent(expression, path, _)) => log::warn!(
+                    "{} {} declares no license, using {} from the SPDX-License-Identifier \
+                     headers of its sources, e.g. {}",
+                    package.name,
+                    package.version,
+                    expression,
+                    path.display()
+                ),
+                Some(SourceHeaders::Conflicting(expressions)) => log::warn!(
+                    "{} {} declares no license and the SPDX-License-Identifier headers of its \
+                     sources conflict: {}",
+                    package.name,
+                    package.version,
+                    describe_conflict(expressions)
+                ),
+                None => (),
+            }
+            for diagnostic in &resolved.diagnostics {
+                if self.strict {
+                    log::error!("{} {}: {}", package.name, package.version, diagnostic);
+                } else {
+                    log::warn!("{} {}: {}", package.name, package.version, diagnostic);
+                }
+            }
+        }
+        found
+    }
+
+    #[cfg(test)]
+    pub fn evaluations(&self) -> usize {
+        self.evaluations.load(Ordering::SeqCst)
+    }
 }
 
 impl Licensed for Package {
-    fn license(&self) -> License {
+    fn declared_license_str(&self) -> Option<&str> {
         self.license
-            .as_ref()
-            .and_then(|license| license.parse::<License>().ok())
-            .or_else(|| self.license_file().map(License::File))
-            .unwrap_or_default()
+            .as_deref()
+            .filter(|license| !license.trim().is_empty())
+    }
+
+    fn declared_license_file(&self) -> Option<PathBuf> {
+        let file = self.license_file.as_ref()?;
+        Some(self.manifest_path.parent()?.join(file))
+    }
+}
+
+/// Parses a declared `license` field, it counts as normalized if anything had
+/// to be fixed up to make sense of it
+fn parse(license: &str, strict: bool) -> (License, Vec<ParseDiagnostic>, Resolution) {
+    let (parsed, diagnostics) = License::parse_with_diagnostics(license, strict);
+    let normalized = diagnostics.iter().any(|diagnostic| {
+        matches!(
+            diagnostic,
+            ParseDiagnostic::Normalized { .. }
+                | ParseDiagnostic::SlashSeparator
+                | ParseDiagnostic::LowercaseOperator { .. }
+        )
+    });
+    let resolution = if normalized {
+        Resolution::Normalized {
+            original: license.to_owned(),
+        }
+    } else {
+        Resolution::Declared
+    };
+    (parsed, diagnostics, resolution)
+}
+
+/// The distinct licenses of conflicting headers with a file naming each
+pub fn describe_conflict(expressions: &[(String, PathBuf)]) -> String {
+    expressions
+        .iter()
+        .map(|(expression, path)| format!("{} in {}", expression, path.display()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Collects the `SPDX-License-Identifier` headers of the first
+/// `MAX_HEADER_FILES` Rust files under the package's `src` directory, `None`
+/// if there are none
+fn read_source_headers(package: &Package) -> Option<SourceHeaders> {
+    fn walk(dir: &Path, files: &mut Vec<PathBuf>) {
+        let mut entries = match fs::read_dir(dir) {
+            Ok(entries) => entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .collect::<Vec<_>>(),
+            Err(_) => return,
+        };
+        entries.sort();
+        for path in entries {
+            if files.len() >= MAX_HEADER_FILES {
+                return;
+            }
+            if path.is_dir() {
+                walk(&path, files);
+            } else if path.extension().is_some_and(|extension| extension == "rs") {
+                files.push(path);
+            }
+        }
+    }
+
+    fn header(path: &Path) -> Option<String> {
+        let text = fs::read_to_string(path).ok()?;
+        text.lines().take(HEADER_LINES).find_map(|line| {
+            let (_, expression) = line.split_once("SPDX-License-Identifier:")?;
+            let expression = expression.trim().trim_end_matches("*/").trim();
+            Some(expression.to_owned()).filter(|expression| !expression.is_empty())
+        })
+    }
+
+    let mut files = Vec::new();
+    walk(&package.manifest_path.parent()?.join("src"), &mut files);
+    // Equivalent expressions, e.g. with the alternatives in another order,
+    // don't conflict
+    let mut found = BTreeMap::<License, (String, PathBuf)>::new();
+    let scanned = files.len();
+    let mut headed = 0;
+    for path in files {
+        if let Some(expression) = header(&path) {
+            headed += 1;
+            found
+                .entry(expression.parse().unwrap())
+                .or_insert((expression, path));
+        }
+    }
+    let mut found = found.into_values().collect::<Vec<_>>();
+    match found.len() {
+        0 => None,
+        1 => {
+            let (expression, path) = found.remove(0);
+            let confidence = (headed * 100 / scanned) as u8;
+            Some(SourceHeaders::Consistent(expression, path, confidence))
+        }
+        _ => {
+            found.sort_by(|a, b| a.1.cmp(&b.1));
+            Some(SourceHeaders::Conflicting(found))
+        }
+    }
+}
+
+fn read_manifest(path: &Path) -> Option<toml::Value> {
+    toml::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+enum Inherited {
+    License(String),
+    File(PathBuf),
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::testutil::{path_package, TempDir};
+
+    /// A package declaring `license` and `license_file` as given
+    fn package(name: &str, license: Option<&str>, license_file: Option<&str>) -> Package {
+        serde_json::from_value(json!({
+            "name": name,
+            "version": "1.0.0",
+            "id": format!("{} 1.0.0 (path+file:///nonexistent/{})", name, name),
+            "source": null,
+            "license": license,
+            "license_file": license_file,
+            "dependencies": [],
+            "targets": [],
+            "features": {},
+            "manifest_path": format!("/nonexistent/{}/Cargo.toml", name),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn declarations() {
+        let cache = LicensedCache::new(false, false);
+        let field = package("licensed-field", Some("MIT/Apache-2.0"), None);
+        assert_eq!(field.declared_license_str(), Some("MIT/Apache-2.0"));
+        assert_eq!(field.declared_license_file(), None);
+        assert_eq!(field.license_source(), LicenseSource::Field);
+        assert_eq!(
+            cache.resolve(&field).resolution,
+            Resolution::Normalized {
+                original: "MIT/Apache-2.0".to_owned()
+            }
+        );
+
+        let file = package("licensed-file", None, Some("COPYING"));
+        let path = PathBuf::from("/nonexistent/licensed-file/COPYING");
+        assert_eq!(file.declared_license_str(), None);
+        assert_eq!(file.declared_license_file(), Some(path.clone()));
+        assert_eq!(file.license_source(), LicenseSource::File);
+        let resolved = cache.resolve(&file);
+        assert_eq!(resolved.license, License::File(path));
+        assert_eq!(resolved.resolution, Resolution::Declared);
+
+        let both = package("licensed-both", Some("MIT"), Some("LICENSE"));
+        assert_eq!(both.declared_license_str(), Some("MIT"));
+        assert_eq!(
+            both.declared_license_file(),
+            Some(PathBuf::from("/nonexistent/licensed-both/LICENSE"))
+        );
+        assert_eq!(both.license_source(), LicenseSource::Both);
+        // The field wins, check warns that the two may disagree
+        assert_eq!(cache.resolve(&both).license, License::MIT);
+
+        let neither = package("licensed-neither", None, None);
+        assert_eq!(neither.declared_license_str(), None);
+        assert_eq!(neither.declared_license_file(), None);
+        assert_eq!(neither.license_source(), LicenseSource::Neither);
+        let resolved = cache.resolve(&neither);
+        assert_eq!(resolved.license, License::Unspecified);
+        assert_eq!(resolved.resolution, Resolution::Unspecified);
+
+        // An empty field counts as not declaring one
+        let empty = package("licensed-empty", Some("  "), None);
+        assert_eq!(empty.declared_license_str(), None);
+        assert_eq!(empty.license_source(), LicenseSource::Neither);
+        assert_eq!(cache.resolve(&empty).diagnostics, [ParseDiagnostic::Empty]);
+    }
+
+    #[test]
+    fn inherited_licenses() {
+        let dir = TempDir::new();
+        dir.write(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"*\"]\n\n[workspace.package]\n\
+             license = \"MIT OR Apache-2.0\"\nlicense-file = \"LICENSE.txt\"\n",
+        );
+        dir.write(
+            "field/Cargo.toml",
+            "[package]\nname = \"field\"\nlicense.workspace = true\n",
+        );
+        dir.write(
+            "file/Cargo.toml",
+            "[package]\nname = \"file\"\nlicense-file.workspace = true\n",
+        );
+        dir.write("own/Cargo.toml", "[package]\nname = \"own\"\n");
+        // The fields cargo would have filled in are missing, as with metadata
+        // from older cargo versions
+        let member = |name: &str| {
+            path_package(
+                &format!("inheriting-{}", name),
+                "0.1.0",
+                None,
+                &dir.path().join(name),
+            )
+        };
+
+        let cache = LicensedCache::new(false, false);
+        let license = |package: &Package| cache.resolve(package).license.clone();
+        assert_eq!(
+            license(&member("field")),
+            "MIT OR Apache-2.0".parse().unwrap()
+        );
+        assert_eq!(
+            license(&member("file")),
+            License::File(dir.path().join("LICENSE.txt"))
+        );
+        // Only members opting in inherit, whatever the workspace sets
+        assert_eq!(license(&member("own")), License::Unspecified);
+        assert_eq!(license(&member("missing")), License::Unspecified);
+
+        // The workspace manifest is only read the first time, later members
+        // don't see it change
+        dir.write(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"*\"]\n\n[workspace.package]\nlicense = \"GPL-3.0-only\"\n",
+        );
+        dir.write(
+            "late/Cargo.toml",
+            "[package]\nname = \"late\"\nlicense.workspace = true\n",
+        );
+        assert_eq!(
+            license(&member("late")),
+            "MIT OR Apache-2.0".parse().unwrap()
+        );
+
+        // A workspace without the field has nothing to inherit
+        let bare = TempDir::new();
+        bare.write("Cargo.toml", "[workspace]\n");
+        bare.write(
+            "field/Cargo.toml",
+            "[package]\nname = \"field\"\nlicense.workspace = true\n",
+        );
+        let package = path_package(
+            "inheriting-nothing",
+            "0.1.0",
+            None,
+            &bare.path().join("field"),
+        );
+        assert_eq!(license(&package), License::Unspecified);
+    }
+
+    #[test]
+    fn licenses_are_worked_out_once() {
+        let dir = TempDir::new();
+        dir.write(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"*\"]\n\n[workspace.package]\nlicense = \"MIT/Apache-2.0\"\n",
+        );
+        dir.write(
+            "field/Cargo.toml",
+            "[package]\nname = \"field\"\nlicense.workspace = true\n",
+        );
+        let inheriting = path_package(
+            "cached-inheriting",
+            "0.1.0",
+            None,
+            &dir.path().join("field"),
+        );
+        let declared = package("cached-declared", Some("MIT/Apache-2.0"), None);
+
+        let cache = LicensedCache::new(false, false);
+        for _ in 0..3 {
+            for package in &[&inheriting, &declared] {
+                let resolved = cache.resolve(package);
+                assert_eq!(resolved.license, "MIT OR Apache-2.0".parse().unwrap());
+                assert_eq!(resolved.diagnostics, [ParseDiagnostic::SlashSeparator]);
+            }
+        }
+        assert_eq!(cache.evaluations(), 2);
+
+        // Diagnostics are still found but only reported the first time
+        assert!(cache.report_diagnostics(&[&declared]));
+        assert!(cache.reported.lock().unwrap().contains(&declared.id));
+        assert!(cache.report_diagnostics(&[&declared]));
+        assert_eq!(cache.evaluations(), 2);
+
+        // Strict parsing takes identifiers as written
+        let fuzzy = package("cached-fuzzy", Some("Apache 2.0"), None);
+        assert_eq!(cache.resolve(&fuzzy).license, License::Apache_2_0);
+        let strict = LicensedCache::new(true, false);
+        assert_eq!(
+            strict.resolve(&fuzzy).license,
+            License::Custom("Apache 2.0".to_owned())
+        );
+    }
+
+    #[test]
+    fn source_headers() {
+        let dir = TempDir::new();
+        dir.write(
+            "consistent/src/lib.rs",
+            "// SPDX-License-Identifier: MIT OR Apache-2.0\nmod inner;\n",
+        );
+        dir.write(
+            "consistent/src/inner/mod.rs",
+            "/* SPDX-License-Identifier: Apache-2.0 OR MIT */\n",
+        );
+        dir.write("consistent/src/bare.rs", "fn main() {}\n");
+        dir.write(
+            "conflicting/src/lib.rs",
+            "// SPDX-License-Identifier: MIT\n",
+        );
+        dir.write(
+            "conflicting/src/vendored.rs",
+            "// SPDX-License-Identifier: GPL-3.0-only\n",
+        );
+        dir.write("none/src/lib.rs", "fn main() {}\n");
+        dir.write(
+            "declared/src/lib.rs",
+            "// SPDX-License-Identifier: GPL-3.0-only\n",
+        );
+        let member = |name: &str, license: Option<&str>| {
+            path_package(
+                &format!("headers-{}", name),
+                "0.1.0",
+                license,
+                &dir.path().join(name),
+            )
+        };
+
+        let cache = LicensedCache::new(false, true);
+        let consistent = member("consistent", None);
+        let resolved = cache.resolve(&consistent);
+        assert_eq!(resolved.license, "MIT OR Apache-2.0".parse().unwrap());
+        assert_eq!(
+            resolved.headers,
+            Some(SourceHeaders::Consistent(
+                "Apache-2.0 OR MIT".to_owned(),
+                dir.path().join("consistent/src/inner/mod.rs"),
+                66
+            ))
+        );
+        assert_eq!(
+            resolved.resolution,
+            Resolution::InferredHeaders {
+                path: dir.path().join("consistent/src/inner/mod.rs"),
+                confidence: 66
+            }
+        );
+
+        let conflicting = cache.resolve(&member("conflicting", None));
+        assert_eq!(conflicting.license, License::Unspecified);
+        assert_eq!(
+            conflicting.headers,
+            Some(SourceHeaders::Conflicting(vec![
+                ("MIT".to_owned(), dir.path().join("conflicting/src/lib.rs")),
+                (
+                    "GPL-3.0-only".to_owned(),
+                    dir.path().join("conflicting/src/vendored.rs")
+                ),
+            ]))
+        );
+
+        assert_eq!(cache.resolve(&member("none", None)).headers, None);
+        // Declared licenses aren't second guessed
+        let declared = cache.resolve(&member("declared", Some("MIT")));
+        assert_eq!(declared.license, License::MIT);
+        assert_eq!(declared.headers, None);
+
+        // Only when asked for
+        let unscanned = LicensedCache::new(false, false).resolve(&consistent);
+        assert_eq!(unscanned.headers, None);
     }
 }
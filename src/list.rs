@@ -1,46 +1,513 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::io;
 
-use cargo_metadata::Package;
+use cargo_metadata::{Package, PackageId};
 use itertools::Itertools;
+use serde::{Serialize, Serializer};
 
-use crate::licensed::Licensed;
-use crate::options::By;
+use crate::baseline::Entry;
+use crate::license::{Family, License};
+use crate::licensed::SourceHeaders;
+use crate::load::{ResolvedPackage, SourceKind};
+use crate::options::{By, LicenseFilter, ListOptions};
+use crate::outcome::Summary;
+use crate::output;
+use crate::policy::Election;
+
+impl LicenseFilter {
+    fn matches(&self, license: &License) -> bool {
+        match self {
+            LicenseFilter::Unknown => license.family() == Family::Unknown,
+            LicenseFilter::Copyleft => matches!(
+                license.family(),
+                Family::WeakCopyleft | Family::StrongCopyleft | Family::NetworkCopyleft
+            ),
+            LicenseFilter::Permissive => {
+                matches!(license.family(), Family::PublicDomain | Family::Permissive)
+            }
+            LicenseFilter::Licenses(licenses) => {
+                let parts = match license {
+                    License::Multiple(parts) | License::All(parts) => parts.iter().collect(),
+                    license => vec![license],
+                };
+                Some(license)
+                    .into_iter()
+                    .chain(parts)
+                    .any(|license| licenses.contains(&license.to_string()))
+            }
+        }
+    }
+}
+
+/// A section header for the licenses of a family
+fn family_header(family: Family, packages: usize, licenses: usize) -> String {
+    let name = family.to_string();
+    format!(
+        "== {}{} ({} package{}, {} license{}) ==",
+        name[..1].to_uppercase(),
+        &name[1..],
+        packages,
+        if packages == 1 { "" } else { "s" },
+        licenses,
+        if licenses == 1 { "" } else { "s" }
+    )
+}
+
+/// What packages are listed under with `--by license-family` and `--by source`
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Group {
+    Family(Family),
+    Source(SourceKind),
+}
+
+impl fmt::Display for Group {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Group::Family(family) => family.to_string().fmt(f),
+            Group::Source(source) => source.to_string().fmt(f),
+        }
+    }
+}
+
+/// Groups packages by license family or source, with the groups in order of
+/// family or source and their packages by name. Packages without a license
+/// come first in the unknown family so they aren't missed.
+pub fn groups<'r, 'a>(
+    resolved: &'r [ResolvedPackage<'a>],
+    by: By,
+    license: impl Fn(&ResolvedPackage) -> License,
+) -> Vec<(Group, Vec<&'r ResolvedPackage<'a>>)> {
+    let mut groups = BTreeMap::<Group, Vec<_>>::new();
+    for resolved in resolved {
+        let license = license(resolved);
+        let group = match by {
+            By::LicenseFamily => Group::Family(license.family()),
+            By::Source => Group::Source(resolved.source.clone()),
+            By::License | By::Crate => unreachable!("not a grouping"),
+        };
+        let first = by == By::LicenseFamily && license == License::Unspecified;
+        groups.entry(group).or_default().push((!first, resolved));
+    }
+    groups
+        .into_iter()
+        .map(|(group, mut packages)| {
+            packages.sort_by_key(|&(later, resolved)| {
+                (later, &resolved.package.name, &resolved.package.version)
+            });
+            let packages = packages.into_iter().map(|(_, resolved)| resolved).collect();
+            (group, packages)
+        })
+        .collect()
+}
+
+/// The groups of `list --by license-family` and `--by source` as a JSON object
+/// from each group to its packages, in the same order as the text output
+struct Groups(Vec<(String, Vec<Entry>)>);
+
+impl Serialize for Groups {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(group, entries)| (group, entries)))
+    }
+}
+
+#[derive(Serialize)]
+struct GroupedInventory {
+    groups: Groups,
+    summary: Summary,
+}
+
+#[derive(Serialize)]
+struct RootGroups {
+    root: String,
+    #[serde(flatten)]
+    inventory: GroupedInventory,
+}
+
+impl GroupedInventory {
+    fn collect(resolved: &[ResolvedPackage], by: By, yanked: &HashSet<&PackageId>) -> Self {
+        let groups = groups(resolved, by, |resolved| resolved.license.license.clone())
+            .into_iter()
+            .map(|(group, packages)| {
+                let entries = packages
+                    .iter()
+                    .map(|resolved| Entry::of(resolved, yanked.contains(&resolved.package.id)))
+                    .collect();
+                (group.to_string(), entries)
+            })
+            .collect();
+        GroupedInventory {
+            groups: Groups(groups),
+            summary: Summary::of(resolved),
+        }
+    }
+}
+
+/// Writes the groups of `--by license-family` or `--by source` as JSON, an
+/// array with each root's groups when listed per root
+pub fn write_groups(
+    sections: &[(Option<&Package>, Vec<ResolvedPackage>)],
+    by: By,
+    yanked: &HashSet<&PackageId>,
+    out: &mut dyn io::Write,
+) -> anyhow::Result<()> {
+    match sections {
+        [(None, resolved)] => serde_json::to_writer_pretty(
+            &mut *out,
+            &GroupedInventory::collect(resolved, by, yanked),
+        )?,
+        sections => {
+            let roots = sections
+                .iter()
+                .map(|(root, resolved)| {
+                    let root = root.expect("per root");
+                    RootGroups {
+                        root: format!("{} {}", root.name, root.version),
+                        inventory: GroupedInventory::collect(resolved, by, yanked),
+                    }
+                })
+                .collect::<Vec<_>>();
+            serde_json::to_writer_pretty(&mut *out, &roots)?
+        }
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+/// The license each package offering a choice of licenses is elected to be
+/// used under
+pub fn elect<'a>(
+    resolved: &[ResolvedPackage<'a>],
+    election: &Election,
+) -> HashMap<&'a PackageId, License> {
+    resolved
+        .iter()
+        .filter_map(|resolved| {
+            let package = resolved.package;
+            Some((
+                &package.id,
+                election.elect(package, &resolved.license.license)?,
+            ))
+        })
+        .collect()
+}
+
+pub fn run(
+    resolved: &[ResolvedPackage],
+    options: &ListOptions,
+    yanked: &HashSet<&PackageId>,
+    elected: &HashMap<&PackageId, License>,
+) -> anyhow::Result<()> {
+    let by = options.by;
+    let license_filter = options.license_filter.as_ref();
+    // Packages are listed under the license they're used under
+    let license = |resolved: &ResolvedPackage| match elected.get(&resolved.package.id) {
+        Some(elected) => elected.clone(),
+        None => resolved.license.license.clone(),
+    };
+    let resolved = &resolved
+        .iter()
+        .filter(|resolved| license_filter.is_none_or(|filter| filter.matches(&license(resolved))))
+        .cloned()
+        .collect::<Vec<_>>();
+    let label = |resolved: &ResolvedPackage| {
+        let mut label = if resolved.root() {
+            " (workspace root)".to_owned()
+        } else {
+            String::new()
+        };
+        // Grouped by source the label only adds the other sources' copies
+        if by != By::Source || !resolved.duplicates.is_empty() {
+            label += &resolved.label();
+        }
+        label += resolved.usage.label();
+        if let Some(SourceHeaders::Consistent(..)) = resolved.license.headers {
+            label.push_str(" (inferred from source headers)");
+        }
+        if elected.contains_key(&resolved.package.id) {
+            label.push_str(&format!(" (elected from {})", resolved.license.license));
+        }
+        if yanked.contains(&resolved.package.id) {
+            label.push_str(" [YANKED]");
+        }
+        if options.verbose && !resolved.features.is_empty() {
+            label.push_str(&format!(
+                " (via feature{} {})",
+                if resolved.features.len() == 1 {
+                    ""
+                } else {
+                    "s"
+                },
+                resolved
+                    .features
+                    .iter()
+                    .map(|feature| format!("{:?}", feature))
+                    .join(", ")
+            ));
+        }
+        label
+    };
+
+    // Crates in use in several versions are told apart by their versions,
+    // which can be under different licenses
+    let mut versions = HashMap::<&str, HashSet<_>>::new();
+    for resolved in resolved {
+        versions
+            .entry(&resolved.package.name)
+            .or_default()
+            .insert(&resolved.package.version);
+    }
+    let name = |resolved: &ResolvedPackage| {
+        let package = resolved.package;
+        if versions[package.name.as_str()].len() > 1 {
+            format!("{} {}", package.name, package.version)
+        } else {
+            package.name.clone()
+        }
+    };
+
+    if options.obligations {
+        let mut license_to_count = BTreeMap::new();
+        for resolved in resolved {
+            *license_to_count.entry(license(resolved)).or_insert(0) += 1;
+        }
+        for (license, count) in license_to_count {
+            let obligations = match license.obligations() {
+                Some(obligations) => obligations.describe().join(", "),
+                None => "unknown obligations".to_owned(),
+            };
+            println!(
+                "{} ({}): {}",
+                output::license(&license, 0),
+                count,
+                obligations
+            );
+        }
+        return Ok(());
+    }
 
-pub fn run(packages: &[&Package], by: By) -> anyhow::Result<()> {
     match by {
+        By::LicenseFamily | By::Source => {
+            for (group, packages) in groups(resolved, by, license) {
+                if options.summary {
+                    println!("{}: {}", group, packages.len());
+                } else {
+                    let names = packages
+                        .iter()
+                        .map(|resolved| {
+                            format!(
+                                "{} ({}){}",
+                                name(resolved),
+                                output::license(&license(resolved), 0),
+                                label(resolved)
+                            )
+                        })
+                        .join(", ");
+                    println!("{} ({}): {}", group, packages.len(), names);
+                }
+            }
+        }
         By::License => {
             let mut license_to_packages = HashMap::new();
 
-            for package in packages {
+            for resolved in resolved {
                 license_to_packages
-                    .entry(package.license())
+                    .entry(license(resolved))
                     .or_insert_with(Vec::new)
-                    .push(package);
+                    .push(resolved);
             }
 
+            // Without a filter the licenses are grouped by family, with totals
+            let families = license_filter.is_none();
+            let mut family_totals = BTreeMap::<Family, (usize, usize)>::new();
+            for (license, packages) in &license_to_packages {
+                let totals = family_totals.entry(license.family()).or_default();
+                totals.0 += packages.len();
+                totals.1 += 1;
+            }
+            let mut current = None;
+
             license_to_packages
                 .iter()
-                .sorted_by_key(|&(license, _)| license)
+                .sorted_by_key(|&(license, _)| (families.then(|| license.family()), license))
                 .for_each(|(license, packages)| {
-                    let packages = packages
-                        .iter()
-                        .map(|package| &package.name)
-                        .sorted()
-                        .join(", ");
-                    println!("{}: {}", license, packages);
+                    let family = license.family();
+                    if families && current != Some(family) {
+                        let (packages, licenses) = family_totals[&family];
+                        println!("{}", family_header(family, packages, licenses));
+                        current = Some(family);
+                    }
+                    if options.summary {
+                        println!("{}: {}", output::license(license, 0), packages.len());
+                    } else {
+                        let names = packages
+                            .iter()
+                            .sorted_by_key(|resolved| {
+                                (&resolved.package.name, &resolved.package.version)
+                            })
+                            .map(|resolved| format!("{}{}", name(resolved), label(resolved)))
+                            .join(", ");
+                        println!(
+                            "{} ({}): {}",
+                            output::license(license, 0),
+                            packages.len(),
+                            names
+                        );
+                    }
                 })
         }
         By::Crate => {
-            let packages = {
-                let mut packages = packages.to_owned();
-                packages.sort_by_key(|package| &package.name);
-                packages
+            let resolved = {
+                let mut resolved = resolved.to_owned();
+                resolved
+                    .sort_by_key(|resolved| (&resolved.package.name, &resolved.package.version));
+                resolved
             };
-            for package in packages {
-                println!("{}: {}", package.name, package.license());
+            let licenses = resolved.iter().map(license).collect::<Vec<_>>();
+            // Align the license column, and the details after it
+            let name_width = resolved
+                .iter()
+                .map(|resolved| name(resolved).chars().count() + 1)
+                .max()
+                .unwrap_or_default();
+            let license_width = licenses
+                .iter()
+                .map(|license| license.to_string().chars().count())
+                .max()
+                .unwrap_or_default();
+            for (resolved, license) in resolved.iter().zip(&licenses) {
+                let label = label(resolved);
+                let details = if resolved.direct() {
+                    format!("(direct){}", label)
+                } else if let Some(parent) = resolved.parent {
+                    format!("(transitive, via {}){}", parent.name, label)
+                } else {
+                    label.trim_start().to_owned()
+                };
+                let line = format!(
+                    "{:<width$} {} {}",
+                    format!("{}:", name(resolved)),
+                    output::license(license, license_width),
+                    details,
+                    width = name_width
+                );
+                println!("{}", line.trim_end());
             }
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::licensed::LicensedCache;
+    use crate::load;
+    use crate::options::Filter;
+    use crate::query::MetadataIndex;
+    use crate::testutil::{self, package, path_package};
+
+    fn names<'a>(groups: &[(Group, Vec<&ResolvedPackage<'a>>)]) -> Vec<(String, Vec<&'a str>)> {
+        groups
+            .iter()
+            .map(|(group, packages)| {
+                let names = packages
+                    .iter()
+                    .map(|resolved| resolved.package.name.as_str())
+                    .collect();
+                (group.to_string(), names)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn families_and_sources() {
+        let dir = Path::new("/nonexistent");
+        let mut forked = testutil::sourced_package(
+            "forked",
+            "1.0.0",
+            "git+https://github.com/someone/forked?branch=fix#0123abcd",
+        );
+        forked.license = Some("GPL-3.0-only".to_owned());
+        let packages = vec![
+            path_package("app", "0.1.0", Some("MIT"), dir),
+            package("serde", "1.0.0", Some("MIT OR Apache-2.0"), dir),
+            forked,
+            path_package("helper", "0.1.0", Some("MPL-2.0"), &dir.join("helper")),
+            package("zzz-mystery", "1.0.0", None, dir),
+            package("custom", "1.0.0", Some("Foo-1.0"), dir),
+        ];
+        let deps = (1..packages.len())
+            .map(|i| (0, i, None))
+            .collect::<Vec<_>>();
+        let metadata = testutil::metadata(&packages, &deps);
+        let index = MetadataIndex::new(&metadata);
+        let licenses = LicensedCache::new(false, false);
+        let resolved =
+            load::resolve_packages(&index, &[&packages[0]], &Filter::default(), &licenses).unwrap();
+        let license = |resolved: &ResolvedPackage| resolved.license.license.clone();
+
+        assert_eq!(
+            names(&groups(&resolved, By::LicenseFamily, license)),
+            [
+                ("permissive".to_owned(), vec!["app", "serde"]),
+                ("weak copyleft".to_owned(), vec!["helper"]),
+                ("strong copyleft".to_owned(), vec!["forked"]),
+                // Missing licenses first
+                ("unknown".to_owned(), vec!["zzz-mystery", "custom"]),
+            ]
+        );
+        assert_eq!(
+            names(&groups(&resolved, By::Source, license)),
+            [
+                (
+                    "crates.io".to_owned(),
+                    vec!["custom", "serde", "zzz-mystery"]
+                ),
+                ("internal".to_owned(), vec!["app", "helper"]),
+                (
+                    "git https://github.com/someone/forked".to_owned(),
+                    vec!["forked"]
+                ),
+            ]
+        );
+
+        let mut out = Vec::new();
+        write_groups(
+            &[(None, resolved.clone())],
+            By::LicenseFamily,
+            &HashSet::new(),
+            &mut out,
+        )
+        .unwrap();
+        let json = String::from_utf8(out).unwrap();
+        let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+        assert_eq!(value["groups"]["unknown"][0]["name"], "zzz-mystery");
+        assert_eq!(
+            value["groups"]["unknown"][0]["license"],
+            "No license specified"
+        );
+        assert_eq!(value["groups"]["permissive"][1]["name"], "serde");
+        assert_eq!(value["summary"]["packages"], 6);
+        // Groups are in the same order as the text output
+        assert!(json.find("\"permissive\"") < json.find("\"unknown\""));
+
+        let mut out = Vec::new();
+        write_groups(
+            &[(Some(&packages[0]), resolved)],
+            By::Source,
+            &HashSet::new(),
+            &mut out,
+        )
+        .unwrap();
+        let value = serde_json::from_slice::<serde_json::Value>(&out).unwrap();
+        assert_eq!(value[0]["root"], "app 0.1.0");
+        assert_eq!(
+            value[0]["groups"]["git https://github.com/someone/forked"][0]["name"],
+            "forked"
+        );
+    }
+}
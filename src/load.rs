@@ -1,28 +1,407 @@
-use std::collections::HashSet;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
 
 use anyhow::anyhow;
-use cargo_metadata::{DependencyKind, Metadata, Package};
+use cargo_metadata::{DependencyKind, Metadata, Package, PackageId};
+use regex::Regex;
 use serde::Deserialize;
 
-use crate::options::SelectedPackage;
-use crate::query::{PackagesExt, ResolveExt};
+use crate::license::License;
+use crate::licensed::{LicensedCache, ResolvedLicense};
+use crate::options::{DepSpec, Filter, PackageIdSpec, SelectedPackage, SourceFilter};
+use crate::query::{MetadataIndex, PackagesExt};
+use crate::yanked::cargo_home;
 
-#[derive(Deserialize)]
+#[derive(Clone, Debug)]
+pub struct ResolvedPackage<'a> {
+    pub package: &'a Package,
+    /// Length of the shortest dependency path from a root, roots themselves
+    /// have depth 0 and their direct dependencies depth 1
+    pub depth: u32,
+    /// The previous package on one of the shortest paths from a root
+    pub parent: Option<&'a Package>,
+    pub source: SourceKind,
+    /// The features of `parent` that enabled this as an optional dependency,
+    /// empty if it isn't optional
+    pub features: Vec<String>,
+    /// The same package from other sources, e.g. when patched with a git fork
+    /// both copies can be in the graph
+    pub duplicates: Vec<&'a Package>,
+    pub usage: Usage,
+    pub license: Arc<ResolvedLicense>,
+}
+
+/// Whether a package ends up in what the roots build or is only used while
+/// compiling them
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Usage {
+    Runtime,
+    /// Only reached through proc-macro crates, or is one itself
+    ProcMacro,
+}
+
+impl Usage {
+    /// A short suffix for labelling packages that aren't distributed
+    pub fn label(self) -> &'static str {
+        match self {
+            Usage::Runtime => "",
+            Usage::ProcMacro => " [proc-macro]",
+        }
+    }
+}
+
+/// Where a package comes from, used to separate third party dependencies from
+/// internal crates in reports
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum SourceKind {
+    CratesIo,
+    /// Path or workspace dependencies, and anything marked `publish = false`
+    Internal,
+    /// Any registry other than crates.io, identified by its index URL
+    Registry(String),
+    /// A git dependency, identified by its repository URL
+    Git(String),
+}
+
+impl SourceKind {
+    pub fn of(package: &Package) -> SourceKind {
+        let source = match &package.source {
+            Some(source) => source.to_string(),
+            None => return SourceKind::Internal,
+        };
+        if package.publish.as_ref().is_some_and(Vec::is_empty) {
+            return SourceKind::Internal;
+        }
+        match source.split_once('+') {
+            Some(("git", url)) => {
+                // Drop the `?branch=...` and `#commit` parts, they're not part
+                // of the repository URL
+                let end = url.find(['?', '#']).unwrap_or(url.len());
+                SourceKind::Git(url[..end].to_owned())
+            }
+            Some(("registry" | "sparse", _))
+                if package.source.as_ref().is_some_and(|s| s.is_crates_io()) =>
+            {
+                SourceKind::CratesIo
+            }
+            Some(("registry" | "sparse", url)) => SourceKind::Registry(registry_url(url)),
+            Some(("path", _)) => SourceKind::Internal,
+            _ => SourceKind::Registry(registry_url(&source)),
+        }
+    }
+
+    /// A short suffix for labelling packages that don't come from crates.io
+    pub fn label(&self) -> String {
+        match self {
+            SourceKind::CratesIo => String::new(),
+            kind => format!(" [{}]", kind),
+        }
+    }
+}
+
+impl std::fmt::Display for SourceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SourceKind::CratesIo => write!(f, "crates.io"),
+            SourceKind::Internal => write!(f, "internal"),
+            SourceKind::Registry(url) => write!(f, "registry {}", url),
+            SourceKind::Git(url) => write!(f, "git {}", url),
+        }
+    }
+}
+
+impl SourceFilter {
+    pub fn matches(&self, kind: &SourceKind) -> bool {
+        match (self, kind) {
+            (SourceFilter::All, _) => true,
+            (SourceFilter::CratesIo, SourceKind::CratesIo) => true,
+            (SourceFilter::Internal, SourceKind::Internal) => true,
+            (SourceFilter::Registry(index), SourceKind::Registry(url)) => index == url,
+            (SourceFilter::Git(None), SourceKind::Git(_)) => true,
+            (SourceFilter::Git(Some(repository)), SourceKind::Git(url)) => {
+                repository.trim_end_matches('/') == url.trim_end_matches('/')
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A registry index URL as it's compared between sources and filters, the
+/// `sparse+` protocol prefix and any trailing slash aren't significant
+pub fn registry_url(url: &str) -> String {
+    let url = url.strip_prefix("sparse+").unwrap_or(url);
+    url.trim_end_matches('/').to_owned()
+}
+
+/// The index URL of a registry named in cargo's configuration, either
+/// through a `CARGO_REGISTRIES_<NAME>_INDEX` variable or a `[registries]`
+/// table in a `.cargo/config.toml` of the current directory, one of its
+/// parents or cargo's home
+pub fn registry_index(name: &str) -> Option<String> {
+    let var = format!(
+        "CARGO_REGISTRIES_{}_INDEX",
+        name.to_uppercase().replace('-', "_")
+    );
+    if let Some(index) = env::var_os(var) {
+        return Some(registry_url(&index.to_string_lossy()));
+    }
+
+    let cwd = env::current_dir().ok()?;
+    let dirs = cwd
+        .ancestors()
+        .map(|dir| dir.join(".cargo"))
+        .chain(cargo_home())
+        .collect::<Vec<_>>();
+    dirs.iter()
+        .flat_map(|dir| vec![dir.join("config.toml"), dir.join("config")])
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|config| config.parse::<toml::Value>().ok())
+        .find_map(|config| {
+            let index = config.get("registries")?.get(name)?.get("index")?;
+            Some(registry_url(index.as_str()?))
+        })
+}
+
+impl<'a> ResolvedPackage<'a> {
+    pub fn direct(&self) -> bool {
+        self.depth == 1
+    }
+
+    /// Whether this is one of the selected packages rather than a dependency
+    pub fn root(&self) -> bool {
+        self.depth == 0
+    }
+
+    /// A short suffix for labelling packages that don't come from crates.io,
+    /// or that come from multiple sources
+    pub fn label(&self) -> String {
+        if self.duplicates.is_empty() {
+            return self.source.label();
+        }
+        let sources = Some(&self.source)
+            .into_iter()
+            .cloned()
+            .chain(
+                self.duplicates
+                    .iter()
+                    .map(|package| SourceKind::of(package)),
+            )
+            .map(|source| source.to_string())
+            .collect::<Vec<_>>();
+        format!(" [{}]", sources.join(", "))
+    }
+}
+
+/// The parts of `[workspace]` we need, anything else like `[workspace.package]`
+/// or `[workspace.dependencies]` is ignored
+#[derive(Default, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 struct Workspace {
     default_members: Option<Vec<String>>,
+    #[serde(default)]
+    exclude: Vec<String>,
 }
 
 #[derive(Deserialize)]
 #[serde(rename_all = "kebab-case")]
 struct Manifest {
+    #[serde(default)]
     workspace: Workspace,
 }
 
+/// Matches `name` against a pattern where `*` matches any run of characters
+/// and `?` any single character
+pub fn glob_matches(pattern: &str, name: &str) -> bool {
+    let mut regex = String::from("^");
+    for c in pattern.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    Regex::new(&regex).is_ok_and(|regex| regex.is_match(name))
+}
+
+/// The url identifying where a package came from, as used in package id specs
+fn source_url(package: &Package) -> String {
+    match &package.source {
+        Some(source) => {
+            let source = source.to_string();
+            let source = source.split_once('+').map_or(&*source, |(_, url)| url);
+            let end = source.find(['?', '#']).unwrap_or(source.len());
+            source[..end].to_owned()
+        }
+        None => format!(
+            "file://{}",
+            package
+                .manifest_path
+                .parent()
+                .unwrap_or(Path::new(""))
+                .display()
+        ),
+    }
+}
+
+impl PackageIdSpec {
+    pub fn matches(&self, package: &Package) -> bool {
+        if let Some(name) = &self.name {
+            if *name != package.name {
+                return false;
+            }
+        }
+        if let Some(version) = &self.version {
+            let actual = &package.version;
+            let parts = version.split('.').collect::<Vec<_>>();
+            let matches = if parts.len() >= 3 {
+                *version == actual.to_string()
+            } else {
+                let expected = [actual.major, actual.minor];
+                parts
+                    .iter()
+                    .zip(&expected)
+                    .all(|(part, expected)| part.parse::<u64>().ok() == Some(*expected))
+            };
+            if !matches {
+                return false;
+            }
+        }
+        if let Some(url) = &self.url {
+            let url = url.split_once('+').map_or(&**url, |(_, url)| url);
+            if url.trim_end_matches('/') != source_url(package).trim_end_matches('/') {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl DepSpec {
+    pub fn matches(&self, package: &Package) -> bool {
+        glob_matches(&self.name, &package.name)
+            && self
+                .version
+                .as_ref()
+                .is_none_or(|version| *version == package.version.to_string())
+    }
+}
+
+/// Reads the output of `cargo metadata --format-version 1` saved to a file
+pub fn read_metadata(path: &str) -> anyhow::Result<Metadata> {
+    let bytes =
+        fs::read(path).map_err(|err| anyhow!("Couldn't read metadata file {}: {}", path, err))?;
+    let value: serde_json::Value = serde_json::from_slice(&bytes)
+        .map_err(|err| anyhow!("Couldn't parse metadata file {}: {}", path, err))?;
+    match value.get("version").and_then(serde_json::Value::as_u64) {
+        Some(1) => (),
+        Some(version) => {
+            return Err(anyhow!(
+                "Metadata file {} has unsupported format version {}, generate it with \
+                 `cargo metadata --format-version 1`",
+                path,
+                version
+            ))
+        }
+        None => {
+            return Err(anyhow!(
+                "Metadata file {} has no format version, is it the output of `cargo metadata`?",
+                path
+            ))
+        }
+    }
+    let metadata: Metadata = serde_json::from_value(value)
+        .map_err(|err| anyhow!("Couldn't parse metadata file {}: {}", path, err))?;
+    if metadata.resolve.is_none() {
+        return Err(anyhow!(
+            "Metadata file {} has no dependency resolve graph, generate it without `--no-deps`",
+            path
+        ));
+    }
+    let missing = metadata
+        .packages
+        .iter()
+        .filter(|package| !package.manifest_path.is_file())
+        .count();
+    if missing > 0 {
+        log::info!(
+            "{} packages in {} have no manifest at their manifest_path, their sources are not \
+             available for license text discovery",
+            missing,
+            path
+        );
+    }
+    Ok(metadata)
+}
+
+/// Normalizes the Windows paths of packages in metadata that a build system
+/// rewrote or that came from another machine, see `normalize_windows_path`
+pub fn normalize_paths(metadata: &mut Metadata) {
+    if !cfg!(windows) {
+        return;
+    }
+    let normalize = |path: &mut PathBuf| {
+        if let Some(normalized) = path.to_str().map(normalize_windows_path) {
+            *path = PathBuf::from(normalized);
+        }
+    };
+    normalize(&mut metadata.workspace_root);
+    for package in &mut metadata.packages {
+        normalize(&mut package.manifest_path);
+    }
+}
+
+/// Verbatim paths (`\\?\C:\...`) turn off the parsing of `/` as a separator,
+/// so relative paths written with forward slashes can't be joined onto them.
+/// Strips the prefix from verbatim drive and UNC paths and uses `\` as the
+/// separator throughout, anything not rooted at a drive or share is kept.
+fn normalize_windows_path(path: &str) -> String {
+    fn drive(path: &str) -> bool {
+        let bytes = path.as_bytes();
+        bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+    }
+
+    let path = if let Some(share) = path.strip_prefix(r"\\?\UNC\") {
+        format!(r"\\{}", share)
+    } else if let Some(rest) = path.strip_prefix(r"\\?\").filter(|rest| drive(rest)) {
+        rest.to_owned()
+    } else if drive(path) || path.starts_with(r"\\") && !path.starts_with(r"\\?\") {
+        path.to_owned()
+    } else {
+        return path.to_owned();
+    };
+    path.replace('/', r"\")
+}
+
 pub fn resolve_roots<'a>(
     metadata: &'a Metadata,
     package: SelectedPackage,
+    filter: &Filter,
 ) -> anyhow::Result<Vec<&'a Package>> {
+    let roots = select_roots(metadata, package)?;
+    let (excluded, roots): (Vec<&Package>, Vec<&Package>) = roots.into_iter().partition(|root| {
+        filter
+            .exclude
+            .iter()
+            .any(|pattern| glob_matches(pattern, &root.name))
+            || filter.exclude_spec.iter().any(|spec| spec.matches(root))
+    });
+    for root in excluded {
+        log::info!("Excluding package {} from analysis", root.name);
+    }
+    if roots.is_empty() {
+        return Err(anyhow!("All selected packages were excluded"));
+    }
+    Ok(roots)
+}
+
+fn select_roots(metadata: &Metadata, package: SelectedPackage) -> anyhow::Result<Vec<&Package>> {
     match package {
         SelectedPackage::All => metadata
             .workspace_members
@@ -37,72 +416,1423 @@ pub fn resolve_roots<'a>(
                 .as_ref()
                 .ok_or_else(|| anyhow!("Couldn't load resolve graph"))?;
             if let Some(root) = &resolve.root {
-                Ok(vec![metadata.packages.by_id(root)?])
-            } else {
-                let manifest: Manifest = toml::from_slice(&std::fs::read({
-                    let mut path = metadata.workspace_root.clone();
-                    path.push("Cargo.toml");
-                    path
-                })?)?;
-                if let Some(default_members) = manifest.workspace.default_members {
-                    default_members
-                        .iter()
-                        .map(|name| {
-                            metadata
-                                .workspace_members
-                                .iter()
-                                .filter_map(|id| metadata.packages.by_id(id).ok())
-                                .find(|p| &p.name == name)
-                                .ok_or_else(|| anyhow!("Couldn't find workspace member {}", name))
-                        })
-                        .collect()
-                } else {
-                    metadata
-                        .workspace_members
-                        .iter()
-                        .map(|id| metadata.packages.by_id(id))
-                        .collect()
+                return Ok(vec![metadata.packages.by_id(root)?]);
+            }
+            let path = metadata.workspace_root.join("Cargo.toml");
+            // Saved metadata may come from a checkout that isn't available
+            match fs::read(&path) {
+                Ok(bytes) => {
+                    let manifest: Manifest = toml::from_slice(&bytes)?;
+                    if let Some(patterns) = &manifest.workspace.default_members {
+                        return select_default_members(metadata, &manifest.workspace, patterns);
+                    }
                 }
+                Err(err) => log::warn!(
+                    "Couldn't read workspace manifest {}, using all workspace members: {}",
+                    path.display(),
+                    err
+                ),
             }
+            metadata
+                .workspace_members
+                .iter()
+                .map(|id| metadata.packages.by_id(id))
+                .collect()
+        }
+        SelectedPackage::Specific(specs) => {
+            let mut roots = Vec::new();
+            for spec in specs {
+                let matching = metadata
+                    .packages
+                    .iter()
+                    .filter(|package| spec.matches(package))
+                    .collect::<Vec<_>>();
+                match matching.as_slice() {
+                    [] => {
+                        return Err(anyhow!(
+                            "Package id specification `{}` did not match any packages",
+                            spec
+                        ))
+                    }
+                    [package] => {
+                        if !roots.iter().any(|root: &&Package| root.id == package.id) {
+                            roots.push(*package);
+                        }
+                    }
+                    candidates => {
+                        return Err(anyhow!(
+                        "Package id specification `{}` is ambiguous, it could refer to any of: {}",
+                        spec,
+                        candidates
+                            .iter()
+                            .map(|package| package.id.repr.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                    }
+                }
+            }
+            Ok(roots)
         }
-        SelectedPackage::Specific(name) => Ok(vec![metadata
-            .packages
-            .iter()
-            .find(|p| p.name == name)
-            .ok_or_else(|| anyhow!("Could not find package {}", name))?]),
     }
 }
 
-pub fn resolve_packages<'a>(
+/// Splits a path from the workspace manifest into its components, dropping
+/// `.` and empty components so `./crates/foo/` is the same as `crates/foo`
+fn path_components(path: &str) -> Vec<&str> {
+    path.split(['/', '\\'])
+        .filter(|component| !component.is_empty() && *component != ".")
+        .collect()
+}
+
+/// Matches path components against a pattern's like cargo's globbing does,
+/// `**` matches any number of components and other pattern components are
+/// matched against a single component with [`glob_matches`]
+fn components_match(pattern: &[&str], path: &[&str]) -> bool {
+    match (pattern.split_first(), path.split_first()) {
+        (None, None) => true,
+        (Some((&"**", rest)), _) => {
+            components_match(rest, path)
+                || path
+                    .split_first()
+                    .is_some_and(|(_, path)| components_match(pattern, path))
+        }
+        (Some((pattern, rest)), Some((component, path))) => {
+            glob_matches(pattern, component) && components_match(rest, path)
+        }
+        _ => false,
+    }
+}
+
+/// Selects the workspace members in the directories `default-members` lists,
+/// which are paths relative to the workspace root and may be globs, skipping
+/// any under a path in `exclude`
+fn select_default_members<'a>(
     metadata: &'a Metadata,
-    roots: &'a [&'a Package],
+    workspace: &Workspace,
+    patterns: &[String],
 ) -> anyhow::Result<Vec<&'a Package>> {
-    let mut result = Vec::new();
-    let mut added = HashSet::new();
+    let excluded = workspace
+        .exclude
+        .iter()
+        .map(|path| path_components(path))
+        .collect::<Vec<_>>();
+    let is_excluded = |path: &[&str]| excluded.iter().any(|prefix| path.starts_with(prefix));
+    let mut members = Vec::new();
+    for id in &metadata.workspace_members {
+        let package = metadata.packages.by_id(id)?;
+        let dir = package
+            .manifest_path
+            .parent()
+            .and_then(|dir| dir.strip_prefix(&metadata.workspace_root).ok());
+        match dir {
+            Some(dir) => members.push((
+                package,
+                dir.components()
+                    .filter_map(|component| component.as_os_str().to_str())
+                    .collect::<Vec<_>>(),
+            )),
+            None => log::debug!(
+                "Workspace member {} is outside the workspace root {}",
+                package.name,
+                metadata.workspace_root.display()
+            ),
+        }
+    }
+
+    let mut roots: Vec<&Package> = Vec::new();
+    for pattern in patterns {
+        let components = path_components(pattern);
+        let mut matched = false;
+        for (package, dir) in &members {
+            if !components_match(&components, dir) {
+                continue;
+            }
+            matched = true;
+            if is_excluded(dir) {
+                log::info!(
+                    "Skipping default member {} as it is excluded from the workspace",
+                    package.name
+                );
+            } else if !roots.iter().any(|root| root.id == package.id) {
+                roots.push(package);
+            }
+        }
+        let glob = pattern.contains(['*', '?']);
+        if !matched && !glob && !is_excluded(&components) {
+            return Err(anyhow!(
+                "Couldn't find a workspace member at {} from workspace.default-members",
+                pattern
+            ));
+        }
+    }
+    if roots.is_empty() {
+        return Err(anyhow!(
+            "workspace.default-members [{}] didn't match any workspace members",
+            patterns.join(", ")
+        ));
+    }
+    Ok(roots)
+}
+
+pub fn resolve_packages<'a>(
+    index: &MetadataIndex<'a>,
+    roots: &[&'a Package],
+    filter: &Filter,
+    licenses: &LicensedCache,
+) -> anyhow::Result<Vec<ResolvedPackage<'a>>> {
+    let graph = DepGraph::new(index, roots, filter)?;
+    let starts = roots
+        .iter()
+        .copied()
+        .zip(graph.reached(roots))
+        .collect::<Vec<_>>();
+    Ok(resolve(index, filter, licenses, &merge(None, &starts)))
+}
 
-    let mut to_check = roots.iter().map(|p| &p.id).collect::<Vec<_>>();
+/// Resolves the packages of each root on its own, the same as
+/// `resolve_packages` with just that root, walking the packages shared by
+/// several roots only once
+pub fn resolve_each<'a>(
+    index: &MetadataIndex<'a>,
+    roots: &[&'a Package],
+    filter: &Filter,
+    licenses: &LicensedCache,
+) -> anyhow::Result<Vec<Vec<ResolvedPackage<'a>>>> {
+    let graph = DepGraph::new(index, roots, filter)?;
+    Ok(graph
+        .reached(roots)
+        .iter()
+        .map(|reached| resolve(index, filter, licenses, reached))
+        .collect())
+}
 
-    let packages = &metadata.packages;
-    let resolve = metadata
-        .resolve
-        .as_ref()
-        .ok_or_else(|| anyhow!("Couldn't load resolve graph"))?;
+/// Resolves exactly the packages listed in `path`, or standard in for `-`, for
+/// when other build tooling decides what ends up in an artifact. Entries are
+/// `name@version` per line or a JSON array of them, and each has to match a
+/// package in the metadata. The same version from several sources is merged
+/// as in `resolve_packages`, and listed `roots` are kept as roots.
+pub fn resolve_explicit<'a>(
+    metadata: &'a Metadata,
+    roots: &[&Package],
+    path: &str,
+    licenses: &LicensedCache,
+) -> anyhow::Result<Vec<ResolvedPackage<'a>>> {
+    let text = if path == "-" {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(path)
+    }
+    .map_err(|err| anyhow!("Couldn't read package list {}: {}", path, err))?;
+    let entries = if text.trim_start().starts_with('[') {
+        serde_json::from_str::<Vec<String>>(&text)
+            .map_err(|err| anyhow!("Couldn't parse package list {}: {}", path, err))?
+    } else {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(ToOwned::to_owned)
+            .collect()
+    };
+    let mut resolved = Vec::new();
+    let mut seen = HashSet::new();
+    let mut unmatched = Vec::new();
+    for entry in entries {
+        let spec = entry
+            .parse::<PackageIdSpec>()
+            .map_err(|err| anyhow!("Invalid entry in package list {}: {}", path, err))?;
+        let mut matched = false;
+        for package in metadata
+            .packages
+            .iter()
+            .filter(|package| spec.matches(package))
+        {
+            matched = true;
+            if !seen.insert(&package.id) {
+                continue;
+            }
+            resolved.push(ResolvedPackage {
+                package,
+                depth: if roots.iter().any(|root| root.id == package.id) {
+                    0
+                } else {
+                    1
+                },
+                parent: None,
+                source: SourceKind::of(package),
+                features: Vec::new(),
+                duplicates: Vec::new(),
+                usage: Usage::Runtime,
+                license: licenses.resolve(package),
+            });
+        }
+        if !matched {
+            unmatched.push(entry);
+        }
+    }
+    if !unmatched.is_empty() {
+        return Err(anyhow!(
+            "No packages in the metadata match these entries of package list {}: {}",
+            path,
+            unmatched.join(", ")
+        ));
+    }
+    Ok(merge_duplicates(resolved))
+}
+
+/// The normal dependencies of the packages reachable from some roots, each
+/// with whether it's dropped by `--exclude-dep`
+pub struct DepGraph<'a> {
+    metadata: &'a Metadata,
+    deps: HashMap<&'a PackageId, Vec<(&'a Package, bool)>>,
+}
 
-    while let Some(id) = to_check.pop() {
-        if added.insert(id) {
-            let package = packages.by_id(&id)?;
-            result.push(package);
-            for dep in resolve.by_id(&id)? {
+impl<'a> DepGraph<'a> {
+    pub fn new(
+        index: &MetadataIndex<'a>,
+        roots: &[&'a Package],
+        filter: &Filter,
+    ) -> anyhow::Result<DepGraph<'a>> {
+        let mut deps = HashMap::new();
+        let mut logged = HashSet::new();
+        let mut to_check = roots.iter().map(|root| &root.id).collect::<Vec<_>>();
+        while let Some(id) = to_check.pop() {
+            if deps.contains_key(id) {
+                continue;
+            }
+            let mut normal = Vec::new();
+            for dep in index.deps(id)? {
                 if dep
                     .dep_kinds
                     .iter()
                     .any(|info| info.kind == DependencyKind::Normal)
                 {
-                    to_check.push(&dep.pkg);
+                    let dep_package = index.package(&dep.pkg)?;
+                    let excluded = filter
+                        .exclude_dep
+                        .iter()
+                        .any(|spec| spec.matches(dep_package));
+                    if excluded && logged.insert(&dep.pkg) {
+                        log::info!(
+                            "Excluding dependency {} {} from analysis",
+                            dep_package.name,
+                            dep_package.version
+                        );
+                    }
+                    // Only the roots' dependencies are looked at for direct
+                    // dependencies
+                    if !excluded && !filter.direct_only {
+                        to_check.push(&dep.pkg);
+                    }
+                    normal.push((dep_package, excluded));
                 }
             }
+            deps.insert(id, normal);
         }
+        Ok(DepGraph {
+            metadata: index.metadata(),
+            deps,
+        })
     }
 
-    Ok(result)
+    pub fn metadata(&self) -> &'a Metadata {
+        self.metadata
+    }
+
+    /// The dependencies of `id` that the filter follows, none for packages
+    /// whose dependencies aren't looked at
+    fn followed(&self, id: &PackageId) -> impl Iterator<Item = &'a Package> + '_ {
+        self.deps
+            .get(id)
+            .into_iter()
+            .flatten()
+            .filter(|(_, excluded)| !excluded)
+            .map(|(package, _)| *package)
+    }
+
+    /// The packages reachable from each of `roots`, in the order a breadth
+    /// first walk from it finds them. What's reachable from a package is
+    /// merged from what's reachable from its dependencies and memoized, so
+    /// packages shared by several roots are only walked once.
+    fn reached(&self, roots: &[&'a Package]) -> Vec<Rc<[Reached<'a>]>> {
+        fn of<'a>(
+            graph: &DepGraph<'a>,
+            package: &'a Package,
+            memo: &mut HashMap<&'a PackageId, Rc<[Reached<'a>]>>,
+        ) -> Rc<[Reached<'a>]> {
+            if let Some(reached) = memo.get(&package.id) {
+                return reached.clone();
+            }
+            // Normal dependencies can't form cycles, but don't loop if they do
+            memo.insert(&package.id, Rc::from(Vec::new()));
+            let deps = graph
+                .followed(&package.id)
+                .map(|dep| (dep, of(graph, dep, memo)))
+                .collect::<Vec<_>>();
+            let reached = Rc::from(merge(Some(package), &deps));
+            memo.insert(&package.id, Rc::clone(&reached));
+            reached
+        }
+
+        let mut memo = HashMap::new();
+        roots.iter().map(|root| of(self, root, &mut memo)).collect()
+    }
+
+    /// Finds the dependency paths from `root` to `target` that the filter
+    /// follows, each including both ends. Only one of the shortest paths
+    /// unless `all` is set, in which case up to `MAX_PATHS` of them are
+    /// returned, shortest first.
+    pub fn paths(&self, root: &'a Package, target: &Package, all: bool) -> Vec<Vec<&'a Package>> {
+        const MAX_PATHS: usize = 16;
+
+        /// How many paths lead from `id` to `target`, memoized so shared
+        /// subgraphs are only counted once
+        fn count<'a>(
+            graph: &DepGraph<'a>,
+            id: &'a PackageId,
+            target: &PackageId,
+            counts: &mut HashMap<&'a PackageId, usize>,
+        ) -> usize {
+            if id == target {
+                return 1;
+            }
+            if let Some(&count) = counts.get(id) {
+                return count;
+            }
+            // Normal dependencies can't form cycles, but don't loop if they do
+            counts.insert(id, 0);
+            let count = graph
+                .followed(id)
+                .map(|dep| count(graph, &dep.id, target, counts))
+                .fold(0, usize::saturating_add);
+            counts.insert(id, count);
+            count
+        }
+
+        /// Only steps towards packages with some path to `target`, so every
+        /// branch taken ends in a path
+        fn collect<'a>(
+            graph: &DepGraph<'a>,
+            target: &PackageId,
+            counts: &HashMap<&'a PackageId, usize>,
+            path: &mut Vec<&'a Package>,
+            paths: &mut Vec<Vec<&'a Package>>,
+        ) {
+            let package = *path.last().unwrap();
+            if package.id == *target {
+                paths.push(path.clone());
+                return;
+            }
+            for dep in graph.followed(&package.id) {
+                if paths.len() >= MAX_PATHS {
+                    break;
+                }
+                if dep.id == *target || counts.get(&dep.id).is_some_and(|&count| count > 0) {
+                    path.push(dep);
+                    collect(graph, target, counts, path, paths);
+                    path.pop();
+                }
+            }
+        }
+
+        if all {
+            let mut counts = HashMap::new();
+            count(self, &root.id, &target.id, &mut counts);
+            let mut paths = Vec::new();
+            collect(self, &target.id, &counts, &mut vec![root], &mut paths);
+            paths.sort_by_key(Vec::len);
+            return paths;
+        }
+
+        let mut parents = HashMap::new();
+        let mut to_check = VecDeque::from(vec![root]);
+        let mut found = Some(root).filter(|root| root.id == target.id);
+        while let Some(package) = to_check.pop_front() {
+            if found.is_some() {
+                break;
+            }
+            for dep in self.followed(&package.id) {
+                if dep.id != root.id && !parents.contains_key(&dep.id) {
+                    parents.insert(&dep.id, package);
+                    if dep.id == target.id {
+                        found = Some(dep);
+                        break;
+                    }
+                    to_check.push_back(dep);
+                }
+            }
+        }
+        let mut path = match found {
+            Some(target) => vec![target],
+            None => return Vec::new(),
+        };
+        while let Some(parent) = parents.get(&path.last().unwrap().id) {
+            path.push(parent);
+        }
+        path.reverse();
+        vec![path]
+    }
+}
+
+fn is_proc_macro(package: &Package) -> bool {
+    package
+        .targets
+        .iter()
+        .any(|target| target.kind.iter().any(|kind| kind == "proc-macro"))
+}
+
+/// A package found walking the graph from some start
+#[derive(Clone, Debug)]
+struct Reached<'a> {
+    package: &'a Package,
+    depth: u32,
+    parent: Option<&'a Package>,
+    /// Whether some path to it doesn't go through a proc-macro crate, which
+    /// makes it used at runtime
+    runtime: bool,
+}
+
+/// Merges what's reachable from each of `starts` level by level into what a
+/// breadth first walk from all of them at once finds. With `from` the starts
+/// are its dependencies, and it's reached first with everything else one
+/// level further down.
+fn merge<'a>(
+    from: Option<&'a Package>,
+    starts: &[(&'a Package, Rc<[Reached<'a>]>)],
+) -> Vec<Reached<'a>> {
+    let mut merged = Vec::new();
+    let mut positions = HashMap::new();
+    if let Some(from) = from {
+        positions.insert(&from.id, 0);
+        merged.push(Reached {
+            package: from,
+            depth: 0,
+            parent: None,
+            runtime: true,
+        });
+    }
+    let offset = u32::from(from.is_some());
+    let mut cursors = vec![0; starts.len()];
+    for depth in 0.. {
+        let mut found = false;
+        for ((start, reached), cursor) in starts.iter().zip(&mut cursors) {
+            // Roots themselves are runtime, their dependencies only if they
+            // aren't proc-macros
+            let through_runtime = from.is_none() || !is_proc_macro(start);
+            while let Some(next) = reached.get(*cursor).filter(|next| next.depth == depth) {
+                *cursor += 1;
+                found = true;
+                let runtime = next.runtime && through_runtime;
+                match positions.entry(&next.package.id) {
+                    // Already found on a path at most as long
+                    Entry::Occupied(position) => merged[*position.get()].runtime |= runtime,
+                    Entry::Vacant(position) => {
+                        position.insert(merged.len());
+                        merged.push(Reached {
+                            package: next.package,
+                            depth: depth + offset,
+                            parent: next.parent.or(from),
+                            runtime,
+                        });
+                    }
+                }
+            }
+        }
+        if !found {
+            break;
+        }
+    }
+    merged
+}
+
+fn resolve<'a>(
+    index: &MetadataIndex<'a>,
+    filter: &Filter,
+    licenses: &LicensedCache,
+    reached: &[Reached<'a>],
+) -> Vec<ResolvedPackage<'a>> {
+    let mut result = Vec::new();
+    // Only the roots' dependencies are looked at for direct dependencies,
+    // though a root can depend on another
+    let reached = reached
+        .iter()
+        .filter(|reached| !filter.direct_only || reached.depth <= 1);
+    for &Reached {
+        package,
+        depth,
+        parent,
+        runtime,
+    } in reached
+    {
+        let source = SourceKind::of(package);
+        let usage = if runtime {
+            Usage::Runtime
+        } else {
+            Usage::ProcMacro
+        };
+        if filter.distribution_only && usage != Usage::Runtime {
+            log::debug!(
+                "Skipping {} {}, it's only used at compile time",
+                package.name,
+                package.version
+            );
+        } else if filter.source.matches(&source) {
+            log::debug!(
+                "Adding {} {} at depth {}{}",
+                package.name,
+                package.version,
+                depth,
+                parent.map_or(" as a root".to_owned(), |parent: &Package| format!(
+                    " as a dependency of {}",
+                    parent.name
+                ))
+            );
+            let features = match parent {
+                Some(parent) => enabling_features(index, parent, package),
+                None => Vec::new(),
+            };
+            result.push(ResolvedPackage {
+                package,
+                depth,
+                parent,
+                source,
+                features,
+                duplicates: Vec::new(),
+                usage,
+                license: licenses.resolve(package),
+            });
+        } else {
+            log::debug!(
+                "Skipping {} {} from {}, only traversing its dependencies",
+                package.name,
+                package.version,
+                source
+            );
+        }
+    }
+    merge_duplicates(result)
+}
+
+/// Warns about roots without a license, compatibility with their dependencies
+/// can't be checked and a bundle can't say what they're under
+pub fn report_unlicensed_roots(roots: &[&Package], licenses: &LicensedCache) {
+    for root in roots {
+        if licenses.resolve(root).license == License::Unspecified {
+            log::warn!(
+                "{} has no license, add a `license` (or `license-file`) field to its Cargo.toml \
+                 so that dependencies can be checked against it",
+                root.name
+            );
+        }
+    }
+}
+
+/// Merges packages with the same name, version and license from different
+/// sources into the one closest to the roots, so they're reported once. If the
+/// licenses differ they're kept separate so both are checked.
+fn merge_duplicates(resolved: Vec<ResolvedPackage>) -> Vec<ResolvedPackage> {
+    let mut merged: Vec<ResolvedPackage> = Vec::new();
+    let mut seen = HashMap::new();
+    for resolved in resolved {
+        let package = resolved.package;
+        let key = (&package.name, &package.version);
+        if let Some(&index) = seen.get(&key) {
+            let primary: &mut ResolvedPackage = &mut merged[index];
+            if primary.license.license == resolved.license.license {
+                log::info!(
+                    "{} {} comes from both {} and {}, reporting it once",
+                    package.name,
+                    package.version,
+                    primary.source,
+                    resolved.source
+                );
+                primary.duplicates.push(package);
+                continue;
+            }
+            log::warn!(
+                "{} {} from {} has a different license than the same version from {}",
+                package.name,
+                package.version,
+                resolved.source,
+                primary.source
+            );
+        } else {
+            seen.insert(key, merged.len());
+        }
+        merged.push(resolved);
+    }
+    merged
+}
+
+/// The other copies of each package that has duplicates from other sources
+pub fn duplicates<'a>(
+    resolved: &[ResolvedPackage<'a>],
+) -> HashMap<&'a PackageId, Vec<&'a Package>> {
+    resolved
+        .iter()
+        .filter(|resolved| !resolved.duplicates.is_empty())
+        .map(|resolved| (&resolved.package.id, resolved.duplicates.clone()))
+        .collect()
+}
+
+/// Finds which of the features enabled on `parent` activate its optional
+/// dependency on `package`, either directly or through the implicit feature
+/// named after the dependency
+fn enabling_features(index: &MetadataIndex, parent: &Package, package: &Package) -> Vec<String> {
+    let keys = parent
+        .dependencies
+        .iter()
+        .filter(|dep| {
+            dep.optional && dep.kind == DependencyKind::Normal && dep.name == package.name
+        })
+        .map(|dep| dep.rename.as_deref().unwrap_or(&dep.name))
+        .collect::<Vec<_>>();
+    if keys.is_empty() {
+        return Vec::new();
+    }
+    let enabled = index.features(&parent.id);
+    let activates = |value: &String| {
+        // `dep?/feature` only enables a feature of the dependency if it's
+        // already enabled by something else
+        if value.contains("?/") {
+            return false;
+        }
+        let value = value.strip_prefix("dep:").unwrap_or(value);
+        let name = value.split('/').next().unwrap_or(value);
+        keys.contains(&name)
+    };
+    let (implicit, named): (Vec<_>, Vec<_>) = enabled
+        .iter()
+        .filter(|feature| {
+            keys.contains(&feature.as_str())
+                || parent
+                    .features
+                    .get(*feature)
+                    .is_some_and(|values| values.iter().any(activates))
+        })
+        .cloned()
+        .partition(|feature| keys.contains(&feature.as_str()));
+    // Enabling e.g. `dep/feature` also enables the implicit feature named
+    // after the dependency, which then says nothing about why it's there
+    if named.is_empty() {
+        implicit
+    } else {
+        named
+    }
+}
+
+pub fn packages<'a>(resolved: &[ResolvedPackage<'a>]) -> Vec<&'a Package> {
+    resolved.iter().map(|resolved| resolved.package).collect()
+}
+
+pub fn normal_deps<'a>(
+    index: &MetadataIndex<'a>,
+    id: &PackageId,
+) -> anyhow::Result<impl Iterator<Item = &'a PackageId>> {
+    Ok(index.deps(id)?.iter().filter_map(|dep| {
+        if dep
+            .dep_kinds
+            .iter()
+            .any(|info| info.kind == DependencyKind::Normal)
+        {
+            Some(&dep.pkg)
+        } else {
+            None
+        }
+    }))
+}
+
+/// Formats a dependency path as `a -> b -> c`
+pub fn format_path(path: &[&Package]) -> String {
+    path.iter()
+        .map(|package| package.name.as_str())
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::testutil::{self, package, path_package};
+
+    fn licenses() -> LicensedCache {
+        LicensedCache::new(false, false)
+    }
+
+    #[test]
+    fn windows_paths() {
+        let cases = [
+            (r"\\?\C:\work\app\Cargo.toml", r"C:\work\app\Cargo.toml"),
+            (
+                r"\\?\C:\work/vendor/dep\Cargo.toml",
+                r"C:\work\vendor\dep\Cargo.toml",
+            ),
+            (
+                r"\\?\UNC\server\share\app\Cargo.toml",
+                r"\\server\share\app\Cargo.toml",
+            ),
+            ("C:/work/app/Cargo.toml", r"C:\work\app\Cargo.toml"),
+            (
+                r"\\server\share/app/Cargo.toml",
+                r"\\server\share\app\Cargo.toml",
+            ),
+            (r"C:Cargo.toml", r"C:Cargo.toml"),
+            // Other verbatim paths can't be written without the prefix
+            (
+                r"\\?\Volume{0b1e}\app/Cargo.toml",
+                r"\\?\Volume{0b1e}\app/Cargo.toml",
+            ),
+            ("/work/app/Cargo.toml", "/work/app/Cargo.toml"),
+            ("Cargo.toml", "Cargo.toml"),
+        ];
+        for (path, expected) in &cases {
+            assert_eq!(normalize_windows_path(path), *expected, "{}", path);
+        }
+    }
+
+    #[test]
+    fn depths_are_the_shortest_path_over_a_diamond() {
+        let dir = Path::new("/nonexistent");
+        let names = ["app", "left", "right", "middle", "shared", "leaf"];
+        let packages = names
+            .iter()
+            .map(|name| package(name, "1.0.0", Some("MIT"), dir))
+            .collect::<Vec<_>>();
+        // app -> left -> shared -> leaf and app -> right -> middle -> shared
+        let deps = [
+            (0, 1, None),
+            (0, 2, None),
+            (1, 4, None),
+            (2, 3, None),
+            (3, 4, None),
+            (4, 5, None),
+        ];
+        let metadata = testutil::metadata(&packages, &deps);
+        let index = MetadataIndex::new(&metadata);
+        let roots = [&packages[0]];
+
+        let resolved = resolve_packages(&index, &roots, &Filter::default(), &licenses()).unwrap();
+        let mut depths = resolved
+            .iter()
+            .map(|resolved| {
+                (
+                    resolved.package.name.as_str(),
+                    resolved.depth,
+                    resolved.parent.map(|parent| parent.name.as_str()),
+                    resolved.direct(),
+                )
+            })
+            .collect::<Vec<_>>();
+        depths.sort();
+        assert_eq!(
+            depths,
+            [
+                ("app", 0, None, false),
+                ("leaf", 3, Some("shared"), false),
+                ("left", 1, Some("app"), true),
+                ("middle", 2, Some("right"), false),
+                ("right", 1, Some("app"), true),
+                ("shared", 2, Some("left"), false),
+            ]
+        );
+
+        let filter = Filter {
+            direct_only: true,
+            ..Filter::default()
+        };
+        let resolved = resolve_packages(&index, &roots, &filter, &licenses()).unwrap();
+        let mut names = resolved
+            .iter()
+            .map(|resolved| resolved.package.name.as_str())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["app", "left", "right"]);
+    }
+
+    #[test]
+    fn usage_is_runtime_if_any_path_is() {
+        let dir = Path::new("/nonexistent");
+        let names = ["app", "lib", "derive", "syn", "shared", "cc", "jobserver"];
+        let mut packages = names
+            .iter()
+            .map(|name| package(name, "1.0.0", Some("MIT"), dir))
+            .collect::<Vec<_>>();
+        packages[2].targets = vec![serde_json::from_value(serde_json::json!({
+            "name": "derive",
+            "kind": ["proc-macro"],
+            "src_path": "/nonexistent/src/lib.rs",
+        }))
+        .unwrap()];
+        // shared is used both at runtime through lib and by the derive macro,
+        // cc only as a build dependency so it isn't resolved at all
+        let deps = [
+            (0, 1, None),
+            (0, 2, None),
+            (2, 3, None),
+            (2, 4, None),
+            (1, 4, None),
+            (0, 5, Some("build")),
+            (5, 6, None),
+            (3, 5, Some("build")),
+        ];
+        let metadata = testutil::metadata(&packages, &deps);
+        let index = MetadataIndex::new(&metadata);
+        let roots = [&packages[0]];
+
+        let names = |filter: &Filter| {
+            let mut names = resolve_packages(&index, &roots, filter, &licenses())
+                .unwrap()
+                .into_iter()
+                .map(|resolved| (resolved.package.name.as_str(), resolved.usage))
+                .collect::<Vec<_>>();
+            names.sort_by_key(|&(name, _)| name);
+            names
+        };
+        assert_eq!(
+            names(&Filter::default()),
+            [
+                ("app", Usage::Runtime),
+                ("derive", Usage::ProcMacro),
+                ("lib", Usage::Runtime),
+                ("shared", Usage::Runtime),
+                ("syn", Usage::ProcMacro),
+            ]
+        );
+        let filter = Filter {
+            distribution_only: true,
+            ..Filter::default()
+        };
+        assert_eq!(
+            names(&filter),
+            [
+                ("app", Usage::Runtime),
+                ("lib", Usage::Runtime),
+                ("shared", Usage::Runtime),
+            ]
+        );
+    }
+
+    #[test]
+    fn sources() {
+        let dir = Path::new("/nonexistent");
+        let registry = "https://registry.example.com/index";
+        let mut unpublished = package("unpublished", "1.0.0", Some("MIT"), dir);
+        unpublished.publish = Some(Vec::new());
+        let packages = vec![
+            path_package("app", "1.0.0", Some("MIT"), dir),
+            package("public", "1.0.0", Some("MIT"), dir),
+            testutil::sourced_package(
+                "forked",
+                "1.0.0",
+                "git+https://github.com/someone/forked?branch=fix#0123abcd",
+            ),
+            testutil::sourced_package("private", "1.0.0", &format!("registry+{}", registry)),
+            unpublished,
+        ];
+        let kinds = packages.iter().map(SourceKind::of).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                SourceKind::Internal,
+                SourceKind::CratesIo,
+                SourceKind::Git("https://github.com/someone/forked".to_owned()),
+                SourceKind::Registry(registry.to_owned()),
+                SourceKind::Internal,
+            ]
+        );
+        assert_eq!(
+            kinds.iter().map(SourceKind::label).collect::<Vec<_>>(),
+            [
+                " [internal]".to_owned(),
+                String::new(),
+                " [git https://github.com/someone/forked]".to_owned(),
+                format!(" [registry {}]", registry),
+                " [internal]".to_owned(),
+            ]
+        );
+
+        // Packages from other sources are still traversed, so the private
+        // registry's dependencies are found through the internal root
+        let deps = [(0, 3, None), (3, 1, None), (0, 2, None), (2, 4, None)];
+        let metadata = testutil::metadata(&packages, &deps);
+        let index = MetadataIndex::new(&metadata);
+        let selected = |source: &str| {
+            let filter = Filter {
+                source: source.parse().unwrap(),
+                ..Filter::default()
+            };
+            let mut names = resolve_packages(&index, &[&packages[0]], &filter, &licenses())
+                .unwrap()
+                .iter()
+                .map(|resolved| resolved.package.name.as_str())
+                .collect::<Vec<_>>();
+            names.sort();
+            names
+        };
+        assert_eq!(selected("crates-io"), ["public"]);
+        assert_eq!(selected("internal"), ["app", "unpublished"]);
+        assert_eq!(selected(&format!("registry:{}", registry)), ["private"]);
+        assert_eq!(
+            selected(&format!("registry:sparse+{}/", registry)),
+            ["private"]
+        );
+        assert_eq!(
+            selected("registry:https://example.com/index"),
+            [] as [&str; 0]
+        );
+        assert_eq!(selected("git"), ["forked"]);
+        assert_eq!(
+            selected("git:https://github.com/someone/forked/"),
+            ["forked"]
+        );
+        assert_eq!(
+            selected("git:https://github.com/someone/else"),
+            [] as [&str; 0]
+        );
+
+        // Registries are named in cargo's configuration
+        env::set_var(
+            "CARGO_REGISTRIES_LICHKING_SOURCES_TEST_INDEX",
+            format!("{}/", registry),
+        );
+        assert_eq!(selected("registry:lichking-sources-test"), ["private"]);
+        assert!("registry:lichking-unconfigured-test"
+            .parse::<SourceFilter>()
+            .is_err());
+        assert_eq!(
+            selected("all"),
+            ["app", "forked", "private", "public", "unpublished"]
+        );
+        assert!("registry:".parse::<SourceFilter>().is_err());
+    }
+
+    /// The walk from before packages were indexed, searching the package
+    /// list and resolve graph at every step and walking once more for usages
+    fn linear_closure<'a>(
+        metadata: &'a Metadata,
+        roots: &[&'a Package],
+        filter: &Filter,
+    ) -> Vec<(&'a str, u32, Option<&'a str>, Usage)> {
+        let resolve = metadata.resolve.as_ref().unwrap();
+        let followed = |id: &PackageId| {
+            let node = resolve.nodes.iter().find(|node| &node.id == id).unwrap();
+            node.deps
+                .iter()
+                .filter(|dep| {
+                    dep.dep_kinds
+                        .iter()
+                        .any(|info| info.kind == DependencyKind::Normal)
+                })
+                .map(|dep| metadata.packages.by_id(&dep.pkg).unwrap())
+                .filter(|package| !filter.exclude_dep.iter().any(|spec| spec.matches(package)))
+                .collect::<Vec<_>>()
+        };
+        let mut runtime = roots.iter().map(|root| &root.id).collect::<HashSet<_>>();
+        let mut to_check = runtime.iter().copied().collect::<Vec<_>>();
+        while let Some(id) = to_check.pop() {
+            for dep in followed(id) {
+                if !is_proc_macro(dep) && runtime.insert(&dep.id) {
+                    to_check.push(&dep.id);
+                }
+            }
+        }
+        let mut result = Vec::new();
+        let mut added = HashSet::new();
+        let mut to_check = roots
+            .iter()
+            .map(|root| (&root.id, 0, None))
+            .collect::<VecDeque<_>>();
+        while let Some((id, depth, parent)) = to_check.pop_front() {
+            if !added.insert(id) {
+                continue;
+            }
+            let package = metadata.packages.by_id(id).unwrap();
+            let usage = if runtime.contains(id) {
+                Usage::Runtime
+            } else {
+                Usage::ProcMacro
+            };
+            result.push((package.name.as_str(), depth, parent, usage));
+            for dep in followed(id) {
+                to_check.push_back((&dep.id, depth + 1, Some(package.name.as_str())));
+            }
+        }
+        result
+    }
+
+    fn closure<'a>(
+        resolved: &[ResolvedPackage<'a>],
+    ) -> Vec<(&'a str, u32, Option<&'a str>, Usage)> {
+        resolved
+            .iter()
+            .map(|resolved| {
+                (
+                    resolved.package.name.as_str(),
+                    resolved.depth,
+                    resolved.parent.map(|parent| parent.name.as_str()),
+                    resolved.usage,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn indexed_closures_match_linear_walks() {
+        let dir = Path::new("/nonexistent");
+        let mut packages = (0..120)
+            .map(|i| package(&format!("crate-{}", i), "1.0.0", Some("MIT"), dir))
+            .collect::<Vec<_>>();
+        // Each package depends on a few later ones picked by a fixed linear
+        // congruential sequence, with dev-dependencies back to earlier ones
+        // making cycles that mustn't be followed. Some are proc-macros so
+        // packages are reached both ways.
+        let mut seed = 12345u64;
+        let mut next = |bound: usize| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 33) as usize % bound
+        };
+        let mut deps = Vec::new();
+        for from in 0..packages.len() - 1 {
+            for _ in 0..1 + next(3) {
+                let to = from + 1 + next(packages.len() - from - 1);
+                if !deps.iter().any(|&(f, t, _)| (f, t) == (from, to)) {
+                    deps.push((from, to, None));
+                }
+            }
+            if from > 0 && next(5) == 0 {
+                deps.push((from, next(from), Some("dev")));
+            }
+        }
+        for package in packages.iter_mut().skip(8).step_by(7) {
+            package.targets = vec![serde_json::from_value(serde_json::json!({
+                "name": package.name,
+                "kind": ["proc-macro"],
+                "src_path": "/nonexistent/src/lib.rs",
+            }))
+            .unwrap()];
+        }
+        let metadata = testutil::metadata(&packages, &deps);
+        let index = MetadataIndex::new(&metadata);
+        let roots = packages.iter().take(8).collect::<Vec<_>>();
+
+        let filters = [
+            Filter::default(),
+            Filter {
+                exclude_dep: ["crate-40", "crate-77"]
+                    .iter()
+                    .map(|name| DepSpec {
+                        name: (*name).to_owned(),
+                        version: None,
+                    })
+                    .collect(),
+                ..Filter::default()
+            },
+        ];
+        for filter in &filters {
+            let closures = resolve_each(&index, &roots, filter, &licenses()).unwrap();
+            assert_eq!(closures.len(), roots.len());
+            for (root, resolved) in roots.iter().zip(&closures) {
+                let expected = linear_closure(&metadata, &[*root], filter);
+                assert_eq!(closure(resolved), expected, "{}", root.name);
+                assert_eq!(
+                    closure(&resolve_packages(&index, &[*root], filter, &licenses()).unwrap()),
+                    expected,
+                    "{}",
+                    root.name
+                );
+            }
+            assert_eq!(
+                closure(&resolve_packages(&index, &roots, filter, &licenses()).unwrap()),
+                linear_closure(&metadata, &roots, filter)
+            );
+        }
+    }
+
+    #[test]
+    fn dependency_paths_over_two_routes() {
+        let dir = Path::new("/nonexistent");
+        let names = ["my-app", "plugin-host", "libloading", "a", "b", "tester"];
+        let packages = names
+            .iter()
+            .map(|name| package(name, "1.0.0", Some("MIT"), dir))
+            .collect::<Vec<_>>();
+        // my-app -> plugin-host -> libloading, my-app -> a -> b -> libloading
+        // and a dev-dependency my-app -> tester -> libloading that isn't
+        // followed
+        let deps = [
+            (0, 3, None),
+            (3, 4, None),
+            (4, 2, None),
+            (0, 1, None),
+            (1, 2, None),
+            (0, 5, Some("dev")),
+            (5, 2, None),
+        ];
+        let metadata = testutil::metadata(&packages, &deps);
+        let index = MetadataIndex::new(&metadata);
+        let paths = |filter: &Filter, target: usize, all| {
+            DepGraph::new(&index, &[&packages[0]], filter)
+                .unwrap()
+                .paths(&packages[0], &packages[target], all)
+                .iter()
+                .map(|path| format_path(path))
+                .collect::<Vec<_>>()
+        };
+        let unfiltered = Filter::default();
+        assert_eq!(
+            paths(&unfiltered, 2, false),
+            ["my-app -> plugin-host -> libloading"]
+        );
+        assert_eq!(
+            paths(&unfiltered, 2, true),
+            [
+                "my-app -> plugin-host -> libloading",
+                "my-app -> a -> b -> libloading",
+            ]
+        );
+        assert!(paths(&unfiltered, 5, true).is_empty());
+
+        // Only over the dependencies the filter follows
+        let excluded = Filter {
+            exclude_dep: vec![DepSpec {
+                name: "plugin-host".to_owned(),
+                version: None,
+            }],
+            ..Filter::default()
+        };
+        assert_eq!(
+            paths(&excluded, 2, false),
+            ["my-app -> a -> b -> libloading"]
+        );
+        assert_eq!(
+            paths(&excluded, 2, true),
+            ["my-app -> a -> b -> libloading"]
+        );
+        let direct = Filter {
+            direct_only: true,
+            ..Filter::default()
+        };
+        assert!(paths(&direct, 2, true).is_empty());
+        assert_eq!(paths(&direct, 1, true), ["my-app -> plugin-host"]);
+    }
+
+    #[test]
+    fn every_path_through_a_ladder() {
+        // 40 rungs of a -> (left, right) -> b give 2^40 paths to the top
+        let dir = Path::new("/nonexistent");
+        let mut packages = vec![package("rung-0", "1.0.0", Some("MIT"), dir)];
+        let mut deps = Vec::new();
+        for rung in 1..=40 {
+            let below = packages.len() - 1;
+            for side in &["left", "right"] {
+                packages.push(package(
+                    &format!("{}-{}", side, rung),
+                    "1.0.0",
+                    Some("MIT"),
+                    dir,
+                ));
+            }
+            packages.push(package(
+                &format!("rung-{}", rung),
+                "1.0.0",
+                Some("MIT"),
+                dir,
+            ));
+            let top = packages.len() - 1;
+            deps.extend(&[
+                (below, top - 2, None),
+                (below, top - 1, None),
+                (top - 2, top, None),
+                (top - 1, top, None),
+            ]);
+        }
+        let metadata = testutil::metadata(&packages, &deps);
+        let index = MetadataIndex::new(&metadata);
+        let graph = DepGraph::new(&index, &[&packages[0]], &Filter::default()).unwrap();
+        let top = packages.last().unwrap();
+        let paths = graph.paths(&packages[0], top, true);
+        assert_eq!(paths.len(), 16);
+        assert!(paths.iter().all(|path| path.len() == 81));
+        assert_eq!(graph.paths(&packages[0], top, false)[0].len(), 81);
+    }
+
+    #[test]
+    fn saved_metadata() {
+        let dir = testutil::TempDir::new();
+        let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/metadata.json");
+        let metadata = read_metadata(fixture).unwrap();
+        assert_eq!(metadata.packages.len(), 3);
+        let roots = select_roots(&metadata, SelectedPackage::Default).unwrap();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].name, "app");
+
+        let saved: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(fixture).unwrap()).unwrap();
+        let error = |name: &str, contents: String| {
+            let path = dir.write(name, &contents);
+            read_metadata(path.to_str().unwrap())
+                .unwrap_err()
+                .to_string()
+        };
+        let mut version = saved.clone();
+        version["version"] = 2.into();
+        assert!(error("version.json", version.to_string()).contains("format version 2"));
+        let mut unversioned = saved.clone();
+        unversioned.as_object_mut().unwrap().remove("version");
+        assert!(error("unversioned.json", unversioned.to_string()).contains("no format version"));
+        let mut no_deps = saved;
+        no_deps["resolve"] = serde_json::Value::Null;
+        assert!(error("no-deps.json", no_deps.to_string()).contains("--no-deps"));
+        assert!(error("truncated.json", "{\"packages\": [".to_owned()).contains("Couldn't parse"));
+        assert!(read_metadata("/nonexistent/metadata.json")
+            .unwrap_err()
+            .to_string()
+            .contains("Couldn't read"));
+    }
+
+    #[test]
+    fn package_id_specs_select_roots() {
+        let dir = Path::new("/nonexistent");
+        let packages = vec![
+            path_package("spec-app", "0.1.0", Some("MIT"), &dir.join("spec-app")),
+            package("spec-serde", "1.0.104", Some("MIT"), dir),
+            package("spec-serde", "0.9.15", Some("MIT"), dir),
+            testutil::sourced_package(
+                "spec-serde",
+                "1.0.104",
+                "git+https://github.com/someone/serde#0123abcd",
+            ),
+        ];
+        let deps = [(0, 1, None), (0, 2, None), (0, 3, None)];
+        let metadata = testutil::metadata(&packages, &deps);
+        let select = |specs: &[&str]| {
+            let specs = specs.iter().map(|spec| spec.parse().unwrap()).collect();
+            resolve_roots(
+                &metadata,
+                SelectedPackage::Specific(specs),
+                &Filter::default(),
+            )
+            .map(|roots| roots.iter().map(|root| &root.id).collect::<Vec<_>>())
+            .map_err(|err| err.to_string())
+        };
+
+        assert_eq!(select(&["spec-app"]), Ok(vec![&packages[0].id]));
+        assert_eq!(select(&["spec-serde@0.9"]), Ok(vec![&packages[2].id]));
+        assert_eq!(
+            select(&[
+                "spec-app",
+                "https://github.com/rust-lang/crates.io-index#spec-serde@1.0.104",
+                "spec-app@0.1.0",
+            ]),
+            Ok(vec![&packages[0].id, &packages[1].id])
+        );
+        assert_eq!(
+            select(&["https://github.com/someone/serde#spec-serde"]),
+            Ok(vec![&packages[3].id])
+        );
+
+        let err = select(&["spec-serde@1.0.104"]).unwrap_err();
+        assert!(err.contains("is ambiguous"), "{}", err);
+        assert!(
+            err.contains(&packages[1].id.repr) && err.contains(&packages[3].id.repr),
+            "{}",
+            err
+        );
+        let err = select(&["spec-serde@2"]).unwrap_err();
+        assert!(err.contains("did not match any packages"), "{}", err);
+    }
+
+    #[test]
+    fn default_members_are_paths() {
+        let dir = testutil::TempDir::new();
+        let packages = [
+            "crates/foo",
+            "crates/bar",
+            "crates/nested/baz",
+            "tools/gen",
+            "examples/demo",
+        ]
+        .iter()
+        .map(|path| {
+            let name = path.rsplit('/').next().unwrap();
+            path_package(name, "0.1.0", Some("MIT"), &dir.path().join(path))
+        })
+        .collect::<Vec<_>>();
+        let mut metadata = testutil::metadata(&packages, &[]);
+        metadata.workspace_root = dir.path().to_owned();
+        metadata.workspace_members = packages.iter().map(|p| p.id.clone()).collect();
+        let select = |workspace: &str| {
+            dir.write("Cargo.toml", &format!("[workspace]\n{}", workspace));
+            select_roots(&metadata, SelectedPackage::Default)
+                .map(|roots| roots.iter().map(|root| &*root.name).collect::<Vec<_>>())
+                .map_err(|err| err.to_string())
+        };
+
+        assert_eq!(
+            select("members = [\"crates/*\"]"),
+            Ok(vec!["foo", "bar", "baz", "gen", "demo"])
+        );
+        assert_eq!(
+            select("default-members = [\"crates/*\"]"),
+            Ok(vec!["foo", "bar"])
+        );
+        assert_eq!(
+            select("default-members = [\"./tools/gen/\", \"crates/**\", \"crates/foo\"]"),
+            Ok(vec!["gen", "foo", "bar", "baz"])
+        );
+        assert_eq!(
+            select("default-members = [\"crates/n?sted/*\"]"),
+            Ok(vec!["baz"])
+        );
+        assert_eq!(
+            select("default-members = [\"crates/**\", \"examples/demo\"]\nexclude = [\"crates/nested\", \"examples\"]"),
+            Ok(vec!["foo", "bar"])
+        );
+
+        let err = select("default-members = [\"foo\"]").unwrap_err();
+        assert!(err.contains("workspace member at foo"), "{}", err);
+        let err =
+            select("default-members = [\"examples/*\", \"missing/*\"]\nexclude = [\"examples\"]")
+                .unwrap_err();
+        assert!(
+            err.contains("[examples/*, missing/*] didn't match any workspace members"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn only_the_same_version_is_merged() {
+        let dir = Path::new("/nonexistent");
+        let old = package("foo", "0.9.0", Some("MIT"), dir);
+        let new = package("foo", "1.0.0", Some("MIT"), dir);
+        let forked = testutil::sourced_package(
+            "foo",
+            "1.0.0",
+            "git+https://github.com/someone/foo#0123abcd",
+        );
+        let merged = merge_duplicates(vec![
+            testutil::resolved(&old),
+            testutil::resolved(&new),
+            testutil::resolved(&forked),
+        ]);
+        assert_eq!(
+            merged
+                .iter()
+                .map(|resolved| resolved.package.version.to_string())
+                .collect::<Vec<_>>(),
+            ["0.9.0", "1.0.0"]
+        );
+        assert!(merged[0].duplicates.is_empty());
+        assert_eq!(
+            merged[1]
+                .duplicates
+                .iter()
+                .map(|package| &package.id)
+                .collect::<Vec<_>>(),
+            [&forked.id]
+        );
+    }
 }
@@ -0,0 +1,396 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use cargo_metadata::Metadata;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::yanked::cargo_home;
+
+/// A `Cargo.lock`, the format versions only differ in how dependencies are
+/// referenced and where checksums are stored
+#[derive(Deserialize)]
+struct Lockfile {
+    version: Option<u32>,
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+    /// Version 1 keeps the checksums here keyed by `checksum <id>`
+    #[serde(default)]
+    metadata: HashMap<String, toml::Value>,
+}
+
+#[derive(Deserialize)]
+struct LockedPackage {
+    name: String,
+    version: String,
+    source: Option<String>,
+    /// `name`, `name version` or `name version (source)`, only as much as
+    /// needed to be unambiguous since version 2, always the full form before
+    #[serde(default)]
+    dependencies: Vec<String>,
+    /// Only since version 2
+    checksum: Option<String>,
+}
+
+/// The parts of a package's `Cargo.toml` we use, registries normalize them so
+/// only local packages can have workspace inherited values, which are ignored
+#[derive(Deserialize)]
+struct Manifest {
+    package: ManifestPackage,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct ManifestPackage {
+    name: String,
+    license: Option<String>,
+    license_file: Option<PathBuf>,
+    description: Option<String>,
+    repository: Option<String>,
+    #[serde(default)]
+    authors: Vec<String>,
+}
+
+impl LockedPackage {
+    fn id(&self, dir: &Path) -> String {
+        match &self.source {
+            Some(source) => format!("{} {} ({})", self.name, self.version, source),
+            None => format!(
+                "{} {} (path+file://{})",
+                self.name,
+                self.version,
+                dir.display()
+            ),
+        }
+    }
+
+    fn matches(&self, dependency: &str) -> bool {
+        let mut parts = dependency.splitn(3, ' ');
+        let name = parts.next().unwrap_or_default();
+        let version = parts.next();
+        let source = parts
+            .next()
+            .map(|source| source.trim_start_matches('(').trim_end_matches(')'));
+        self.name == name
+            && version.is_none_or(|version| self.version == version)
+            && source.is_none_or(|source| self.source.as_deref() == Some(source))
+    }
+
+    /// The manifest of a local package, only found when it's next to the
+    /// lockfile or in a directory named after the package beside it
+    fn local_manifest(&self, dir: &Path) -> Option<(PathBuf, Manifest)> {
+        vec![
+            dir.join("Cargo.toml"),
+            dir.join(&self.name).join("Cargo.toml"),
+        ]
+        .into_iter()
+        .filter_map(|path| Some((path.clone(), read_manifest(&path)?)))
+        .find(|(_, manifest)| manifest.package.name == self.name)
+    }
+
+    /// Where cargo extracted the package's sources in its registry cache
+    fn cached_manifest(&self) -> Option<PathBuf> {
+        let source = self.source.as_deref()?;
+        if !source.starts_with("registry+") && !source.starts_with("sparse+") {
+            return None;
+        }
+        let src = cargo_home()?.join("registry").join("src");
+        let mut registries = fs::read_dir(src)
+            .ok()?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .collect::<Vec<_>>();
+        registries.sort();
+        registries
+            .into_iter()
+            .map(|registry| {
+                registry
+                    .join(format!("{}-{}", self.name, self.version))
+                    .join("Cargo.toml")
+            })
+            .find(|path| path.is_file())
+    }
+}
+
+fn read_manifest(path: &Path) -> Option<Manifest> {
+    let manifest = fs::read(path).ok()?;
+    toml::from_slice(&manifest)
+        .map_err(|err| log::debug!("Couldn't parse {}: {}", path.display(), err))
+        .ok()
+}
+
+/// The checksums of the registry packages in a `Cargo.lock`, keyed by
+/// `name version (source)`
+pub fn checksums(path: &Path) -> anyhow::Result<HashMap<String, String>> {
+    let bytes = fs::read(path)
+        .map_err(|err| anyhow!("Couldn't read lockfile {}: {}", path.display(), err))?;
+    let lockfile: Lockfile = toml::from_slice(&bytes)
+        .map_err(|err| anyhow!("Couldn't parse lockfile {}: {}", path.display(), err))?;
+    let mut checksums = lockfile
+        .metadata
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let id = key.strip_prefix("checksum ")?.to_owned();
+            Some((id, value.as_str()?.to_owned()))
+        })
+        .collect::<HashMap<_, _>>();
+    for package in lockfile.package {
+        if let (Some(source), Some(checksum)) = (&package.source, package.checksum) {
+            let id = format!("{} {} ({})", package.name, package.version, source);
+            checksums.insert(id, checksum);
+        }
+    }
+    Ok(checksums)
+}
+
+/// Builds metadata for the packages in a `Cargo.lock` without running cargo,
+/// their licenses are read from the registry cache when the sources have been
+/// downloaded before. The lockfile doesn't say what kind each dependency is,
+/// so they're all treated as normal dependencies.
+pub fn read_metadata(path: &str) -> anyhow::Result<Metadata> {
+    let bytes =
+        fs::read(path).map_err(|err| anyhow!("Couldn't read lockfile {}: {}", path, err))?;
+    let lockfile: Lockfile = toml::from_slice(&bytes)
+        .map_err(|err| anyhow!("Couldn't parse lockfile {}: {}", path, err))?;
+    match lockfile.version {
+        None | Some(2..=4) => (),
+        Some(version) => {
+            return Err(anyhow!(
+                "Lockfile {} has unsupported format version {}",
+                path,
+                version
+            ))
+        }
+    }
+
+    let dir = Path::new(path)
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."))
+        .canonicalize()?;
+    let packages = &lockfile.package;
+
+    let mut nodes = Vec::new();
+    let mut depended_on = HashSet::new();
+    for package in packages {
+        let mut deps = Vec::new();
+        for dependency in &package.dependencies {
+            let dep = packages
+                .iter()
+                .find(|candidate| candidate.matches(dependency))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Couldn't find dependency `{}` of {} {} in lockfile {}",
+                        dependency,
+                        package.name,
+                        package.version,
+                        path
+                    )
+                })?;
+            depended_on.insert(dep.id(&dir));
+            deps.push(json!({
+                "name": dep.name.replace('-', "_"),
+                "pkg": dep.id(&dir),
+                "dep_kinds": [{ "kind": null, "target": null }],
+            }));
+        }
+        nodes.push(json!({
+            "id": package.id(&dir),
+            "deps": deps,
+            "dependencies": deps.iter().map(|dep| dep["pkg"].clone()).collect::<Vec<_>>(),
+            "features": [],
+        }));
+    }
+
+    let mut missing = 0;
+    let mut entries = Vec::new();
+    for package in packages {
+        let (manifest_path, manifest) = match &package.source {
+            Some(_) => {
+                let path = package.cached_manifest();
+                let manifest = path.as_deref().and_then(read_manifest);
+                (path, manifest)
+            }
+            None => package.local_manifest(&dir).unzip(),
+        };
+        if package.source.is_some() && manifest.is_none() {
+            log::warn!(
+                "{} {}: license unknown (source not cached)",
+                package.name,
+                package.version
+            );
+            missing += 1;
+        }
+        let manifest = manifest.map(|manifest| manifest.package);
+        entries.push(json!({
+            "name": package.name,
+            "version": package.version,
+            "id": package.id(&dir),
+            "source": package.source,
+            "authors": manifest.as_ref().map(|m| m.authors.clone()).unwrap_or_default(),
+            "description": manifest.as_ref().and_then(|m| m.description.clone()),
+            "repository": manifest.as_ref().and_then(|m| m.repository.clone()),
+            "license": manifest.as_ref().and_then(|m| m.license.clone()),
+            "license_file": manifest.as_ref().and_then(|m| m.license_file.clone()),
+            "dependencies": [],
+            "targets": [],
+            "features": {},
+            "manifest_path": manifest_path.unwrap_or_else(|| {
+                dir.join(format!("{}-{}", package.name, package.version))
+                    .join("Cargo.toml")
+            }),
+        }));
+    }
+    if missing > 0 {
+        log::warn!(
+            "{} of {} packages in {} aren't in the registry cache, run `cargo fetch` to download them",
+            missing,
+            packages.len(),
+            path
+        );
+    }
+
+    // The packages without a source are the workspace members and path
+    // dependencies, one that nothing depends on is the root
+    let members = packages
+        .iter()
+        .filter(|package| package.source.is_none())
+        .map(|package| package.id(&dir))
+        .collect::<Vec<_>>();
+    let top = members
+        .iter()
+        .filter(|id| !depended_on.contains(*id))
+        .collect::<Vec<_>>();
+    let root = match top.as_slice() {
+        [root] => Some((*root).clone()),
+        _ => None,
+    };
+
+    let metadata = json!({
+        "packages": entries,
+        "workspace_members": members,
+        "resolve": { "nodes": nodes, "root": root },
+        "workspace_root": dir,
+        "target_directory": dir.join("target"),
+        "version": 1,
+    });
+    Ok(serde_json::from_value(metadata)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(version: &str) -> String {
+        format!(
+            "{}/tests/fixtures/lockfile-{}/Cargo.lock",
+            env!("CARGO_MANIFEST_DIR"),
+            version
+        )
+    }
+
+    #[test]
+    fn dependencies_of_each_format() {
+        for version in &["v1", "v3"] {
+            let metadata = read_metadata(&fixture(version)).unwrap();
+            let resolve = metadata.resolve.as_ref().unwrap();
+            let name = |id| {
+                let package = &metadata[id];
+                format!("{} {}", package.name, package.version)
+            };
+            let mut deps = resolve
+                .nodes
+                .iter()
+                .flat_map(|node| {
+                    node.deps
+                        .iter()
+                        .map(move |dep| (name(&node.id), name(&dep.pkg)))
+                })
+                .collect::<Vec<_>>();
+            deps.sort();
+            let mut expected = vec![
+                ("app 0.1.0", "fixture-cached 1.0.0"),
+                ("app 0.1.0", "fixture-uncached 0.3.1"),
+                ("fixture-cached 1.0.0", "fixture-uncached 0.3.1"),
+            ];
+            if *version == "v3" {
+                // Only versions need telling apart
+                expected.insert(2, ("app 0.1.0", "fixture-uncached 0.4.0"));
+            }
+            let expected = expected
+                .into_iter()
+                .map(|(from, to)| (from.to_owned(), to.to_owned()))
+                .collect::<Vec<_>>();
+            assert_eq!(deps, expected, "{}", version);
+
+            let root = resolve.root.as_ref().unwrap();
+            assert_eq!(name(root), "app 0.1.0");
+            assert_eq!(metadata.workspace_members, std::slice::from_ref(root));
+        }
+    }
+
+    #[test]
+    fn local_manifests() {
+        // Only the v3 fixture has the root's manifest next to it
+        let metadata = read_metadata(&fixture("v3")).unwrap();
+        let app = &metadata[metadata.resolve.as_ref().unwrap().root.as_ref().unwrap()];
+        assert_eq!(app.license.as_deref(), Some("MIT"));
+        assert!(app.manifest_path.ends_with("lockfile-v3/Cargo.toml"));
+
+        let metadata = read_metadata(&fixture("v1")).unwrap();
+        let app = &metadata[metadata.resolve.as_ref().unwrap().root.as_ref().unwrap()];
+        assert_eq!(app.license, None);
+    }
+
+    #[test]
+    fn checksums_of_each_format() {
+        let dir = crate::testutil::TempDir::new();
+        let v3 = dir.write(
+            "Cargo.lock",
+            "version = 3\n\n\
+             [[package]]\n\
+             name = \"fixture-cached\"\n\
+             version = \"1.0.0\"\n\
+             source = \"registry+https://github.com/rust-lang/crates.io-index\"\n\
+             checksum = \"3333\"\n",
+        );
+        let id = |name, version| {
+            format!(
+                "{} {} (registry+https://github.com/rust-lang/crates.io-index)",
+                name, version
+            )
+        };
+        for (path, name, version, checksum) in [
+            (
+                fixture("v1").into(),
+                "fixture-cached",
+                "1.0.0",
+                "1".repeat(64),
+            ),
+            (
+                fixture("v1").into(),
+                "fixture-uncached",
+                "0.3.1",
+                "2".repeat(64),
+            ),
+            (v3, "fixture-cached", "1.0.0", "3333".to_owned()),
+        ] {
+            let checksums = checksums(&path).unwrap();
+            assert_eq!(checksums.get(&id(name, version)), Some(&checksum));
+        }
+    }
+
+    #[test]
+    fn unsupported_versions() {
+        let dir = crate::testutil::TempDir::new();
+        let path = dir.write("Cargo.lock", "version = 5\n");
+        let err = read_metadata(path.to_str().unwrap()).unwrap_err();
+        assert!(
+            err.to_string().contains("unsupported format version 5"),
+            "{}",
+            err
+        );
+    }
+}
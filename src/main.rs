@@ -1,115 +1,680 @@
+mod analysis;
+mod baseline;
 mod bundle;
+mod cache;
 mod check;
+mod compat;
+mod config;
+mod cyclonedx;
+mod date;
+mod decisions;
 mod discovery;
+mod fix;
+mod graph;
+mod holders;
+mod inspect;
 mod license;
 mod licensed;
 mod list;
 mod load;
+mod lockfile;
+mod manifest;
 mod options;
+mod outcome;
+mod output;
+mod policy;
+mod progress;
 mod query;
+mod source_offer;
+#[cfg(test)]
+mod testutil;
+mod textutil;
 mod thirdparty;
+mod validate;
+mod vendor;
+mod yanked;
 
-use cargo_metadata::MetadataCommand;
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
 
-use crate::options::{Cmd, Options};
+use cargo_metadata::{Metadata, MetadataCommand};
 
-fn main() {
-    fn inner() -> anyhow::Result<()> {
-        let matches = Options::app(false).get_matches();
-        let options = Options::from_matches(&matches);
+use crate::baseline::{Inventory, RootInventory};
+use crate::cache::Cache;
+use crate::decisions::Decisions;
+use crate::license::{License, TemplateStore};
+use crate::licensed::LicensedCache;
+use crate::options::{Cmd, Format, Options, Scope, TargetKind};
+use crate::outcome::{Outcome, Summary};
+use crate::policy::{Election, Policy};
+use crate::query::MetadataIndex;
 
-        let mut logger = pretty_env_logger::formatted_builder();
-        if let Some(color) = options.color {
-            logger.parse_write_style(&color);
-        }
-        logger.init();
+/// The cargo binary to run: an explicit `--cargo-path`, otherwise the one that
+/// invoked us as a subcommand (cargo sets `$CARGO` for them) so that the same
+/// toolchain is used, falling back to whichever is on the `PATH`
+fn cargo_path(flag: Option<&str>, env: Option<OsString>) -> PathBuf {
+    flag.map(PathBuf::from)
+        .or_else(|| env.map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from("cargo"))
+}
 
-        log::warn!("IANAL: This is not legal advice and is not guaranteed to be correct.");
+/// Runs `cargo metadata`, forwarding the cargo options we were given
+fn cargo_metadata(options: &Options) -> anyhow::Result<Metadata> {
+    let opt_map = [
+        (options.verbose > 0, "--verbose"),
+        (options.verbose > 1, "--verbose"),
+        (options.verbose > 2, "--verbose"),
+        (options.verbose > 3, "--verbose"),
+        (options.quiet, "--quiet"),
+        (options.frozen, "--frozen"),
+        (options.locked, "--locked"),
+        (options.offline, "--offline"),
+    ];
 
-        let opt_map = [
-            (options.verbose > 0, "--verbose"),
-            (options.verbose > 1, "--verbose"),
-            (options.verbose > 2, "--verbose"),
-            (options.verbose > 3, "--verbose"),
-            (options.quiet, "--quiet"),
-            (options.frozen, "--frozen"),
-            (options.locked, "--locked"),
-        ];
+    let mut other_options = opt_map
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, opt)| (*opt).to_owned())
+        .collect::<Vec<_>>();
+    // `MetadataCommand::features` only takes one of these at a time
+    if options.all_features {
+        other_options.push("--all-features".to_owned());
+    }
+    if options.no_default_features {
+        other_options.push("--no-default-features".to_owned());
+    }
+    if !options.features.is_empty() {
+        other_options.push("--features".to_owned());
+        other_options.push(options.features.join(","));
+    }
+    for config in &options.config {
+        other_options.push("--config".to_owned());
+        other_options.push(config.clone());
+    }
 
-        let other_options = opt_map
+    let cargo = cargo_path(options.cargo_path.as_deref(), env::var_os("CARGO"));
+    log::debug!("Running {} metadata", cargo.display());
+    let mut command = MetadataCommand::new();
+    command.cargo_path(cargo);
+    command.other_options(other_options);
+    if let Some(manifest_path) = &options.manifest_path {
+        command.manifest_path(manifest_path);
+    }
+    let offline = options.offline;
+    let searched = options.manifest_path.is_none();
+    command.exec().map_err(|err| {
+        let message = err.to_string();
+        let network = ["network", "registry", "download", "index", "spurious"]
             .iter()
-            .filter(|(enabled, _)| *enabled)
-            .map(|(_, opt)| (*opt).to_owned())
-            .collect::<Vec<_>>();
+            .any(|hint| message.contains(hint));
+        let err = anyhow::Error::new(err);
+        if searched && message.contains("could not find `Cargo.toml`") {
+            let dir =
+                env::current_dir().map_or_else(|_| ".".into(), |dir| dir.display().to_string());
+            err.context(format!(
+                "No Cargo.toml found in {} or any of its parents, run from within a cargo project \
+                 or pass --manifest-path PATH/Cargo.toml or --workspace-root PATH",
+                dir
+            ))
+        } else if let Some(flag) = stale_lockfile(&message) {
+            err.context(format!(
+                "Cargo.lock is out of date, run `cargo update` to update it or drop {}",
+                flag
+            ))
+        } else if network && !offline {
+            err.context(
+                "cargo metadata failed while accessing the network, \
+                 try passing --offline or --frozen if dependencies are already available",
+            )
+        } else {
+            err
+        }
+    })
+}
 
-        let metadata = MetadataCommand::new().other_options(other_options).exec()?;
+/// The flag that stopped cargo updating an out of date lockfile, if that's
+/// what it failed with
+fn stale_lockfile(message: &str) -> Option<&'static str> {
+    if !message.contains("lock file") {
+        return None;
+    }
+    ["--frozen", "--locked"]
+        .iter()
+        .copied()
+        .find(|flag| message.contains(&format!("{} was passed", flag)))
+}
+
+fn main() {
+    fn inner(mut options: Options) -> anyhow::Result<Outcome> {
+        if !options.quiet {
+            log::warn!("IANAL: This is not legal advice and is not guaranteed to be correct.");
+        }
+
+        // Only looks at the bundled templates, there's no need for a workspace
+        if let Cmd::ValidateTemplates { config } = &options.cmd {
+            return validate::run(config);
+        }
+
+        if let Some(dir) = &options.license_templates {
+            let store = TemplateStore::load(Path::new(dir))?;
+            for (path, license) in store.overridden() {
+                output::warning(&format!(
+                    "{} replaces the bundled template of {}",
+                    path.display(),
+                    license
+                ));
+            }
+            if let Some(config) = options.cmd.discovery_mut() {
+                config.templates = Some(Arc::new(store));
+            }
+        }
 
+        let mut metadata = match (&options.metadata_json, &options.from_lockfile) {
+            (Some(path), _) => load::read_metadata(path)?,
+            (None, Some(path)) => lockfile::read_metadata(path)?,
+            (None, None) => cargo_metadata(&options)?,
+        };
+        load::normalize_paths(&mut metadata);
+        let index = MetadataIndex::new(&metadata);
+
+        let strict = match &options.cmd {
+            Cmd::Check { options, .. } => options.strict_spdx,
+            Cmd::List { options, .. } => options.strict_spdx,
+            _ => false,
+        };
+        // A policy's `clarify` overrides replace the licenses crates declare,
+        // the policies electing licenses in list and bundle only describe
+        // licenses as declared
+        let policy_path = match &options.cmd {
+            Cmd::Check { options, .. } => options.policy.clone(),
+            _ => None,
+        };
+        let policy = policy_path.as_deref().map(Policy::load_about).transpose()?;
+        let mut licenses = LicensedCache::new(strict, options.scan_source_headers);
+        if let (Some(path), Some(policy)) = (&policy_path, &policy) {
+            licenses = licenses.with_overrides(policy.clarifications(), path);
+        }
+
+        let mut outcome = Outcome::default();
+        if let Some(dir) = &options.vendor_dir {
+            let (copies, vendor_outcome) = vendor::copies(Path::new(dir), &metadata.packages)?;
+            outcome.merge(vendor_outcome);
+            licenses = licenses.with_vendored(copies);
+        }
         match options.cmd {
-            Cmd::Check { package } => {
-                let mut error = Ok(());
-                let roots = load::resolve_roots(&metadata, package)?;
-                for root in roots {
-                    let roots = [root];
-                    let packages = load::resolve_packages(&metadata, &roots)?;
-                    if let Err(err) = check::run(root, &packages) {
-                        error = Err(err);
+            Cmd::Check {
+                options,
+                package,
+                filter,
+            } => {
+                let mut decisions = options
+                    .record_decisions
+                    .as_deref()
+                    .map(|path| Decisions::load(path, options.non_interactive))
+                    .transpose()?;
+                let (roots, skipped): (Vec<_>, Vec<_>) =
+                    load::resolve_roots(&metadata, package, &filter)?
+                        .into_iter()
+                        .partition(|root| check::selected(root, options.target_kind));
+                for root in &skipped {
+                    log::info!(
+                        "Skipping {}, it has no {} target",
+                        root.name,
+                        match options.target_kind {
+                            TargetKind::Lib => "library",
+                            _ => "binary",
+                        }
+                    );
+                }
+                let analyzed = roots.len();
+                let against = options
+                    .against
+                    .iter()
+                    .map(|license| license.parse().expect("validated"))
+                    .collect::<Vec<License>>();
+                // Without hypothetical licenses each root is checked under its own
+                let candidates = if against.is_empty() {
+                    vec![None]
+                } else {
+                    against.iter().map(Some).collect()
+                };
+                // A listed set of packages is checked against each root's license
+                let resolved = match &filter.packages_from {
+                    Some(path) => {
+                        vec![
+                            load::resolve_explicit(&metadata, &roots, path, &licenses)?;
+                            roots.len()
+                        ]
+                    }
+                    None => load::resolve_each(&index, &roots, &filter, &licenses)?,
+                };
+                // Findings say how each root depends on the package
+                let graph = load::DepGraph::new(&index, &roots, &filter)?;
+                let checker = check::Checker {
+                    graph: &graph,
+                    licenses: &licenses,
+                    options: &options,
+                    policy: policy.as_ref(),
+                };
+                // Dependencies shared by several roots are counted once
+                let mut checked = Vec::new();
+                let mut seen = HashSet::new();
+                let mut candidate_outcomes = Vec::new();
+                for candidate in candidates {
+                    let mut candidate_outcome = Outcome::default();
+                    for (root, resolved) in roots.iter().zip(&resolved) {
+                        checked.extend(
+                            resolved
+                                .iter()
+                                .filter(|resolved| seen.insert(&resolved.package.id))
+                                .cloned(),
+                        );
+                        candidate_outcome.merge(check::run(
+                            &checker,
+                            root,
+                            candidate,
+                            resolved,
+                            decisions.as_mut(),
+                        )?);
+                    }
+                    if let Some(candidate) = candidate {
+                        let reasons = candidate_outcome
+                            .failures
+                            .iter()
+                            .chain(&candidate_outcome.warnings)
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        let status = if !candidate_outcome.failures.is_empty() {
+                            "fails"
+                        } else if !candidate_outcome.warnings.is_empty() {
+                            "passes with warnings"
+                        } else {
+                            "passes"
+                        };
+                        candidate_outcomes.push((candidate, status, reasons));
+                    }
+                    outcome.merge(candidate_outcome);
+                }
+                let width = against
+                    .iter()
+                    .map(|license| license.to_string().chars().count())
+                    .max()
+                    .unwrap_or(0);
+                for (candidate, status, reasons) in candidate_outcomes {
+                    if reasons.is_empty() {
+                        println!("{}  {}", output::license(candidate, width), status);
+                    } else {
+                        println!(
+                            "{}  {}: {}",
+                            output::license(candidate, width),
+                            status,
+                            reasons.join("; ")
+                        );
+                    }
+                }
+                outcome.summary = Summary::of(&checked);
+                if policy
+                    .as_ref()
+                    .is_some_and(|policy| policy.report_unused_waivers())
+                {
+                    outcome.warn("Unused waivers");
+                }
+                let kinds = match options.target_kind {
+                    TargetKind::All => None,
+                    TargetKind::Bin => Some(("binary", "library-only")),
+                    TargetKind::Lib => Some(("library", "binary-only")),
+                };
+                if let Some((kind, other)) = kinds {
+                    let roots = |count: usize| if count == 1 { "root" } else { "roots" };
+                    output::note(&format!(
+                        "analyzed {} {} {}, skipped {} {} {}",
+                        analyzed,
+                        kind,
+                        roots(analyzed),
+                        skipped.len(),
+                        other,
+                        roots(skipped.len())
+                    ));
+                }
+            }
+
+            Cmd::List {
+                options,
+                package,
+                filter,
+            } => {
+                let roots = load::resolve_roots(&metadata, package, &filter)?;
+                load::report_unlicensed_roots(&roots, &licenses);
+                let mut resolved = match &filter.packages_from {
+                    Some(path) => load::resolve_explicit(&metadata, &roots, path, &licenses)?,
+                    None => load::resolve_packages(&index, &roots, &filter, &licenses)?,
+                };
+                // Diagnostics, baselines and the summary cover every root's
+                // dependencies whatever the scope
+                let mut sections = match options.scope {
+                    Scope::Union => vec![(None, resolved.clone())],
+                    Scope::PerRoot => roots
+                        .iter()
+                        .map(|root| Some(*root))
+                        .zip(load::resolve_each(&index, &roots, &filter, &licenses)?)
+                        .collect(),
+                };
+                if options.exclude_roots {
+                    resolved.retain(|resolved| !resolved.root());
+                    for (_, resolved) in &mut sections {
+                        resolved.retain(|resolved| !resolved.root());
+                    }
+                }
+                let packages = load::packages(&resolved);
+                let invalid = licenses.report_diagnostics(&packages);
+                analysis::report_license_changes(&packages, &licenses, false);
+                let yanked = if options.check_yanked {
+                    yanked::yanked(&packages, options.allow_network)
+                } else {
+                    HashSet::new()
+                };
+                let elected = match Election::load(&options.elect)? {
+                    Some(election) => list::elect(&resolved, &election),
+                    None => HashMap::new(),
+                };
+                match options.format {
+                    Format::Text => {
+                        for (i, (root, resolved)) in sections.iter().enumerate() {
+                            if let Some(root) = root {
+                                if i > 0 {
+                                    println!();
+                                }
+                                println!("{} {}:", root.name, root.version);
+                                println!();
+                            }
+                            list::run(resolved, &options, &yanked, &elected)?;
+                        }
+                    }
+                    Format::Json if options.by.groups() => {
+                        list::write_groups(&sections, options.by, &yanked, &mut io::stdout())?
+                    }
+                    Format::Json if options.scope == Scope::PerRoot => {
+                        let inventories = sections
+                            .iter()
+                            .map(|(root, resolved)| {
+                                let root = root.expect("per root");
+                                let mut inventory =
+                                    Inventory::collect_with_yanked(resolved, &yanked);
+                                inventory.summary = Some(Summary::of(resolved));
+                                RootInventory {
+                                    root: format!("{} {}", root.name, root.version),
+                                    inventory,
+                                }
+                            })
+                            .collect::<Vec<_>>();
+                        serde_json::to_writer_pretty(io::stdout(), &inventories)?;
+                        println!();
                     }
+                    Format::Json => {
+                        let mut inventory = Inventory::collect_with_yanked(&resolved, &yanked);
+                        inventory.summary = Some(Summary::of(&resolved));
+                        inventory.write(&mut io::stdout())?
+                    }
+                    Format::CycloneDx => cyclonedx::run(&index, &resolved, options.reproducible)?,
+                }
+                if let Some(path) = &options.save_baseline {
+                    Inventory::collect(&resolved).save(path)?;
+                }
+                if invalid && options.strict_spdx {
+                    outcome.fail("Invalid SPDX license identifiers");
                 }
-                error?;
+                outcome.summary = Summary::of(&resolved);
             }
 
-            Cmd::List { by, package } => {
-                let roots = load::resolve_roots(&metadata, package)?;
-                let packages = load::resolve_packages(&metadata, &roots)?;
-                list::run(&packages, by)?;
+            Cmd::Diff {
+                baseline,
+                format,
+                package,
+                filter,
+            } => {
+                let roots = load::resolve_roots(&metadata, package, &filter)?;
+                let resolved = load::resolve_packages(&index, &roots, &filter, &licenses)?;
+                outcome.merge(baseline::run(&baseline, &resolved, format)?);
             }
 
-            Cmd::Bundle { variant, package } => {
-                let roots = load::resolve_roots(&metadata, package)?;
-                let packages = load::resolve_packages(&metadata, &roots)?;
-                bundle::run(&roots, &packages, variant)?;
+            Cmd::Bundle {
+                variant,
+                options,
+                package,
+                filter,
+            } => {
+                let roots = load::resolve_roots(&metadata, package, &filter)?;
+                load::report_unlicensed_roots(&roots, &licenses);
+                let cache = if options.no_cache {
+                    None
+                } else {
+                    options
+                        .cache
+                        .as_ref()
+                        .map(PathBuf::from)
+                        .or_else(Cache::default_path)
+                        .map(Cache::load)
+                };
+                let shared = bundle::Shared {
+                    workspace_root: &metadata.workspace_root,
+                    licenses: &licenses,
+                    election: Election::load(&options.elect)?,
+                };
+                let per_root = || -> anyhow::Result<Vec<_>> {
+                    Ok(roots
+                        .iter()
+                        .copied()
+                        .zip(load::resolve_each(&index, &roots, &filter, &licenses)?)
+                        .collect())
+                };
+                if let Some(dir) = &options.output_dir {
+                    outcome.merge(bundle::run_per_root(
+                        &per_root()?,
+                        &shared,
+                        variant,
+                        &options,
+                        cache,
+                        Path::new(dir),
+                    )?);
+                } else if options.scope == Scope::PerRoot {
+                    outcome.merge(bundle::run_sections(
+                        &per_root()?,
+                        &shared,
+                        variant,
+                        &options,
+                        cache,
+                    )?);
+                } else {
+                    let resolved = match &filter.packages_from {
+                        Some(path) => load::resolve_explicit(&metadata, &roots, path, &licenses)?,
+                        None => load::resolve_packages(&index, &roots, &filter, &licenses)?,
+                    };
+                    outcome.merge(bundle::run(
+                        &roots, &resolved, &shared, variant, &options, cache,
+                    )?);
+                }
             }
 
-            Cmd::ThirdParty { full } => {
-                println!(
-                    "cargo-lichking uses some third party libraries under their own license terms:"
-                );
-                println!();
-                for krate in thirdparty::CRATES {
-                    print!(
-                        " * {} v{} under the terms of {}",
-                        krate.name, krate.version, krate.licenses.name
+            Cmd::Compat {
+                as_licenses,
+                format,
+                package,
+                filter,
+            } => {
+                let roots = load::resolve_roots(&metadata, package, &filter)?;
+                let resolved = load::resolve_packages(&index, &roots, &filter, &licenses)?;
+                compat::run(&roots, &resolved, &licenses, &as_licenses, format)?;
+                outcome.summary = Summary::of(&resolved);
+            }
+
+            Cmd::SourceOffer {
+                format,
+                package,
+                filter,
+            } => {
+                let roots = load::resolve_roots(&metadata, package, &filter)?;
+                let resolved = load::resolve_packages(&index, &roots, &filter, &licenses)?;
+                let lockfile = metadata.workspace_root.join("Cargo.lock");
+                let checksums = if lockfile.is_file() {
+                    lockfile::checksums(&lockfile)?
+                } else {
+                    log::warn!(
+                        "No lockfile at {}, leaving out checksums",
+                        lockfile.display()
                     );
-                    if full {
-                        println!(":");
-                        let mut first = true;
-                        for license in krate.licenses.licenses {
-                            if first {
-                                first = false;
-                            } else {
-                                println!();
-                                println!("    ===============");
-                            }
-                            println!();
-                            if let Some(text) = license.text {
-                                for line in text.lines() {
-                                    println!("    {}", line);
-                                }
-                            } else {
-                                println!("    Missing {} license text", license.name);
-                            }
-                        }
-                    }
-                    println!();
+                    Default::default()
+                };
+                source_offer::run(&resolved, &checksums, format)?;
+                outcome.summary = Summary::of(&resolved);
+            }
+
+            Cmd::Graph {
+                format,
+                file,
+                max_depth,
+                package,
+                filter,
+            } => {
+                let roots = load::resolve_roots(&metadata, package, &filter)?;
+                let resolved = load::resolve_packages(&index, &roots, &filter, &licenses)?;
+                if let Some(file) = file {
+                    graph::run(
+                        &index,
+                        &resolved,
+                        format,
+                        max_depth,
+                        &mut bundle::create(&file, false)?,
+                    )?;
+                } else {
+                    graph::run(&index, &resolved, format, max_depth, &mut io::stdout())?;
                 }
             }
+
+            Cmd::ThirdParty { full, file } => {
+                outcome.merge(thirdparty::run(full, file)?);
+            }
+
+            Cmd::Inspect {
+                config,
+                package,
+                filter,
+            } => {
+                for package in load::resolve_roots(&metadata, package, &filter)? {
+                    inspect::run(
+                        package,
+                        &metadata.workspace_root,
+                        &config,
+                        &licenses,
+                        &mut io::stdout(),
+                    )?;
+                }
+            }
+
+            Cmd::Fix {
+                dry_run,
+                package,
+                filter,
+            } => {
+                let roots = load::resolve_roots(&metadata, package, &filter)?;
+                outcome.merge(fix::run(&roots, &licenses, dry_run)?);
+            }
+
+            Cmd::ValidateTemplates { .. } => unreachable!("handled before loading metadata"),
+        }
+
+        Ok(outcome)
+    }
+
+    let start = Instant::now();
+    let args = Options::command_line(env::args_os());
+    let matches = Options::app(false).get_matches_from(&args);
+    let options = Options::from_matches(&matches)
+        .and_then(|options| options.merge(&matches, &args))
+        .unwrap_or_else(|err| clap::Error::from(err).exit());
+
+    let mut logger = pretty_env_logger::formatted_builder();
+    if let Some(color) = &options.color {
+        logger.parse_write_style(color);
+    }
+    logger.filter_level(options.log_level());
+    if let Ok(filters) = env::var("RUST_LOG") {
+        logger.parse_filters(&filters);
+    }
+    let logger = logger.build();
+    log::set_max_level(logger.filter());
+    log::set_logger(Box::leak(Box::new(progress::Logger::new(logger))))
+        .expect("no other logger is set");
+    progress::init(options.quiet);
+    output::init(options.color.as_deref(), options.quiet);
+
+    let (warnings_as_errors, fail_on_missing_text, quiet) = (
+        options.warnings_as_errors,
+        options.fail_on_missing_text,
+        options.quiet,
+    );
+    match inner(options) {
+        Ok(outcome) => {
+            if !quiet && outcome.summary.packages > 0 {
+                eprintln!("{}", outcome.summary.footer(start.elapsed()));
+            }
+            std::process::exit(outcome.report(warnings_as_errors, fail_on_missing_text));
+        }
+        Err(error) => {
+            log::error!("{:#}", error);
+            std::process::exit(1);
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        Ok(())
+    #[test]
+    fn cargo_paths() {
+        let env = || Some(OsString::from("/toolchains/nightly/bin/cargo"));
+        assert_eq!(cargo_path(None, None), PathBuf::from("cargo"));
+        assert_eq!(
+            cargo_path(None, env()),
+            PathBuf::from("/toolchains/nightly/bin/cargo")
+        );
+        assert_eq!(
+            cargo_path(Some("/opt/cargo"), env()),
+            PathBuf::from("/opt/cargo")
+        );
+        assert_eq!(
+            cargo_path(Some("/opt/cargo"), None),
+            PathBuf::from("/opt/cargo")
+        );
     }
 
-    if let Err(error) = inner() {
-        log::error!("{}", error);
-        std::process::exit(1);
+    #[test]
+    fn stale_lockfiles() {
+        let error = |flag| {
+            format!(
+                "`cargo metadata` exited with an error: error: cannot update the lock file \
+                 /work/Cargo.lock because {} was passed to prevent this",
+                flag
+            )
+        };
+        assert_eq!(stale_lockfile(&error("--locked")), Some("--locked"));
+        assert_eq!(stale_lockfile(&error("--frozen")), Some("--frozen"));
+        // Older cargo versions
+        assert_eq!(
+            stale_lockfile(
+                "error: the lock file /work/Cargo.lock needs to be updated but --locked was \
+                 passed to prevent this"
+            ),
+            Some("--locked")
+        );
+        assert_eq!(
+            stale_lockfile("error: failed to download from `https://static.crates.io`"),
+            None
+        );
     }
 }
@@ -0,0 +1,127 @@
+use std::fs;
+
+use cargo_metadata::{Dependency, DependencyKind, Package};
+use toml_edit::{ImDocument, Item, TableLike};
+
+/// A line and column in a manifest, both starting at 1
+#[derive(Debug, Eq, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Where `package`'s manifest declares its dependency on `dependency`, `None`
+/// if it doesn't or the manifest can't be read or parsed
+pub fn dependency_location(package: &Package, dependency: &Package) -> Option<Location> {
+    let text = fs::read_to_string(&package.manifest_path).ok()?;
+    let document = match ImDocument::parse(text.as_str()) {
+        Ok(document) => document,
+        Err(err) => {
+            log::debug!(
+                "Couldn't parse {}: {}",
+                package.manifest_path.display(),
+                err
+            );
+            return None;
+        }
+    };
+    let offset = package
+        .dependencies
+        .iter()
+        .filter(|declared| declared.name == dependency.name)
+        .find_map(|declared| declaration(document.as_item(), declared))?;
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    Some(Location {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+    })
+}
+
+/// The `homepage` of `package`, which cargo metadata doesn't give us. `None`
+/// if it has none, or it's inherited from the workspace.
+pub fn homepage(package: &Package) -> Option<String> {
+    let text = fs::read_to_string(&package.manifest_path).ok()?;
+    let document = ImDocument::parse(text.as_str()).ok()?;
+    document
+        .get("package")?
+        .get("homepage")?
+        .as_str()
+        .map(ToOwned::to_owned)
+}
+
+/// The offset of the key declaring `declared`, under its platform's
+/// `[target]` table if it has one and by the name it's renamed to if it is
+fn declaration(root: &Item, declared: &Dependency) -> Option<usize> {
+    let section = match declared.kind {
+        DependencyKind::Development => "dev-dependencies",
+        DependencyKind::Build => "build-dependencies",
+        _ => "dependencies",
+    };
+    let parent = match &declared.target {
+        Some(platform) => root.get("target")?.get(platform.to_string())?,
+        None => root,
+    };
+    let key = declared.rename.as_ref().unwrap_or(&declared.name);
+    // Cargo still accepts the old underscored spelling of the sections
+    [section.to_owned(), section.replace('-', "_")]
+        .iter()
+        .filter_map(|section| parent.get(section)?.as_table_like())
+        .find_map(|table: &dyn TableLike| table.get_key_value(key)?.0.span())
+        .map(|span| span.start)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::testutil::{path_package, TempDir};
+
+    fn dependency(name: &str, kind: Option<&str>, target: Option<&str>) -> Dependency {
+        serde_json::from_value(json!({
+            "name": name,
+            "source": null,
+            "req": "*",
+            "kind": kind,
+            "optional": false,
+            "uses_default_features": true,
+            "features": [],
+            "target": target,
+            "rename": if name == "renamed" { Some("alias") } else { None },
+            "registry": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn dependencies_are_located_in_their_section() {
+        let dir = TempDir::new();
+        dir.write(
+            "app/Cargo.toml",
+            "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n\
+             [dependencies]\nserde = \"1\"\n  alias = { package = \"renamed\", version = \"1\" }\n\n\
+             [dev_dependencies]\nserde = \"1\"\ntester = \"1\"\n\n\
+             [target.'cfg(unix)'.dependencies.libc]\nversion = \"0.2\"\n",
+        );
+        let mut app = path_package("app", "0.1.0", None, &dir.path().join("app"));
+        app.dependencies = vec![
+            dependency("serde", None, None),
+            dependency("renamed", None, None),
+            dependency("tester", Some("dev"), None),
+            dependency("libc", None, Some("cfg(unix)")),
+        ];
+        let locate = |name: &str| {
+            let dependency = path_package(name, "1.0.0", None, dir.path());
+            dependency_location(&app, &dependency).map(|location| (location.line, location.column))
+        };
+        assert_eq!(locate("serde"), Some((6, 1)));
+        assert_eq!(locate("renamed"), Some((7, 3)));
+        assert_eq!(locate("tester"), Some((11, 1)));
+        assert_eq!(locate("libc"), Some((13, 34)));
+        assert_eq!(locate("missing"), None);
+
+        dir.write("app/Cargo.toml", "[dependencies\nserde = \"1\"\n");
+        assert_eq!(locate("serde"), None);
+    }
+}
@@ -1,48 +1,372 @@
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use log::LevelFilter;
+use regex::Regex;
 
-// TODO
-pub type PackageIdSpec = String;
+use crate::config::Config;
+use crate::discovery::DiscoveryConfig;
+use crate::license::{License, Linkage, ParseDiagnostic};
+use crate::load::{registry_index, registry_url};
+use crate::textutil::LineEnding;
 
-#[derive(Copy, Clone, Debug)]
+/// Skips checking that a bundle has exactly one entry per package, for
+/// emergencies only
+pub const NO_VERIFY_VAR: &str = "CARGO_LICHKING_NO_VERIFY";
+
+/// A cargo package id specification, e.g. `serde`, `serde@1.0.104` or
+/// `https://github.com/rust-lang/crates.io-index#serde@1.0.104`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PackageIdSpec {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum By {
     License,
     Crate,
+    /// Packages per license family, e.g. permissive or strong copyleft
+    LicenseFamily,
+    /// Packages per source, e.g. crates.io or a git repository
+    Source,
+}
+
+/// Which licenses to list
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LicenseFilter {
+    /// Custom, file and unspecified licenses
+    Unknown,
+    Copyleft,
+    Permissive,
+    /// Any of these licenses, as SPDX identifiers
+    Licenses(Vec<String>),
+}
+
+/// License families that fail check when any dependency has one, regardless
+/// of compatibility with the root
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FailOn {
+    /// Weak, strong or network copyleft
+    Copyleft,
+    /// Strong or network copyleft
+    StrongCopyleft,
+    NetworkCopyleft,
+    Unknown,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+    CycloneDx,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DiffFormat {
+    Text,
+    Json,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+/// Which roots to check based on the kinds of targets they build
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum TargetKind {
+    #[default]
+    All,
+    Bin,
+    Lib,
+}
+
+/// Whether the dependencies of several roots are listed or bundled together,
+/// or each root's on their own as check does
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Scope {
+    #[default]
+    Union,
+    PerRoot,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SelectedPackage {
     All,
     Default,
-    Specific(PackageIdSpec),
+    Specific(Vec<PackageIdSpec>),
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum SourceFilter {
+    #[default]
+    All,
+    CratesIo,
+    Internal,
+    /// A registry, by its index URL
+    Registry(String),
+    /// Any git repository, or only the one with this URL
+    Git(Option<String>),
+}
+
+/// A dependency to exclude, `NAME[@VERSION]` where the name may be a glob
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepSpec {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Filter {
+    pub direct_only: bool,
+    /// Leave out packages only used at compile time, proc-macro crates along
+    /// with what only they depend on
+    pub distribution_only: bool,
+    pub source: SourceFilter,
+    pub exclude: Vec<String>,
+    pub exclude_spec: Vec<PackageIdSpec>,
+    pub exclude_dep: Vec<DepSpec>,
+    /// A file, or `-` for standard in, listing exactly the packages to use in
+    /// place of the roots' dependencies
+    pub packages_from: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CheckOptions {
+    pub strict_spdx: bool,
+    pub deny_license_changes: bool,
+    pub deny_undecided: bool,
+    pub deny_lgpl_static: bool,
+    pub linkage: Linkage,
+    pub record_decisions: Option<String>,
+    pub non_interactive: bool,
+    /// An about.toml style list of accepted licenses
+    pub policy: Option<String>,
+    /// Show every dependency path to problematic packages, not just one, and
+    /// how licenses not checked as declared were arrived at
+    pub verbose: bool,
+    pub check_yanked: bool,
+    pub allow_network: bool,
+    pub target_kind: TargetKind,
+    /// Check declared licenses against the license files packages contain
+    pub cross_check: bool,
+    /// How license files are matched when cross-checking
+    pub discovery: DiscoveryConfig,
+    /// Hypothetical root licenses to check in place of the roots' own
+    pub against: Vec<String>,
+    pub fail_on: Vec<FailOn>,
+    /// Check roots declaring no license as private even when publishable
+    pub assume_private: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct ListOptions {
+    pub by: By,
+    pub format: Format,
+    pub reproducible: bool,
+    pub save_baseline: Option<String>,
+    pub strict_spdx: bool,
+    /// Only the number of packages under each license
+    pub summary: bool,
+    /// What each license requires instead of its packages
+    pub obligations: bool,
+    /// Show the features enabling optional dependencies
+    pub verbose: bool,
+    pub check_yanked: bool,
+    pub allow_network: bool,
+    pub exclude_roots: bool,
+    pub license_filter: Option<LicenseFilter>,
+    pub scope: Scope,
+    pub elect: ElectOptions,
+}
+
+/// How packages offering a choice of licenses are elected to be used under
+/// one of them, with neither they're used under all of them
+#[derive(Clone, Debug, Default)]
+pub struct ElectOptions {
+    /// Use the first of these licenses offered
+    pub prefer: Vec<License>,
+    /// Use the first alternative accepted by this about.toml style policy
+    pub policy: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BundleOptions {
+    pub discovery: DiscoveryConfig,
+    pub cache: Option<String>,
+    pub no_cache: bool,
+    pub require_sources: bool,
+    /// Fail if a license file confidently matches an undeclared license
+    pub cross_check: bool,
+    /// Leave out timestamps and machine specific paths
+    pub reproducible: bool,
+    /// Files whose text is put before and after the generated content
+    pub preamble: Option<String>,
+    pub postamble: Option<String>,
+    /// Include the preamble and postamble of an HTML bundle as they are
+    /// rather than escaped
+    pub raw_html: bool,
+    /// Append to `--file` instead of truncating it
+    pub append: bool,
+    pub scope: Scope,
+    /// Write a bundle per root into this directory instead of a section per
+    /// root of a single output
+    pub output_dir: Option<String>,
+    /// Note the file and SHA-256 of each text in inline and split bundles
+    pub print_hashes: bool,
+    /// Add each package's description, upstream link and authors to inline,
+    /// markdown, html, grouped and json bundles
+    pub with_metadata: bool,
+    pub elect: ElectOptions,
+    /// Warn about license texts apparently copied from another project
+    pub check_holders: bool,
+    /// Write license texts as they are instead of normalizing their line
+    /// endings and whitespace
+    pub no_normalize: bool,
+    pub line_ending: LineEnding,
+    /// Fail if the bundle doesn't have exactly one entry per package
+    pub verify: bool,
+}
+
+/// How license texts are laid out in an inline bundle
+#[derive(Clone, Debug)]
+pub struct Layout {
+    /// Re-wrap paragraphs to this many columns, including the indentation
+    pub wrap: Option<usize>,
+    /// How many spaces each line of a license text is indented by
+    pub indent: usize,
+}
+
+impl Default for Layout {
+    fn default() -> Layout {
+        Layout {
+            wrap: None,
+            indent: 4,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub enum Bundle {
-    Inline { file: Option<String> },
-    NameOnly { file: Option<String> },
-    Source { file: Option<String> },
-    Split { file: Option<String>, dir: String },
+    Inline {
+        file: Option<String>,
+        layout: Layout,
+    },
+    NameOnly {
+        file: Option<String>,
+    },
+    Source {
+        file: Option<String>,
+    },
+    Markdown {
+        file: Option<String>,
+    },
+    Html {
+        file: Option<String>,
+    },
+    Grouped {
+        file: Option<String>,
+    },
+    Json {
+        file: Option<String>,
+    },
+    Paths {
+        file: Option<String>,
+        relative_to: Option<String>,
+    },
+    Split {
+        file: Option<String>,
+        dir: String,
+        verbatim: bool,
+        /// Empty a `dir` that already has files in it rather than failing
+        force: bool,
+    },
+    Template {
+        file: Option<String>,
+        template: String,
+    },
 }
 
 #[derive(Clone, Debug)]
 #[allow(clippy::large_enum_variant)]
 pub enum Cmd {
     List {
-        by: By,
+        options: ListOptions,
         package: SelectedPackage,
+        filter: Filter,
     },
     Check {
+        options: CheckOptions,
+        package: SelectedPackage,
+        filter: Filter,
+    },
+    Diff {
+        baseline: String,
+        format: DiffFormat,
         package: SelectedPackage,
+        filter: Filter,
     },
     Bundle {
         variant: Bundle,
+        options: BundleOptions,
         package: SelectedPackage,
+        filter: Filter,
+    },
+    Compat {
+        as_licenses: Vec<String>,
+        format: Format,
+        package: SelectedPackage,
+        filter: Filter,
+    },
+    SourceOffer {
+        format: Format,
+        package: SelectedPackage,
+        filter: Filter,
+    },
+    Graph {
+        format: GraphFormat,
+        file: Option<String>,
+        max_depth: Option<u32>,
+        package: SelectedPackage,
+        filter: Filter,
     },
     ThirdParty {
+        /// Include the license texts, as an inline bundle does, rather than
+        /// just naming the licenses
         full: bool,
+        file: Option<String>,
+    },
+    Inspect {
+        config: DiscoveryConfig,
+        package: SelectedPackage,
+        filter: Filter,
+    },
+    Fix {
+        dry_run: bool,
+        package: SelectedPackage,
+        filter: Filter,
     },
+    /// Checks the bundled license templates against each other, for
+    /// contributors changing them or the scoring
+    ValidateTemplates { config: DiscoveryConfig },
+}
+
+impl Cmd {
+    /// How the command scores license texts, if it looks for any
+    pub fn discovery_mut(&mut self) -> Option<&mut DiscoveryConfig> {
+        match self {
+            Cmd::Check { options, .. } => Some(&mut options.discovery),
+            Cmd::Bundle { options, .. } => Some(&mut options.discovery),
+            Cmd::Inspect { config, .. } => Some(config),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -52,6 +376,27 @@ pub struct Options {
     pub color: Option<String>,
     pub frozen: bool,
     pub locked: bool,
+    pub offline: bool,
+    pub manifest_path: Option<String>,
+    pub features: Vec<String>,
+    pub all_features: bool,
+    pub no_default_features: bool,
+    pub config: Vec<String>,
+    pub cargo_path: Option<String>,
+    pub metadata_json: Option<String>,
+    /// Read the packages from a `Cargo.lock` instead of running cargo
+    pub from_lockfile: Option<String>,
+    pub warnings_as_errors: bool,
+    pub fail_on_missing_text: bool,
+    /// Infer the license of packages declaring none from the
+    /// `SPDX-License-Identifier` headers of their sources
+    pub scan_source_headers: bool,
+    /// A directory of `<identifier>.txt` templates for licenses that have
+    /// none bundled, or to replace bundled ones
+    pub license_templates: Option<String>,
+    /// A `cargo vendor` directory whose copies of the packages are read in
+    /// place of their sources
+    pub vendor_dir: Option<String>,
     pub cmd: Cmd,
 }
 
@@ -60,9 +405,9 @@ impl By {
         vec![Arg::with_name("by")
             .long("by")
             .takes_value(true)
-            .possible_values(&["license", "crate"])
+            .possible_values(&["license", "crate", "license-family", "source"])
             .default_value("license")
-            .help("Whether to list crates per license or licenses per crate")]
+            .help("Whether to list crates per license, license family or source, or licenses per crate")]
     }
 
     fn from_matches(matches: &ArgMatches) -> By {
@@ -72,6 +417,89 @@ impl By {
             .parse()
             .expect("constrained")
     }
+
+    /// Whether packages are grouped by something other than their license,
+    /// which also applies to JSON output
+    pub fn groups(self) -> bool {
+        matches!(self, By::LicenseFamily | By::Source)
+    }
+}
+
+impl Scope {
+    fn arg() -> Arg<'static, 'static> {
+        Arg::with_name("scope")
+            .long("scope")
+            .takes_value(true)
+            .possible_values(&["union", "per-root"])
+            .help("Whether to take the selected packages' dependencies together, or each package's on its own in a section of its own [default: union]")
+    }
+
+    /// `--per-root` is the same as `--scope per-root`
+    fn from_matches(matches: &ArgMatches) -> Scope {
+        if matches.is_present("per-root") {
+            return Scope::PerRoot;
+        }
+        matches
+            .value_of("scope")
+            .map_or(Scope::Union, |scope| scope.parse().expect("constrained"))
+    }
+}
+
+impl ElectOptions {
+    fn args() -> Vec<Arg<'static, 'static>> {
+        vec![
+            Arg::with_name("prefer")
+                .long("prefer")
+                .takes_value(true)
+                .value_name("LICENSES")
+                .use_delimiter(true)
+                .conflicts_with("policy")
+                .help("Use packages offering a choice of licenses under the first of these comma separated licenses they offer"),
+            Arg::with_name("policy")
+                .long("policy")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Use packages offering a choice of licenses under the first one accepted by this about.toml policy"),
+        ]
+    }
+
+    fn from_matches(matches: &ArgMatches) -> ElectOptions {
+        ElectOptions {
+            prefer: matches
+                .values_of("prefer")
+                .into_iter()
+                .flatten()
+                .map(|license| {
+                    let Ok(license) = license.trim().parse();
+                    license
+                })
+                .collect(),
+            policy: matches.value_of("policy").map(ToOwned::to_owned),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        !self.prefer.is_empty() || self.policy.is_some()
+    }
+}
+
+impl Format {
+    fn arg(possible_values: &'static [&'static str]) -> Arg<'static, 'static> {
+        Arg::with_name("format")
+            .long("format")
+            .takes_value(true)
+            .possible_values(possible_values)
+            .default_value("text")
+            .help("Output format")
+    }
+
+    fn from_matches(matches: &ArgMatches) -> Format {
+        matches
+            .value_of("format")
+            .expect("defaulted")
+            .parse()
+            .expect("constrained")
+    }
 }
 
 impl SelectedPackage {
@@ -79,21 +507,26 @@ impl SelectedPackage {
         vec![
             Arg::with_name("all")
                 .long("all")
+                .alias("all-workspace")
                 .help("Apply to all packages in workspace"),
             Arg::with_name("package")
                 .short("p")
                 .long("package")
                 .takes_value(true)
-                .value_name("NAME")
-                .help("Package to apply this command to"),
+                .value_name("SPEC")
+                .multiple(true)
+                .number_of_values(1)
+                .validator(|spec| spec.parse::<PackageIdSpec>().map(|_| ()))
+                .help("Package(s) to apply this command to"),
         ]
     }
 
     fn help() -> &'static str {
         "\
-            If the --package argument is given, then NAME is a package name which \
-            indicates which package this command should apply to. If it is not given, \
-            then the current package is used.
+            If the --package argument is given, then SPEC is a package id specification \
+            (e.g. `name`, `name@version` or a url as accepted by `cargo pkgid`) which \
+            indicates which package this command should apply to, it may be given multiple \
+            times. If it is not given, then the current package is used.
 
 \
             All packages in the workspace are used if the `--all` flag is supplied. \
@@ -106,23 +539,159 @@ impl SelectedPackage {
             SelectedPackage::All
         } else {
             matches
-                .value_of("package")
-                .map(|s| s.to_owned())
+                .values_of("package")
+                .map(|specs| specs.map(|spec| spec.parse().expect("validated")).collect())
                 .map(SelectedPackage::Specific)
                 .unwrap_or(SelectedPackage::Default)
         }
     }
 }
 
+impl Filter {
+    /// Only for the subcommands whose output is about what's distributed
+    fn distribution_only_arg() -> Arg<'static, 'static> {
+        Arg::with_name("distribution-only")
+            .long("distribution-only")
+            .help("Leave out proc-macro crates along with the packages only they depend on")
+    }
+
+    /// Only for the subcommands that can work from a list of packages made
+    /// by other build tooling
+    fn packages_from_arg() -> Arg<'static, 'static> {
+        Arg::with_name("packages-from")
+            .long("packages-from")
+            .takes_value(true)
+            .value_name("FILE")
+            .conflicts_with_all(&["scope", "per-root", "output-dir", "from-lockfile"])
+            .help("Use exactly the packages listed in FILE (- for standard in) instead of the selected packages' dependencies, one name@version per line or a JSON array")
+    }
+
+    fn args() -> Vec<Arg<'static, 'static>> {
+        fn is_source_filter(value: String) -> Result<(), String> {
+            value.parse::<SourceFilter>().map(|_| ())
+        }
+
+        vec![
+            Arg::with_name("direct-only")
+                .long("direct-only")
+                .help("Only include direct dependencies of the selected packages"),
+            Arg::with_name("source-filter")
+                .long("source-filter")
+                .takes_value(true)
+                .value_name("SOURCE")
+                .default_value("all")
+                .validator(is_source_filter)
+                .help(
+                    "Only include packages from this source: \
+                     crates-io, internal, registry:<NAME|INDEX-URL>, git, git:<URL> or all",
+                ),
+            Arg::with_name("exclude")
+                .long("exclude")
+                .takes_value(true)
+                .value_name("NAME")
+                .multiple(true)
+                .number_of_values(1)
+                .help("Exclude workspace packages matching this name (supports * and ? globs)"),
+            Arg::with_name("exclude-spec")
+                .long("exclude-spec")
+                .takes_value(true)
+                .value_name("PKGID")
+                .multiple(true)
+                .number_of_values(1)
+                .validator(|spec| spec.parse::<PackageIdSpec>().map(|_| ()))
+                .help("Exclude workspace packages matching this package id specification"),
+            Arg::with_name("exclude-dep")
+                .long("exclude-dep")
+                .takes_value(true)
+                .value_name("NAME[@VERSION]")
+                .multiple(true)
+                .number_of_values(1)
+                .help("Exclude a dependency, and anything only reachable through it"),
+        ]
+    }
+
+    fn from_matches(matches: &ArgMatches) -> Filter {
+        Filter {
+            direct_only: matches.is_present("direct-only"),
+            distribution_only: matches.is_present("distribution-only"),
+            source: matches
+                .value_of("source-filter")
+                .expect("defaulted")
+                .parse()
+                .expect("validated"),
+            exclude: matches
+                .values_of("exclude")
+                .into_iter()
+                .flatten()
+                .map(ToOwned::to_owned)
+                .collect(),
+            exclude_spec: matches
+                .values_of("exclude-spec")
+                .into_iter()
+                .flatten()
+                .map(|spec| spec.parse().expect("validated"))
+                .collect(),
+            exclude_dep: matches
+                .values_of("exclude-dep")
+                .into_iter()
+                .flatten()
+                .map(|spec| match spec.split_once('@') {
+                    Some((name, version)) => DepSpec {
+                        name: name.to_owned(),
+                        version: Some(version.to_owned()),
+                    },
+                    None => DepSpec {
+                        name: spec.to_owned(),
+                        version: None,
+                    },
+                })
+                .collect(),
+            packages_from: matches.value_of("packages-from").map(ToOwned::to_owned),
+        }
+    }
+}
+
 impl Bundle {
+    fn file_arg() -> Arg<'static, 'static> {
+        Arg::with_name("file")
+            .long("file")
+            .takes_value(true)
+            .value_name("FILE")
+            .help("The file to output to (standard out if not specified)")
+    }
+
     fn args() -> Vec<Arg<'static, 'static>> {
+        fn is_columns(value: String) -> Result<(), String> {
+            match value.parse::<usize>() {
+                Ok(columns) if columns > 0 => Ok(()),
+                _ => Err(format!("'{}' is not a positive number", value)),
+            }
+        }
+
+        fn is_indent(value: String) -> Result<(), String> {
+            value
+                .parse::<usize>()
+                .map(|_| ())
+                .map_err(|_| format!("'{}' is not a number", value))
+        }
+
         vec![
             Arg::with_name("variant")
                 .long("variant")
                 .takes_value(true)
-                .possible_values(&["inline", "name-only", "source", "split"])
+                .possible_values(&[
+                    "inline",
+                    "name-only",
+                    "source",
+                    "markdown",
+                    "html",
+                    "grouped",
+                    "json",
+                    "split",
+                    "template",
+                ])
                 .default_value("inline")
-                .requires_if("split", "dir")
+                .requires_ifs(&[("split", "dir"), ("template", "template")])
                 .help("")
                 .long_help(
                     "\
@@ -130,7 +699,8 @@ What sort of bundle to produce:
 
     inline:
         Output a single file to location specified by --file containing the
-        name and content of the license used by each dependency
+        name and content of the license used by each dependency, with --wrap
+        paragraphs are re-wrapped and --indent sets the indentation of texts
 
     name-only:
         Output a single file to location specified by --file containing just
@@ -140,32 +710,209 @@ What sort of bundle to produce:
         Output a single file to location specified by --file containing Rust
         source with the name and content of the license used by each dependency
 
+    markdown:
+        Output a single Markdown file to location specified by --file with a
+        section per dependency containing its license text in a code block
+
+    html:
+        Output a standalone HTML document to location specified by --file with
+        a table of contents and a section per dependency containing its license
+        text
+
+    grouped:
+        Output a single file to location specified by --file with a section
+        per license listing the dependencies using it, followed by a single
+        copy of the license text
+
+    json:
+        Output a JSON array to location specified by --file with an object per
+        dependency containing its name, version, license, repository and the
+        chosen text of each of its licenses
+
     split:
         Output a file to location specified by --file containing the name of
         the license used by each dependency, along with a folder at the location
         specified by --dir containing the text of each dependency's license in a
-        separate file inside
+        separate file inside, with --verbatim the original license files are
+        copied byte-for-byte as <name>-<version>-<file name> instead
+
+    template:
+        Output a single file to location specified by --file rendered from the
+        Tera template specified by --template, see templates/attribution.md.tera
+        for an example of the available context
 
 \
                 ",
                 ),
-            Arg::with_name("file")
-                .long("file")
-                .takes_value(true)
-                .value_name("FILE")
-                .help("The file to output to (standard out if not specified)"),
+            Bundle::file_arg(),
             Arg::with_name("dir")
                 .long("dir")
                 .takes_value(true)
                 .value_name("DIR")
                 .help("The directory to output to"),
+            Arg::with_name("verbatim")
+                .long("verbatim")
+                .requires("dir")
+                .help("Copy the original license files into --dir instead of their text"),
+            Arg::with_name("force")
+                .long("force")
+                .requires("dir")
+                .help("Delete the files already in --dir, which otherwise fails so that texts of removed dependencies aren't left behind"),
+            Arg::with_name("template")
+                .long("template")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("The Tera template to render the bundle with"),
+            Arg::with_name("wrap")
+                .long("wrap")
+                .takes_value(true)
+                .value_name("N")
+                .validator(is_columns)
+                .help("Re-wrap paragraphs of license texts to N columns, leaving indented lines and lists as they are"),
+            Arg::with_name("indent")
+                .long("indent")
+                .takes_value(true)
+                .value_name("N")
+                .validator(is_indent)
+                .help("How many spaces to indent license texts by [default: 4]"),
+            Arg::with_name("preamble")
+                .long("preamble")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Text to put before the generated content"),
+            Arg::with_name("postamble")
+                .long("postamble")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Text to put after the generated content"),
+            Arg::with_name("preamble-raw-html")
+                .long("preamble-raw-html")
+                .help("Include the preamble and postamble of an HTML bundle as HTML instead of escaping them"),
+            Arg::with_name("append")
+                .long("append")
+                .requires("file")
+                .help("Append to the output file instead of overwriting it"),
+            Arg::with_name("print-hashes")
+                .long("print-hashes")
+                .help("Note the file each license text came from and its SHA-256 under it"),
+            Arg::with_name("with-metadata")
+                .long("with-metadata")
+                .help("Add each package's description, repository or homepage and authors to its entry"),
+            Arg::with_name("check-holders")
+                .long("check-holders")
+                .help("Warn about license texts whose copyright holder matches none of the package's name, authors or repository"),
+            Arg::with_name("no-normalize")
+                .long("no-normalize")
+                .help("Write license texts byte-for-byte instead of removing byte order marks, trailing whitespace and runs of blank lines and converting their line endings"),
+            Arg::with_name("line-endings")
+                .long("line-endings")
+                .takes_value(true)
+                .possible_values(&["lf", "crlf", "native"])
+                .conflicts_with("no-normalize")
+                .help("The line endings to write the bundle with, files copied by --verbatim are left as they are [default: lf]"),
+            Arg::with_name("verify")
+                .long("verify")
+                .help("Fail if the bundle doesn't have exactly one entry per package, even when CARGO_LICHKING_NO_VERIFY is set [default: on unless CARGO_LICHKING_NO_VERIFY is set]"),
         ]
     }
 
     fn from_matches(matches: &ArgMatches) -> Bundle {
-        match matches.value_of("variant").expect("defaulted") {
+        let variant = matches.value_of("variant").expect("defaulted");
+        if matches.is_present("verbatim") && variant != "split" {
+            clap::Error::with_description(
+                "--verbatim can only be used with --variant split",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        if matches.is_present("from-lockfile") && variant != "name-only" {
+            clap::Error::with_description(
+                "--from-lockfile can only be used with --variant name-only, the other variants need the full package sources",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        if (matches.is_present("wrap") || matches.is_present("indent")) && variant != "inline" {
+            clap::Error::with_description(
+                "--wrap and --indent can only be used with --variant inline",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        if matches.is_present("per-root") && variant == "split" {
+            clap::Error::with_description(
+                "--per-root can't be used with --variant split",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        if matches.is_present("per-root") && matches.value_of("scope") == Some("union") {
+            clap::Error::with_description(
+                "--per-root cannot be used with --scope union",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        let per_root = Scope::from_matches(matches) == Scope::PerRoot;
+        if matches.is_present("output-dir") && !per_root {
+            clap::Error::with_description(
+                "--output-dir can only be used with --per-root or --scope per-root",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        let sectioned = ["inline", "name-only", "grouped", "markdown"];
+        if per_root && !matches.is_present("output-dir") && !sectioned.contains(&variant) {
+            clap::Error::with_description(
+                "--scope per-root can only combine --variant inline, name-only, grouped or markdown bundles into one output, pass --output-dir to write a file per package",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        let framed = matches.is_present("preamble") || matches.is_present("postamble");
+        if framed && !["inline", "name-only", "grouped", "markdown", "html"].contains(&variant) {
+            clap::Error::with_description(
+                "--preamble and --postamble can only be used with --variant inline, name-only, grouped, markdown or html",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        if matches.is_present("preamble-raw-html") && (!framed || variant != "html") {
+            clap::Error::with_description(
+                "--preamble-raw-html can only be used with --variant html and a --preamble or --postamble",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        if matches.is_present("print-hashes") && !["inline", "split"].contains(&variant) {
+            clap::Error::with_description(
+                "--print-hashes can only be used with --variant inline or split, the json and template variants always include the hashes",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        if matches.is_present("with-metadata")
+            && !["inline", "markdown", "html", "grouped", "json"].contains(&variant)
+        {
+            clap::Error::with_description(
+                "--with-metadata can only be used with --variant inline, markdown, html, grouped or json",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        match variant {
             "inline" => Bundle::Inline {
                 file: matches.value_of("file").map(ToOwned::to_owned),
+                layout: Layout {
+                    wrap: matches
+                        .value_of("wrap")
+                        .map(|wrap| wrap.parse().expect("validated")),
+                    indent: matches
+                        .value_of("indent")
+                        .map_or(Layout::default().indent, |indent| {
+                            indent.parse().expect("validated")
+                        }),
+                },
             },
             "name-only" => Bundle::NameOnly {
                 file: matches.value_of("file").map(ToOwned::to_owned),
@@ -173,16 +920,191 @@ What sort of bundle to produce:
             "source" => Bundle::Source {
                 file: matches.value_of("file").map(ToOwned::to_owned),
             },
+            "markdown" => Bundle::Markdown {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+            },
+            "html" => Bundle::Html {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+            },
+            "grouped" => Bundle::Grouped {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+            },
+            "json" => Bundle::Json {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+            },
             "split" => Bundle::Split {
                 file: matches.value_of("file").map(ToOwned::to_owned),
                 dir: matches.value_of("dir").expect("required").to_owned(),
+                verbatim: matches.is_present("verbatim"),
+                force: matches.is_present("force"),
+            },
+            "template" => Bundle::Template {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+                template: matches.value_of("template").expect("required").to_owned(),
             },
             variant => panic!("Unexpected variant value {}", variant),
         }
     }
 }
 
+impl BundleOptions {
+    /// The options shared by bundle and locate, cross checking is only
+    /// available for bundle
+    fn from_matches(matches: &ArgMatches) -> BundleOptions {
+        BundleOptions {
+            discovery: DiscoveryConfig::from_matches(matches),
+            cache: matches.value_of("cache").map(ToOwned::to_owned),
+            no_cache: matches.is_present("no-cache"),
+            require_sources: matches.is_present("require-sources"),
+            cross_check: false,
+            reproducible: matches.is_present("reproducible"),
+            preamble: matches.value_of("preamble").map(ToOwned::to_owned),
+            postamble: matches.value_of("postamble").map(ToOwned::to_owned),
+            raw_html: matches.is_present("preamble-raw-html"),
+            append: matches.is_present("append"),
+            scope: Scope::from_matches(matches),
+            output_dir: matches.value_of("output-dir").map(ToOwned::to_owned),
+            print_hashes: matches.is_present("print-hashes"),
+            with_metadata: matches.is_present("with-metadata"),
+            check_holders: matches.is_present("check-holders"),
+            no_normalize: matches.is_present("no-normalize"),
+            line_ending: matches
+                .value_of("line-endings")
+                .map_or(LineEnding::Lf, |ending| {
+                    ending.parse().expect("constrained")
+                }),
+            elect: ElectOptions::default(),
+            verify: matches.is_present("verify")
+                || env::var_os(NO_VERIFY_VAR).is_none_or(|value| value.is_empty()),
+        }
+    }
+}
+
+impl DiscoveryConfig {
+    fn args() -> Vec<Arg<'static, 'static>> {
+        fn is_score(value: String) -> Result<(), String> {
+            match value.parse::<f32>() {
+                Ok(score) if (0.0..=1.0).contains(&score) => Ok(()),
+                _ => Err(format!("'{}' is not a number between 0 and 1", value)),
+            }
+        }
+
+        fn is_size(value: String) -> Result<(), String> {
+            parse_size(&value)
+                .map(|_| ())
+                .ok_or_else(|| format!("'{}' is not a size like 4194304, 512K or 4M", value))
+        }
+
+        fn is_jobs(value: String) -> Result<(), String> {
+            match value.parse::<usize>() {
+                Ok(jobs) if jobs > 0 => Ok(()),
+                _ => Err(format!("'{}' is not a positive number", value)),
+            }
+        }
+
+        vec![
+            Arg::with_name("confidence-threshold")
+                .long("confidence-threshold")
+                .takes_value(true)
+                .value_name("SCORE")
+                .default_value("0.10")
+                .validator(is_score)
+                .help("License texts scoring below this are a confident match"),
+            Arg::with_name("semi-confidence-threshold")
+                .long("semi-confidence-threshold")
+                .takes_value(true)
+                .value_name("SCORE")
+                .default_value("0.15")
+                .validator(is_score)
+                .help("License texts scoring below this are a low-confidence match"),
+            Arg::with_name("jobs")
+                .long("jobs")
+                .short("j")
+                .takes_value(true)
+                .value_name("N")
+                .validator(is_jobs)
+                .help("How many packages to search for license texts in parallel [default: number of cores]"),
+            Arg::with_name("max-license-file-size")
+                .long("max-license-file-size")
+                .takes_value(true)
+                .value_name("BYTES")
+                .default_value("4M")
+                .validator(is_size)
+                .help("Skip candidate license files larger than this, with K, M or G suffixes for binary multiples"),
+            Arg::with_name("max-search-entries")
+                .long("max-search-entries")
+                .takes_value(true)
+                .value_name("N")
+                .default_value("5000")
+                .validator(is_jobs)
+                .help("Stop searching the subdirectories of path and git packages for license files after this many entries"),
+        ]
+    }
+
+    fn from_matches(matches: &ArgMatches) -> DiscoveryConfig {
+        let config = DiscoveryConfig {
+            confident: matches
+                .value_of("confidence-threshold")
+                .expect("defaulted")
+                .parse()
+                .expect("validated"),
+            semi_confident: matches
+                .value_of("semi-confidence-threshold")
+                .expect("defaulted")
+                .parse()
+                .expect("validated"),
+            jobs: matches
+                .value_of("jobs")
+                .map(|jobs| jobs.parse().expect("validated")),
+            max_file_size: parse_size(
+                matches
+                    .value_of("max-license-file-size")
+                    .expect("defaulted"),
+            )
+            .expect("validated"),
+            max_entries: matches
+                .value_of("max-search-entries")
+                .expect("defaulted")
+                .parse()
+                .expect("validated"),
+            // Read from `--license-templates` once the options are parsed
+            templates: None,
+        };
+        if config.semi_confident < config.confident {
+            clap::Error::with_description(
+                "--semi-confidence-threshold must not be less than --confidence-threshold",
+                clap::ErrorKind::ArgumentConflict,
+            )
+            .exit();
+        }
+        config
+    }
+}
+
 impl Options {
+    /// How much of our own logging to show, `RUST_LOG` takes precedence
+    pub fn log_level(&self) -> LevelFilter {
+        if self.quiet {
+            return LevelFilter::Error;
+        }
+        match self.verbose {
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Info,
+            2 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        }
+    }
+
+    /// The command line as cargo passes it to its subcommands, with the
+    /// `lichking` argument added when run directly as `cargo-lichking`
+    pub fn command_line(args: impl IntoIterator<Item = OsString>) -> Vec<OsString> {
+        let mut args = args.into_iter().collect::<Vec<_>>();
+        if args.get(1).map(|arg| arg != "lichking").unwrap_or(true) {
+            args.insert(args.len().min(1), OsString::from("lichking"));
+        }
+        args
+    }
+
     pub fn app(subcommand_required: bool) -> App<'static, 'static> {
         App::new("cargo")
             .bin_name("cargo")
@@ -210,7 +1132,16 @@ impl Options {
             .version(clap::crate_version!())
             .about(clap::crate_description!())
             .args(&Options::args())
-            .subcommands(Options::subcommands());
+            .subcommands(Options::subcommands())
+            .after_help(
+                "\
+EXIT STATUS:
+    0    Completed without problems
+    1    Fatal error or invalid invocation
+    2    Policy failure, e.g. an incompatible or denied license
+    3    Completed with warnings, e.g. unknown compatibility or missing license
+         texts",
+            );
         if subcommand_required {
             app = app.setting(AppSettings::SubcommandRequiredElseHelp);
         }
@@ -240,61 +1171,808 @@ impl Options {
             Arg::with_name("locked")
                 .long("locked")
                 .help("Require Cargo.lock is up to date"),
+            Arg::with_name("offline")
+                .long("offline")
+                .help("Run without accessing the network"),
+            Arg::with_name("manifest-path")
+                .long("manifest-path")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("Path to Cargo.toml"),
+            Arg::with_name("workspace-root")
+                .long("workspace-root")
+                .takes_value(true)
+                .value_name("DIR")
+                .conflicts_with("manifest-path")
+                .help("Directory of the workspace's Cargo.toml, short for --manifest-path DIR/Cargo.toml"),
+            Arg::with_name("features")
+                .long("features")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("FEATURES")
+                .help("Space or comma separated list of features to resolve the dependencies with"),
+            Arg::with_name("all-features")
+                .long("all-features")
+                .help("Resolve the dependencies with all features enabled"),
+            Arg::with_name("no-default-features")
+                .long("no-default-features")
+                .help("Resolve the dependencies without the default features"),
+            Arg::with_name("config")
+                .long("config")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .value_name("KEY=VALUE")
+                .help("Override a cargo configuration value"),
+            Arg::with_name("cargo-path")
+                .long("cargo-path")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("The cargo binary to run [default: $CARGO, or cargo from PATH]"),
+            Arg::with_name("metadata-json")
+                .long("metadata-json")
+                .takes_value(true)
+                .value_name("FILE")
+                .conflicts_with_all(&[
+                    "manifest-path",
+                    "workspace-root",
+                    "frozen",
+                    "locked",
+                    "offline",
+                    "features",
+                    "all-features",
+                    "no-default-features",
+                    "config",
+                    "cargo-path",
+                ])
+                .help(
+                    "Use the output of `cargo metadata --format-version 1` instead of running it",
+                ),
+            Arg::with_name("warnings-as-errors")
+                .long("warnings-as-errors")
+                .help("Exit with the policy failure code when there are warnings"),
+            Arg::with_name("no-fail-on-missing-text")
+                .long("no-fail-on-missing-text")
+                .help("Don't treat license texts missing from a bundle as warnings"),
+            Arg::with_name("scan-source-headers")
+                .long("scan-source-headers")
+                .help("Infer the license of packages declaring none from the SPDX-License-Identifier headers of their sources"),
+            Arg::with_name("license-templates")
+                .long("license-templates")
+                .takes_value(true)
+                .value_name("DIR")
+                .help("Score license texts against the <identifier>.txt templates in this directory, for licenses named like <identifier>"),
+            Arg::with_name("vendor-dir")
+                .long("vendor-dir")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("Read the copies of packages vendored into this directory by `cargo vendor` in place of their sources"),
+            Arg::with_name("config-file")
+                .long("config-file")
+                .takes_value(true)
+                .value_name("PATH")
+                .help("Read default options from this file [default: the nearest lichking.toml]"),
+            Arg::with_name("no-config")
+                .long("no-config")
+                .conflicts_with("config-file")
+                .help("Don't read default options from a lichking.toml"),
         ]
     }
 
     pub fn subcommands() -> Vec<App<'static, 'static>> {
+        let strict_spdx = Arg::with_name("strict-spdx")
+            .long("strict-spdx")
+            .help("Don't normalize invalid SPDX license identifiers, report them as errors");
+        let yanked = [
+            Arg::with_name("check-yanked").long("check-yanked").help(
+                "Check whether crates.io dependencies have been yanked, using the local index",
+            ),
+            Arg::with_name("allow-network")
+                .long("allow-network")
+                .requires("check-yanked")
+                .help("Fetch index data missing locally from the crates.io sparse index"),
+        ];
+        let from_lockfile = Arg::with_name("from-lockfile")
+            .long("from-lockfile")
+            .takes_value(true)
+            .value_name("PATH")
+            .help("Read the packages from this Cargo.lock instead of running cargo, licenses come from the registry cache");
+        // Only so that check can say why it doesn't support it
+        let check_from_lockfile = from_lockfile.clone().hidden(true);
+
         vec![
             SubCommand::with_name("check")
                 .about("Check that all dependencies have a compatible license with a package")
+                .arg(strict_spdx.clone())
+                .args(&yanked)
+                .arg(check_from_lockfile)
+                .args(&[Arg::with_name("deny")
+                    .long("deny")
+                    .takes_value(true)
+                    .value_name("LINT")
+                    .multiple(true)
+                    .number_of_values(1)
+                    .possible_values(&[
+                        "license-change-across-versions",
+                        "undecided",
+                        "lgpl-static",
+                    ])
+                    .help("Treat the given lint as an error")])
+                .arg(
+                    Arg::with_name("fail-on")
+                        .long("fail-on")
+                        .takes_value(true)
+                        .value_name("FAMILY")
+                        .multiple(true)
+                        .number_of_values(1)
+                        .possible_values(&[
+                            "copyleft",
+                            "strong-copyleft",
+                            "network-copyleft",
+                            "unknown",
+                        ])
+                        .help("Fail if any dependency's license is of this family, whatever the root's license"),
+                )
+                .args(&[
+                    Arg::with_name("record-decisions")
+                        .long("record-decisions")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Apply and record decisions for licenses not known to be compatible"),
+                    Arg::with_name("non-interactive")
+                        .long("non-interactive")
+                        .requires("record-decisions")
+                        .help("Don't prompt for missing decisions, report them as undecided"),
+                    Arg::with_name("policy")
+                        .long("policy")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Require all dependencies to be licensed under an accepted license"),
+                    Arg::with_name("policy-format")
+                        .long("policy-format")
+                        .takes_value(true)
+                        .possible_values(&["about"])
+                        .default_value("about")
+                        .help(
+                            "Format of the --policy file, `about` reads a cargo-about about.toml",
+                        ),
+                    Arg::with_name("target-kind")
+                        .long("target-kind")
+                        .takes_value(true)
+                        .possible_values(&["bin", "lib", "all"])
+                        .default_value("all")
+                        .help("Only check packages that build a binary or a library"),
+                    Arg::with_name("linkage")
+                        .long("linkage")
+                        .takes_value(true)
+                        .possible_values(&["static", "dynamic"])
+                        .default_value("static")
+                        .help("How dependencies are linked, which decides whether LGPL dependencies are compatible with permissive licenses"),
+                    Arg::with_name("no-cross-check")
+                        .long("no-cross-check")
+                        .help("Don't check declared licenses against the packages' license files"),
+                    Arg::with_name("against")
+                        .long("against")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .value_name("LICENSE")
+                        .validator(is_license_expression)
+                        .help("Check as if the roots were licensed under this SPDX expression instead, repeat to compare several"),
+                    Arg::with_name("assume-private")
+                        .long("assume-private")
+                        .help("Check roots declaring no license as all rights reserved and not distributed, as is done for those with `publish = false`"),
+                ])
+                .arg(Filter::distribution_only_arg())
+                .arg(Filter::packages_from_arg())
+                .args(&DiscoveryConfig::args())
                 .args(&SelectedPackage::args())
+                .args(&Filter::args())
                 .after_help(SelectedPackage::help()),
             SubCommand::with_name("list")
                 .about("List licensing of all dependencies")
                 .args(&By::args())
+                .arg(Format::arg(&["text", "json", "cyclonedx"]))
+                .args(&[
+                    Arg::with_name("reproducible").long("reproducible").help(
+                        "Derive the CycloneDX serial number from its content instead of randomly",
+                    ),
+                    Arg::with_name("save-baseline")
+                        .long("save-baseline")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("Also save the package licenses as a baseline for the diff command"),
+                    strict_spdx,
+                    Arg::with_name("summary")
+                        .long("summary")
+                        .help("Only print the number of packages per license"),
+                    Arg::with_name("obligations")
+                        .long("obligations")
+                        .conflicts_with("summary")
+                        .help("Print what each license obliges along with the number of packages"),
+                    Arg::with_name("include-roots")
+                        .long("include-roots")
+                        .help("List the selected packages themselves, marked as workspace roots (the default)"),
+                    Arg::with_name("exclude-roots")
+                        .long("exclude-roots")
+                        .conflicts_with("include-roots")
+                        .help("Only list the dependencies of the selected packages"),
+                    Arg::with_name("filter")
+                        .long("filter")
+                        .takes_value(true)
+                        .value_name("LICENSES")
+                        .validator(|value| value.parse::<LicenseFilter>().map(|_| ()))
+                        .help("Only list licenses that are unknown, copyleft, permissive or one of a comma separated list of SPDX identifiers"),
+                    Arg::with_name("unknown-only")
+                        .long("unknown-only")
+                        .conflicts_with("filter")
+                        .help("Only list custom, file and unspecified licenses, same as --filter unknown"),
+                ])
+                .args(&yanked)
+                .arg(Scope::arg())
+                .args(&ElectOptions::args())
+                .arg(from_lockfile.clone())
+                .arg(Filter::packages_from_arg())
+                .args(&SelectedPackage::args())
+                .args(&Filter::args())
+                .after_help(SelectedPackage::help()),
+            SubCommand::with_name("diff")
+                .about("Compare dependencies licenses against a saved baseline")
+                .args(&[Arg::with_name("baseline")
+                    .long("baseline")
+                    .takes_value(true)
+                    .value_name("FILE")
+                    .required(true)
+                    .help("Baseline previously saved with `list --save-baseline`")])
+                .arg(Format::arg(&["text", "json"]))
                 .args(&SelectedPackage::args())
+                .args(&Filter::args())
                 .after_help(SelectedPackage::help()),
             SubCommand::with_name("bundle")
                 .about("Bundle all dependencies licenses ready for distribution")
                 .args(&Bundle::args())
+                .arg(Filter::distribution_only_arg())
+                .arg(Filter::packages_from_arg())
+                .args(&DiscoveryConfig::args())
+                .args(&Options::cache_args())
+                .arg(
+                    Arg::with_name("cross-check")
+                        .long("cross-check")
+                        .help("Fail if a license file confidently matches an undeclared license"),
+                )
+                .arg(Scope::arg().conflicts_with("from-lockfile"))
+                .args(&ElectOptions::args())
+                .arg(
+                    Arg::with_name("per-root")
+                        .long("per-root")
+                        .conflicts_with("from-lockfile")
+                        .help("Bundle each selected package's dependencies separately, same as --scope per-root"),
+                )
+                .arg(
+                    Arg::with_name("output-dir")
+                        .long("output-dir")
+                        .takes_value(true)
+                        .value_name("DIR")
+                        .conflicts_with_all(&["file", "append", "from-lockfile"])
+                        .help("The directory to write the --per-root bundles and their index to, one file each"),
+                )
+                .arg(from_lockfile)
+                .args(&SelectedPackage::args())
+                .args(&Filter::args())
+                .after_help(SelectedPackage::help()),
+            SubCommand::with_name("locate")
+                .about("List the license files chosen for each dependency")
+                .args(&[
+                    Arg::with_name("file")
+                        .long("file")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("The file to output to (standard out if not specified)"),
+                    Arg::with_name("relative-to")
+                        .long("relative-to")
+                        .takes_value(true)
+                        .value_name("DIR")
+                        .help("Output paths relative to this directory instead of absolute"),
+                ])
+                .args(&DiscoveryConfig::args())
+                .args(&Options::cache_args())
+                .args(&SelectedPackage::args())
+                .args(&Filter::args())
+                .after_help(SelectedPackage::help()),
+            SubCommand::with_name("compat")
+                .about("Show which dependency licenses each root license could include")
+                .arg(
+                    Arg::with_name("as")
+                        .long("as")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .value_name("LICENSE")
+                        .help("Also show compatibility with this root license"),
+                )
+                .arg(Format::arg(&["text", "json"]))
+                .args(&SelectedPackage::args())
+                .args(&Filter::args())
+                .after_help(SelectedPackage::help()),
+            SubCommand::with_name("source-offer")
+                .about("List the packages whose license requires offering their source, with where it's from")
+                .arg(Format::arg(&["text", "json"]))
                 .args(&SelectedPackage::args())
+                .args(&Filter::args())
+                .after_help(SelectedPackage::help()),
+            SubCommand::with_name("graph")
+                .about("Output the dependency graph annotated with licenses")
+                .args(&[
+                    Arg::with_name("format")
+                        .long("format")
+                        .takes_value(true)
+                        .possible_values(&["dot", "mermaid"])
+                        .default_value("dot")
+                        .help("Output a Graphviz DOT graph or a Mermaid flowchart"),
+                    Arg::with_name("file")
+                        .long("file")
+                        .takes_value(true)
+                        .value_name("FILE")
+                        .help("The file to output to (standard out if not specified)"),
+                    Arg::with_name("max-depth")
+                        .long("max-depth")
+                        .takes_value(true)
+                        .value_name("DEPTH")
+                        .validator(|value| {
+                            value
+                                .parse::<u32>()
+                                .map(drop)
+                                .map_err(|_| format!("'{}' is not a non-negative number", value))
+                        })
+                        .help("Only include dependencies up to this many levels below the roots"),
+                ])
+                .args(&SelectedPackage::args())
+                .args(&Filter::args())
+                .after_help(SelectedPackage::help()),
+            SubCommand::with_name("inspect")
+                .about("Show how license texts are discovered for a package and why each file was chosen or skipped")
+                .args(&DiscoveryConfig::args())
+                .args(&SelectedPackage::args())
+                .args(&Filter::args())
+                .after_help(SelectedPackage::help()),
+            SubCommand::with_name("fix")
+                .about("Add missing license files to local packages from the bundled license texts")
+                .arg(
+                    Arg::with_name("dry-run")
+                        .long("dry-run")
+                        .help("Only list the license files that would be created"),
+                )
+                .args(&SelectedPackage::args())
+                .args(&Filter::args())
                 .after_help(SelectedPackage::help()),
             SubCommand::with_name("thirdparty")
                 .about("List dependencies of cargo-lichking")
-                .args(&[Arg::with_name("full")
-                    .long("full")
-                    .help("Whether to list license content for each dependency")]),
+                .args(&[
+                    Arg::with_name("variant")
+                        .long("variant")
+                        .takes_value(true)
+                        .possible_values(&["inline", "name-only"])
+                        .default_value("name-only")
+                        .help("Whether to include the license texts, see bundle --help"),
+                    Arg::with_name("full")
+                        .long("full")
+                        .conflicts_with("variant")
+                        .help("Whether to list license content for each dependency, same as --variant inline"),
+                    Bundle::file_arg(),
+                ]),
+            SubCommand::with_name("validate-templates")
+                .about("Check the bundled license templates match themselves and can be told apart")
+                .setting(AppSettings::Hidden)
+                .args(&DiscoveryConfig::args()),
         ]
     }
 
-    pub fn from_matches(matches: &ArgMatches) -> Options {
+    fn cache_args() -> Vec<Arg<'static, 'static>> {
+        vec![
+            Arg::with_name("cache")
+                .long("cache")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Where to cache discovered license texts [default: $CARGO_HOME/lichking-cache.json]"),
+            Arg::with_name("no-cache")
+                .long("no-cache")
+                .conflicts_with("cache")
+                .help("Don't use the discovered license text cache"),
+            Arg::with_name("require-sources")
+                .long("require-sources")
+                .help("Fail immediately if a package's sources are not available locally"),
+            Arg::with_name("reproducible")
+                .long("reproducible")
+                .help("Leave out timestamps and make paths independent of the machine"),
+        ]
+    }
+
+    pub fn from_matches(matches: &ArgMatches) -> Result<Options, OptionsError> {
         let matches = matches.subcommand_matches("lichking").expect("required");
-        Options {
-            verbose: matches.occurrences_of("verbose") as u32,
-            quiet: matches.is_present("quiet"),
+        let verbose = matches.occurrences_of("verbose") as u32;
+        let quiet = matches.is_present("quiet");
+        if quiet && verbose > 0 {
+            return Err(OptionsError::QuietAndVerbose);
+        }
+        let from_lockfile = matches
+            .subcommand()
+            .1
+            .and_then(|matches| matches.value_of("from-lockfile"));
+        if from_lockfile.is_some()
+            && ["metadata-json", "manifest-path", "workspace-root"]
+                .iter()
+                .any(|arg| matches.is_present(arg))
+        {
+            return Err(OptionsError::LockfileWithMetadata);
+        }
+        if from_lockfile.is_some() && matches.subcommand_name() == Some("check") {
+            return Err(OptionsError::LockfileWithCheck);
+        }
+        // As with cargo `--frozen` is `--locked` and `--offline` together
+        let frozen = matches.is_present("frozen");
+        Ok(Options {
+            verbose,
+            quiet,
             color: matches.value_of("color").map(ToOwned::to_owned),
-            frozen: matches.is_present("frozen"),
-            locked: matches.is_present("locked"),
+            frozen,
+            locked: frozen || matches.is_present("locked"),
+            offline: frozen || matches.is_present("offline"),
+            manifest_path: matches
+                .value_of("manifest-path")
+                .map(ToOwned::to_owned)
+                .or_else(|| {
+                    let root = Path::new(matches.value_of("workspace-root")?);
+                    Some(root.join("Cargo.toml").display().to_string())
+                }),
+            features: matches
+                .values_of("features")
+                .into_iter()
+                .flatten()
+                .map(ToOwned::to_owned)
+                .collect(),
+            all_features: matches.is_present("all-features"),
+            no_default_features: matches.is_present("no-default-features"),
+            config: matches
+                .values_of("config")
+                .into_iter()
+                .flatten()
+                .map(ToOwned::to_owned)
+                .collect(),
+            cargo_path: matches.value_of("cargo-path").map(ToOwned::to_owned),
+            metadata_json: matches.value_of("metadata-json").map(ToOwned::to_owned),
+            from_lockfile: from_lockfile.map(ToOwned::to_owned),
+            warnings_as_errors: matches.is_present("warnings-as-errors"),
+            fail_on_missing_text: !matches.is_present("no-fail-on-missing-text"),
+            scan_source_headers: matches.is_present("scan-source-headers"),
+            license_templates: matches.value_of("license-templates").map(ToOwned::to_owned),
+            vendor_dir: matches.value_of("vendor-dir").map(ToOwned::to_owned),
             cmd: match matches.subcommand() {
                 ("check", Some(matches)) => Cmd::Check {
+                    options: CheckOptions {
+                        strict_spdx: matches.is_present("strict-spdx"),
+                        deny_license_changes: matches
+                            .values_of("deny")
+                            .into_iter()
+                            .flatten()
+                            .any(|lint| lint == "license-change-across-versions"),
+                        deny_undecided: matches
+                            .values_of("deny")
+                            .into_iter()
+                            .flatten()
+                            .any(|lint| lint == "undecided"),
+                        deny_lgpl_static: matches
+                            .values_of("deny")
+                            .into_iter()
+                            .flatten()
+                            .any(|lint| lint == "lgpl-static"),
+                        linkage: matches
+                            .value_of("linkage")
+                            .expect("defaulted")
+                            .parse()
+                            .expect("constrained"),
+                        record_decisions: matches
+                            .value_of("record-decisions")
+                            .map(ToOwned::to_owned),
+                        non_interactive: matches.is_present("non-interactive"),
+                        policy: matches.value_of("policy").map(ToOwned::to_owned),
+                        verbose: verbose > 0,
+                        check_yanked: matches.is_present("check-yanked"),
+                        allow_network: matches.is_present("allow-network"),
+                        target_kind: match matches.value_of("target-kind").expect("defaulted") {
+                            "all" => TargetKind::All,
+                            "bin" => TargetKind::Bin,
+                            "lib" => TargetKind::Lib,
+                            kind => panic!("Unexpected target kind {}", kind),
+                        },
+                        cross_check: !matches.is_present("no-cross-check"),
+                        discovery: DiscoveryConfig::from_matches(matches),
+                        against: matches
+                            .values_of("against")
+                            .into_iter()
+                            .flatten()
+                            .map(ToOwned::to_owned)
+                            .collect(),
+                        fail_on: matches
+                            .values_of("fail-on")
+                            .into_iter()
+                            .flatten()
+                            .map(|family| family.parse().expect("constrained"))
+                            .collect(),
+                        assume_private: matches.is_present("assume-private"),
+                    },
                     package: SelectedPackage::from_matches(matches),
+                    filter: Filter::from_matches(matches),
                 },
-                ("list", Some(matches)) => Cmd::List {
-                    by: By::from_matches(matches),
+                ("list", Some(matches)) => {
+                    if matches.value_of("scope") == Some("per-root")
+                        && matches.value_of("format") == Some("cyclonedx")
+                    {
+                        clap::Error::with_description(
+                            "--scope per-root can only be used with --format text or json, a CycloneDX document describes a single component",
+                            clap::ErrorKind::ArgumentConflict,
+                        )
+                        .exit();
+                    }
+                    let elect = ElectOptions::from_matches(matches);
+                    if elect.enabled() && matches.value_of("format") != Some("text") {
+                        clap::Error::with_description(
+                            "--prefer and --policy can only be used with --format text, the json and cyclonedx formats describe the licenses as declared",
+                            clap::ErrorKind::ArgumentConflict,
+                        )
+                        .exit();
+                    }
+                    Cmd::List {
+                        options: ListOptions {
+                            by: By::from_matches(matches),
+                            format: Format::from_matches(matches),
+                            reproducible: matches.is_present("reproducible"),
+                            save_baseline: matches.value_of("save-baseline").map(ToOwned::to_owned),
+                            strict_spdx: matches.is_present("strict-spdx"),
+                            summary: matches.is_present("summary"),
+                            obligations: matches.is_present("obligations"),
+                            check_yanked: matches.is_present("check-yanked"),
+                            allow_network: matches.is_present("allow-network"),
+                            exclude_roots: matches.is_present("exclude-roots"),
+                            license_filter: if matches.is_present("unknown-only") {
+                                Some(LicenseFilter::Unknown)
+                            } else {
+                                matches
+                                    .value_of("filter")
+                                    .map(|filter| filter.parse().expect("validated"))
+                            },
+                            scope: Scope::from_matches(matches),
+                            elect,
+                            verbose: verbose > 0,
+                        },
+                        package: SelectedPackage::from_matches(matches),
+                        filter: Filter::from_matches(matches),
+                    }
+                }
+                ("diff", Some(matches)) => Cmd::Diff {
+                    baseline: matches.value_of("baseline").expect("required").to_owned(),
+                    format: matches
+                        .value_of("format")
+                        .expect("defaulted")
+                        .parse()
+                        .expect("constrained"),
                     package: SelectedPackage::from_matches(matches),
+                    filter: Filter::from_matches(matches),
                 },
                 ("bundle", Some(matches)) => Cmd::Bundle {
                     variant: Bundle::from_matches(matches),
+                    options: BundleOptions {
+                        cross_check: matches.is_present("cross-check"),
+                        elect: ElectOptions::from_matches(matches),
+                        ..BundleOptions::from_matches(matches)
+                    },
+                    package: SelectedPackage::from_matches(matches),
+                    filter: Filter::from_matches(matches),
+                },
+                ("locate", Some(matches)) => Cmd::Bundle {
+                    variant: Bundle::Paths {
+                        file: matches.value_of("file").map(ToOwned::to_owned),
+                        relative_to: matches.value_of("relative-to").map(ToOwned::to_owned),
+                    },
+                    options: BundleOptions::from_matches(matches),
+                    package: SelectedPackage::from_matches(matches),
+                    filter: Filter::from_matches(matches),
+                },
+                ("compat", Some(matches)) => Cmd::Compat {
+                    as_licenses: matches
+                        .values_of("as")
+                        .into_iter()
+                        .flatten()
+                        .map(ToOwned::to_owned)
+                        .collect(),
+                    format: Format::from_matches(matches),
+                    package: SelectedPackage::from_matches(matches),
+                    filter: Filter::from_matches(matches),
+                },
+                ("source-offer", Some(matches)) => Cmd::SourceOffer {
+                    format: Format::from_matches(matches),
+                    package: SelectedPackage::from_matches(matches),
+                    filter: Filter::from_matches(matches),
+                },
+                ("graph", Some(matches)) => Cmd::Graph {
+                    format: matches
+                        .value_of("format")
+                        .expect("defaulted")
+                        .parse()
+                        .expect("constrained"),
+                    file: matches.value_of("file").map(ToOwned::to_owned),
+                    max_depth: matches
+                        .value_of("max-depth")
+                        .map(|depth| depth.parse().expect("validated")),
+                    package: SelectedPackage::from_matches(matches),
+                    filter: Filter::from_matches(matches),
+                },
+                ("inspect", Some(matches)) => Cmd::Inspect {
+                    config: DiscoveryConfig::from_matches(matches),
                     package: SelectedPackage::from_matches(matches),
+                    filter: Filter::from_matches(matches),
+                },
+                ("fix", Some(matches)) => Cmd::Fix {
+                    dry_run: matches.is_present("dry-run"),
+                    package: SelectedPackage::from_matches(matches),
+                    filter: Filter::from_matches(matches),
                 },
                 ("thirdparty", Some(matches)) => Cmd::ThirdParty {
-                    full: matches.is_present("full"),
+                    full: matches.is_present("full")
+                        || matches.value_of("variant") == Some("inline"),
+                    file: matches.value_of("file").map(ToOwned::to_owned),
+                },
+                ("validate-templates", Some(matches)) => Cmd::ValidateTemplates {
+                    config: DiscoveryConfig::from_matches(matches),
                 },
                 (subcommand, _) => {
-                    Options::app(true).get_matches();
+                    Options::app(true).get_matches_from(Options::command_line(env::args_os()));
                     panic!("Unexpected subcommand {}", subcommand)
                 }
             },
+        })
+    }
+}
+
+impl Options {
+    /// Fills in options not given on the command line from a config file,
+    /// either `--config-file` or the nearest `lichking.toml` to the manifest or
+    /// current directory, which it's fine not to find. The config's options
+    /// are added to the command line `args` and it's parsed again, so they're
+    /// validated just the same. Options conflicting with any given on the
+    /// command line are left out, the command line always wins.
+    pub fn merge(self, matches: &ArgMatches, args: &[OsString]) -> Result<Options, OptionsError> {
+        let matches = matches.subcommand_matches("lichking").expect("required");
+        if matches.is_present("no-config") {
+            return Ok(self);
+        }
+        let path = match matches.value_of("config-file") {
+            Some(path) => PathBuf::from(path),
+            None => {
+                let dir = match &self.manifest_path {
+                    Some(manifest_path) => Path::new(manifest_path)
+                        .parent()
+                        .unwrap_or(Path::new(""))
+                        .to_owned(),
+                    None => env::current_dir().unwrap_or_default(),
+                };
+                match Config::find(&dir) {
+                    Some(path) => path,
+                    None => return Ok(self),
+                }
+            }
+        };
+        let config = Config::load(&path).map_err(|err| OptionsError::Config(err.to_string()))?;
+
+        let as_arg = |(name, value): (&str, Option<String>)| match value {
+            Some(value) => OsString::from(format!("--{}={}", name, value)),
+            None => OsString::from(format!("--{}", name)),
+        };
+        // Whether the option clap rejects together with the command line, e.g.
+        // a `file` for bundles with `--output-dir`
+        let conflicts = |at: Option<usize>, arg: &OsString| {
+            let mut trial = args.to_vec();
+            match at {
+                Some(at) => trial.insert(at, arg.clone()),
+                None => trial.push(arg.clone()),
+            }
+            let conflicts = matches!(
+                Options::app(false).get_matches_from_safe(trial),
+                Err(err) if err.kind == clap::ErrorKind::ArgumentConflict
+            );
+            if conflicts {
+                log::debug!(
+                    "Leaving out {:?} from {}, it conflicts with the command line",
+                    arg,
+                    path.display()
+                );
+            }
+            conflicts
+        };
+        let mut merged = args.to_vec();
+        // Before the subcommand, right after `lichking`
+        let defaults = config
+            .defaults()
+            .into_iter()
+            .filter(|(name, _)| matches.occurrences_of(name) == 0)
+            .map(as_arg)
+            .filter(|arg| !conflicts(Some(2), arg))
+            .collect::<Vec<_>>();
+        merged.splice(2..2, defaults);
+        if let (subcommand, Some(matches)) = matches.subcommand() {
+            merged.extend(
+                config
+                    .subcommand(subcommand)
+                    .into_iter()
+                    .filter(|(name, _)| matches.occurrences_of(name) == 0)
+                    .map(as_arg)
+                    .filter(|arg| !conflicts(None, arg)),
+            );
+        }
+        log::debug!("Merged {} into {:?}", path.display(), merged);
+
+        let matches = Options::app(false)
+            .get_matches_from_safe(merged)
+            .map_err(|err| {
+                // Without clap's colors, it's shown as part of our own error
+                let message = Regex::new("\x1b\\[[0-9;]*m")
+                    .unwrap()
+                    .replace_all(&err.message, "")
+                    .into_owned();
+                let message = message.lines().next().unwrap_or_default();
+                OptionsError::Config(format!(
+                    "Invalid option from {}: {}",
+                    path.display(),
+                    message.trim_start_matches("error: ")
+                ))
+            })?;
+        Options::from_matches(&matches)
+    }
+}
+
+/// Combinations of arguments that clap can't rule out on its own
+#[derive(Debug, PartialEq, Eq)]
+pub enum OptionsError {
+    QuietAndVerbose,
+    LockfileWithMetadata,
+    LockfileWithCheck,
+    /// An unreadable or invalid config file
+    Config(String),
+}
+
+impl std::fmt::Display for OptionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            OptionsError::QuietAndVerbose => write!(f, "--quiet cannot be used with --verbose"),
+            OptionsError::LockfileWithMetadata => write!(
+                f,
+                "--from-lockfile can't be used with --metadata-json, --manifest-path or --workspace-root"
+            ),
+            OptionsError::LockfileWithCheck => write!(
+                f,
+                "--from-lockfile can't be used with check, it needs the dependency kinds and targets only cargo metadata has"
+            ),
+            OptionsError::Config(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for OptionsError {}
+
+impl From<OptionsError> for clap::Error {
+    fn from(err: OptionsError) -> clap::Error {
+        clap::Error::with_description(&err.to_string(), clap::ErrorKind::ArgumentConflict)
+    }
+}
+
+impl FromStr for LineEnding {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lf" => Ok(LineEnding::Lf),
+            "crlf" => Ok(LineEnding::Crlf),
+            "native" => Ok(LineEnding::native()),
+            s => Err(format!("Cannot parse LineEnding from '{}'", s)),
         }
     }
 }
@@ -305,7 +1983,498 @@ impl FromStr for By {
         match s {
             "license" => Ok(By::License),
             "crate" => Ok(By::Crate),
+            "license-family" => Ok(By::LicenseFamily),
+            "source" => Ok(By::Source),
             s => Err(format!("Cannot parse By from '{}'", s)),
         }
     }
 }
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "cyclonedx" => Ok(Format::CycloneDx),
+            s => Err(format!("Cannot parse Format from '{}'", s)),
+        }
+    }
+}
+
+impl FromStr for DiffFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(DiffFormat::Text),
+            "json" => Ok(DiffFormat::Json),
+            s => Err(format!("Cannot parse DiffFormat from '{}'", s)),
+        }
+    }
+}
+
+impl FromStr for Linkage {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "static" => Ok(Linkage::Static),
+            "dynamic" => Ok(Linkage::Dynamic),
+            s => Err(format!("Cannot parse Linkage from '{}'", s)),
+        }
+    }
+}
+
+impl FromStr for GraphFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dot" => Ok(GraphFormat::Dot),
+            "mermaid" => Ok(GraphFormat::Mermaid),
+            s => Err(format!("Cannot parse GraphFormat from '{}'", s)),
+        }
+    }
+}
+
+impl FromStr for SourceFilter {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(SourceFilter::All),
+            "crates-io" => Ok(SourceFilter::CratesIo),
+            "internal" => Ok(SourceFilter::Internal),
+            "git" => Ok(SourceFilter::Git(None)),
+            s => match s.split_once(':') {
+                Some(("git", url)) if !url.is_empty() => {
+                    Ok(SourceFilter::Git(Some(url.to_owned())))
+                }
+                Some(("registry", url)) if url.contains("://") => {
+                    Ok(SourceFilter::Registry(registry_url(url)))
+                }
+                Some(("registry", name)) if !name.is_empty() => registry_index(name)
+                    .map(SourceFilter::Registry)
+                    .ok_or_else(|| format!("No registry named {} is configured for cargo", name)),
+                _ => Err(format!("Cannot parse SourceFilter from '{}'", s)),
+            },
+        }
+    }
+}
+
+/// Parses a number of bytes, optionally with a `K`, `M` or `G` suffix
+fn parse_size(value: &str) -> Option<u64> {
+    let (number, multiplier) = match value.char_indices().last()? {
+        (index, 'K' | 'k') => (&value[..index], 1 << 10),
+        (index, 'M' | 'm') => (&value[..index], 1 << 20),
+        (index, 'G' | 'g') => (&value[..index], 1 << 30),
+        _ => (value, 1),
+    };
+    number.parse::<u64>().ok()?.checked_mul(multiplier)
+}
+
+/// Accepts license expressions made up only of recognised licenses, a
+/// hypothetical root license we know nothing about can't be checked
+fn is_license_expression(value: String) -> Result<(), String> {
+    let (_, diagnostics) = License::parse_with_diagnostics(&value, false);
+    match diagnostics.iter().find(|diagnostic| {
+        matches!(
+            diagnostic,
+            ParseDiagnostic::Ambiguous { .. }
+                | ParseDiagnostic::Unknown { .. }
+                | ParseDiagnostic::Empty
+                | ParseDiagnostic::Malformed { .. }
+        )
+    }) {
+        Some(diagnostic) => Err(diagnostic.to_string()),
+        None => Ok(()),
+    }
+}
+
+impl FromStr for Scope {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "union" => Ok(Scope::Union),
+            "per-root" => Ok(Scope::PerRoot),
+            s => Err(format!("Cannot parse Scope from '{}'", s)),
+        }
+    }
+}
+
+impl FromStr for FailOn {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "copyleft" => Ok(FailOn::Copyleft),
+            "strong-copyleft" => Ok(FailOn::StrongCopyleft),
+            "network-copyleft" => Ok(FailOn::NetworkCopyleft),
+            "unknown" => Ok(FailOn::Unknown),
+            s => Err(format!("Cannot parse FailOn from '{}'", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for FailOn {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            FailOn::Copyleft => "copyleft",
+            FailOn::StrongCopyleft => "strong-copyleft",
+            FailOn::NetworkCopyleft => "network-copyleft",
+            FailOn::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for LicenseFilter {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unknown" => Ok(LicenseFilter::Unknown),
+            "copyleft" => Ok(LicenseFilter::Copyleft),
+            "permissive" => Ok(LicenseFilter::Permissive),
+            s => {
+                let mut licenses = Vec::new();
+                for id in s.split(',').map(str::trim) {
+                    match id.parse::<License>().unwrap() {
+                        License::Unspecified => {
+                            return Err(format!(
+                                "'{}' is not unknown, copyleft, permissive or a list of SPDX license identifiers",
+                                s
+                            ))
+                        }
+                        License::Custom(_) => {
+                            return Err(format!("'{}' is not a known SPDX license identifier", id))
+                        }
+                        license => licenses.push(license.to_string()),
+                    }
+                }
+                Ok(LicenseFilter::Licenses(licenses))
+            }
+        }
+    }
+}
+
+impl FromStr for PackageIdSpec {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn name_and_version(s: &str) -> (Option<String>, Option<String>) {
+            let (name, version) = match s.find(['@', ':']) {
+                Some(index) => (&s[..index], Some(&s[index + 1..])),
+                None if s.starts_with(|c: char| c.is_ascii_digit()) => ("", Some(s)),
+                None => (s, None),
+            };
+            let name = Some(name.to_owned()).filter(|name| !name.is_empty());
+            (name, version.map(ToOwned::to_owned))
+        }
+
+        if s.is_empty() {
+            return Err("Package id specification cannot be empty".to_owned());
+        }
+
+        let spec = if s.contains("://") {
+            let (url, fragment) = match s.split_once('#') {
+                Some((url, fragment)) => (url, Some(fragment)),
+                None => (s, None),
+            };
+            let (name, version) = fragment.map(name_and_version).unwrap_or_default();
+            // Without a name in the fragment the last path segment of the url
+            // is the name, like cargo, the host is never a name
+            let name = name.or_else(|| {
+                let (_, rest) = url.split_once("://")?;
+                let (_, path) = rest.split_once('/')?;
+                path.trim_end_matches('/')
+                    .rsplit('/')
+                    .next()
+                    .filter(|segment| !segment.is_empty())
+                    .map(ToOwned::to_owned)
+            });
+            PackageIdSpec {
+                name,
+                version,
+                url: Some(url.to_owned()),
+            }
+        } else {
+            let (name, version) = name_and_version(s);
+            PackageIdSpec {
+                name,
+                version,
+                url: None,
+            }
+        };
+
+        if spec.name.is_none() {
+            return Err(format!("Package id specification '{}' has no name", s));
+        }
+        if let Some(version) = &spec.version {
+            let valid = version
+                .split(['.', '-', '+'])
+                .next()
+                .is_some_and(|major| major.parse::<u64>().is_ok());
+            if !valid {
+                return Err(format!(
+                    "Invalid version '{}' in package id specification '{}'",
+                    version, s
+                ));
+            }
+        }
+        Ok(spec)
+    }
+}
+
+impl std::fmt::Display for PackageIdSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(url) = &self.url {
+            write!(f, "{}#", url)?;
+        }
+        write!(f, "{}", self.name.as_deref().unwrap_or_default())?;
+        if let Some(version) = &self.version {
+            write!(f, "@{}", version)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses `args` as given after `cargo lichking`
+    fn parse(args: &[&str]) -> Result<Options, clap::Error> {
+        let args = ["cargo", "lichking"].iter().chain(args);
+        Ok(Options::from_matches(
+            &Options::app(true).get_matches_from_safe(args)?,
+        )?)
+    }
+
+    fn spec(name: &str, version: Option<&str>, url: Option<&str>) -> PackageIdSpec {
+        PackageIdSpec {
+            name: Some(name.to_owned()),
+            version: version.map(ToOwned::to_owned),
+            url: url.map(ToOwned::to_owned),
+        }
+    }
+
+    #[test]
+    fn package_id_specs() {
+        let index = "https://github.com/rust-lang/crates.io-index";
+        let cases = vec![
+            ("serde", spec("serde", None, None)),
+            ("serde@1.0.104", spec("serde", Some("1.0.104"), None)),
+            ("serde:1.0", spec("serde", Some("1.0"), None)),
+            (
+                "https://github.com/rust-lang/crates.io-index#serde@1.0.104",
+                spec("serde", Some("1.0.104"), Some(index)),
+            ),
+            (
+                "https://github.com/someone/forked#1.2.3",
+                spec(
+                    "forked",
+                    Some("1.2.3"),
+                    Some("https://github.com/someone/forked"),
+                ),
+            ),
+            (
+                "file:///work/app/",
+                spec("app", None, Some("file:///work/app/")),
+            ),
+        ];
+        for (input, expected) in cases {
+            let parsed = input.parse::<PackageIdSpec>().unwrap();
+            assert_eq!(parsed, expected, "{}", input);
+            assert_eq!(parsed.to_string().parse(), Ok(parsed), "{}", input);
+        }
+
+        for invalid in &[
+            "",
+            "@1.0.0",
+            "serde@one",
+            "https://example.com/#1.0.0",
+            "https://example.com",
+        ] {
+            assert!(invalid.parse::<PackageIdSpec>().is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn packages_are_repeatable() {
+        let options = parse(&["list", "-p", "app", "--package", "serde@1.0.104"]).unwrap();
+        match options.cmd {
+            Cmd::List { package, .. } => assert!(matches!(
+                package,
+                SelectedPackage::Specific(specs)
+                    if specs == [spec("app", None, None), spec("serde", Some("1.0.104"), None)]
+            )),
+            _ => panic!("not list"),
+        }
+    }
+
+    #[test]
+    fn offline() {
+        let options = parse(&["list"]).unwrap();
+        assert!(!options.offline && !options.locked && !options.frozen);
+
+        let options = parse(&["--offline", "list"]).unwrap();
+        assert!(options.offline && !options.locked && !options.frozen);
+
+        let options = parse(&["--frozen", "list"]).unwrap();
+        assert!(options.frozen && options.locked && options.offline);
+
+        let options = parse(&["--frozen", "--locked", "--offline", "list"]).unwrap();
+        assert!(options.frozen && options.locked && options.offline);
+
+        let options = parse(&["--locked", "--offline", "list"]).unwrap();
+        assert!(!options.frozen && options.locked && options.offline);
+    }
+
+    #[test]
+    fn conflicting_options() {
+        let error = |args: &[&str]| {
+            let matches = Options::app(true)
+                .get_matches_from_safe(["cargo", "lichking"].iter().chain(args))
+                .unwrap();
+            Options::from_matches(&matches).err()
+        };
+        assert_eq!(error(&["-q", "list"]), None);
+        assert_eq!(error(&["-vv", "list"]), None);
+        assert_eq!(error(&["list", "--from-lockfile", "Cargo.lock"]), None);
+        assert_eq!(
+            error(&[
+                "--metadata-json",
+                "metadata.json",
+                "list",
+                "--from-lockfile",
+                "Cargo.lock"
+            ]),
+            Some(OptionsError::LockfileWithMetadata)
+        );
+        assert_eq!(
+            error(&[
+                "--manifest-path",
+                "Cargo.toml",
+                "list",
+                "--from-lockfile",
+                "Cargo.lock"
+            ]),
+            Some(OptionsError::LockfileWithMetadata)
+        );
+        assert_eq!(
+            error(&["check", "--from-lockfile", "Cargo.lock"]),
+            Some(OptionsError::LockfileWithCheck)
+        );
+        assert_eq!(
+            error(&["-q", "-vv", "list"]),
+            Some(OptionsError::QuietAndVerbose)
+        );
+        assert_eq!(
+            error(&["--verbose", "--quiet", "list"]),
+            Some(OptionsError::QuietAndVerbose)
+        );
+    }
+
+    #[test]
+    fn log_levels() {
+        let level = |args: &[&str]| {
+            let args: Vec<_> = args.iter().chain(&["list"]).copied().collect();
+            parse(&args).unwrap().log_level()
+        };
+        assert_eq!(level(&[]), LevelFilter::Warn);
+        assert_eq!(level(&["-q"]), LevelFilter::Error);
+        assert_eq!(level(&["--quiet"]), LevelFilter::Error);
+        assert_eq!(level(&["-v"]), LevelFilter::Info);
+        assert_eq!(level(&["-vv"]), LevelFilter::Debug);
+        assert_eq!(level(&["-v", "--verbose"]), LevelFilter::Debug);
+        assert_eq!(level(&["-vvv"]), LevelFilter::Trace);
+        assert_eq!(level(&["-vvvv"]), LevelFilter::Trace);
+
+        let error = parse(&["-q", "-v", "list"]).err().unwrap();
+        assert_eq!(error.kind, clap::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn jobs() {
+        let jobs = |args: &[&str]| {
+            let args: Vec<_> = ["bundle"].iter().chain(args).copied().collect();
+            parse(&args).map(|options| match options.cmd {
+                Cmd::Bundle { options, .. } => options.discovery.jobs,
+                _ => unreachable!(),
+            })
+        };
+        assert_eq!(jobs(&[]).unwrap(), None);
+        assert_eq!(jobs(&["--jobs", "4"]).unwrap(), Some(4));
+        assert_eq!(jobs(&["-j", "1"]).unwrap(), Some(1));
+        for invalid in &["0", "1.5", "many"] {
+            let error = jobs(&["--jobs", invalid]).err().unwrap();
+            assert_eq!(error.kind, clap::ErrorKind::ValueValidation, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn manifest_path() {
+        assert_eq!(parse(&["list"]).unwrap().manifest_path, None);
+
+        let options = parse(&["--manifest-path", "../other/Cargo.toml", "list"]).unwrap();
+        assert_eq!(
+            options.manifest_path.as_deref(),
+            Some("../other/Cargo.toml")
+        );
+
+        assert!(parse(&["--manifest-path"]).is_err());
+
+        let options = parse(&["--workspace-root", "../other", "list"]).unwrap();
+        assert_eq!(
+            options.manifest_path.as_deref(),
+            Some(Path::new("../other").join("Cargo.toml").to_str().unwrap())
+        );
+        assert!(parse(&[
+            "--workspace-root",
+            "../other",
+            "--manifest-path",
+            "../other/Cargo.toml",
+            "list"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn license_filters() {
+        let filter = |value: &str| match parse(&["list", "--filter", value]).map(|o| o.cmd) {
+            Ok(Cmd::List { options, .. }) => Ok(options.license_filter.unwrap()),
+            Ok(_) => panic!("not list"),
+            Err(err) => Err(err.message),
+        };
+        assert_eq!(filter("copyleft"), Ok(LicenseFilter::Copyleft));
+        assert_eq!(
+            filter("MIT, mpl-2.0"),
+            Ok(LicenseFilter::Licenses(vec![
+                "MIT".to_owned(),
+                "MPL-2.0".to_owned()
+            ]))
+        );
+        let err = filter("MIT,Frobnicate-1.0").unwrap_err();
+        assert!(
+            err.contains("'Frobnicate-1.0' is not a known SPDX license identifier"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn command_lines() {
+        let command_line = |args: &[&str]| {
+            Options::command_line(args.iter().map(OsString::from))
+                .into_iter()
+                .map(|arg| arg.into_string().unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            command_line(&["cargo-lichking", "lichking", "list"]),
+            ["cargo-lichking", "lichking", "list"]
+        );
+        assert_eq!(
+            command_line(&["cargo-lichking", "list"]),
+            ["cargo-lichking", "lichking", "list"]
+        );
+        assert_eq!(
+            command_line(&["cargo-lichking"]),
+            ["cargo-lichking", "lichking"]
+        );
+    }
+}
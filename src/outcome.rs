@@ -0,0 +1,181 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::license::Family;
+use crate::load::ResolvedPackage;
+
+/// The process exit codes, a fatal error or invalid invocation exits with 1
+pub const EXIT_FAILURE: i32 = 2;
+pub const EXIT_WARNINGS: i32 = 3;
+
+/// The problems found by a command that still completed, fatal errors are
+/// returned as `Err` instead. Each is a short description of the problem, the
+/// details have already been logged.
+#[derive(Debug, Default)]
+pub struct Outcome {
+    /// Policy failures, e.g. incompatible or denied licenses
+    pub failures: Vec<String>,
+    /// Results that may be incomplete, e.g. unknown compatibility
+    pub warnings: Vec<String>,
+    /// License texts that couldn't be found for a bundle
+    pub missing_texts: Vec<String>,
+    pub summary: Summary,
+}
+
+/// Counts of what a command analyzed, for a footer after long runs
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Summary {
+    pub packages: usize,
+    /// Packages whose license isn't one we know anything about
+    pub unknown_licenses: usize,
+    /// The license texts chosen for a bundle by confidence, or that couldn't
+    /// be found
+    #[serde(default)]
+    pub confident: usize,
+    #[serde(default)]
+    pub semi_confident: usize,
+    #[serde(default)]
+    pub unsure: usize,
+    #[serde(default)]
+    pub missing_texts: usize,
+}
+
+impl Summary {
+    pub fn of(resolved: &[ResolvedPackage]) -> Summary {
+        Summary {
+            packages: resolved.len(),
+            unknown_licenses: resolved
+                .iter()
+                .filter(|resolved| resolved.license.license.family() == Family::Unknown)
+                .count(),
+            ..Summary::default()
+        }
+    }
+
+    pub fn merge(&mut self, other: Summary) {
+        self.packages += other.packages;
+        self.unknown_licenses += other.unknown_licenses;
+        self.confident += other.confident;
+        self.semi_confident += other.semi_confident;
+        self.unsure += other.unsure;
+        self.missing_texts += other.missing_texts;
+    }
+
+    /// e.g. `analyzed 412 packages in 3.2s: 2 unknown licenses; 412 license
+    /// texts: 396 confident, 9 semi-confident, 4 unsure, 3 missing`, the text
+    /// counts are only included when texts were searched for
+    pub fn footer(&self, elapsed: Duration) -> String {
+        let mut footer = format!(
+            "analyzed {} packages in {:.1}s: {} unknown licenses",
+            self.packages,
+            elapsed.as_secs_f64(),
+            self.unknown_licenses
+        );
+        let texts = self.confident + self.semi_confident + self.unsure + self.missing_texts;
+        if texts > 0 {
+            footer.push_str(&format!(
+                "; {} license texts: {} confident, {} semi-confident, {} unsure, {} missing",
+                texts, self.confident, self.semi_confident, self.unsure, self.missing_texts
+            ));
+        }
+        footer
+    }
+}
+
+impl Outcome {
+    pub fn fail(&mut self, reason: impl Into<String>) {
+        self.failures.push(reason.into());
+    }
+
+    pub fn warn(&mut self, reason: impl Into<String>) {
+        self.warnings.push(reason.into());
+    }
+
+    pub fn missing_text(&mut self, reason: impl Into<String>) {
+        self.missing_texts.push(reason.into());
+    }
+
+    pub fn merge(&mut self, other: Outcome) {
+        self.failures.extend(other.failures);
+        self.warnings.extend(other.warnings);
+        self.missing_texts.extend(other.missing_texts);
+        self.summary.merge(other.summary);
+    }
+
+    /// Logs the problems and returns the exit code for them: 0 when clean,
+    /// `EXIT_FAILURE` for policy failures and `EXIT_WARNINGS` when completed
+    /// with warnings. Missing texts are warnings unless `fail_on_missing_text`
+    /// is disabled, in which case they're ignored.
+    pub fn report(self, warnings_as_errors: bool, fail_on_missing_text: bool) -> i32 {
+        let mut warnings = self.warnings;
+        if fail_on_missing_text {
+            warnings.extend(self.missing_texts);
+        }
+        let mut failures = self.failures;
+        if warnings_as_errors {
+            failures.append(&mut warnings);
+        }
+
+        for reason in &failures {
+            log::error!("{}", reason);
+        }
+        for reason in &warnings {
+            log::warn!("{}", reason);
+        }
+
+        if !failures.is_empty() {
+            EXIT_FAILURE
+        } else if !warnings.is_empty() {
+            EXIT_WARNINGS
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(failures: usize, warnings: usize, missing_texts: usize) -> Outcome {
+        Outcome {
+            failures: vec!["failure".to_owned(); failures],
+            warnings: vec!["warning".to_owned(); warnings],
+            missing_texts: vec!["missing".to_owned(); missing_texts],
+            summary: Summary::default(),
+        }
+    }
+
+    #[test]
+    fn exit_codes() {
+        let cases = vec![
+            // failures, warnings, missing texts, warnings as errors, fail on
+            // missing text, exit code
+            (0, 0, 0, false, true, 0),
+            (0, 0, 0, true, true, 0),
+            (1, 0, 0, false, true, EXIT_FAILURE),
+            (1, 1, 1, false, true, EXIT_FAILURE),
+            (0, 1, 0, false, true, EXIT_WARNINGS),
+            (0, 1, 0, true, true, EXIT_FAILURE),
+            (0, 0, 1, false, true, EXIT_WARNINGS),
+            (0, 0, 1, true, true, EXIT_FAILURE),
+            (0, 0, 1, false, false, 0),
+            (0, 0, 1, true, false, 0),
+            (0, 1, 1, false, false, EXIT_WARNINGS),
+            (1, 0, 1, false, false, EXIT_FAILURE),
+        ];
+        for (failures, warnings, missing, as_errors, fail_on_missing, code) in cases {
+            assert_eq!(
+                outcome(failures, warnings, missing).report(as_errors, fail_on_missing),
+                code,
+                "{} {} {} {} {}",
+                failures,
+                warnings,
+                missing,
+                as_errors,
+                fail_on_missing
+            );
+        }
+    }
+}
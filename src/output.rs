@@ -0,0 +1,127 @@
+use std::env;
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::license::{Family, License};
+use crate::progress;
+
+static STDOUT_COLOR: AtomicBool = AtomicBool::new(false);
+static STDERR_COLOR: AtomicBool = AtomicBool::new(false);
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Yellow,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn code(self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+            Color::Magenta => "35",
+            Color::Cyan => "36",
+        }
+    }
+
+    fn of(family: Family) -> Color {
+        match family {
+            Family::PublicDomain | Family::Permissive => Color::Green,
+            Family::WeakCopyleft => Color::Yellow,
+            Family::StrongCopyleft | Family::NetworkCopyleft => Color::Red,
+            Family::Unknown => Color::Magenta,
+        }
+    }
+}
+
+fn paint(enabled: bool, color: Color, bold: bool, text: &str) -> String {
+    if !enabled {
+        return text.to_owned();
+    }
+    let bold = if bold { "1;" } else { "" };
+    format!("\x1b[{}{}m{}\x1b[0m", bold, color.code(), text)
+}
+
+/// Decides whether subcommand output is colored, like cargo `auto` colors
+/// each stream only when it's a terminal and `NO_COLOR` isn't set
+pub fn init(color: Option<&str>, quiet: bool) {
+    let enabled = |terminal: bool| match color {
+        Some("always") => true,
+        Some("never") => false,
+        _ => terminal && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()),
+    };
+    STDOUT_COLOR.store(enabled(io::stdout().is_terminal()), Ordering::SeqCst);
+    STDERR_COLOR.store(enabled(io::stderr().is_terminal()), Ordering::SeqCst);
+    QUIET.store(quiet, Ordering::SeqCst);
+}
+
+/// A license name for stdout, colored by its family and padded to `width`
+/// (the padding isn't colored so it aligns either way)
+pub fn license(license: &License, width: usize) -> String {
+    let name = license.to_string();
+    let padding = " ".repeat(width.saturating_sub(name.chars().count()));
+    let enabled = STDOUT_COLOR.load(Ordering::SeqCst);
+    // Unspecified licenses are what an audit most needs to notice
+    let bold = *license == License::Unspecified;
+    paint(enabled, Color::of(license.family()), bold, &name) + &padding
+}
+
+/// Writes `rows` under `header` as left aligned columns two spaces apart
+pub fn table<const N: usize>(
+    header: [&str; N],
+    rows: &[[String; N]],
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    let header = header.map(ToOwned::to_owned);
+    let mut widths = [0; N];
+    for row in Some(&header).into_iter().chain(rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    for row in Some(&header).into_iter().chain(rows) {
+        let mut line = String::new();
+        for (cell, width) in row.iter().zip(&widths) {
+            line += &format!("{:<width$}  ", cell, width = width);
+        }
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+/// Prints a finding to stderr prefixed like cargo's `error:` lines
+pub fn error(message: &str) {
+    let enabled = STDERR_COLOR.load(Ordering::SeqCst);
+    progress::above(|| eprintln!("{}: {}", paint(enabled, Color::Red, true, "error"), message));
+}
+
+/// Prints a finding to stderr prefixed like cargo's `warning:` lines, unless
+/// quiet
+pub fn warning(message: &str) {
+    if QUIET.load(Ordering::SeqCst) {
+        return;
+    }
+    let enabled = STDERR_COLOR.load(Ordering::SeqCst);
+    progress::above(|| {
+        eprintln!(
+            "{}: {}",
+            paint(enabled, Color::Yellow, true, "warning"),
+            message
+        )
+    });
+}
+
+/// Prints context for the findings to stderr prefixed like cargo's `note:`
+/// lines, unless quiet
+pub fn note(message: &str) {
+    if QUIET.load(Ordering::SeqCst) {
+        return;
+    }
+    let enabled = STDERR_COLOR.load(Ordering::SeqCst);
+    progress::above(|| eprintln!("{}: {}", paint(enabled, Color::Cyan, true, "note"), message));
+}
@@ -0,0 +1,589 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use anyhow::anyhow;
+use cargo_metadata::{Package, PackageId};
+use toml::Value;
+
+use crate::date::Date;
+use crate::license::License;
+use crate::load::ResolvedPackage;
+use crate::options::ElectOptions;
+use crate::output;
+
+/// Waivers expiring within this many days are reported as warnings, so they
+/// can be renewed before findings turn back into errors
+const EXPIRY_WARNING_DAYS: i64 = 30;
+
+/// Per-crate overrides of the policy
+#[derive(Debug, Default)]
+struct CratePolicy {
+    /// Licenses accepted for this crate in addition to the global ones
+    accepted: BTreeSet<String>,
+    /// The license to use instead of the one declared by the crate
+    clarify: Option<License>,
+}
+
+/// A temporary exception for a package's license findings
+#[derive(Debug)]
+struct Waiver {
+    package: String,
+    /// The licenses it applies to, any license when empty
+    licenses: BTreeSet<String>,
+    expires: Date,
+    reason: String,
+    used: AtomicBool,
+}
+
+/// Whether a finding is covered by a waiver
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Waived {
+    No,
+    Yes,
+    /// Covered by a waiver that expires soon, which has been warned about
+    Expiring,
+}
+
+/// A list of accepted licenses that every dependency must be satisfiable by
+#[derive(Debug, Default)]
+pub struct Policy {
+    accepted: BTreeSet<String>,
+    crates: HashMap<String, CratePolicy>,
+    waivers: Vec<Waiver>,
+}
+
+/// How a package offering a choice of licenses is elected to be used under
+/// one of them
+#[derive(Debug)]
+pub struct Election {
+    preference: Preference,
+    /// Packages already warned about offering none of the preferred licenses
+    warned: Mutex<HashSet<PackageId>>,
+}
+
+#[derive(Debug)]
+enum Preference {
+    /// The first of these licenses offered
+    Prefer(Vec<License>),
+    /// The first alternative accepted by the policy
+    Policy(Policy),
+}
+
+fn normalize(license: &str) -> String {
+    let Ok(license) = license.parse::<License>();
+    license.to_string()
+}
+
+fn accepted_list(value: &Value, context: &str) -> anyhow::Result<BTreeSet<String>> {
+    value
+        .as_array()
+        .ok_or_else(|| anyhow!("{} must be an array of license identifiers", context))?
+        .iter()
+        .map(|license| {
+            license
+                .as_str()
+                .map(normalize)
+                .ok_or_else(|| anyhow!("{} must only contain strings", context))
+        })
+        .collect()
+}
+
+impl Policy {
+    /// Loads a cargo-about style `about.toml`, only the `accepted` list and
+    /// the per-crate `accepted` and `clarify.license` overrides are used, along
+    /// with our own `[[waiver]]` entries
+    pub fn load_about(path: &str) -> anyhow::Result<Policy> {
+        let bytes = fs::read(path).map_err(|err| anyhow!("Couldn't read {}: {}", path, err))?;
+        let config: Value =
+            toml::from_slice(&bytes).map_err(|err| anyhow!("Couldn't parse {}: {}", path, err))?;
+        let table = config
+            .as_table()
+            .ok_or_else(|| anyhow!("{} is not a table", path))?;
+
+        let mut policy = Policy::default();
+        for (key, value) in table {
+            match key.as_str() {
+                "accepted" => policy.accepted = accepted_list(value, "accepted")?,
+                "crates" => {
+                    let crates = value
+                        .as_table()
+                        .ok_or_else(|| anyhow!("crates must be a table"))?;
+                    for (name, value) in crates {
+                        policy
+                            .crates
+                            .insert(name.clone(), CratePolicy::parse(name, value)?);
+                    }
+                }
+                "waiver" => {
+                    let waivers = value
+                        .as_array()
+                        .ok_or_else(|| anyhow!("waiver must be an array of tables"))?;
+                    for waiver in waivers {
+                        policy.waivers.push(
+                            Waiver::parse(waiver)
+                                .map_err(|err| anyhow!("Invalid waiver in {}: {}", path, err))?,
+                        );
+                    }
+                }
+                key => log::debug!("Ignoring unsupported key {} in {}", key, path),
+            }
+        }
+        Ok(policy)
+    }
+
+    /// The `clarify.license` overrides by crate name
+    pub fn clarifications(&self) -> HashMap<String, License> {
+        self.crates
+            .iter()
+            .filter_map(|(name, krate)| Some((name.clone(), krate.clarify.clone()?)))
+            .collect()
+    }
+
+    /// Checks whether `finding` about `package` is waived, logging it as a
+    /// note when it is or as a warning when the waiver is about to expire
+    pub fn waive(&self, package: &Package, license: &License, finding: &str) -> Waived {
+        self.waive_on(Date::today(), package, license, finding)
+    }
+
+    fn waive_on(&self, today: Date, package: &Package, license: &License, finding: &str) -> Waived {
+        let waiver = match self
+            .waivers
+            .iter()
+            .find(|waiver| waiver.matches(package, license))
+        {
+            Some(waiver) => waiver,
+            None => return Waived::No,
+        };
+        waiver.used.store(true, Ordering::SeqCst);
+        let remaining = today.days_until(waiver.expires);
+        if remaining < 0 {
+            log::warn!(
+                "The waiver for {} expired on {}: {}",
+                package.name,
+                waiver.expires,
+                waiver.reason
+            );
+            Waived::No
+        } else if remaining <= EXPIRY_WARNING_DAYS {
+            output::warning(&format!(
+                "{} (waived until {}, expiring in {} days: {})",
+                finding, waiver.expires, remaining, waiver.reason
+            ));
+            Waived::Expiring
+        } else {
+            log::info!(
+                "{} (waived until {}: {})",
+                finding,
+                waiver.expires,
+                waiver.reason
+            );
+            Waived::Yes
+        }
+    }
+
+    /// Warns about waivers that didn't match any finding, returns whether any
+    /// were found
+    pub fn report_unused_waivers(&self) -> bool {
+        let mut found = false;
+        for waiver in &self.waivers {
+            if !waiver.used.load(Ordering::SeqCst) {
+                output::warning(&format!(
+                    "The waiver for {} matched nothing and can be removed",
+                    waiver.package
+                ));
+                found = true;
+            }
+        }
+        found
+    }
+
+    fn accepts(&self, name: &str, license: &License) -> bool {
+        let extra = self.crates.get(name).map(|krate| &krate.accepted);
+        let accepted = |license: &str| {
+            self.accepted.contains(license) || extra.is_some_and(|extra| extra.contains(license))
+        };
+        match license {
+            License::Multiple(licenses) => {
+                licenses.iter().any(|license| self.accepts(name, license))
+            }
+            License::All(licenses) => licenses.iter().all(|license| self.accepts(name, license)),
+            License::WithException { base, exception } => {
+                accepted(&license.to_string()) || (self.accepts(name, base) && accepted(exception))
+            }
+            License::Unspecified => false,
+            license => accepted(&license.to_string()),
+        }
+    }
+
+    /// Reports every dependency of `root` whose license is not satisfiable by
+    /// the accepted licenses and isn't waived, returns whether any were found
+    /// and whether any waivers used are expiring
+    pub fn report(&self, root: &Package, resolved: &[ResolvedPackage]) -> (bool, bool) {
+        let mut found = false;
+        let mut expiring = false;
+        for resolved in resolved {
+            let package = resolved.package;
+            if package.id == root.id {
+                continue;
+            }
+            // Already replaced by any `clarify.license`
+            let license = &resolved.license.license;
+            if !self.accepts(&package.name, license) {
+                let finding = format!(
+                    "{} {} is licensed under {} which is not accepted by the policy",
+                    package.name, package.version, license
+                );
+                match self.waive(package, license, &finding) {
+                    Waived::No => {
+                        output::error(&finding);
+                        found = true;
+                    }
+                    Waived::Yes => (),
+                    Waived::Expiring => expiring = true,
+                }
+            }
+        }
+        (found, expiring)
+    }
+}
+
+impl Election {
+    fn new(preference: Preference) -> Election {
+        Election {
+            preference,
+            warned: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// The election asked for, loading its policy
+    pub fn load(options: &ElectOptions) -> anyhow::Result<Option<Election>> {
+        if let Some(path) = &options.policy {
+            let policy = Policy::load_about(path)?;
+            return Ok(Some(Election::new(Preference::Policy(policy))));
+        }
+        let preferred = options.prefer.clone();
+        Ok(Some(Election::new(Preference::Prefer(preferred)))
+            .filter(|_| !options.prefer.is_empty()))
+    }
+
+    /// The alternative of `license` that `package` is used under, `None` for
+    /// licenses offering no choice or when no alternative is preferred, which
+    /// is warned about the first time
+    pub fn elect(&self, package: &Package, license: &License) -> Option<License> {
+        let alternatives = match license {
+            License::Multiple(alternatives) => alternatives,
+            _ => return None,
+        };
+        let elected = match &self.preference {
+            Preference::Prefer(preferred) => preferred
+                .iter()
+                .find(|preferred| alternatives.contains(preferred)),
+            Preference::Policy(policy) => alternatives
+                .iter()
+                .find(|alternative| policy.accepts(&package.name, alternative)),
+        };
+        if elected.is_none() && self.warned.lock().unwrap().insert(package.id.clone()) {
+            log::warn!(
+                "{} {} is licensed under {} and none of them is preferred, using all of them",
+                package.name,
+                package.version,
+                license
+            );
+        }
+        elected.cloned()
+    }
+}
+
+impl CratePolicy {
+    fn parse(name: &str, value: &Value) -> anyhow::Result<CratePolicy> {
+        let table = value
+            .as_table()
+            .ok_or_else(|| anyhow!("crates.{} must be a table", name))?;
+        let mut policy = CratePolicy::default();
+        for (key, value) in table {
+            match key.as_str() {
+                "accepted" => {
+                    policy.accepted = accepted_list(value, &format!("crates.{}.accepted", name))?
+                }
+                "clarify" => {
+                    if let Some(license) = value.get("license").and_then(Value::as_str) {
+                        let Ok(license) = license.parse();
+                        policy.clarify = Some(license);
+                    } else {
+                        log::debug!("Ignoring crates.{}.clarify without a license", name);
+                    }
+                }
+                key => log::debug!("Ignoring unsupported key crates.{}.{}", name, key),
+            }
+        }
+        Ok(policy)
+    }
+}
+
+impl Waiver {
+    fn parse(value: &Value) -> anyhow::Result<Waiver> {
+        let string = |key: &str| {
+            value
+                .get(key)
+                .and_then(Value::as_str)
+                .ok_or_else(|| anyhow!("missing {} string", key))
+        };
+        let package = string("package")?;
+        let expires = string("expires")?
+            .parse()
+            .map_err(|err| anyhow!("{} expires: {}", package, err))?;
+        let reason = string("reason")?;
+        if reason.trim().is_empty() {
+            return Err(anyhow!("{} needs a reason", package));
+        }
+        let licenses = match value.get("licenses") {
+            Some(licenses) => accepted_list(licenses, &format!("{} licenses", package))?,
+            None => BTreeSet::new(),
+        };
+        Ok(Waiver {
+            package: package.to_owned(),
+            licenses,
+            expires,
+            reason: reason.to_owned(),
+            used: AtomicBool::new(false),
+        })
+    }
+
+    /// Whether this waiver covers `package`, by its whole license or any of
+    /// the alternatives or parts it's made of
+    fn matches(&self, package: &Package, license: &License) -> bool {
+        if package.name != self.package {
+            return false;
+        }
+        if self.licenses.is_empty() || self.licenses.contains(&license.to_string()) {
+            return true;
+        }
+        match license {
+            License::Multiple(licenses) | License::All(licenses) => licenses
+                .iter()
+                .any(|license| self.licenses.contains(&license.to_string())),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::licensed::LicensedCache;
+    use crate::testutil::{self, TempDir};
+
+    const ABOUT: &str = r#"accepted = ["MIT"]
+
+[[waiver]]
+package = "openssl-src"
+licenses = ["OpenSSL"]
+expires = "2025-06-30"
+reason = "approved by legal ticket LEG-123"
+"#;
+
+    fn policy(about: &str) -> anyhow::Result<Policy> {
+        let dir = TempDir::new();
+        let path = dir.write("about.toml", about);
+        Policy::load_about(path.to_str().unwrap())
+    }
+
+    fn date(date: &str) -> Date {
+        date.parse().unwrap()
+    }
+
+    /// Along the lines of what cargo-about users have, with keys we don't use
+    const REAL_WORLD_ABOUT: &str = r#"accepted = [
+    "Apache-2.0",
+    "MIT",
+    "BSD-3-Clause",
+    "Unicode-DFS-2016",
+]
+targets = ["x86_64-unknown-linux-gnu"]
+ignore-build-dependencies = true
+
+[crates.ring]
+clarify.license = "MIT AND ISC AND OpenSSL"
+clarify.files = [{ path = "LICENSE", license = "OpenSSL", checksum = "0000" }]
+accepted = ["ISC", "OpenSSL"]
+
+[crates.webpki]
+accepted = ["ISC"]
+
+[crates.encoding_rs]
+clarify.files = []
+"#;
+
+    #[test]
+    fn about_toml() {
+        let policy = policy(REAL_WORLD_ABOUT).unwrap();
+        let license = |license: &str| license.parse::<License>().unwrap();
+        let accepts = |name: &str, expression: &str| policy.accepts(name, &license(expression));
+        assert!(accepts("any", "MIT"));
+        assert!(accepts("any", "MIT OR GPL-3.0"));
+        assert!(accepts("any", "GPL-3.0 OR Apache-2.0"));
+        assert!(accepts("any", "MIT AND BSD-3-Clause"));
+        assert!(!accepts("any", "MIT AND GPL-3.0"));
+        assert!(!accepts("any", "GPL-3.0"));
+        assert!(!accepts("any", "ISC"));
+        // Per-crate accepted licenses only apply to that crate
+        assert!(accepts("webpki", "ISC"));
+        assert!(accepts("webpki", "ISC AND MIT"));
+        assert!(!accepts("webpki", "OpenSSL"));
+        // A clarify without a license is ignored
+        assert_eq!(policy.crates["encoding_rs"].clarify, None);
+
+        let dir = Path::new("/nonexistent");
+        let root = testutil::package("about-app", "1.0.0", Some("MIT"), dir);
+        // The declared license doesn't matter once it's clarified
+        let ring = testutil::package("ring", "0.16.20", None, dir);
+        let ok = testutil::package("about-ok", "1.0.0", Some("Apache-2.0 OR GPL-3.0"), dir);
+        let bad = testutil::package("about-bad", "1.0.0", Some("MPL-2.0"), dir);
+        let licenses =
+            LicensedCache::new(false, false).with_overrides(policy.clarifications(), "about.toml");
+        let report = |packages: &[&Package]| {
+            let resolved = packages
+                .iter()
+                .map(|package| ResolvedPackage {
+                    license: licenses.resolve(package),
+                    ..testutil::resolved(package)
+                })
+                .collect::<Vec<_>>();
+            policy.report(&root, &resolved)
+        };
+        assert_eq!(report(&[&root, &ring, &ok]), (false, false));
+        assert_eq!(report(&[&root, &bad]), (true, false));
+        assert_eq!(
+            policy.crates["ring"].clarify,
+            Some(license("MIT AND ISC AND OpenSSL"))
+        );
+    }
+
+    #[test]
+    fn invalid_about_toml() {
+        for (about, error) in &[
+            ("accepted = \"MIT\"", "accepted must be an array"),
+            ("accepted = [1]", "accepted must only contain strings"),
+            ("crates = 1", "crates must be a table"),
+            ("[crates]\nring = 1", "crates.ring must be a table"),
+            ("accepted = [", "Couldn't parse"),
+        ] {
+            let err = policy(about).unwrap_err().to_string();
+            assert!(err.contains(error), "{} doesn't contain {}", err, error);
+        }
+    }
+
+    #[test]
+    fn expiry_boundaries() {
+        let policy = policy(ABOUT).unwrap();
+        let package = testutil::package(
+            "openssl-src",
+            "111.0.0",
+            Some("OpenSSL"),
+            Path::new("/nonexistent"),
+        );
+        let license = testutil::resolved(&package).license.license.clone();
+        let waive = |today| policy.waive_on(date(today), &package, &license, "finding");
+        assert_eq!(waive("2024-12-31"), Waived::Yes);
+        assert_eq!(waive("2025-05-30"), Waived::Yes);
+        assert_eq!(waive("2025-05-31"), Waived::Expiring);
+        assert_eq!(waive("2025-06-30"), Waived::Expiring);
+        assert_eq!(waive("2025-07-01"), Waived::No);
+        assert_eq!(waive("2026-01-01"), Waived::No);
+    }
+
+    #[test]
+    fn waivers_only_cover_their_package_and_licenses() {
+        let policy = policy(ABOUT).unwrap();
+        let dir = Path::new("/nonexistent");
+        let today = date("2025-01-01");
+        let other = testutil::package("other", "1.0.0", Some("OpenSSL"), dir);
+        let dual = testutil::package("openssl-src", "1.0.0", Some("OpenSSL OR GPL-3.0"), dir);
+        let gpl = testutil::package("openssl-src", "2.0.0", Some("GPL-3.0"), dir);
+        let waive = |package: &Package| {
+            let resolved = testutil::resolved(package);
+            policy.waive_on(today, package, &resolved.license.license, "")
+        };
+        assert_eq!(waive(&other), Waived::No);
+        assert_eq!(waive(&gpl), Waived::No);
+        assert!(policy.report_unused_waivers());
+        assert_eq!(waive(&dual), Waived::Yes);
+        assert!(!policy.report_unused_waivers());
+    }
+
+    #[test]
+    fn invalid_waivers() {
+        for (waiver, error) in &[
+            (
+                "expires = \"2025-02-30\"\nreason = \"x\"",
+                "openssl-src expires: '2025-02-30' is not a valid date",
+            ),
+            (
+                "expires = \"30/06/2025\"\nreason = \"x\"",
+                "openssl-src expires: '30/06/2025' is not a valid date",
+            ),
+            ("expires = \"2025-06-30\"", "missing reason string"),
+            (
+                "expires = \"2025-06-30\"\nreason = \" \"",
+                "openssl-src needs a reason",
+            ),
+            ("reason = \"x\"", "missing expires string"),
+        ] {
+            let about = format!("[[waiver]]\npackage = \"openssl-src\"\n{}\n", waiver);
+            let err = policy(&about).unwrap_err().to_string();
+            assert!(err.contains("Invalid waiver in"), "{}", err);
+            assert!(err.contains(error), "{} doesn't contain {}", err, error);
+        }
+    }
+
+    #[test]
+    fn elections() {
+        let dir = Path::new("/nonexistent");
+        let license = |license: &str| license.parse::<License>().unwrap();
+        let dual = testutil::package("elect-dual", "1.0.0", Some("MIT OR Apache-2.0"), dir);
+        let dual_license = license("MIT OR Apache-2.0");
+
+        let prefer = |preferred: &[&str]| {
+            Election::new(Preference::Prefer(
+                preferred
+                    .iter()
+                    .map(|preferred| license(preferred))
+                    .collect(),
+            ))
+        };
+        // The first preferred license offered, whatever order they're offered in
+        assert_eq!(
+            prefer(&["Apache-2.0", "MIT"]).elect(&dual, &dual_license),
+            Some(License::Apache_2_0)
+        );
+        assert_eq!(
+            prefer(&["ISC", "MIT"]).elect(&dual, &dual_license),
+            Some(License::MIT)
+        );
+        assert_eq!(prefer(&["ISC"]).elect(&dual, &dual_license), None);
+        // Only choices are elected from
+        assert_eq!(prefer(&["MIT"]).elect(&dual, &License::MIT), None);
+        assert_eq!(
+            prefer(&["MIT"]).elect(&dual, &license("MIT AND Apache-2.0")),
+            None
+        );
+
+        let policy = Election::new(Preference::Policy(
+            policy("accepted = [\"Apache-2.0\"]\n").unwrap(),
+        ));
+        assert_eq!(
+            policy.elect(&dual, &dual_license),
+            Some(License::Apache_2_0)
+        );
+
+        // Packages offering nothing preferred are only warned about once
+        let isc = prefer(&["ISC"]);
+        assert_eq!(isc.elect(&dual, &dual_license), None);
+        assert_eq!(isc.elect(&dual, &dual_license), None);
+        assert_eq!(isc.warned.lock().unwrap().len(), 1);
+    }
+}
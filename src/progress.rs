@@ -0,0 +1,172 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use log::{Log, Metadata, Record};
+
+/// Only show progress when processing at least this many packages, smaller
+/// runs finish quickly enough to not need it
+const MIN_PACKAGES: usize = 20;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: Mutex<Option<State>> = Mutex::new(None);
+
+struct State {
+    label: &'static str,
+    total: usize,
+    processed: usize,
+    name: String,
+    /// How many callers are writing output with the line cleared, it's only
+    /// drawn again once they're all done
+    hidden: usize,
+}
+
+impl State {
+    fn draw(&self) {
+        let stderr = io::stderr();
+        let mut out = stderr.lock();
+        let _ = write!(
+            out,
+            "\r\x1b[K{} [{}/{}] {}",
+            self.label, self.processed, self.total, self.name
+        );
+        let _ = out.flush();
+    }
+}
+
+fn clear() {
+    let stderr = io::stderr();
+    let mut out = stderr.lock();
+    let _ = write!(out, "\r\x1b[K");
+    let _ = out.flush();
+}
+
+/// Enables showing progress, only when stderr is a terminal so it never ends
+/// up in redirected output
+pub fn init(quiet: bool) {
+    ENABLED.store(!quiet && io::stderr().is_terminal(), Ordering::SeqCst);
+}
+
+/// A `processed/total` counter with the name of the last processed package
+/// shown on stderr, cleared when dropped
+pub struct Progress {
+    active: bool,
+}
+
+impl Progress {
+    pub fn new(label: &'static str, total: usize) -> Progress {
+        let active = ENABLED.load(Ordering::SeqCst) && total >= MIN_PACKAGES;
+        if active {
+            let state = State {
+                label,
+                total,
+                processed: 0,
+                name: String::new(),
+                hidden: 0,
+            };
+            state.draw();
+            *CURRENT.lock().unwrap() = Some(state);
+        }
+        Progress { active }
+    }
+
+    pub fn inc(&self, name: &str) {
+        if !self.active {
+            return;
+        }
+        if let Some(state) = CURRENT.lock().unwrap().as_mut() {
+            state.processed += 1;
+            state.name = name.to_owned();
+            if state.hidden == 0 {
+                state.draw();
+            }
+        }
+    }
+
+    /// Hides the progress line while running `f`, for writing output to a
+    /// terminal that may be shared with stderr
+    pub fn suspend<T>(&self, f: impl FnOnce() -> T) -> T {
+        if !self.active {
+            return f();
+        }
+        above(f)
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.active {
+            *CURRENT.lock().unwrap() = None;
+            clear();
+        }
+    }
+}
+
+/// Runs `f`, which writes to stderr, with any progress line cleared first and
+/// redrawn below its output. The lock isn't held while `f` runs, so it may
+/// log or print findings itself.
+pub fn above<T>(f: impl FnOnce() -> T) -> T {
+    if let Some(state) = CURRENT.lock().unwrap().as_mut() {
+        if state.hidden == 0 {
+            clear();
+        }
+        state.hidden += 1;
+    }
+    let result = f();
+    if let Some(state) = CURRENT.lock().unwrap().as_mut() {
+        state.hidden = state.hidden.saturating_sub(1);
+        if state.hidden == 0 {
+            state.draw();
+        }
+    }
+    result
+}
+
+/// Wraps a logger so that log lines are written above the progress line
+/// instead of being interleaved with it
+pub struct Logger<L> {
+    inner: L,
+}
+
+impl<L> Logger<L> {
+    pub fn new(inner: L) -> Logger<L> {
+        Logger { inner }
+    }
+}
+
+impl<L: Log> Log for Logger<L> {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.inner.enabled(record.metadata()) {
+            return;
+        }
+        above(|| self.inner.log(record));
+    }
+
+    fn flush(&self) {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_while_suspended() {
+        ENABLED.store(true, Ordering::SeqCst);
+        let progress = Progress::new("Testing", MIN_PACKAGES);
+        ENABLED.store(false, Ordering::SeqCst);
+        progress.inc("first");
+        // Writing findings from inside doesn't wait on the progress line
+        let nested = progress.suspend(|| {
+            progress.inc("second");
+            above(|| CURRENT.lock().unwrap().as_ref().map(|state| state.hidden))
+        });
+        assert!(nested.is_some_and(|hidden| hidden >= 2), "{:?}", nested);
+        assert_eq!(CURRENT.lock().unwrap().as_ref().unwrap().processed, 2);
+    }
+}
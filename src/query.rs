@@ -1,5 +1,7 @@
+use std::collections::HashMap;
+
 use anyhow::anyhow;
-use cargo_metadata::{NodeDep, Package, PackageId, Resolve};
+use cargo_metadata::{Metadata, Node, NodeDep, Package, PackageId};
 
 pub trait PackagesExt {
     fn by_id(&self, id: &PackageId) -> anyhow::Result<&Package>;
@@ -13,16 +15,56 @@ impl PackagesExt for Vec<Package> {
     }
 }
 
-pub trait ResolveExt {
-    fn by_id(&self, id: &PackageId) -> anyhow::Result<&[NodeDep]>;
+/// Packages and resolve graph nodes of a `Metadata` by id, built once so that
+/// walking the graph doesn't search the whole package list at every step
+pub struct MetadataIndex<'a> {
+    metadata: &'a Metadata,
+    packages: HashMap<&'a PackageId, &'a Package>,
+    /// `None` without a resolve graph, e.g. from `cargo metadata --no-deps`
+    nodes: Option<HashMap<&'a PackageId, &'a Node>>,
 }
 
-impl ResolveExt for Resolve {
-    fn by_id(&self, id: &PackageId) -> anyhow::Result<&[NodeDep]> {
+impl<'a> MetadataIndex<'a> {
+    pub fn new(metadata: &'a Metadata) -> MetadataIndex<'a> {
+        MetadataIndex {
+            metadata,
+            packages: metadata
+                .packages
+                .iter()
+                .map(|package| (&package.id, package))
+                .collect(),
+            nodes: metadata
+                .resolve
+                .as_ref()
+                .map(|resolve| resolve.nodes.iter().map(|node| (&node.id, node)).collect()),
+        }
+    }
+
+    pub fn metadata(&self) -> &'a Metadata {
+        self.metadata
+    }
+
+    pub fn package(&self, id: &PackageId) -> anyhow::Result<&'a Package> {
+        self.packages
+            .get(id)
+            .copied()
+            .ok_or_else(|| anyhow!("Couldn't find package {}", id))
+    }
+
+    pub fn deps(&self, id: &PackageId) -> anyhow::Result<&'a [NodeDep]> {
         self.nodes
-            .iter()
-            .find(|node| &node.id == id)
+            .as_ref()
+            .ok_or_else(|| anyhow!("Couldn't load resolve graph"))?
+            .get(id)
             .map(|node| node.deps.as_ref())
             .ok_or_else(|| anyhow!("Couldn't find deps for package {}", id))
     }
+
+    /// The features enabled on `id`, none if it isn't in the resolve graph
+    pub fn features(&self, id: &PackageId) -> &'a [String] {
+        self.nodes
+            .as_ref()
+            .and_then(|nodes| nodes.get(id))
+            .map_or(&[][..], |node| &node.features[..])
+    }
 }
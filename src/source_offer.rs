@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use cargo_metadata::Package;
+use serde::Serialize;
+
+use crate::load::ResolvedPackage;
+use crate::options::Format;
+use crate::output;
+
+#[derive(Debug, Serialize)]
+struct Entry {
+    name: String,
+    version: String,
+    license: String,
+    source: Source,
+    /// From the lockfile, only registry packages have one
+    checksum: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+enum Source {
+    /// With the URL to download the `.crate` from
+    CratesIo {
+        url: String,
+    },
+    /// The index URL, other registries' download URLs are in their config
+    Registry {
+        url: String,
+    },
+    Git {
+        url: String,
+        rev: Option<String>,
+    },
+    /// Workspace members and other path dependencies
+    LocalPath {
+        path: String,
+    },
+}
+
+impl Source {
+    fn of(package: &Package) -> Source {
+        let source = match &package.source {
+            Some(source) => source,
+            None => {
+                let dir = package
+                    .manifest_path
+                    .parent()
+                    .unwrap_or(&package.manifest_path);
+                return Source::LocalPath {
+                    path: dir.display().to_string(),
+                };
+            }
+        };
+        if source.is_crates_io() {
+            return Source::CratesIo {
+                url: format!(
+                    "https://static.crates.io/crates/{name}/{name}-{version}.crate",
+                    name = package.name,
+                    version = package.version
+                ),
+            };
+        }
+        let source = source.to_string();
+        if let Some(url) = source.strip_prefix("git+") {
+            // The locked commit follows the `#`, a `?rev=...` is only what
+            // was asked for
+            let (url, rev) = match url.split_once('#') {
+                Some((url, rev)) => (url, Some(rev.to_owned())),
+                None => (url, None),
+            };
+            let end = url.find('?').unwrap_or(url.len());
+            return Source::Git {
+                url: url[..end].to_owned(),
+                rev,
+            };
+        }
+        let url = source.split_once('+').map_or(&*source, |(_, url)| url);
+        Source::Registry {
+            url: url.to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Source::CratesIo { url } => write!(f, "{}", url),
+            Source::Registry { url } => write!(f, "registry {}", url),
+            Source::Git {
+                url,
+                rev: Some(rev),
+            } => write!(f, "git {}#{}", url, rev),
+            Source::Git { url, rev: None } => write!(f, "git {}", url),
+            Source::LocalPath { .. } => write!(f, "local path"),
+        }
+    }
+}
+
+/// Lists the packages whose license obliges offering the source of modified
+/// versions, with where to get that source from and the lockfile `checksums`
+pub fn run(
+    resolved: &[ResolvedPackage],
+    checksums: &HashMap<String, String>,
+    format: Format,
+) -> anyhow::Result<()> {
+    let entries = entries(resolved, checksums);
+    match format {
+        Format::Text => write_text(&entries, &mut io::stdout())?,
+        Format::Json => {
+            serde_json::to_writer_pretty(io::stdout(), &entries)?;
+            println!();
+        }
+        Format::CycloneDx => unreachable!("not an allowed format"),
+    }
+    Ok(())
+}
+
+fn entries(resolved: &[ResolvedPackage], checksums: &HashMap<String, String>) -> Vec<Entry> {
+    let mut resolved = resolved.iter().collect::<Vec<_>>();
+    resolved.sort_by_key(|resolved| (&resolved.package.name, &resolved.package.version));
+    resolved
+        .iter()
+        .filter_map(|resolved| {
+            let (package, license) = (resolved.package, &resolved.license.license);
+            let obligations = license.obligations()?;
+            if !obligations.requires_source_disclosure_for_modifications {
+                return None;
+            }
+            let checksum = package.source.as_ref().and_then(|source| {
+                let id = format!("{} {} ({})", package.name, package.version, source);
+                checksums.get(&id).cloned()
+            });
+            Some(Entry {
+                name: package.name.clone(),
+                version: package.version.to_string(),
+                license: license.to_string(),
+                source: Source::of(package),
+                checksum,
+            })
+        })
+        .collect()
+}
+
+fn write_text(entries: &[Entry], out: &mut dyn Write) -> io::Result<()> {
+    let rows = entries
+        .iter()
+        .map(|entry| {
+            [
+                entry.name.clone(),
+                entry.version.clone(),
+                entry.license.clone(),
+                entry.source.to_string(),
+                entry.checksum.clone().unwrap_or_else(|| "-".to_owned()),
+            ]
+        })
+        .collect::<Vec<_>>();
+    output::table(
+        ["Name", "Version", "License", "Source", "Checksum"],
+        &rows,
+        out,
+    )
+}
@@ -0,0 +1,179 @@
+//! Helpers shared by the unit tests
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cargo_metadata::{Metadata, Package};
+use serde_json::json;
+
+use crate::licensed::LicensedCache;
+use crate::load::{ResolvedPackage, SourceKind, Usage};
+
+/// A temporary directory, removed again when dropped
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new() -> TempDir {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let path = env::temp_dir().join(format!(
+            "cargo-lichking-unit-{}-{}",
+            process::id(),
+            COUNT.fetch_add(1, Ordering::SeqCst)
+        ));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Writes `contents` to `path` relative to the directory, creating its
+    /// parents
+    pub fn write(&self, path: &str, contents: &str) -> PathBuf {
+        let path = self.0.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, contents).unwrap();
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// A crates.io package with its manifest in `dir`, which doesn't need to
+/// exist for tests that don't look at the package's files
+pub fn package(name: &str, version: &str, license: Option<&str>, dir: &Path) -> Package {
+    package_from(name, version, license, dir, Some(CRATES_IO))
+}
+
+/// A path dependency with its manifest in `dir`
+pub fn path_package(name: &str, version: &str, license: Option<&str>, dir: &Path) -> Package {
+    package_from(name, version, license, dir, None)
+}
+
+/// A package from `source`, as written in package ids, e.g. `git+URL#COMMIT`
+pub fn sourced_package(name: &str, version: &str, source: &str) -> Package {
+    package_from(
+        name,
+        version,
+        Some("MIT"),
+        Path::new("/nonexistent"),
+        Some(source),
+    )
+}
+
+/// A package in `dir` checked out from a git repository
+pub fn git_package(name: &str, version: &str, dir: &Path) -> Package {
+    let source = format!("git+https://example.com/{}#0123abcd", name);
+    package_from(name, version, Some("MIT"), dir, Some(&source))
+}
+
+const CRATES_IO: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+fn package_from(
+    name: &str,
+    version: &str,
+    license: Option<&str>,
+    dir: &Path,
+    source: Option<&str>,
+) -> Package {
+    let id = match source {
+        Some(source) => format!("{} {} ({})", name, version, source),
+        None => format!("{} {} (path+file://{})", name, version, dir.display()),
+    };
+    serde_json::from_value(json!({
+        "name": name,
+        "version": version,
+        "id": id,
+        "source": source,
+        "license": license,
+        "dependencies": [],
+        "targets": [],
+        "features": {},
+        "manifest_path": dir.join("Cargo.toml"),
+    }))
+    .unwrap()
+}
+
+/// `package` resolved as a direct dependency of the roots
+pub fn resolved(package: &Package) -> ResolvedPackage<'_> {
+    ResolvedPackage {
+        package,
+        depth: 1,
+        parent: None,
+        source: SourceKind::of(package),
+        features: Vec::new(),
+        duplicates: Vec::new(),
+        usage: Usage::Runtime,
+        license: LicensedCache::new(false, false).resolve(package),
+    }
+}
+
+/// Metadata for `packages` with the first one as the workspace member and
+/// `deps` as `(dependent, dependency, kind)` indices into `packages`, the kind
+/// as in `cargo metadata`'s output, `None` for a normal dependency
+pub fn metadata(packages: &[Package], deps: &[(usize, usize, Option<&str>)]) -> Metadata {
+    let nodes = packages
+        .iter()
+        .enumerate()
+        .map(|(i, package)| {
+            let deps = deps
+                .iter()
+                .filter(|(from, _, _)| *from == i)
+                .map(|&(_, to, kind)| {
+                    json!({
+                        "name": packages[to].name.replace('-', "_"),
+                        "pkg": packages[to].id,
+                        "dep_kinds": [{ "kind": kind, "target": null }],
+                    })
+                })
+                .collect::<Vec<_>>();
+            json!({
+                "id": package.id,
+                "deps": deps,
+                "dependencies": [],
+                "features": [],
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::from_value(json!({
+        "packages": packages,
+        "workspace_members": [packages[0].id],
+        "resolve": { "nodes": nodes, "root": null },
+        "workspace_root": "/nonexistent",
+        "target_directory": "/nonexistent/target",
+        "version": 1,
+    }))
+    .unwrap()
+}
+
+pub const MIT_TEXT: &str = "MIT License
+
+Copyright (c) 2020 The Authors
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the \"Software\"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+";
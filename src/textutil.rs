@@ -0,0 +1,156 @@
+use std::io::{self, Write};
+
+/// The line endings a bundle is written with
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    Crlf,
+}
+
+impl LineEnding {
+    /// The convention of the platform we're running on
+    pub fn native() -> LineEnding {
+        if cfg!(windows) {
+            LineEnding::Crlf
+        } else {
+            LineEnding::Lf
+        }
+    }
+}
+
+/// Cleans up a license text for writing into a bundle, dropping a leading byte
+/// order mark, converting CRLF and lone CR line endings to LF, trimming
+/// trailing whitespace from each line and collapsing runs of more than two
+/// blank lines
+pub fn normalize(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let mut normalized = String::with_capacity(text.len());
+    let mut blank = 0;
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            blank += 1;
+            if blank > 2 {
+                continue;
+            }
+        } else {
+            blank = 0;
+        }
+        normalized.push_str(line);
+        normalized.push('\n');
+    }
+    if !text.ends_with('\n') {
+        normalized.pop();
+    }
+    normalized
+}
+
+/// Writes to `W` with LF line endings converted to CRLF, leaving those that
+/// already are CRLF as they are
+pub struct CrlfWriter<W> {
+    inner: W,
+    /// Whether the last byte written was a CR, which may be followed by the
+    /// LF in the next write
+    after_cr: bool,
+}
+
+impl<W: Write> CrlfWriter<W> {
+    pub fn new(inner: W) -> CrlfWriter<W> {
+        CrlfWriter {
+            inner,
+            after_cr: false,
+        }
+    }
+}
+
+impl<W: Write> Write for CrlfWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut converted = Vec::with_capacity(buf.len() + buf.len() / 32);
+        for &byte in buf {
+            if byte == b'\n' && !self.after_cr {
+                converted.push(b'\r');
+            }
+            converted.push(byte);
+            self.after_cr = byte == b'\r';
+        }
+        self.inner.write_all(&converted)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Writes to `out` with the given line endings, the bundle writers only ever
+/// write LF
+pub fn with_line_endings<'a>(out: impl Write + 'a, ending: LineEnding) -> Box<dyn Write + 'a> {
+    match ending {
+        LineEnding::Lf => Box::new(out),
+        LineEnding::Crlf => Box::new(CrlfWriter::new(out)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_order_marks() {
+        assert_eq!(normalize("\u{feff}MIT License\n"), "MIT License\n");
+        // Only a leading one is a byte order mark
+        assert_eq!(normalize("MIT\u{feff} License\n"), "MIT\u{feff} License\n");
+    }
+
+    #[test]
+    fn line_endings() {
+        assert_eq!(normalize("one\r\ntwo\r\n"), "one\ntwo\n");
+        assert_eq!(normalize("one\rtwo\r"), "one\ntwo\n");
+        assert_eq!(
+            normalize("one\r\ntwo\rthree\nfour"),
+            "one\ntwo\nthree\nfour"
+        );
+        // A CR LF is one line ending, a CR before it another
+        assert_eq!(normalize("one\r\r\ntwo\n"), "one\n\ntwo\n");
+    }
+
+    #[test]
+    fn trailing_whitespace() {
+        assert_eq!(
+            normalize("MIT License  \n\n   Indented\t\nlast \t"),
+            "MIT License\n\n   Indented\nlast"
+        );
+        assert_eq!(normalize("one \r\ntwo\t\r"), "one\ntwo\n");
+    }
+
+    #[test]
+    fn blank_lines() {
+        assert_eq!(normalize("one\n\n\ntwo\n"), "one\n\n\ntwo\n");
+        assert_eq!(normalize("one\n\n\n\n\n\ntwo\n"), "one\n\n\ntwo\n");
+        // Lines of only whitespace are blank too
+        assert_eq!(normalize("one\n \n\t\n  \n \ntwo\n"), "one\n\n\ntwo\n");
+        assert_eq!(normalize("one\r\n\r\n\r\n\r\n\r\n"), "one\n\n\n");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn normalized_texts_are_unchanged() {
+        let text = "MIT License\n\nCopyright (c) 2020 The Authors\n\n\n    Indented\n";
+        assert_eq!(normalize(text), text);
+        assert_eq!(
+            normalize(&normalize("\u{feff}a \r\n\r\n\r\n\r\nb\r")),
+            "a\n\n\nb\n"
+        );
+    }
+
+    #[test]
+    fn crlf_writer() {
+        let mut out = CrlfWriter::new(Vec::new());
+        out.write_all(b"one\ntwo\r\nthree\r").unwrap();
+        // The LF of a CRLF split between writes is left alone
+        out.write_all(b"\nfour\n").unwrap();
+        assert_eq!(out.inner, b"one\r\ntwo\r\nthree\r\nfour\r\n");
+    }
+}
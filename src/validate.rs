@@ -0,0 +1,275 @@
+use std::io::{self, Write};
+
+use crate::discovery::{self, Confidence, DiscoveryConfig};
+use crate::license::{License, KNOWN};
+use crate::outcome::Outcome;
+use crate::output;
+
+/// A template scoring further than this from itself has words that don't
+/// survive the frequency count, e.g. a mangled encoding
+const SELF_SCORE_LIMIT: f32 = 0.001;
+
+struct SelfCheck {
+    license: &'static License,
+    confidence: Confidence,
+    score: f32,
+    /// `None` for templates starting with a copyright notice instead of a
+    /// title
+    title: Option<bool>,
+}
+
+impl SelfCheck {
+    fn problems(&self) -> Vec<&'static str> {
+        let mut problems = Vec::new();
+        if self.confidence != Confidence::Confident {
+            problems.push("not a confident match for itself");
+        }
+        if self.score > SELF_SCORE_LIMIT {
+            problems.push("scores above zero against itself");
+        }
+        if self.title == Some(false) {
+            problems.push("first line lacks the license name");
+        }
+        problems
+    }
+}
+
+/// A template scoring under the semi-confidence threshold as another license
+struct LookAlike {
+    text: &'static License,
+    scored_as: &'static License,
+    score: f32,
+    /// The two are already treated as the same text, e.g. a similarity group
+    expected: bool,
+}
+
+/// The licenses with a bundled template, only the first of those sharing one
+/// e.g. GPL-3.0-only and GPL-3.0-or-later
+fn templated() -> Vec<&'static License> {
+    let mut licenses = Vec::<&License>::new();
+    for license in KNOWN {
+        let template = match license.template() {
+            Some(template) => template,
+            None => continue,
+        };
+        if !licenses
+            .iter()
+            .any(|other| other.template() == Some(template))
+        {
+            licenses.push(license);
+        }
+    }
+    licenses
+}
+
+/// The words of the license name the first line of its template should
+/// contain, `None` for templates starting with a copyright notice
+fn title(license: &License) -> Option<&'static [&'static str]> {
+    use crate::license::License::*;
+    Some(match license {
+        Unlicense => &["public", "domain"],
+        X11 => &["X", "Consortium"],
+        CC0_1_0 | CC_BY_3_0 | CC_BY_SA_3_0 => &["Creative", "Commons"],
+        CC_BY_4_0 => &["Creative", "Commons", "Attribution", "4.0"],
+        CC_BY_SA_4_0 => &["Creative", "Commons", "Attribution", "ShareAlike", "4.0"],
+        Apache_2_0 => &["Apache", "License"],
+        LGPL_2_0 => &["GNU", "Library", "General", "Public", "License"],
+        LGPL_2_1 | LGPL_2_1Plus | LGPL_3_0 | LGPL_3_0Plus => {
+            &["GNU", "Lesser", "General", "Public", "License"]
+        }
+        MPL_1_1 => &["Mozilla", "Public", "License", "1.1"],
+        MPL_2_0 => &["Mozilla", "Public", "License", "2.0"],
+        GPL_2_0 | GPL_2_0Plus | GPL_3_0 | GPL_3_0Plus => &["GNU", "General", "Public", "License"],
+        AGPL_3_0 | AGPL_3_0Plus => &["GNU", "Affero", "General", "Public", "License"],
+        GFDL_1_2 | GFDL_1_2Plus | GFDL_1_3 | GFDL_1_3Plus => {
+            &["GNU", "Free", "Documentation", "License"]
+        }
+        _ => return None,
+    })
+}
+
+/// Whether the first non-blank line of `template` contains all of `words`,
+/// ignoring case
+fn has_title(template: &str, words: &[&str]) -> bool {
+    let line = template
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or_default()
+        .to_lowercase();
+    let tokens = line
+        .split(|c: char| !c.is_alphanumeric() && c != '.')
+        .map(|token| token.trim_end_matches('.'))
+        .collect::<Vec<_>>();
+    words
+        .iter()
+        .all(|word| tokens.contains(&&*word.to_lowercase()))
+}
+
+fn self_checks(config: &DiscoveryConfig) -> Vec<SelfCheck> {
+    templated()
+        .into_iter()
+        .map(|license| {
+            let template = license.template().expect("templated");
+            let (confidence, score) = discovery::check_against_template(template, license, config);
+            SelfCheck {
+                license,
+                confidence,
+                score: score.expect("templated"),
+                title: title(license).map(|words| has_title(template, words)),
+            }
+        })
+        .collect()
+}
+
+fn look_alikes(config: &DiscoveryConfig) -> Vec<LookAlike> {
+    let licenses = templated();
+    let mut look_alikes = Vec::new();
+    for &text in &licenses {
+        let template = text.template().expect("templated");
+        for (scored_as, score) in discovery::template_scores(template, config) {
+            if scored_as == text || !licenses.contains(&scored_as) {
+                continue;
+            }
+            if score < config.semi_confident {
+                look_alikes.push(LookAlike {
+                    text,
+                    scored_as,
+                    score,
+                    expected: discovery::same_text(text, scored_as),
+                });
+            }
+        }
+    }
+    look_alikes
+}
+
+/// Checks the bundled license templates still tell themselves apart from each
+/// other with the thresholds of `config`, for catching regressions in the
+/// templates or the scoring
+pub fn run(config: &DiscoveryConfig) -> anyhow::Result<Outcome> {
+    let checks = self_checks(config);
+    let look_alikes = look_alikes(config);
+    write_text(&checks, &look_alikes, &mut io::stdout())?;
+
+    let mut outcome = Outcome::default();
+    let mut failed = 0;
+    for check in &checks {
+        for problem in check.problems() {
+            output::error(&format!("{} template {}", check.license, problem));
+            failed += 1;
+        }
+    }
+    let mut ambiguous = 0;
+    for look_alike in look_alikes.iter().filter(|look_alike| !look_alike.expected) {
+        output::warning(&format!(
+            "{} template scores {:.3} as {}, below the semi-confidence threshold of {}, \
+             consider a similarity group",
+            look_alike.text, look_alike.score, look_alike.scored_as, config.semi_confident
+        ));
+        ambiguous += 1;
+    }
+    if failed > 0 {
+        outcome.fail("Templates failing their self-check".to_owned());
+    }
+    if ambiguous > 0 {
+        outcome.warn("Templates that can't be told apart".to_owned());
+    }
+    Ok(outcome)
+}
+
+fn write_text(
+    checks: &[SelfCheck],
+    look_alikes: &[LookAlike],
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    let rows = checks
+        .iter()
+        .map(|check| {
+            let problems = check.problems();
+            [
+                check.license.to_string(),
+                format!("{:.3}", check.score),
+                match check.title {
+                    Some(true) => "ok".to_owned(),
+                    Some(false) => "missing".to_owned(),
+                    None => "-".to_owned(),
+                },
+                if problems.is_empty() {
+                    "ok".to_owned()
+                } else {
+                    problems.join(", ")
+                },
+            ]
+        })
+        .collect::<Vec<_>>();
+    output::table(["License", "Self score", "Title", "Result"], &rows, out)?;
+
+    if !look_alikes.is_empty() {
+        writeln!(out)?;
+        let rows = look_alikes
+            .iter()
+            .map(|look_alike| {
+                [
+                    look_alike.text.to_string(),
+                    look_alike.scored_as.to_string(),
+                    format!("{:.3}", look_alike.score),
+                    if look_alike.expected {
+                        "same text".to_owned()
+                    } else {
+                        "ambiguous".to_owned()
+                    },
+                ]
+            })
+            .collect::<Vec<_>>();
+        output::table(["Template", "Scored as", "Score", "Result"], &rows, out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn templates_tell_themselves_apart() {
+        let config = DiscoveryConfig::default();
+        for check in self_checks(&config) {
+            assert_eq!(check.problems(), Vec::<&str>::new(), "{}", check.license);
+        }
+        // CC-BY-SA-4.0 is CC-BY-4.0 with the share-alike terms added, but
+        // still far enough apart not to be confidently mistaken for it
+        let ambiguous = look_alikes(&config)
+            .into_iter()
+            .filter(|look_alike| !look_alike.expected)
+            .inspect(|look_alike| assert!(look_alike.score >= config.confident))
+            .map(|look_alike| {
+                (
+                    look_alike.text.to_string(),
+                    look_alike.scored_as.to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ambiguous,
+            [("CC-BY-4.0", "CC-BY-SA-4.0"), ("CC-BY-SA-4.0", "CC-BY-4.0")]
+                .map(|(text, scored_as)| (text.to_owned(), scored_as.to_owned()))
+        );
+    }
+
+    #[test]
+    fn titles() {
+        assert!(has_title(
+            "\n   GNU LESSER GENERAL PUBLIC LICENSE\n",
+            &["GNU", "Lesser", "General", "Public", "License"]
+        ));
+        assert!(has_title(
+            "released into the public domain.",
+            &["public", "domain"]
+        ));
+        assert!(!has_title(
+            "GNU GENERAL PUBLIC LICENSE",
+            &["GNU", "Affero", "General", "Public", "License"]
+        ));
+        assert!(!has_title("Mozilla Public License Version 1.1", &["2.0"]));
+    }
+}
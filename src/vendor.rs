@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context};
+use cargo_metadata::{Package, PackageId};
+
+use crate::outcome::Outcome;
+use crate::output;
+
+/// The directories `cargo vendor` may have put a package in, it leaves out
+/// the version when only one version of a crate is vendored
+fn candidates(dir: &Path, package: &Package) -> [PathBuf; 2] {
+    [
+        dir.join(format!("{}-{}", package.name, package.version)),
+        dir.join(&package.name),
+    ]
+}
+
+/// The version declared by the manifest of a vendored copy
+fn manifest_version(dir: &Path) -> anyhow::Result<String> {
+    let path = dir.join("Cargo.toml");
+    let manifest =
+        fs::read_to_string(&path).with_context(|| format!("Couldn't read {}", path.display()))?;
+    let manifest = toml::from_str::<toml::Value>(&manifest)
+        .with_context(|| format!("Couldn't parse {}", path.display()))?;
+    manifest
+        .get("package")
+        .and_then(|package| package.get("version"))
+        .and_then(toml::Value::as_str)
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| anyhow!("{} declares no version", path.display()))
+}
+
+/// Copies of the packages from a registry or git repository with their
+/// manifest path pointing into `dir` instead, for those vendored there. A
+/// vendored copy of another version than the one resolved isn't used and is
+/// warned about, its license may well differ.
+pub fn copies(
+    dir: &Path,
+    packages: &[Package],
+) -> anyhow::Result<(HashMap<PackageId, Package>, Outcome)> {
+    if !dir.is_dir() {
+        return Err(anyhow!("Vendor directory {} doesn't exist", dir.display()));
+    }
+    // Like the manifest paths cargo reports, so texts are shown the same
+    let dir = &std::path::absolute(dir)?;
+    let mut copies = HashMap::new();
+    let mut mismatched = 0;
+    for package in packages.iter().filter(|package| package.source.is_some()) {
+        for candidate in candidates(dir, package) {
+            if !candidate.is_dir() {
+                continue;
+            }
+            let version = match manifest_version(&candidate) {
+                Ok(version) => version,
+                Err(err) => {
+                    output::warning(&format!("{:#}, not using it for {}", err, package.name));
+                    continue;
+                }
+            };
+            if version != package.version.to_string() {
+                output::warning(&format!(
+                    "{} is a vendored copy of {} {} but {} is resolved, its license texts \
+                     aren't used, run `cargo vendor` again to update it",
+                    candidate.display(),
+                    package.name,
+                    version,
+                    package.version
+                ));
+                mismatched += 1;
+                continue;
+            }
+            let mut copy = package.clone();
+            copy.manifest_path = candidate.join("Cargo.toml");
+            copies.insert(package.id.clone(), copy);
+            break;
+        }
+    }
+    log::info!(
+        "Found vendored copies of {} packages in {}",
+        copies.len(),
+        dir.display()
+    );
+
+    let mut outcome = Outcome::default();
+    if mismatched > 0 {
+        outcome.warn("Vendored copies of other versions than those resolved");
+    }
+    Ok((copies, outcome))
+}
@@ -0,0 +1,185 @@
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::anyhow;
+use cargo_metadata::{Package, PackageId};
+use serde::Deserialize;
+
+use crate::output;
+use crate::progress::Progress;
+
+#[derive(Deserialize)]
+struct IndexEntry {
+    vers: String,
+    #[serde(default)]
+    yanked: bool,
+}
+
+/// The path of a crate's file within a registry index
+fn index_path(name: &str) -> String {
+    let name = name.to_lowercase();
+    match name.len() {
+        1 => format!("1/{}", name),
+        2 => format!("2/{}", name),
+        3 => format!("3/{}/{}", &name[..1], name),
+        _ => format!("{}/{}/{}", &name[..2], &name[2..4], name),
+    }
+}
+
+/// Parses the files cargo caches index entries in, a small header followed
+/// by NUL separated pairs of version and JSON entry
+fn parse_cache(bytes: &[u8]) -> Option<HashMap<String, bool>> {
+    let mut fields = bytes.get(5..)?.split(|&b| b == 0);
+    // The etag or commit the cache was created from
+    fields.next()?;
+    let mut versions = HashMap::new();
+    while let (Some(_), Some(json)) = (fields.next(), fields.next()) {
+        if let Ok(entry) = serde_json::from_slice::<IndexEntry>(json) {
+            versions.insert(entry.vers, entry.yanked);
+        }
+    }
+    Some(versions)
+}
+
+/// Parses the newline separated JSON entries of an index file
+fn parse_index(text: &str) -> HashMap<String, bool> {
+    text.lines()
+        .filter_map(|line| serde_json::from_str::<IndexEntry>(line).ok())
+        .map(|entry| (entry.vers, entry.yanked))
+        .collect()
+}
+
+pub fn cargo_home() -> Option<PathBuf> {
+    env::var_os("CARGO_HOME").map(PathBuf::from).or_else(|| {
+        env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(|home| PathBuf::from(home).join(".cargo"))
+    })
+}
+
+/// Looks up the crates.io index data cargo has already downloaded
+fn local_versions(name: &str) -> Option<HashMap<String, bool>> {
+    let index = cargo_home()?.join("registry").join("index");
+    let path = index_path(name);
+    fs::read_dir(index)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            let dir = entry.file_name().to_string_lossy().into_owned();
+            dir.starts_with("index.crates.io-") || dir.starts_with("github.com-")
+        })
+        .find_map(|entry| {
+            let bytes = fs::read(entry.path().join(".cache").join(&path)).ok()?;
+            parse_cache(&bytes)
+        })
+}
+
+/// Fetches the index file from the crates.io sparse index with curl, `None`
+/// if crates.io doesn't know the crate
+fn remote_versions(name: &str) -> anyhow::Result<Option<HashMap<String, bool>>> {
+    // curl's exit code when the server responds with an HTTP error
+    const HTTP_ERROR: i32 = 22;
+
+    let url = format!("https://index.crates.io/{}", index_path(name));
+    let output = Command::new("curl")
+        .args(["-sSfL", &url])
+        .output()
+        .map_err(|err| anyhow!("couldn't run curl: {}", err))?;
+    match output.status.code() {
+        Some(0) => Ok(Some(parse_index(&String::from_utf8_lossy(&output.stdout)))),
+        Some(HTTP_ERROR) if String::from_utf8_lossy(&output.stderr).contains("404") => Ok(None),
+        _ => Err(anyhow!(
+            "fetching {} failed: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )),
+    }
+}
+
+/// Finds which of the crates.io packages have been yanked, packages whose
+/// index data isn't available are logged and treated as not yanked
+pub fn yanked<'a>(packages: &[&'a Package], allow_network: bool) -> HashSet<&'a PackageId> {
+    let mut yanked = HashSet::new();
+    let mut index = HashMap::new();
+    // Stop going to the network after the first failure, it'd only fail again
+    let mut allow_network = allow_network;
+    let progress = Progress::new("Checking yanked", packages.len());
+    for package in packages {
+        progress.inc(&package.name);
+        if !package.source.as_ref().is_some_and(|s| s.is_crates_io()) {
+            continue;
+        }
+        let versions = index.entry(package.name.as_str()).or_insert_with(|| {
+            local_versions(&package.name).or_else(|| {
+                if !allow_network {
+                    return None;
+                }
+                remote_versions(&package.name).unwrap_or_else(|err| {
+                    output::warning(&format!(
+                        "couldn't check crates.io for yanked versions, {}; packages without \
+                         local index data are treated as not yanked",
+                        err
+                    ));
+                    allow_network = false;
+                    None
+                })
+            })
+        });
+        match versions
+            .as_ref()
+            .and_then(|versions| versions.get(&package.version.to_string()))
+        {
+            Some(true) => {
+                yanked.insert(&package.id);
+            }
+            Some(false) => (),
+            None => log::info!(
+                "Couldn't find index data for {} {}, unknown whether it has been yanked",
+                package.name,
+                package.version
+            ),
+        }
+    }
+    yanked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_paths() {
+        assert_eq!(index_path("a"), "1/a");
+        assert_eq!(index_path("cc"), "2/cc");
+        assert_eq!(index_path("syn"), "3/s/syn");
+        assert_eq!(index_path("Serde"), "se/rd/serde");
+        assert_eq!(index_path("cargo-lichking"), "ca/rg/cargo-lichking");
+    }
+
+    #[test]
+    fn index_files() {
+        let text = "{\"name\":\"foo\",\"vers\":\"0.9.0\",\"deps\":[],\"cksum\":\"\"}\n\
+                    {\"name\":\"foo\",\"vers\":\"1.0.0\",\"yanked\":true}\n\
+                    not json\n";
+        let versions = parse_index(text);
+        assert_eq!(versions.len(), 2);
+        assert!(!versions["0.9.0"]);
+        assert!(versions["1.0.0"]);
+
+        let mut cache = vec![3, 2, 0, 0, 0];
+        cache.extend_from_slice(b"etag: \"abc\"\0");
+        // Each entry is preceded by its version, unparseable ones are skipped
+        let versions_and_entries = ["0.9.0", "1.0.0", "2.0.0"].iter().zip(text.lines());
+        for (version, json) in versions_and_entries {
+            cache.extend_from_slice(version.as_bytes());
+            cache.push(0);
+            cache.extend_from_slice(json.as_bytes());
+            cache.push(0);
+        }
+        assert_eq!(parse_cache(&cache), Some(versions));
+        assert_eq!(parse_cache(&[3, 2]), None);
+    }
+}
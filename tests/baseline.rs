@@ -0,0 +1,161 @@
+mod common;
+
+use std::fs;
+
+use common::{manifest, Workspace, MIT_TEXT};
+
+fn dependencies(deps: &[&str]) -> String {
+    let deps = deps
+        .iter()
+        .map(|name| format!("{} = {{ path = \"{}\" }}\n", name, name))
+        .collect::<String>();
+    format!("[dependencies]\n{}", deps)
+}
+
+#[test]
+fn save_and_diff() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest("app", "0.1.0", "MIT", &dependencies(&["one", "two"])),
+        ),
+        ("LICENSE", MIT_TEXT),
+        ("one/Cargo.toml", &manifest("one", "0.1.0", "MIT", "")),
+        ("two/Cargo.toml", &manifest("two", "0.1.0", "Zlib", "")),
+    ]);
+    let baseline = workspace.path("baseline.json");
+    let baseline = baseline.to_str().unwrap();
+    workspace
+        .run(&["list", "--save-baseline", baseline])
+        .expect(0);
+    let saved: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(baseline).unwrap()).unwrap();
+    let names = saved["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["app", "one", "two"]);
+
+    let diff = |args: &[&str]| {
+        let mut all = vec!["diff", "--baseline", baseline];
+        all.extend(args);
+        workspace.run(&all)
+    };
+    let json = |stdout: &str| serde_json::from_str::<serde_json::Value>(stdout).unwrap();
+
+    let run = diff(&["--format", "json"]).expect(0);
+    assert_eq!(
+        json(&run.stdout),
+        serde_json::json!({
+            "added": [],
+            "removed": [],
+            "version_changed": [],
+            "license_changed": [],
+            "new_licenses": [],
+        })
+    );
+    assert_eq!(diff(&[]).expect(0).stdout, "");
+
+    // A version bump, a removal and an addition under a known license only
+    // inform
+    fs::write(
+        workspace.path("Cargo.toml"),
+        manifest("app", "0.1.0", "MIT", &dependencies(&["one", "three"])),
+    )
+    .unwrap();
+    fs::write(
+        workspace.path("one/Cargo.toml"),
+        manifest("one", "0.2.0", "MIT", ""),
+    )
+    .unwrap();
+    fs::create_dir_all(workspace.path("three/src")).unwrap();
+    fs::write(workspace.path("three/src/lib.rs"), "").unwrap();
+    fs::write(
+        workspace.path("three/Cargo.toml"),
+        manifest("three", "0.1.0", "Zlib", ""),
+    )
+    .unwrap();
+    let run = diff(&["--format", "json"]).expect(0);
+    let out = json(&run.stdout);
+    assert_eq!(out["added"][0]["name"], "three");
+    assert_eq!(out["removed"][0]["name"], "two");
+    assert_eq!(
+        out["version_changed"],
+        serde_json::json!([{
+            "name": "one",
+            "license": "MIT",
+            "old": ["0.1.0"],
+            "new": ["0.2.0"],
+        }])
+    );
+    assert_eq!(out["license_changed"], serde_json::json!([]));
+    assert_eq!(out["new_licenses"], serde_json::json!([]));
+    assert_eq!(
+        diff(&[]).expect(0).stdout,
+        "added: three 0.1.0 (Zlib)\n\
+         removed: two 0.1.0 (Zlib)\n\
+         version changed: one 0.1.0 -> 0.2.0 (MIT)\n"
+    );
+
+    // A license change or a license the baseline never had fails
+    fs::write(
+        workspace.path("one/Cargo.toml"),
+        manifest("one", "0.2.0", "Apache-2.0", ""),
+    )
+    .unwrap();
+    fs::write(
+        workspace.path("three/Cargo.toml"),
+        manifest("three", "0.1.0", "GPL-3.0-only", ""),
+    )
+    .unwrap();
+    let run = diff(&["--format", "json"]).expect(2);
+    let out = json(&run.stdout);
+    assert_eq!(
+        out["license_changed"],
+        serde_json::json!([{
+            "name": "one",
+            "version": "0.2.0",
+            "old": ["MIT"],
+            "new": "Apache-2.0",
+        }])
+    );
+    assert_eq!(out["new_licenses"][0]["name"], "three");
+    assert!(
+        run.stderr.contains(
+            "three 0.1.0 is licensed under GPL-3.0-only which is not used by any package in the baseline"
+        ),
+        "{}",
+        run.stderr
+    );
+    let run = diff(&[]).expect(2);
+    assert!(
+        run.stdout
+            .contains("license changed: one 0.2.0 (MIT -> Apache-2.0)\n"),
+        "{}",
+        run.stdout
+    );
+
+    // Identical output run to run
+    assert_eq!(
+        diff(&["--format", "json"]).stdout,
+        diff(&["--format", "json"]).stdout
+    );
+}
+
+#[test]
+fn missing_baseline() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", &manifest("app", "0.1.0", "MIT", "")),
+        ("LICENSE", MIT_TEXT),
+    ]);
+    let run = workspace
+        .run(&["diff", "--baseline", "nowhere.json"])
+        .expect(1);
+    assert!(
+        run.stderr.contains("Couldn't open nowhere.json"),
+        "{}",
+        run.stderr
+    );
+}
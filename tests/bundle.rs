@@ -0,0 +1,2265 @@
+mod common;
+
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use common::{manifest, Run, Workspace, MIT_TEXT};
+use sha2::{Digest, Sha256};
+
+/// `app` depending on two MIT packages that ship their license text
+fn simple() -> Workspace {
+    Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app\", \"one\", \"two\"]\n",
+        ),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\none = { path = \"../one\" }\ntwo = { path = \"../two\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        ("one/Cargo.toml", &manifest("one", "0.1.0", "MIT", "")),
+        ("one/LICENSE", MIT_TEXT),
+        ("two/Cargo.toml", &manifest("two", "0.1.0", "MIT", "")),
+        ("two/LICENSE", MIT_TEXT),
+    ])
+}
+
+#[test]
+fn confidence_thresholds_are_configurable() {
+    let workspace = simple();
+    workspace.run(&["bundle"]).expect(0);
+    let run = workspace
+        .run(&[
+            "bundle",
+            "--confidence-threshold",
+            "0",
+            "--semi-confidence-threshold",
+            "0",
+        ])
+        .expect(3);
+    assert!(
+        run.stderr.contains("very low-confidence candidate")
+            && run.stderr.contains("(threshold 0.00)"),
+        "{}",
+        run.stderr
+    );
+    workspace
+        .run(&["bundle", "--confidence-threshold", "lots"])
+        .expect(1);
+}
+
+/// `app` depending on `fetched` and `unfetched`, resolved into
+/// `metadata.json` before the sources of `unfetched` were removed as a pruned
+/// cache would
+fn unfetched() -> Workspace {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nfetched = { path = \"../fetched\" }\n\
+                 unfetched = { path = \"../unfetched\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "fetched/Cargo.toml",
+            &manifest("fetched", "0.1.0", "MIT", ""),
+        ),
+        ("fetched/LICENSE", MIT_TEXT),
+        (
+            "unfetched/Cargo.toml",
+            &manifest("unfetched", "0.1.0", "MIT", ""),
+        ),
+        ("unfetched/LICENSE", MIT_TEXT),
+    ]);
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+    let metadata = Command::new(cargo)
+        .args(["metadata", "--format-version", "1", "--offline"])
+        .current_dir(workspace.path("app"))
+        .output()
+        .unwrap();
+    assert!(metadata.status.success());
+    fs::write(workspace.path("metadata.json"), metadata.stdout).unwrap();
+    fs::remove_dir_all(workspace.path("unfetched")).unwrap();
+    workspace
+}
+
+/// Not through `Workspace::run`, --offline can't be used with saved metadata
+fn with_metadata(workspace: &Workspace, args: &[&str]) -> Run {
+    Run::from(
+        Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+            .arg("lichking")
+            .arg("--metadata-json")
+            .arg(workspace.path("metadata.json"))
+            .args(args)
+            .current_dir(&workspace.root)
+            .env("RUST_LOG", "warn")
+            .output()
+            .unwrap(),
+    )
+}
+
+#[test]
+fn missing_sources_are_reported_per_package() {
+    let workspace = unfetched();
+    let run = with_metadata(&workspace, &["bundle"]).expect(3);
+    assert!(
+        run.stderr
+            .contains("unfetched 0.1.0 sources are not available")
+            && run.stderr.contains("cargo fetch"),
+        "{}",
+        run.stderr
+    );
+    assert!(run.stdout.contains("fetched"), "{}", run.stdout);
+
+    let run = with_metadata(&workspace, &["bundle", "--require-sources"]).expect(1);
+    assert!(
+        run.stderr
+            .contains("Couldn't read source directory of unfetched 0.1.0"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn failing_packages_fail_every_variant() {
+    let workspace = unfetched();
+    for variant in [
+        "inline", "source", "markdown", "html", "grouped", "json", "split",
+    ] {
+        let dir = workspace.path(variant);
+        let file = workspace.path(&format!("{}.out", variant));
+        let mut args = vec!["bundle", "--require-sources", "--variant", variant];
+        if variant == "split" {
+            args.extend(["--dir", dir.to_str().unwrap()]);
+        }
+        args.extend(["--file", file.to_str().unwrap()]);
+        let run = with_metadata(&workspace, &args).expect(1);
+        assert!(
+            run.stderr
+                .contains("Couldn't read source directory of unfetched 0.1.0"),
+            "{}: {}",
+            variant,
+            run.stderr
+        );
+    }
+
+    // Without --require-sources the unfetched package is still written, and
+    // verifying what each writer serialized finds exactly one entry per package
+    let template = concat!(env!("CARGO_MANIFEST_DIR"), "/templates/attribution.md.tera");
+    for variant in [
+        &["--variant", "json"][..],
+        &["--variant", "source"],
+        &["--variant", "template", "--template", template],
+    ] {
+        let args = [&["bundle", "--verify"][..], variant].concat();
+        let run = with_metadata(&workspace, &args).expect(3);
+        assert!(
+            !run.stderr.contains("doesn't match the packages"),
+            "{:?}: {}",
+            variant,
+            run.stderr
+        );
+        assert!(run.stdout.contains("unfetched"), "{:?}", variant);
+    }
+}
+
+#[test]
+fn parallel_bundles_keep_the_package_order() {
+    let names = (1..=16)
+        .map(|i| format!("dep-{:02}", i))
+        .collect::<Vec<_>>();
+    let mut files = vec![
+        (
+            "Cargo.toml".to_owned(),
+            "[workspace]\nmembers = [\"app\"]\n".to_owned(),
+        ),
+        (
+            "app/Cargo.toml".to_owned(),
+            manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                &format!(
+                    "[dependencies]\n{}",
+                    names
+                        .iter()
+                        .rev()
+                        .map(|name| format!("{} = {{ path = \"../{}\" }}\n", name, name))
+                        .collect::<String>()
+                ),
+            ),
+        ),
+        ("app/LICENSE".to_owned(), MIT_TEXT.to_owned()),
+    ];
+    for name in &names {
+        files.push((
+            format!("{}/Cargo.toml", name),
+            manifest(name, "0.1.0", "MIT", ""),
+        ));
+        files.push((format!("{}/LICENSE", name), MIT_TEXT.to_owned()));
+    }
+    let files = files
+        .iter()
+        .map(|(path, contents)| (path.as_str(), contents.as_str()))
+        .collect::<Vec<_>>();
+    let workspace = Workspace::new(&files);
+
+    let serial = workspace
+        .run(&["bundle", "--variant", "inline", "--jobs", "1"])
+        .expect(0)
+        .stdout;
+    for _ in 0..4 {
+        let parallel = workspace
+            .run(&["bundle", "--variant", "inline", "--jobs", "8"])
+            .expect(0)
+            .stdout;
+        assert_eq!(parallel, serial);
+    }
+
+    let positions = names
+        .iter()
+        .map(|name| serial.find(&format!("{} 0.1.0", name)).unwrap())
+        .collect::<Vec<_>>();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // Split bundles are written as the texts come in
+    let split = |jobs: &str| {
+        let dir = workspace.path(&format!("split-{}", jobs));
+        let index = workspace
+            .run(&[
+                "bundle",
+                "--variant",
+                "split",
+                "--dir",
+                dir.to_str().unwrap(),
+                "--jobs",
+                jobs,
+            ])
+            .expect(0)
+            .stdout;
+        let mut files = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                let text = fs::read_to_string(&path).unwrap();
+                (path.file_name().unwrap().to_owned(), text)
+            })
+            .collect::<Vec<_>>();
+        files.sort();
+        (index, files)
+    };
+    let serial = split("1");
+    assert_eq!(serial.1.len(), names.len() + 1, "{:?}", serial.1);
+    assert_eq!(split("8"), serial);
+}
+
+#[test]
+fn split_bundle_directories() {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\ndep = { path = \"../dep\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        ("dep/Cargo.toml", &manifest("dep", "0.1.0", "MIT", "")),
+        ("dep/LICENSE", MIT_TEXT),
+    ]);
+    // Neither the directory nor the summary file's parents exist yet
+    let dir = workspace.path("target/licenses/thirdparty");
+    let file = workspace.path("reports/nested/THIRDPARTY.txt");
+    let split = |force: bool| {
+        let mut args = vec![
+            "bundle",
+            "--variant",
+            "split",
+            "--dir",
+            dir.to_str().unwrap(),
+            "--file",
+            file.to_str().unwrap(),
+        ];
+        if force {
+            args.push("--force");
+        }
+        workspace.run_in("app", &args)
+    };
+    split(false).expect(0);
+    assert!(fs::read_to_string(&file).unwrap().contains(" * dep 0.1.0"));
+    assert_eq!(fs::read_to_string(dir.join("dep-0.1.0")).unwrap(), MIT_TEXT);
+
+    // A text of a dependency that has since been removed
+    fs::write(dir.join("removed"), MIT_TEXT).unwrap();
+    let run = split(false).expect(1);
+    assert!(
+        run.stderr.contains(&format!(
+            "{} already has files in it, pass --force",
+            dir.display()
+        )),
+        "{}",
+        run.stderr
+    );
+    assert!(dir.join("removed").exists());
+
+    // Subdirectories are left alone
+    fs::create_dir(dir.join("nested")).unwrap();
+    fs::write(dir.join("nested/kept"), MIT_TEXT).unwrap();
+    split(true).expect(0);
+    assert!(!dir.join("removed").exists());
+    assert!(dir.join("nested/kept").exists());
+    assert_eq!(fs::read_to_string(dir.join("dep-0.1.0")).unwrap(), MIT_TEXT);
+
+    // Nothing is deleted from what looks like a source tree
+    let refused = |dir: &str, reason: &str| {
+        let run = workspace
+            .run_in(
+                "app",
+                &["bundle", "--variant", "split", "--dir", dir, "--force"],
+            )
+            .expect(1);
+        assert!(
+            run.stderr.contains("Refusing to delete the files in") && run.stderr.contains(reason),
+            "{}",
+            run.stderr
+        );
+    };
+    refused(".", "it has a Cargo.toml");
+    assert!(workspace.path("app/LICENSE").exists());
+    fs::create_dir(workspace.path(".git")).unwrap();
+    refused("..", "it has a .git");
+    fs::remove_dir(workspace.path(".git")).unwrap();
+    refused("..", "it holds the workspace");
+    assert!(workspace.path("dep/LICENSE").exists());
+}
+
+#[test]
+fn suspicious_texts_are_warnings() {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nstub = { path = \"../stub\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        ("stub/Cargo.toml", &manifest("stub", "0.1.0", "MIT", "")),
+        ("stub/LICENSE-MIT", "MIT\n"),
+    ]);
+    let run = workspace.run_in("app", &["bundle"]).expect(3);
+    assert!(
+        run.stderr.contains("stub license text for MIT at")
+            && run.stderr.contains("is only 4 bytes long"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn grouped_by_license() {
+    let dep = |name: &str| format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    let holder = |name: &str| MIT_TEXT.replace("The Authors", &format!("The {} Authors", name));
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                &format!(
+                    "[dependencies]\n{}{}{}{}",
+                    dep("beta"),
+                    dep("alpha"),
+                    dep("dual"),
+                    dep("odd")
+                ),
+            ),
+        ),
+        ("app/LICENSE", &holder("App")),
+        ("alpha/Cargo.toml", &manifest("alpha", "0.1.0", "MIT", "")),
+        ("alpha/LICENSE", &holder("Alpha")),
+        ("beta/Cargo.toml", &manifest("beta", "0.2.0", "MIT", "")),
+        ("beta/LICENSE", &holder("Beta")),
+        (
+            "dual/Cargo.toml",
+            &manifest("dual", "1.0.0", "MIT OR Apache-2.0", ""),
+        ),
+        ("dual/LICENSE-MIT", &holder("Dual")),
+        ("dual/LICENSE-APACHE", include_str!("../LICENSE-APACHE")),
+        (
+            "odd/Cargo.toml",
+            &manifest("odd", "0.3.0", "LicenseRef-Odd", ""),
+        ),
+        ("odd/LICENSE", "Do whatever you like with odd.\n"),
+    ]);
+    let run = workspace
+        .run_in("app", &["bundle", "--variant", "grouped"])
+        .expect(3);
+    // The odd license has no template, so its text is an unsure match. Both
+    // of dual's texts are confident, it goes under the more permissive MIT.
+    let indent = |text: &str| {
+        text.lines()
+            .map(|line| format!("    {}\n", line))
+            .collect::<String>()
+    };
+    let expected = format!(
+        "\
+The app package (MIT) uses some third party libraries under their own license terms.
+
+===============
+
+The following components are licensed under MIT:
+
+ * alpha 0.1.0
+     Copyright (c) 2020 The Alpha Authors
+ * app 0.1.0
+     Copyright (c) 2020 The App Authors
+ * beta 0.2.0
+     Copyright (c) 2020 The Beta Authors
+ * dual 1.0.0
+     Copyright (c) 2020 The Dual Authors
+
+{}
+===============
+
+The following component is licensed under LicenseRef-Odd:
+
+ * odd 0.3.0
+
+    Do whatever you like with odd.
+",
+        indent(&MIT_TEXT.replace("Copyright (c) 2020 The Authors\n\n", ""))
+    );
+    assert_eq!(run.stdout, expected);
+    assert!(!run.stdout.contains("<COPYRIGHT HOLDER>"), "{}", run.stdout);
+}
+
+#[test]
+fn example_template() {
+    let dep = |name: &str| format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                &format!("[dependencies]\n{}{}", dep("documented"), dep("bare")),
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "documented/Cargo.toml",
+            &manifest(
+                "documented",
+                "1.2.3",
+                "MIT",
+                "description = \"Does things \"\nauthors = [\"Ann <ann@example.com>\", \"Bob\"]\n\
+                 repository = \"https://example.com/documented\"\n",
+            ),
+        ),
+        ("documented/LICENSE", MIT_TEXT),
+        ("bare/Cargo.toml", &manifest("bare", "0.0.1", "MIT", "")),
+    ]);
+    let template = concat!(env!("CARGO_MANIFEST_DIR"), "/templates/attribution.md.tera");
+    let run = workspace
+        .run_in(
+            "app",
+            &[
+                "bundle",
+                "--variant",
+                "template",
+                "--template",
+                template,
+                "--reproducible",
+            ],
+        )
+        .expect(3);
+    let expected = format!(
+        "\
+# Third party licenses
+
+The app package (MIT) uses some third party libraries under their own license
+terms, this list was generated on 1970-01-01T00:00:00Z.
+
+| Package | Version | License |
+|---------|---------|---------|
+| app | 0.1.0 | MIT |
+| bare | 0.0.1 | MIT |
+| [documented](https://example.com/documented) | 1.2.3 | MIT |
+
+## app 0.1.0
+
+### [MIT](https://spdx.org/licenses/MIT.html)
+
+```text
+{mit}
+```
+
+## bare 0.0.1
+
+### [MIT](https://spdx.org/licenses/MIT.html)
+
+*License text not found.*
+
+## documented 1.2.3
+
+Does things
+
+By Ann <ann@example.com>, Bob
+
+### [MIT](https://spdx.org/licenses/MIT.html)
+
+```text
+{mit}
+```
+
+",
+        mit = MIT_TEXT.trim()
+    );
+    assert_eq!(run.stdout, expected);
+}
+
+#[test]
+fn template_errors_point_at_the_expression() {
+    let workspace = simple();
+    let template = workspace.path("broken.tera");
+    fs::write(
+        &template,
+        "{% for package in packages %}\n* {{ package.name }}\n  {{ package.nmae | upper }}\n{% endfor %}\n",
+    )
+    .unwrap();
+    let run = workspace
+        .run(&[
+            "bundle",
+            "--variant",
+            "template",
+            "--template",
+            template.to_str().unwrap(),
+        ])
+        .expect(1);
+    assert!(
+        run.stderr.contains("package.nmae") && run.stderr.contains(":3:6"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn concatenated_license_files_are_split() {
+    // With its long preamble the file as a whole is a poor match for both
+    let preamble = "Short version for non-lawyers: this project is dual-licensed \
+                    under either of the licenses below, at your option. Unless you \
+                    explicitly state otherwise, any contribution intentionally submitted \
+                    for inclusion in the work by you shall be dual licensed as above, \
+                    without any additional terms or conditions.\n\n";
+    let combined = format!(
+        "{}\n==========\n\n{}\n==========\n\n{}",
+        preamble.repeat(8),
+        include_str!("../LICENSE-APACHE"),
+        include_str!("../LICENSE-MIT")
+    );
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\ndual = { path = \"../dual\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "dual/Cargo.toml",
+            &manifest("dual", "1.0.0", "MIT OR Apache-2.0", ""),
+        ),
+        ("dual/LICENSE", &combined),
+    ]);
+    let run = workspace
+        .run_in("app", &["bundle", "--variant", "inline"])
+        .expect(0);
+    let count = |needle: &str| run.stdout.matches(needle).count();
+    assert_eq!(count("The cargo-lichking developers"), 1, "{}", run.stdout);
+    assert_eq!(count("TERMS AND CONDITIONS FOR USE"), 1, "{}", run.stdout);
+    assert_eq!(count("Short version"), 0, "{}", run.stdout);
+    assert_eq!(count("=========="), 0, "{}", run.stdout);
+}
+
+#[test]
+fn verbatim_copies_latin_1_files() {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nlatin = { path = \"../latin\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        ("latin/Cargo.toml", &manifest("latin", "0.3.0", "MIT", "")),
+    ]);
+    // An ISO-8859-1 copyright sign, which isn't valid UTF-8
+    let latin_1: Vec<u8> = MIT_TEXT
+        .replace("(c)", "\u{a9}")
+        .chars()
+        .map(|c| c as u8)
+        .collect();
+    fs::write(workspace.path("latin/LICENSE"), &latin_1).unwrap();
+
+    let dir = workspace.path("licenses");
+    let run = workspace
+        .run_in(
+            "app",
+            &[
+                "bundle",
+                "--variant",
+                "split",
+                "--dir",
+                dir.to_str().unwrap(),
+                "--verbatim",
+            ],
+        )
+        .expect(0);
+    assert!(run.stdout.contains("latin 0.3.0"), "{}", run.stdout);
+    assert_eq!(
+        fs::read(workspace.path("licenses/latin-0.3.0-LICENSE")).unwrap(),
+        latin_1
+    );
+    assert_eq!(
+        fs::read_to_string(workspace.path("licenses/app-0.1.0-LICENSE")).unwrap(),
+        MIT_TEXT
+    );
+
+    // Verbatim copies only go in a directory
+    let run = workspace
+        .run_in("app", &["bundle", "--variant", "inline", "--verbatim"])
+        .expect(1);
+    assert!(run.stderr.contains("--dir <DIR>"), "{}", run.stderr);
+}
+
+#[test]
+fn inline_labels_each_alternative() {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nhalf = { path = \"../half\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "half/Cargo.toml",
+            &manifest("half", "1.0.0", "MIT OR Apache-2.0", ""),
+        ),
+        ("half/LICENSE-MIT", MIT_TEXT),
+    ]);
+    let run = workspace
+        .run_in("app", &["bundle", "--variant", "inline"])
+        .expect(3);
+    let indent = |text: &str| {
+        text.lines()
+            .map(|line| match line {
+                "" => "\n".to_owned(),
+                line => format!("    {}\n", line),
+            })
+            .collect::<String>()
+    };
+    let expected = format!(
+        "\
+The app package (MIT) uses some third party libraries under their own license terms:
+
+ * app 0.1.0 [internal] under the terms of MIT:
+
+{mit}
+
+ * half 1.0.0 [internal] under the terms of MIT / Apache-2.0 (declared as \"MIT OR Apache-2.0\"):
+
+    --- MIT ---
+
+{mit}
+    --- Apache-2.0 --- (license text not found)
+
+
+
+",
+        mit = indent(MIT_TEXT)
+    );
+    assert_eq!(run.stdout, expected);
+}
+
+/// The same packages, with dependencies declared and files written in the
+/// given order
+fn ordered(names: &[&str]) -> Workspace {
+    let deps: String = names
+        .iter()
+        .map(|name| match *name {
+            "beta-2" => "beta2 = { package = \"beta\", path = \"../beta-2\" }\n".to_owned(),
+            name => format!("{} = {{ path = \"../{}\" }}\n", name, name),
+        })
+        .collect();
+    let mut files = vec![
+        (
+            "app/Cargo.toml".to_owned(),
+            manifest("app", "0.1.0", "MIT", &format!("[dependencies]\n{}", deps)),
+        ),
+        ("app/LICENSE".to_owned(), MIT_TEXT.to_owned()),
+    ];
+    for name in names {
+        let (package, version) = match *name {
+            "beta-2" => ("beta", "2.0.0"),
+            name => (name, "1.0.0"),
+        };
+        files.push((
+            format!("{}/Cargo.toml", name),
+            manifest(package, version, "MIT OR Apache-2.0", ""),
+        ));
+        // Several candidates for the same license
+        for file in &["LICENSE-MIT", "LICENSE", "COPYING"] {
+            files.push((format!("{}/{}", name, file), MIT_TEXT.to_owned()));
+        }
+        files.push((
+            format!("{}/LICENSE-APACHE", name),
+            include_str!("../LICENSE-APACHE").to_owned(),
+        ));
+    }
+    let files: Vec<_> = files
+        .iter()
+        .map(|(path, contents)| (path.as_str(), contents.as_str()))
+        .collect();
+    Workspace::new(&files)
+}
+
+#[test]
+fn reproducible_output() {
+    let forwards = ordered(&["alpha", "beta", "beta-2", "gamma"]);
+    let backwards = ordered(&["gamma", "beta-2", "beta", "alpha"]);
+    let template = concat!(env!("CARGO_MANIFEST_DIR"), "/templates/attribution.md.tera");
+    for variant in &[
+        &["--variant", "inline"][..],
+        &["--variant", "markdown"],
+        &["--variant", "template", "--template", template],
+    ] {
+        let bundle = |workspace: &Workspace| {
+            let args: Vec<_> = ["bundle", "--reproducible"]
+                .iter()
+                .chain(*variant)
+                .copied()
+                .collect();
+            workspace.run_in("app", &args).stdout
+        };
+        let first = bundle(&forwards);
+        assert!(!first.is_empty(), "{:?}", variant);
+        assert!(
+            !first.contains(forwards.root.to_str().unwrap()),
+            "{:?}: {}",
+            variant,
+            first
+        );
+        assert_eq!(first, bundle(&backwards), "{:?}", variant);
+        if variant[1] == "inline" {
+            let position = |package: &str| first.find(package).unwrap();
+            assert!(position("alpha 1.0.0") < position("beta 1.0.0"));
+            assert!(position("beta 1.0.0") < position("beta 2.0.0"));
+            assert!(position("beta 2.0.0") < position("gamma 1.0.0"));
+        }
+    }
+}
+
+/// Two workspace members sharing a dependency, `cli` without a license
+fn roots() -> Workspace {
+    let dep = "[dependencies]\nshared = { path = \"../shared\" }\n";
+    Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"core\", \"cli\"]\nexclude = [\"shared\"]\n",
+        ),
+        (
+            "core/Cargo.toml",
+            &manifest("core", "0.1.0", "MIT OR Apache-2.0", dep),
+        ),
+        ("core/LICENSE", MIT_TEXT),
+        (
+            "cli/Cargo.toml",
+            &format!("[package]\nname = \"cli\"\nversion = \"0.1.0\"\n\n{}", dep),
+        ),
+        ("shared/Cargo.toml", &manifest("shared", "0.1.0", "MIT", "")),
+        ("shared/LICENSE", MIT_TEXT),
+    ])
+}
+
+#[test]
+fn headers_name_the_roots_licenses() {
+    let workspace = roots();
+    let header = |run: &Run| run.stdout.lines().next().unwrap().to_owned();
+    let warning = "cli has no license, add a `license` (or `license-file`) field";
+
+    let run = workspace
+        .run(&["bundle", "--variant", "name-only", "-p", "core"])
+        .expect(0);
+    assert_eq!(
+        header(&run),
+        "The core package (MIT / Apache-2.0) uses some third party libraries under their own license terms:"
+    );
+    assert!(!run.stderr.contains(warning), "{}", run.stderr);
+
+    let run = workspace.run(&["bundle", "--variant", "name-only"]);
+    assert_eq!(
+        header(&run),
+        "The core (MIT / Apache-2.0) and cli packages uses some third party libraries under their own license terms:"
+    );
+    assert!(run.stderr.contains(warning), "{}", run.stderr);
+
+    let run = workspace.run(&["bundle", "--variant", "name-only", "-p", "cli"]);
+    assert_eq!(
+        header(&run),
+        "The cli package uses some third party libraries under their own license terms:"
+    );
+    assert!(run.stderr.contains(warning), "{}", run.stderr);
+
+    // List marks the roots by default, or leaves them out
+    let run = workspace.run(&["list", "--by", "crate"]).expect(0);
+    assert_eq!(
+        run.stdout,
+        "\
+cli:    No license specified (workspace root) [internal]
+core:   MIT / Apache-2.0     (workspace root) [internal]
+shared: MIT                  (direct) [internal]
+"
+    );
+    assert!(run.stderr.contains(warning), "{}", run.stderr);
+    let run = workspace
+        .run(&["list", "--by", "crate", "--exclude-roots"])
+        .expect(0);
+    assert_eq!(run.stdout, "shared: MIT (direct) [internal]\n");
+}
+
+/// One package for each kind of license text outcome
+fn outcomes() -> Workspace {
+    let semi = MIT_TEXT
+        .replace("free of charge", "for a modest fee")
+        .replace("without restriction", "with some restrictions")
+        .replace("merchantability", "usefulness");
+    let unsure = MIT_TEXT
+        .replace("Permission is hereby granted", "Nothing is granted")
+        .replace("The above copyright notice", "Nothing")
+        .replace("WITHOUT WARRANTY OF ANY KIND", "WITH A WARRANTY")
+        .replace("IN NO EVENT", "IN EVERY EVENT")
+        .replace("to deal\nin the Software", "to look\nat the Software")
+        .replace(
+            "subject to the following conditions",
+            "provided they pay up front",
+        )
+        .replace("AUTHORS OR COPYRIGHT HOLDERS", "LAWYERS");
+    let deps = ["confident", "semi", "unsure", "missing", "custom"]
+        .iter()
+        .map(|name| format!("{} = {{ path = \"{}\" }}\n", name, name))
+        .collect::<String>();
+    Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest("app", "0.1.0", "MIT", &format!("[dependencies]\n{}", deps)),
+        ),
+        ("LICENSE", MIT_TEXT),
+        (
+            "confident/Cargo.toml",
+            &manifest("confident", "0.1.0", "MIT", ""),
+        ),
+        ("confident/LICENSE", MIT_TEXT),
+        ("semi/Cargo.toml", &manifest("semi", "0.1.0", "MIT", "")),
+        ("semi/LICENSE", &semi),
+        ("unsure/Cargo.toml", &manifest("unsure", "0.1.0", "MIT", "")),
+        ("unsure/LICENSE", &unsure),
+        (
+            "missing/Cargo.toml",
+            &manifest("missing", "0.1.0", "MIT", ""),
+        ),
+        (
+            "custom/Cargo.toml",
+            &manifest("custom", "0.1.0", "Proprietary", ""),
+        ),
+    ])
+}
+
+#[test]
+fn summary_counts() {
+    let workspace = outcomes();
+    // Times vary, only the counts are compared
+    let counts = |stderr: &str| {
+        let footer = stderr
+            .lines()
+            .find(|line| line.starts_with("analyzed "))
+            .unwrap_or_else(|| panic!("no footer:\n{}", stderr))
+            .to_owned();
+        let (packages, rest) = footer.split_once(" in ").unwrap();
+        format!("{}:{}", packages, rest.split_once(':').unwrap().1)
+    };
+
+    // The app and confident texts, and custom's missing one as it's unknown
+    let run = workspace.run(&["bundle"]).expect(3);
+    assert_eq!(
+        counts(&run.stderr),
+        "analyzed 6 packages: 1 unknown licenses; 6 license texts: 2 confident, \
+         1 semi-confident, 1 unsure, 2 missing"
+    );
+    let run = workspace.run(&["list"]).expect(0);
+    assert_eq!(
+        counts(&run.stderr),
+        "analyzed 6 packages: 1 unknown licenses"
+    );
+    let run = workspace.run(&["--quiet", "bundle"]).expect(3);
+    assert!(!run.stderr.contains("analyzed"), "{}", run.stderr);
+
+    let run = workspace.run(&["list", "--format", "json"]).expect(0);
+    let summary = run.stdout.split("\"summary\": ").nth(1).unwrap();
+    let summary = summary.split_whitespace().collect::<Vec<_>>().join(" ");
+    assert!(
+        summary.starts_with(
+            "{ \"packages\": 6, \"unknown_licenses\": 1, \"confident\": 0, \
+             \"semi_confident\": 0, \"unsure\": 0, \"missing_texts\": 0 }"
+        ),
+        "{}",
+        summary
+    );
+}
+
+#[test]
+fn oversized_binary_and_html_files() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nbinary = { path = \"binary\" }\nhtml = { path = \"html\" }\n",
+            ),
+        ),
+        ("LICENSE", MIT_TEXT),
+        ("binary/Cargo.toml", &manifest("binary", "0.1.0", "MIT", "")),
+        ("binary/LICENSE", "%PDF-1.4\n\0\0\0\n"),
+        ("html/Cargo.toml", &manifest("html", "0.1.0", "MIT", "")),
+        (
+            "html/LICENSE.html",
+            &format!("<html><body><pre>{}</pre></body></html>\n", MIT_TEXT),
+        ),
+    ]);
+    let binary = format!(
+        "binary 0.1.0 license file {} was skipped, binary content",
+        workspace.path("binary/LICENSE").display()
+    );
+
+    // Skipped files are reported, and leave the package without a text
+    let run = workspace.run(&["bundle"]).expect(3);
+    assert!(run.stderr.contains(&binary), "{}", run.stderr);
+    assert!(
+        run.stderr
+            .contains("Some license files were skipped as too large or binary"),
+        "{}",
+        run.stderr
+    );
+    assert!(
+        run.stderr
+            .contains("binary has no candidate texts for license MIT"),
+        "{}",
+        run.stderr
+    );
+    let html = run.stdout.split(" * html 0.1.0").nth(1).unwrap();
+    assert!(
+        html.starts_with(
+            " [internal] under the terms of MIT:\n\n    (markup stripped from LICENSE.html)\n\n    MIT License\n"
+        ),
+        "{}",
+        html
+    );
+    assert!(!html.contains("<pre>"), "{}", html);
+
+    let run = workspace
+        .run(&["bundle", "--max-license-file-size", "1K"])
+        .expect(3);
+    let html = format!(
+        "html 0.1.0 license file {} was skipped, {} bytes is over the 1024 byte limit",
+        workspace.path("html/LICENSE.html").display(),
+        MIT_TEXT.len() + "<html><body><pre></pre></body></html>\n".len()
+    );
+    assert!(run.stderr.contains(&html), "{}", run.stderr);
+    assert!(run.stderr.contains("0 unsure, 3 missing"), "{}", run.stderr);
+
+    // And listed as issues with the package
+    let run = workspace
+        .run(&["bundle", "--variant", "json", "--reproducible"])
+        .expect(3);
+    let bundle: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let issues = |name: &str| {
+        bundle
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|package| package["name"] == name)
+            .unwrap()
+            .get("issues")
+            .cloned()
+    };
+    assert_eq!(
+        issues("binary"),
+        Some(serde_json::json!([
+            "license file binary/LICENSE was skipped, binary content"
+        ]))
+    );
+    assert_eq!(issues("html"), None);
+}
+
+#[test]
+fn json_bundle() {
+    let workspace = outcomes();
+    let run = workspace
+        .run(&["bundle", "--variant", "json", "--reproducible"])
+        .expect(3);
+    assert!(run.stdout.ends_with("]\n"), "{}", run.stdout);
+    let bundle: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let packages = bundle.as_array().unwrap();
+    assert_eq!(
+        packages
+            .iter()
+            .map(|package| package["name"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        ["app", "confident", "custom", "missing", "semi", "unsure"]
+    );
+
+    let confident = &packages[1];
+    assert_eq!(confident["version"], "0.1.0");
+    assert_eq!(confident["license"], "MIT");
+    let license = &confident["licenses"][0];
+    assert_eq!(license["spdx_id_or_name"], "MIT");
+    assert_eq!(license["text"], MIT_TEXT);
+    assert_eq!(license["path"], "confident/LICENSE");
+    assert_eq!(license["confidence"], "confident");
+    assert_eq!(license["info"], "license-file");
+    assert_eq!(packages[4]["licenses"][0]["confidence"], "semi-confident");
+
+    let missing = &packages[3]["licenses"][0];
+    assert_eq!(missing["text"], serde_json::Value::Null);
+    assert_eq!(missing["confidence"], serde_json::Value::Null);
+    assert_eq!(missing["info"], "missing");
+
+    // Deterministic, and written to --file like the other variants
+    let file = workspace.path("licenses.json");
+    let run_file = workspace
+        .run(&[
+            "bundle",
+            "--variant",
+            "json",
+            "--reproducible",
+            "--file",
+            file.to_str().unwrap(),
+        ])
+        .expect(3);
+    assert_eq!(run_file.stdout, "");
+    assert_eq!(fs::read_to_string(&file).unwrap(), run.stdout);
+}
+
+#[test]
+fn license_text_provenance() {
+    let workspace = simple();
+    // Hashed as it is, not as the text decoded from it
+    let mut license = MIT_TEXT.as_bytes().to_vec();
+    license.extend_from_slice(b"\nCopyright \xa9 The Authors\n");
+    fs::remove_file(workspace.path("one/LICENSE")).unwrap();
+    fs::write(workspace.path("one/LICENSE-MIT"), &license).unwrap();
+    let sha256 = format!(
+        "{:x}",
+        Sha256::digest(&fs::read(workspace.path("one/LICENSE-MIT")).unwrap())
+    );
+
+    let run = workspace
+        .run(&["bundle", "--variant", "json", "--reproducible", "-p", "app"])
+        .expect(0);
+    let bundle: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let one = bundle
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|package| package["name"] == "one")
+        .unwrap();
+    let provenance = &one["licenses"][0]["provenance"];
+    assert_eq!(provenance["path"], "LICENSE-MIT");
+    assert_eq!(provenance["size"], license.len());
+    assert_eq!(provenance["sha256"], sha256);
+    assert_eq!(provenance.get("absolute_path"), None);
+
+    let run = workspace
+        .run(&["bundle", "--variant", "json", "-p", "app"])
+        .expect(0);
+    let bundle: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let absolute = bundle[1]["licenses"][0]["provenance"]["absolute_path"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+    assert_eq!(
+        fs::canonicalize(absolute).unwrap(),
+        fs::canonicalize(workspace.path("one/LICENSE-MIT")).unwrap()
+    );
+
+    let annotation = format!("    (source: LICENSE-MIT, sha256: {})\n", sha256);
+    let run = workspace.run(&["bundle", "-p", "app"]).expect(0);
+    assert!(!run.stdout.contains("sha256"), "{}", run.stdout);
+    let run = workspace
+        .run(&["bundle", "--print-hashes", "-p", "app"])
+        .expect(0);
+    assert!(run.stdout.contains(&annotation), "{}", run.stdout);
+
+    let run = workspace
+        .run(&[
+            "bundle",
+            "--variant",
+            "split",
+            "--dir",
+            "licenses",
+            "--print-hashes",
+            "-p",
+            "app",
+        ])
+        .expect(0);
+    assert!(
+        run.stdout.contains(&format!(
+            " * one 0.1.0 [internal] under the terms of MIT\n{}",
+            &annotation[1..]
+        )),
+        "{}",
+        run.stdout
+    );
+
+    workspace
+        .run(&["bundle", "--variant", "markdown", "--print-hashes"])
+        .expect(1);
+}
+
+#[test]
+fn preamble_and_postamble() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"app\", \"one\"]\n"),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\none = { path = \"../one\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        ("one/Cargo.toml", &manifest("one", "0.1.0", "MIT", "")),
+        ("one/LICENSE", MIT_TEXT),
+        ("NOTICE.head", "Copyright ACME\n\nAll the notices follow."),
+        ("NOTICE.tail", "End of notices.\n"),
+    ]);
+    let preamble = workspace.path("NOTICE.head");
+    let postamble = workspace.path("NOTICE.tail");
+    let run = workspace
+        .run(&[
+            "bundle",
+            "--variant",
+            "name-only",
+            "--preamble",
+            preamble.to_str().unwrap(),
+            "--postamble",
+            postamble.to_str().unwrap(),
+        ])
+        .expect(0);
+    assert_eq!(
+        run.stdout,
+        "Copyright ACME\n\nAll the notices follow.\n\n\
+         The app (MIT) and one (MIT) packages uses some third party libraries under their own \
+         license terms:\n\n \
+         * app 0.1.0 [internal] under the terms of MIT <https://spdx.org/licenses/MIT.html>\n \
+         * one 0.1.0 [internal] under the terms of MIT <https://spdx.org/licenses/MIT.html>\n\n\
+         End of notices.\n"
+    );
+
+    let run = workspace
+        .run(&["bundle", "--preamble", preamble.to_str().unwrap()])
+        .expect(0);
+    assert!(
+        run.stdout
+            .starts_with("Copyright ACME\n\nAll the notices follow.\n\nThe app (MIT)"),
+        "{}",
+        run.stdout
+    );
+
+    let run = workspace
+        .run(&[
+            "bundle",
+            "--variant",
+            "html",
+            "--preamble",
+            preamble.to_str().unwrap(),
+        ])
+        .expect(0);
+    assert!(
+        run.stdout
+            .contains("<body>\n<p>Copyright ACME</p>\n<p>All the notices follow.</p>\n<h1>"),
+        "{}",
+        run.stdout
+    );
+
+    // Appending keeps what's already in the file
+    let file = workspace.path("NOTICE");
+    fs::write(&file, "Existing notices.\n").unwrap();
+    workspace
+        .run(&[
+            "bundle",
+            "--variant",
+            "name-only",
+            "--file",
+            file.to_str().unwrap(),
+            "--append",
+        ])
+        .expect(0);
+    let notice = fs::read_to_string(&file).unwrap();
+    assert!(
+        notice.starts_with("Existing notices.\nThe app (MIT)"),
+        "{}",
+        notice
+    );
+}
+
+#[test]
+fn missing_preamble_writes_nothing() {
+    let workspace = simple();
+    let file = workspace.path("NOTICE");
+    fs::write(&file, "Existing notices.\n").unwrap();
+    let run = workspace
+        .run(&[
+            "bundle",
+            "--preamble",
+            workspace.path("missing").to_str().unwrap(),
+            "--file",
+            file.to_str().unwrap(),
+        ])
+        .expect(1);
+    assert!(
+        run.stderr.contains("Couldn't read preamble"),
+        "{}",
+        run.stderr
+    );
+    assert_eq!(fs::read_to_string(&file).unwrap(), "Existing notices.\n");
+
+    workspace
+        .run(&["bundle", "--variant", "json", "--preamble", "Cargo.toml"])
+        .expect(1);
+}
+
+#[test]
+fn per_root_bundles() {
+    let semi = MIT_TEXT
+        .replace("free of charge", "for a modest fee")
+        .replace("without restriction", "with some restrictions")
+        .replace("merchantability", "usefulness");
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"one\", \"two\"]\n"),
+        (
+            "one/Cargo.toml",
+            &manifest(
+                "one",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nshared = { path = \"../shared\" }\n\
+                 onlyone = { path = \"../onlyone\" }\n",
+            ),
+        ),
+        ("one/LICENSE", MIT_TEXT),
+        (
+            "two/Cargo.toml",
+            &manifest(
+                "two",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nshared = { path = \"../shared\" }\n\
+                 onlytwo = { path = \"../onlytwo\" }\n",
+            ),
+        ),
+        ("two/LICENSE", MIT_TEXT),
+        ("shared/Cargo.toml", &manifest("shared", "0.1.0", "MIT", "")),
+        ("shared/LICENSE", &semi),
+        (
+            "onlyone/Cargo.toml",
+            &manifest("onlyone", "0.1.0", "MIT", ""),
+        ),
+        ("onlyone/LICENSE", MIT_TEXT),
+        // No license text to be found
+        (
+            "onlytwo/Cargo.toml",
+            &manifest("onlytwo", "0.1.0", "MIT", ""),
+        ),
+    ]);
+    let dir = workspace.path("thirdparty");
+    let run = workspace
+        .run(&[
+            "bundle",
+            "-p",
+            "one",
+            "-p",
+            "two",
+            "--per-root",
+            "--output-dir",
+            dir.to_str().unwrap(),
+        ])
+        .expect(3);
+    assert_eq!(run.stdout, "");
+    assert!(
+        run.stderr
+            .contains("two: Couldn't find license texts for some packages")
+            && !run.stderr.contains("one: Couldn't find"),
+        "{}",
+        run.stderr
+    );
+    // The shared dependency is only searched once
+    assert_eq!(
+        run.stderr
+            .matches("shared has only a low-confidence candidate")
+            .count(),
+        1,
+        "{}",
+        run.stderr
+    );
+
+    let one = fs::read_to_string(dir.join("one-THIRDPARTY.txt")).unwrap();
+    let two = fs::read_to_string(dir.join("two-THIRDPARTY.txt")).unwrap();
+    assert!(
+        one.starts_with("The one package (MIT) uses")
+            && one.contains(" * shared 0.1.0")
+            && one.contains(" * onlyone 0.1.0")
+            && !one.contains("onlytwo"),
+        "{}",
+        one
+    );
+    assert!(
+        two.starts_with("The two package (MIT) uses")
+            && two.contains(" * shared 0.1.0")
+            && two.contains(" * onlytwo 0.1.0")
+            && !two.contains("onlyone"),
+        "{}",
+        two
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("index.txt")).unwrap(),
+        "Third party license bundles, one per package:\n\n\
+         one 0.1.0: one-THIRDPARTY.txt (succeeded)\n\
+         two 0.1.0: two-THIRDPARTY.txt (missing licenses: Couldn't find license texts for \
+         some packages)\n"
+    );
+
+    workspace
+        .run(&["bundle", "--per-root", "--variant", "json"])
+        .expect(1);
+}
+
+#[test]
+fn per_root_sections() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"tool\", \"lib\"]\n"),
+        (
+            "tool/Cargo.toml",
+            &manifest(
+                "tool",
+                "0.1.0",
+                "GPL-3.0-only",
+                "[dependencies]\nshared = { path = \"../shared\" }\n\
+                 gpldep = { path = \"../gpldep\" }\n",
+            ),
+        ),
+        (
+            "lib/Cargo.toml",
+            &manifest(
+                "lib",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nshared = { path = \"../shared\" }\n",
+            ),
+        ),
+        ("shared/Cargo.toml", &manifest("shared", "0.1.0", "MIT", "")),
+        (
+            "gpldep/Cargo.toml",
+            &manifest("gpldep", "0.1.0", "GPL-3.0-only", ""),
+        ),
+    ]);
+    let bundle = |scope: &str| {
+        workspace
+            .run(&[
+                "bundle",
+                "--variant",
+                "name-only",
+                "-p",
+                "tool",
+                "-p",
+                "lib",
+                "--scope",
+                scope,
+            ])
+            .expect(0)
+            .stdout
+    };
+
+    let union = bundle("union");
+    assert!(
+        union.starts_with("The tool (GPL-3.0-only) and lib (MIT) packages uses")
+            && union.contains(" * gpldep 0.1.0"),
+        "{}",
+        union
+    );
+
+    let per_root = bundle("per-root");
+    let sections = per_root
+        .split("\n\nThe ")
+        .map(|section| section.trim_start_matches("The "))
+        .collect::<Vec<_>>();
+    assert_eq!(sections.len(), 2, "{}", per_root);
+    assert!(
+        sections[0].starts_with("tool package (GPL-3.0-only) uses")
+            && sections[0].contains(" * gpldep 0.1.0")
+            && sections[0].contains(" * shared 0.1.0"),
+        "{}",
+        per_root
+    );
+    // Only the tool depends on the GPL package
+    assert!(
+        sections[1].starts_with("lib package (MIT) uses")
+            && sections[1].contains(" * shared 0.1.0")
+            && !sections[1].contains("gpldep"),
+        "{}",
+        per_root
+    );
+
+    // Into a file, each section following the last
+    let file = workspace.path("THIRDPARTY");
+    workspace
+        .run(&[
+            "bundle",
+            "--variant",
+            "name-only",
+            "-p",
+            "tool",
+            "-p",
+            "lib",
+            "--scope",
+            "per-root",
+            "--file",
+            file.to_str().unwrap(),
+        ])
+        .expect(0);
+    assert_eq!(fs::read_to_string(&file).unwrap(), per_root);
+
+    workspace
+        .run(&["bundle", "--scope", "per-root", "--variant", "json"])
+        .expect(1);
+    workspace.run(&["bundle", "--output-dir", "out"]).expect(1);
+}
+
+#[test]
+fn copied_license_holders() {
+    let authored = |name: &str, rest: &str| {
+        manifest(
+            name,
+            "0.1.0",
+            "MIT",
+            &format!("authors = [\"Jane Doe <jane@example.com>\"]\n{}", rest),
+        )
+    };
+    let copied = MIT_TEXT.replace("The Authors", "Foo Corp");
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app\", \"copied\"]\n",
+        ),
+        (
+            "app/Cargo.toml",
+            &authored("app", "[dependencies]\ncopied = { path = \"../copied\" }\n"),
+        ),
+        ("app/LICENSE", &MIT_TEXT.replace("The Authors", "Jane Doe")),
+        ("copied/Cargo.toml", &authored("copied", "")),
+        ("copied/LICENSE", &copied),
+    ]);
+    let warning = "has copyright holder 'Foo Corp' which doesn't match";
+
+    let run = workspace.run_in("app", &["bundle"]).expect(0);
+    assert!(!run.stderr.contains(warning), "{}", run.stderr);
+
+    // Only a warning, the bundle still succeeds
+    let run = workspace
+        .run_in("app", &["bundle", "--check-holders"])
+        .expect(0);
+    assert_eq!(run.stderr.matches(warning).count(), 1, "{}", run.stderr);
+    assert!(
+        run.stderr.contains("copied license text for MIT"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn elected_license_texts() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"app\", \"dual\"]\n"),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\ndual = { path = \"../dual\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "dual/Cargo.toml",
+            &manifest("dual", "0.1.0", "MIT OR Apache-2.0", ""),
+        ),
+        ("dual/LICENSE-MIT", MIT_TEXT),
+    ]);
+
+    // Every alternative's text is needed, the Apache-2.0 one is missing
+    let run = workspace.run_in("app", &["bundle"]).expect(3);
+    assert!(run.stdout.contains("Apache-2.0"), "{}", run.stdout);
+
+    let run = workspace
+        .run_in("app", &["bundle", "--prefer", "MIT"])
+        .expect(0);
+    assert!(
+        run.stdout.contains(
+            "dual 0.1.0 [internal] under the terms of MIT (declared as \"MIT OR Apache-2.0\"):"
+        ),
+        "{}",
+        run.stdout
+    );
+    assert_eq!(
+        run.stdout.matches("Apache-2.0").count(),
+        1,
+        "{}",
+        run.stdout
+    );
+
+    // Falling back to all of them when none is preferred, warned about once
+    // however many times the package is looked at
+    let run = workspace
+        .run(&[
+            "bundle",
+            "--variant",
+            "name-only",
+            "--prefer",
+            "ISC",
+            "--scope",
+            "per-root",
+            "-p",
+            "app",
+            "-p",
+            "dual",
+        ])
+        .expect(0);
+    assert_eq!(
+        run.stderr
+            .matches("dual 0.1.0 is licensed under MIT / Apache-2.0 and none of them is preferred")
+            .count(),
+        1,
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn normalized_license_texts() {
+    let text = format!(
+        "\u{feff}{}",
+        MIT_TEXT
+            .replace("MIT License\n", "MIT License   \n\n\n\n\n")
+            .replace('\n', "\r\n")
+    );
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", &manifest("app", "0.1.0", "MIT", "")),
+        ("LICENSE", &text),
+        ("src/lib.rs", ""),
+    ]);
+
+    let run = workspace.run(&["bundle"]).expect(0);
+    assert!(!run.stdout.contains(['\r', '\u{feff}']), "{:?}", run.stdout);
+    assert!(!run.stdout.contains(" \n"), "{:?}", run.stdout);
+    assert!(
+        run.stdout.contains("MIT License\n\n\n    Copyright"),
+        "{}",
+        run.stdout
+    );
+
+    let run = workspace
+        .run(&["bundle", "--line-endings", "crlf"])
+        .expect(0);
+    assert_eq!(
+        run.stdout.matches('\n').count(),
+        run.stdout.matches("\r\n").count(),
+        "{:?}",
+        run.stdout
+    );
+    assert!(!run.stdout.contains('\u{feff}'), "{:?}", run.stdout);
+
+    // Byte-for-byte texts keep their whitespace, an inline bundle still
+    // writes its own line endings
+    let run = workspace.run(&["bundle", "--no-normalize"]).expect(0);
+    assert!(
+        run.stdout.contains("\u{feff}MIT License   \n\n\n\n\n\n"),
+        "{:?}",
+        run.stdout
+    );
+    workspace
+        .run(&["bundle", "--no-normalize", "--line-endings", "lf"])
+        .expect(1);
+}
+
+#[test]
+fn links_to_license_texts() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\ndual = { path = \"dual\" }\nllvm = { path = \"llvm\" }\n\
+                 odd = { path = \"odd\" }\n",
+            ),
+        ),
+        ("LICENSE", MIT_TEXT),
+        (
+            "dual/Cargo.toml",
+            &manifest("dual", "0.1.0", "MIT OR Apache-2.0", ""),
+        ),
+        ("dual/LICENSE-MIT", MIT_TEXT),
+        ("dual/LICENSE-APACHE", include_str!("../LICENSE-APACHE")),
+        (
+            "llvm/Cargo.toml",
+            &manifest("llvm", "0.1.0", "Apache-2.0 WITH LLVM-exception", ""),
+        ),
+        ("llvm/LICENSE", include_str!("../LICENSE-APACHE")),
+        (
+            "odd/Cargo.toml",
+            &manifest("odd", "0.1.0", "LicenseRef-Odd", ""),
+        ),
+    ]);
+    let mit = "https://spdx.org/licenses/MIT.html";
+    let apache = "https://spdx.org/licenses/Apache-2.0.html";
+
+    let run = workspace
+        .run(&["bundle", "--variant", "name-only"])
+        .expect(0);
+    for line in [
+        format!(
+            " * dual 0.1.0 [internal] under the terms of MIT / Apache-2.0 <{}> <{}> (declared",
+            mit, apache
+        ),
+        format!(
+            " * llvm 0.1.0 [internal] under the terms of Apache-2.0 WITH LLVM-exception <{}>\n",
+            apache
+        ),
+        " * odd 0.1.0 [internal] under the terms of LicenseRef-Odd\n".to_owned(),
+    ] {
+        assert!(run.stdout.contains(&line), "{}", run.stdout);
+    }
+
+    let run = workspace.run(&["bundle", "--variant", "markdown"]);
+    for heading in [
+        format!(
+            "## dual 0.1.0 \\[internal\\] — [MIT]({}) / [Apache-2.0]({}) (declared",
+            mit, apache
+        ),
+        format!(
+            "## llvm 0.1.0 \\[internal\\] — [Apache-2.0]({}) WITH LLVM-exception\n",
+            apache
+        ),
+        "## odd 0.1.0 \\[internal\\] — LicenseRef-Odd\n".to_owned(),
+    ] {
+        assert!(run.stdout.contains(&heading), "{}", run.stdout);
+    }
+
+    let run = workspace.run(&["bundle", "--variant", "html"]);
+    assert!(
+        run.stdout.contains(&format!(
+            "<h2>llvm 0.1.0 [internal] — <a href=\"{}\">Apache-2.0</a> WITH LLVM-exception</h2>",
+            apache
+        )),
+        "{}",
+        run.stdout
+    );
+
+    let run = workspace.run(&["bundle", "--variant", "json"]);
+    let packages: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let urls = |name: &str| {
+        packages
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|package| package["name"] == name)
+            .unwrap()["licenses"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|license| license["license_url"].clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(urls("dual"), [mit, apache]);
+    assert_eq!(urls("llvm"), [apache]);
+    assert_eq!(urls("odd"), [serde_json::Value::Null]);
+}
+
+#[test]
+fn vendored_copies() {
+    let patched = MIT_TEXT.replace("The Authors", "Patched Holder");
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\npatched = { path = \"../registry/patched-1.0.0\" }\n\
+                 stale = { path = \"../registry/stale-2.0.0\" }\n\
+                 bare = { path = \"../registry/bare-1.0.0\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "registry/patched-1.0.0/Cargo.toml",
+            &manifest("patched", "1.0.0", "MIT", ""),
+        ),
+        ("registry/patched-1.0.0/LICENSE", MIT_TEXT),
+        (
+            "registry/stale-2.0.0/Cargo.toml",
+            &manifest("stale", "2.0.0", "MIT", ""),
+        ),
+        ("registry/stale-2.0.0/LICENSE", MIT_TEXT),
+        (
+            "registry/bare-1.0.0/Cargo.toml",
+            &manifest("bare", "1.0.0", "MIT", ""),
+        ),
+        ("registry/bare-1.0.0/LICENSE", MIT_TEXT),
+        // The patched license file wins over the registry's
+        (
+            "vendor/patched-1.0.0/Cargo.toml",
+            &manifest("patched", "1.0.0", "MIT", ""),
+        ),
+        ("vendor/patched-1.0.0/LICENSE", &patched),
+        // Vendored before an update, so not used
+        (
+            "vendor/stale/Cargo.toml",
+            &manifest("stale", "1.9.0", "MIT", ""),
+        ),
+        ("vendor/stale/LICENSE", &patched),
+        // Without a license file of its own the registry's is used
+        (
+            "vendor/bare/Cargo.toml",
+            &manifest("bare", "1.0.0", "MIT", ""),
+        ),
+    ]);
+    workspace.registry_metadata("app");
+
+    let run = Run::from(
+        Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+            .args(["lichking", "--metadata-json", "metadata.json"])
+            .args(["--vendor-dir", "vendor", "bundle", "--variant", "json"])
+            .current_dir(&workspace.root)
+            .output()
+            .unwrap(),
+    )
+    .expect(3);
+    assert!(
+        run.stderr.contains(&format!(
+            "{} is a vendored copy of stale 1.9.0 but 2.0.0 is resolved",
+            workspace.path("vendor/stale").display()
+        )),
+        "{}",
+        run.stderr
+    );
+    let packages: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let license = |name: &str| {
+        packages
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|package| package["name"] == name)
+            .unwrap()["licenses"][0]
+            .clone()
+    };
+
+    let vendored = license("patched");
+    assert_eq!(vendored["text"], patched);
+    assert_eq!(
+        vendored["path"],
+        workspace
+            .path("vendor/patched-1.0.0/LICENSE")
+            .to_str()
+            .unwrap()
+    );
+    assert_eq!(vendored["provenance"]["path"], "LICENSE");
+    assert_eq!(vendored["provenance"]["vendored"], true);
+    for name in ["stale", "bare"] {
+        let license = license(name);
+        assert_eq!(license["text"], MIT_TEXT, "{}", name);
+        assert!(
+            license["path"]
+                .as_str()
+                .unwrap()
+                .contains(&format!("registry/{}-", name)),
+            "{}",
+            license
+        );
+        assert_eq!(license["provenance"].get("vendored"), None);
+    }
+
+    // Without it the registry copies are used
+    let run = Run::from(
+        Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+            .args(["lichking", "--metadata-json", "metadata.json"])
+            .args(["bundle", "--variant", "json"])
+            .current_dir(&workspace.root)
+            .output()
+            .unwrap(),
+    )
+    .expect(0);
+    assert!(!run.stdout.contains("Patched Holder"), "{}", run.stdout);
+}
+
+const INTERNAL_TEXT: &str = "\
+Proprietary Internal Use License, version 1.0
+
+Copyright (c) 2024 Example Corporation. All rights reserved.
+
+This software and its documentation are the confidential property of Example
+Corporation and may only be used by its employees and contractors for internal
+business purposes. It may not be copied, modified, distributed, sublicensed or
+disclosed to any third party without prior written permission.
+
+THE SOFTWARE IS PROVIDED AS IS, WITHOUT WARRANTY OF ANY KIND.
+";
+
+#[test]
+fn custom_license_templates() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\ninternal = { path = \"internal\" }\n",
+            ),
+        ),
+        ("LICENSE", MIT_TEXT),
+        (
+            "internal/Cargo.toml",
+            &manifest("internal", "0.1.0", "Proprietary-InternalUse-1.0", ""),
+        ),
+        ("internal/LICENSE", &INTERNAL_TEXT.replace("2024", "2025")),
+        ("templates/proprietary-internaluse-1.0.txt", INTERNAL_TEXT),
+        ("overrides/MIT.txt", MIT_TEXT),
+    ]);
+    let run = workspace.run(&["bundle"]).expect(3);
+    assert!(
+        run.stderr.contains("very low-confidence candidate"),
+        "{}",
+        run.stderr
+    );
+
+    let run = workspace
+        .run(&[
+            "--license-templates",
+            "templates",
+            "bundle",
+            "--variant",
+            "json",
+        ])
+        .expect(0);
+    let bundle: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let internal = &bundle[1];
+    assert_eq!(internal["license"], "Proprietary-InternalUse-1.0");
+    assert_eq!(internal["licenses"][0]["confidence"], "confident");
+
+    let run = workspace
+        .run(&["--license-templates", "overrides", "bundle"])
+        .expect(3);
+    assert!(
+        run.stderr
+            .contains("warning: overrides/MIT.txt replaces the bundled template of MIT"),
+        "{}",
+        run.stderr
+    );
+
+    workspace
+        .run(&["--license-templates", "missing", "bundle"])
+        .expect(1);
+}
+
+#[test]
+fn packages_from_a_list() {
+    let workspace = simple();
+    fs::write(
+        workspace.path("packages.txt"),
+        "one@0.1.0\nbogus@1.0.0\n\ntwo@0.1.0\n",
+    )
+    .unwrap();
+    let run = workspace
+        .run(&["bundle", "--packages-from", "packages.txt"])
+        .expect(1);
+    assert!(
+        run.stderr
+            .contains("match these entries of package list packages.txt: bogus@1.0.0"),
+        "{}",
+        run.stderr
+    );
+    assert!(!run.stderr.contains("one@0.1.0"), "{}", run.stderr);
+
+    // Read from standard in as a JSON array, and exactly those are bundled
+    let mut child = workspace
+        .command(
+            "",
+            &[
+                "bundle",
+                "--variant",
+                "json",
+                "--reproducible",
+                "--packages-from",
+                "-",
+            ],
+        )
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(br#"["two@0.1.0", "app@0.1.0"]"#)
+        .unwrap();
+    let run = Run::from(child.wait_with_output().unwrap()).expect(0);
+    let bundle: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    assert_eq!(
+        bundle
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|package| package["name"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        ["app", "two"]
+    );
+
+    workspace
+        .run(&[
+            "list",
+            "--packages-from",
+            "packages.txt",
+            "--scope",
+            "per-root",
+        ])
+        .expect(1);
+}
+
+/// `app` depending on packages with all, some and none of the metadata
+/// `--with-metadata` adds
+fn attributed() -> Workspace {
+    let long = "A description long enough that it has to be shortened to keep the entry \
+                readable, it goes on and on about what the package does";
+    Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"app\"]\n"),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nfull = { path = \"../full\" }\n\
+                 homepage = { path = \"../homepage\" }\nbare = { path = \"../bare\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "full/Cargo.toml",
+            &manifest(
+                "full",
+                "0.1.0",
+                "MIT",
+                &format!(
+                    "description = \"\"\"\n{}\nSecond line.\"\"\"\n\
+                     repository = \"https://example.com/full\"\n\
+                     homepage = \"https://full.example.com\"\n\
+                     authors = [\"Zoë Ångström <zoe@example.com>\", \"李雷\"]\n",
+                    long
+                ),
+            ),
+        ),
+        ("full/LICENSE", MIT_TEXT),
+        (
+            "homepage/Cargo.toml",
+            &manifest(
+                "homepage",
+                "0.1.0",
+                "MIT",
+                "description = \"Has a *homepage*\"\nhomepage = \"https://homepage.example.com\"\n",
+            ),
+        ),
+        ("homepage/LICENSE", MIT_TEXT),
+        ("bare/Cargo.toml", &manifest("bare", "0.1.0", "MIT", "")),
+        ("bare/LICENSE", MIT_TEXT),
+    ])
+}
+
+#[test]
+fn package_metadata_in_entries() {
+    let workspace = attributed();
+    // Each entry up to its license text
+    let heads = |run: &Run, start: &str, end: &str| {
+        let mut heads = String::new();
+        let mut inside = false;
+        for line in run.stdout.lines() {
+            if line.starts_with(start) {
+                inside = true;
+            } else if line.contains(end) {
+                inside = false;
+            }
+            if inside {
+                heads.push_str(line);
+                heads.push('\n');
+            }
+        }
+        heads
+    };
+
+    let run = workspace.run(&["bundle", "-p", "app"]).expect(0);
+    assert_eq!(
+        heads(&run, " * ", "MIT License"),
+        " * app 0.1.0 [internal] under the terms of MIT:
+
+ * bare 0.1.0 [internal] under the terms of MIT:
+
+ * full 0.1.0 [internal] under the terms of MIT:
+
+ * homepage 0.1.0 [internal] under the terms of MIT:
+
+"
+    );
+    let run = workspace
+        .run(&["bundle", "-p", "app", "--with-metadata"])
+        .expect(0);
+    assert_eq!(
+        heads(&run, " * ", "MIT License"),
+        " * app 0.1.0 [internal] under the terms of MIT:
+
+ * bare 0.1.0 [internal] under the terms of MIT:
+
+ * full 0.1.0 [internal] under the terms of MIT:
+
+    (A description long enough that it has to be shortened to keep the entry readable, it goes on and on about what the pack… — https://example.com/full — by Zoë Ångström <zoe@example.com>, 李雷)
+
+ * homepage 0.1.0 [internal] under the terms of MIT:
+
+    (Has a *homepage* — https://homepage.example.com)
+
+"
+    );
+
+    let run = workspace
+        .run(&["bundle", "-p", "app", "--variant", "markdown"])
+        .expect(0);
+    assert_eq!(
+        heads(&run, "## ", "```text"),
+        "\
+## app 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)
+
+## bare 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)
+
+## full 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)
+
+## homepage 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)
+
+"
+    );
+    let run = workspace
+        .run(&[
+            "bundle",
+            "-p",
+            "app",
+            "--variant",
+            "markdown",
+            "--with-metadata",
+        ])
+        .expect(0);
+    assert_eq!(
+        heads(&run, "## ", "```text"),
+        "\
+## app 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)
+
+## bare 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)
+
+## full 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)
+
+A description long enough that it has to be shortened to keep the entry readable, it goes on and on about what the pack…
+
+[https://example.com/full](https://example.com/full) — by Zoë Ångström \\<zoe@example.com\\>, 李雷
+
+## homepage 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)
+
+Has a \\*homepage\\*
+
+[https://homepage.example.com](https://homepage.example.com)
+
+"
+    );
+
+    let run = workspace
+        .run(&[
+            "bundle",
+            "-p",
+            "app",
+            "--variant",
+            "json",
+            "--reproducible",
+            "--with-metadata",
+        ])
+        .expect(0);
+    let bundle: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let bare = &bundle[1];
+    assert_eq!(bare["name"], "bare");
+    for field in ["description", "url", "authors"] {
+        assert!(bare.get(field).is_none(), "{}", run.stdout);
+    }
+    let full = &bundle[2];
+    assert_eq!(full["url"], "https://example.com/full");
+    assert_eq!(
+        full["authors"],
+        serde_json::json!(["Zoë Ångström <zoe@example.com>", "李雷"])
+    );
+    assert_eq!(bundle[3]["url"], "https://homepage.example.com");
+
+    workspace
+        .run(&["bundle", "--variant", "name-only", "--with-metadata"])
+        .expect(1);
+}
+
+/// `app` depending on `snake_case`, whose license text names its holders with
+/// markup characters, and `textless` which ships no license text
+fn markup() -> Workspace {
+    Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app\", \"snake_case\", \"textless\"]\n",
+        ),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\n\
+                 snake_case = { path = \"../snake_case\" }\n\
+                 textless = { path = \"../textless\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "snake_case/Cargo.toml",
+            &manifest("snake_case", "0.1.0", "MIT", ""),
+        ),
+        (
+            "snake_case/LICENSE",
+            &MIT_TEXT.replace("The Authors", "Tom & Jerry <tj@example.com>"),
+        ),
+        (
+            "textless/Cargo.toml",
+            &manifest("textless", "0.1.0", "MIT", ""),
+        ),
+    ])
+}
+
+#[test]
+fn markdown_and_html_snapshots() {
+    let workspace = markup();
+    let mit = MIT_TEXT.trim_end();
+    let marked = mit.replace("The Authors", "Tom & Jerry <tj@example.com>");
+
+    let run = workspace
+        .run(&["bundle", "-p", "app", "--variant", "markdown"])
+        .expect(3);
+    assert_eq!(
+        run.stdout,
+        format!(
+            "# Third party licenses\n\n\
+             The app package (MIT) uses some third party libraries under their own license terms.\n\n\
+             ## app 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)\n\n\
+             ```text\n{}\n```\n\n\
+             ## snake\\_case 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)\n\n\
+             ```text\n{}\n```\n\n\
+             ## textless 0.1.0 \\[internal\\] — [MIT](https://spdx.org/licenses/MIT.html)\n\n\
+             *License text not found.*\n",
+            mit, marked
+        )
+    );
+
+    let escape = |text: &str| {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    };
+    let run = workspace
+        .run(&["bundle", "-p", "app", "--variant", "html"])
+        .expect(3);
+    assert_eq!(
+        run.stdout,
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Third party licenses</title>\n</head>\n<body>\n\
+             <h1>Third party licenses</h1>\n\
+             <p>The app package (MIT) uses some third party libraries under their own license terms:</p>\n\
+             <ul>\n\
+             <li><a href=\"#app-0.1.0\">app 0.1.0</a> — MIT</li>\n\
+             <li><a href=\"#snake_case-0.1.0\">snake_case 0.1.0</a> — MIT</li>\n\
+             <li><a href=\"#textless-0.1.0\">textless 0.1.0</a> — MIT</li>\n\
+             </ul>\n\
+             <section id=\"app-0.1.0\">\n\
+             <h2>app 0.1.0 [internal] — <a href=\"https://spdx.org/licenses/MIT.html\">MIT</a></h2>\n\
+             <pre>{}</pre>\n</section>\n\
+             <section id=\"snake_case-0.1.0\">\n\
+             <h2>snake_case 0.1.0 [internal] — <a href=\"https://spdx.org/licenses/MIT.html\">MIT</a></h2>\n\
+             <pre>{}</pre>\n</section>\n\
+             <section id=\"textless-0.1.0\">\n\
+             <h2>textless 0.1.0 [internal] — <a href=\"https://spdx.org/licenses/MIT.html\">MIT</a></h2>\n\
+             <p><em>License text not found.</em></p>\n</section>\n\
+             </body>\n</html>\n",
+            escape(mit),
+            escape(&marked)
+        )
+    );
+    assert!(
+        run.stdout
+            .contains("Copyright (c) 2020 Tom &amp; Jerry &lt;tj@example.com&gt;"),
+        "{}",
+        run.stdout
+    );
+}
@@ -0,0 +1,1044 @@
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+use common::{manifest, Run, Workspace, MIT_TEXT};
+use sha2::{Digest, Sha256};
+
+/// `app` is MIT and depends on packages whose licenses it can't include
+fn incompatible() -> Workspace {
+    Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app\", \"gpl\", \"mitx\", \"apachedep\", \"tools\"]\n",
+        ),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "\n[package.metadata]\ngpl.note = \"not a dependency\"\n\n\
+                 [dependencies]\ngpl = { path = \"../gpl\" }\n\
+                 mitx = { path = \"../mitx\" }\n\n\
+                 [dependencies.apachedep]\npath = \"../apachedep\"\n\n\
+                 [target.'cfg(unix)'.dev-dependencies]\ntools = { path = \"../tools\" }\n",
+            ),
+        ),
+        ("gpl/Cargo.toml", &manifest("gpl", "0.1.0", "GPL-3.0", "")),
+        (
+            "mitx/Cargo.toml",
+            &manifest("mitx", "0.1.0", "(MIT OR Apache-2.0) AND GPL-3.0", ""),
+        ),
+        (
+            "apachedep/Cargo.toml",
+            &manifest("apachedep", "0.1.0", "Apache-2.0", ""),
+        ),
+        (
+            "tools/Cargo.toml",
+            &manifest("tools", "0.1.0", "GPL-3.0", ""),
+        ),
+    ])
+}
+
+/// `app` depending on a package under a license we know nothing about
+fn undecided() -> Workspace {
+    Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"app\", \"odd\"]\n"),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nodd = { path = \"../odd\" }\n",
+            ),
+        ),
+        (
+            "odd/Cargo.toml",
+            &manifest("odd", "1.2.0", "LicenseRef-Odd", ""),
+        ),
+    ])
+}
+
+#[test]
+fn recorded_decisions() {
+    let workspace = undecided();
+    let decisions = workspace.path("decisions.toml");
+    let decisions = decisions.to_str().unwrap();
+    let check = [
+        "check",
+        "--record-decisions",
+        decisions,
+        "--non-interactive",
+    ];
+    let deny_undecided = [&check[..], &["--deny", "undecided"]].concat();
+
+    let run = workspace.run_in("app", &check).expect(3);
+    assert!(
+        run.stderr.contains("no recorded decision"),
+        "{}",
+        run.stderr
+    );
+    workspace.run_in("app", &deny_undecided).expect(2);
+    // Nothing is recorded without asking
+    assert!(!workspace.path("decisions.toml").exists());
+
+    let decide = |version_req: &str, verdict: &str| {
+        std::fs::write(
+            workspace.path("decisions.toml"),
+            format!(
+                "[[decision]]\npackage = \"odd\"\nversion_req = \"{}\"\nverdict = \"{}\"\n",
+                version_req, verdict
+            ),
+        )
+        .unwrap();
+    };
+    decide("^1.0", "allow");
+    workspace.run_in("app", &deny_undecided).expect(0);
+    decide("^1.0", "deny");
+    workspace.run_in("app", &check).expect(2);
+    // Decisions about other major versions don't apply
+    decide("^2.0", "allow");
+    workspace.run_in("app", &check).expect(3);
+    workspace.run_in("app", &deny_undecided).expect(2);
+}
+
+#[test]
+fn about_toml_policy() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"app\", \"mpl\"]\n"),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MPL-2.0",
+                "[dependencies]\nmpl = { path = \"../mpl\" }\n",
+            ),
+        ),
+        ("mpl/Cargo.toml", &manifest("mpl", "0.1.0", "MPL-2.0", "")),
+        (
+            "about.toml",
+            "accepted = [\"MIT\"]\nno-clearly-defined = true\n",
+        ),
+        (
+            "clarified.toml",
+            "accepted = [\"MIT\"]\n\n[crates.mpl]\nclarify.license = \"MIT\"\n",
+        ),
+    ]);
+    let policy = |file: &str| workspace.path(file).to_str().unwrap().to_owned();
+
+    let run = workspace
+        .run_in("app", &["check", "--policy", &policy("about.toml")])
+        .expect(2);
+    assert!(
+        run.stderr
+            .contains("mpl 0.1.0 is licensed under MPL-2.0 which is not accepted by the policy"),
+        "{}",
+        run.stderr
+    );
+    let run = workspace
+        .run_in("app", &["check", "--policy", &policy("clarified.toml")])
+        .expect(0);
+    assert!(!run.stderr.contains("is checked under"), "{}", run.stderr);
+
+    // Verbose output says where the license came from
+    let run = workspace
+        .run_in(
+            "app",
+            &["-v", "check", "--policy", &policy("clarified.toml")],
+        )
+        .expect(0);
+    assert!(
+        run.stderr.contains(&format!(
+            "mpl 0.1.0 is checked under license MIT, overridden by {}",
+            policy("clarified.toml")
+        )),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn failures_show_the_dependency_chain() {
+    let dep = |name: &str| format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    let package = |name: &str, license: &str, deps: &[&str]| {
+        let deps = deps.iter().map(|name| dep(name)).collect::<String>();
+        manifest(name, "0.1.0", license, &format!("[dependencies]\n{}", deps))
+    };
+    let workspace = Workspace::new(&[
+        ("app/Cargo.toml", &package("app", "MIT", &["host", "a"])),
+        ("host/Cargo.toml", &package("host", "MIT", &["gpl"])),
+        ("a/Cargo.toml", &package("a", "MIT", &["b"])),
+        ("b/Cargo.toml", &package("b", "MIT", &["gpl"])),
+        ("gpl/Cargo.toml", &package("gpl", "GPL-3.0", &[])),
+    ]);
+
+    let run = workspace.run_in("app", &["check"]).expect(2);
+    assert!(
+        run.stderr.contains("(via app -> host -> gpl)"),
+        "{}",
+        run.stderr
+    );
+    // app is the only workspace member, the line to change is its
+    // dependency on host
+    assert!(
+        run.stderr.contains(
+            "(via app -> host -> gpl)\n  --> Cargo.toml:7:1 (introduced via host 0.1.0)\n"
+        ),
+        "{}",
+        run.stderr
+    );
+    let run = workspace.run_in("app", &["-v", "check"]).expect(2);
+    assert!(
+        run.stderr
+            .contains("(via app -> host -> gpl; app -> a -> b -> gpl)"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn failures_point_at_the_dependency_declaration() {
+    let workspace = incompatible();
+    let run = workspace.run_in("app", &["check"]).expect(2);
+    for (package, location) in [
+        ("gpl", "app/Cargo.toml:11:1"),
+        ("mitx", "app/Cargo.toml:12:1"),
+    ] {
+        let line = run
+            .stderr
+            .lines()
+            .position(|line| line.contains(&format!("cannot include package {},", package)))
+            .unwrap_or_else(|| panic!("{}", run.stderr));
+        assert_eq!(
+            run.stderr.lines().nth(line + 1),
+            Some(format!("  --> {}", location).as_str()),
+            "{}",
+            run.stderr
+        );
+    }
+}
+
+#[test]
+fn packages_from_a_list() {
+    let workspace = incompatible();
+    fs::write(workspace.path("app.txt"), "app@0.1.0\n").unwrap();
+    fs::write(workspace.path("gpl.txt"), "[\"gpl@0.1.0\"]").unwrap();
+    // Only the listed packages are checked, not the roots' dependencies
+    workspace
+        .run_in("app", &["check", "--packages-from", "../app.txt"])
+        .expect(0);
+    let run = workspace
+        .run_in("app", &["check", "--packages-from", "../gpl.txt"])
+        .expect(2);
+    assert!(run.stderr.contains("package gpl,"), "{}", run.stderr);
+    assert!(!run.stderr.contains("package mitx,"), "{}", run.stderr);
+    workspace
+        .run_in(
+            "app",
+            &[
+                "check",
+                "--packages-from",
+                "../gpl.txt",
+                "--against",
+                "GPL-3.0",
+            ],
+        )
+        .expect(0);
+}
+
+/// A binary and a library, differently licensed and both depending on a GPL
+/// package
+fn bin_and_lib() -> Workspace {
+    let deps = "[dependencies]\ngpl = { path = \"../gpl\" }\n";
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"tool\", \"library\"]\n",
+        ),
+        ("tool/Cargo.toml", &manifest("tool", "0.1.0", "MIT", deps)),
+        (
+            "library/Cargo.toml",
+            &manifest("library", "0.1.0", "Apache-2.0", deps),
+        ),
+        ("gpl/Cargo.toml", &manifest("gpl", "0.1.0", "GPL-3.0", "")),
+    ]);
+    fs::remove_file(workspace.path("tool/src/lib.rs")).unwrap();
+    fs::write(workspace.path("tool/src/main.rs"), "fn main() {}\n").unwrap();
+    workspace
+}
+
+#[test]
+fn target_kinds() {
+    let workspace = bin_and_lib();
+    let check = |kind: &str| {
+        workspace
+            .run(&[
+                "check",
+                "-p",
+                "tool",
+                "-p",
+                "library",
+                "--target-kind",
+                kind,
+            ])
+            .expect(2)
+    };
+
+    let run = check("bin");
+    assert!(
+        run.stderr.contains("tool [bin] cannot include package gpl"),
+        "{}",
+        run.stderr
+    );
+    assert!(!run.stderr.contains("library ["), "{}", run.stderr);
+    assert!(
+        run.stderr
+            .contains("note: analyzed 1 binary root, skipped 1 library-only root"),
+        "{}",
+        run.stderr
+    );
+
+    let run = check("lib");
+    assert!(
+        run.stderr
+            .contains("library [lib] cannot include package gpl"),
+        "{}",
+        run.stderr
+    );
+    assert!(!run.stderr.contains("tool ["), "{}", run.stderr);
+    assert!(
+        run.stderr
+            .contains("note: analyzed 1 library root, skipped 1 binary-only root"),
+        "{}",
+        run.stderr
+    );
+
+    let run = check("all");
+    assert!(
+        run.stderr.contains("tool cannot include package gpl")
+            && run.stderr.contains("library cannot include package gpl"),
+        "{}",
+        run.stderr
+    );
+    assert!(!run.stderr.contains("analyzed 1"), "{}", run.stderr);
+}
+
+#[test]
+fn license_files_contradicting_the_declaration() {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nmislabeled = { path = \"../mislabeled\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "mislabeled/Cargo.toml",
+            &manifest("mislabeled", "1.0.0", "MIT", ""),
+        ),
+        (
+            "mislabeled/LICENSE",
+            include_str!("../src/licenses/GPL-3.0"),
+        ),
+    ]);
+    let run = workspace.run_in("app", &["check"]).expect(2);
+    let finding = format!(
+        "mislabeled 1.0.0 declares license MIT but {} (sha256: {:x}) matches GPL-3.0-only with \
+         high confidence (via app -> mislabeled)",
+        workspace.path("mislabeled/LICENSE").display(),
+        Sha256::digest(&fs::read(workspace.path("mislabeled/LICENSE")).unwrap())
+    );
+    assert!(run.stderr.contains(&finding), "{}", run.stderr);
+    workspace
+        .run_in("app", &["check", "--no-cross-check"])
+        .expect(0);
+
+    // Bundling only cross-checks when asked to
+    workspace.run_in("app", &["bundle"]).expect(3);
+    let run = workspace
+        .run_in("app", &["bundle", "--cross-check"])
+        .expect(2);
+    assert!(
+        run.stderr
+            .contains("License files contradict the declared licenses"),
+        "{}",
+        run.stderr
+    );
+
+    // Both agree on what's a confident match
+    let run = workspace
+        .run_in("app", &["check", "--confidence-threshold", "0"])
+        .expect(0);
+    assert!(!run.stderr.contains("declares license"), "{}", run.stderr);
+    let run = workspace
+        .run_in(
+            "app",
+            &["bundle", "--cross-check", "--confidence-threshold", "0"],
+        )
+        .expect(3);
+    assert!(!run.stderr.contains("declares license"), "{}", run.stderr);
+
+    // And the finding can be waived like any other
+    fs::write(
+        workspace.path("about.toml"),
+        "accepted = [\"MIT\"]\n\n[[waiver]]\npackage = \"mislabeled\"\n\
+         licenses = [\"MIT\"]\nexpires = \"2999-12-31\"\nreason = \"relicensed upstream\"\n",
+    )
+    .unwrap();
+    let run = Run::from(
+        workspace
+            .command("app", &["check", "--policy", "../about.toml"])
+            .env("RUST_LOG", "info")
+            .output()
+            .unwrap(),
+    )
+    .expect(0);
+    assert!(
+        run.stderr.contains(&finding)
+            && run
+                .stderr
+                .contains("(waived until 2999-12-31: relicensed upstream)"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn skipped_license_files() {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nscanned = { path = \"../scanned\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "scanned/Cargo.toml",
+            &manifest("scanned", "1.0.0", "MIT", ""),
+        ),
+        ("scanned/LICENSE", "%PDF-1.4\n\0\0\0\n"),
+    ]);
+    let run = workspace.run_in("app", &["check"]).expect(3);
+    let warning = format!(
+        "scanned 1.0.0 license file {} was skipped, binary content",
+        workspace.path("scanned/LICENSE").display()
+    );
+    assert!(run.stderr.contains(&warning), "{}", run.stderr);
+    assert!(
+        run.stderr
+            .contains("License files skipped as too large or binary"),
+        "{}",
+        run.stderr
+    );
+    workspace
+        .run_in("app", &["check", "--no-cross-check"])
+        .expect(0);
+}
+
+#[test]
+fn compat_matrix_as_json() {
+    let workspace = incompatible();
+    let run = workspace
+        .run(&[
+            "compat",
+            "-p",
+            "app",
+            "--as",
+            "GPL-3.0-only",
+            "--format",
+            "json",
+        ])
+        .expect(0);
+    let matrix: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    assert_eq!(matrix["roots"], serde_json::json!(["MIT", "GPL-3.0-only"]));
+    let row = |license: &str| {
+        matrix["rows"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|row| row["license"] == license)
+            .unwrap_or_else(|| panic!("no row for {}:\n{}", license, run.stdout))
+            .clone()
+    };
+    let gpl = row("GPL-3.0-only");
+    assert_eq!(gpl["packages"], 1);
+    assert_eq!(gpl["cells"][0]["root"], "MIT");
+    assert_eq!(gpl["cells"][0]["compatible"], false);
+    assert_eq!(gpl["cells"][1]["compatible"], true);
+    assert_eq!(
+        gpl["cells"][0]["reason"],
+        "GPL-3.0-only is copyleft, it requires the combined work to be licensed compatibly and \
+         MIT is not compatible with it in that direction"
+    );
+}
+
+#[test]
+fn copyleft_scope_of_compatible_dependencies() {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "GPL-3.0-only",
+                "[dependencies]\n\
+                 weak = { path = \"../weak\" }\n\
+                 lib = { path = \"../lib\" }\n\
+                 plain = { path = \"../plain\" }\n",
+            ),
+        ),
+        ("weak/Cargo.toml", &manifest("weak", "0.1.0", "MPL-2.0", "")),
+        (
+            "lib/Cargo.toml",
+            &manifest("lib", "0.1.0", "LGPL-2.1-only", ""),
+        ),
+        ("plain/Cargo.toml", &manifest("plain", "0.1.0", "MIT", "")),
+    ]);
+    // Informational only, they don't change the exit code
+    let run = workspace.run_in("app", &["check"]).expect(0);
+    assert!(
+        run.stderr.contains(
+            "warning: weak 0.1.0: MPL-2.0 is file-level copyleft, compatible but modified \
+             MPL-2.0 files must be published (via app -> weak)"
+        ),
+        "{}",
+        run.stderr
+    );
+    assert!(
+        run.stderr.contains(
+            "warning: lib 0.1.0: LGPL-2.1-only is library copyleft, compatible but \
+             modifications to lib must be published and users must be able to replace it"
+        ),
+        "{}",
+        run.stderr
+    );
+    assert!(!run.stderr.contains("plain"), "{}", run.stderr);
+    assert!(run.stderr.contains("IANAL"), "{}", run.stderr);
+}
+
+#[test]
+fn fail_on_license_families() {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "AGPL-3.0-only",
+                "[dependencies]\nserver = { path = \"../server\" }\n\
+                 plain = { path = \"../plain\" }\n",
+            ),
+        ),
+        (
+            "server/Cargo.toml",
+            &manifest("server", "0.1.0", "AGPL-3.0-only", ""),
+        ),
+        ("plain/Cargo.toml", &manifest("plain", "0.1.0", "MIT", "")),
+    ]);
+    // AGPL can include AGPL, so only the flag fails it
+    workspace.run_in("app", &["check"]).expect(0);
+    for family in &["network-copyleft", "strong-copyleft", "copyleft"] {
+        let run = workspace
+            .run_in("app", &["check", "--fail-on", family])
+            .expect(2);
+        assert!(
+            run.stderr.contains(&format!(
+                "error: server 0.1.0 has license AGPL-3.0-only of family network copyleft, \
+                 rejected by --fail-on {} (via app -> server)",
+                family
+            )),
+            "{}",
+            run.stderr
+        );
+        assert!(!run.stderr.contains("error: plain"), "{}", run.stderr);
+        assert!(
+            run.stderr
+                .contains("app: Dependencies with rejected license families"),
+            "{}",
+            run.stderr
+        );
+    }
+    workspace
+        .run_in("app", &["check", "--fail-on", "unknown"])
+        .expect(0);
+}
+
+#[test]
+fn colored_and_plain_findings() {
+    let workspace = incompatible();
+    let findings = |color: &str| {
+        let run = workspace
+            .run_in("app", &["--color", color, "check", "-p", "app"])
+            .expect(2);
+        run.stderr
+            .lines()
+            .filter(|line| line.contains("cannot include"))
+            .map(|line| line.split(" cannot include").next().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(findings("never"), ["error: app"; 3]);
+    assert_eq!(findings("always"), ["\x1b[1;31merror\x1b[0m: app"; 3]);
+}
+
+/// Two roots sharing both of their dependencies, one with a license field
+/// that needs normalizing
+fn shared() -> Workspace {
+    let deps =
+        "[dependencies]\nshared = { path = \"../shared\" }\nsloppy = { path = \"../sloppy\" }\n";
+    Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"one\", \"two\"]\n"),
+        ("one/Cargo.toml", &manifest("one", "0.1.0", "MIT", deps)),
+        ("two/Cargo.toml", &manifest("two", "0.1.0", "MIT", deps)),
+        ("shared/Cargo.toml", &manifest("shared", "0.1.0", "MIT", "")),
+        ("sloppy/Cargo.toml", &manifest("sloppy", "0.1.0", "mit", "")),
+    ])
+}
+
+#[test]
+fn summary_counts_shared_dependencies_once() {
+    let workspace = shared();
+    let run = workspace.run(&["check"]).expect(0);
+    assert!(run.stderr.contains("analyzed 4 packages"), "{}", run.stderr);
+    // And a shared package's problems are reported once
+    assert_eq!(
+        run.stderr
+            .matches("sloppy 0.1.0: 'mit' is not an SPDX identifier")
+            .count(),
+        1,
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn lgpl_linkage() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nlgpl = { path = \"lgpl\" }\n",
+            ),
+        ),
+        (
+            "lgpl/Cargo.toml",
+            &manifest("lgpl", "0.1.0", "LGPL-3.0-or-later", ""),
+        ),
+    ]);
+    let finding = "app statically links package lgpl 0.1.0 under LGPL-3.0-or-later, \
+                   distributing it requires letting users relink against a modified lgpl";
+
+    let run = workspace.run(&["check"]).expect(3);
+    assert!(
+        run.stderr.contains(&format!("warning: {}", finding)),
+        "{}",
+        run.stderr
+    );
+    let run = workspace.run(&["check", "--linkage", "static"]).expect(3);
+    assert!(run.stderr.contains(finding), "{}", run.stderr);
+
+    let run = workspace.run(&["check", "--deny", "lgpl-static"]).expect(2);
+    assert!(
+        run.stderr.contains(&format!("error: {}", finding)),
+        "{}",
+        run.stderr
+    );
+
+    let run = workspace.run(&["check", "--linkage", "dynamic"]).expect(0);
+    assert!(!run.stderr.contains("statically links"), "{}", run.stderr);
+    let run = workspace
+        .run(&["check", "--linkage", "dynamic", "--deny", "lgpl-static"])
+        .expect(0);
+    assert!(!run.stderr.contains("statically links"), "{}", run.stderr);
+}
+
+#[test]
+fn hypothetical_root_licenses() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\napachedep = { path = \"apachedep\" }\n",
+            ),
+        ),
+        (
+            "apachedep/Cargo.toml",
+            &manifest("apachedep", "0.1.0", "Apache-2.0", ""),
+        ),
+    ]);
+
+    // The declared MIT can't carry over the dependency's Apache-2.0 terms,
+    // relicensing as Apache-2.0 could
+    let run = workspace.run(&["check"]).expect(2);
+    assert_eq!(run.stdout, "");
+    assert!(
+        run.stderr
+            .contains("app cannot include package apachedep, license Apache-2.0"),
+        "{}",
+        run.stderr
+    );
+    let run = workspace
+        .run(&["check", "--against", "Apache-2.0"])
+        .expect(0);
+    assert!(
+        run.stderr
+            .contains("note: checking as if app were licensed Apache-2.0"),
+        "{}",
+        run.stderr
+    );
+    assert_eq!(run.stdout, "Apache-2.0  passes\n");
+
+    let run = workspace
+        .run(&[
+            "check",
+            "--against",
+            "Apache-2.0",
+            "--against",
+            "GPL-2.0-only",
+        ])
+        .expect(2);
+    assert!(
+        run.stderr.contains(
+            "app (as GPL-2.0-only) cannot include package apachedep, license Apache-2.0 is \
+             incompatible with GPL-2.0-only: Apache-2.0 has terms that GPL-2.0-only cannot \
+             carry over (via app -> apachedep)"
+        ),
+        "{}",
+        run.stderr
+    );
+    assert_eq!(
+        run.stdout,
+        "Apache-2.0    passes\n\
+         GPL-2.0-only  fails: app (as GPL-2.0-only): Incompatible license\n"
+    );
+
+    // The expression is validated before cargo metadata is run
+    let run = workspace
+        .run(&["check", "--against", "MIT OR Nonsense-1.0"])
+        .expect(1);
+    assert!(
+        run.stderr
+            .contains("'Nonsense-1.0' is not a recognised license identifier"),
+        "{}",
+        run.stderr
+    );
+    workspace.run(&["check", "--against", "(MIT"]).expect(1);
+}
+
+#[test]
+fn license_warnings_are_reported_once() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "Apache-2.0",
+                "[dependencies]\nslashed = { path = \"slashed\" }\n",
+            ),
+        ),
+        (
+            "slashed/Cargo.toml",
+            &manifest("slashed", "0.1.0", "MIT/Apache-2.0", ""),
+        ),
+    ]);
+
+    // Checking against several licenses goes over the packages once each
+    let run = workspace
+        .run(&["check", "--against", "Apache-2.0", "--against", "MIT"])
+        .expect(0);
+    assert_eq!(
+        run.stderr
+            .matches("slashed 0.1.0: '/' is a deprecated license separator")
+            .count(),
+        1,
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn licenses_inferred_from_source_headers() {
+    let unlicensed = |name: &str| {
+        format!(
+            "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2018\"\n",
+            name
+        )
+    };
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nheaders = { path = \"headers\" }\n\
+                 mixed = { path = \"mixed\" }\ndeclared = { path = \"declared\" }\n",
+            ),
+        ),
+        ("headers/Cargo.toml", &unlicensed("headers")),
+        ("headers/src/lib.rs", "// SPDX-License-Identifier: MIT\n"),
+        ("mixed/Cargo.toml", &unlicensed("mixed")),
+        ("mixed/src/lib.rs", "// SPDX-License-Identifier: MIT\n"),
+        (
+            "mixed/src/vendored.rs",
+            "// SPDX-License-Identifier: GPL-3.0-only\n",
+        ),
+        // Declares a license but has no text for it
+        (
+            "declared/Cargo.toml",
+            &manifest("declared", "0.1.0", "MIT", ""),
+        ),
+        ("declared/src/lib.rs", "// SPDX-License-Identifier: MIT\n"),
+    ]);
+
+    // Without the flag both are unlicensed
+    let run = workspace.run(&["check"]).expect(2);
+    assert!(
+        run.stderr.contains("app cannot include package headers"),
+        "{}",
+        run.stderr
+    );
+
+    // Conflicting headers are no better than none
+    let run = workspace.run(&["--scan-source-headers", "check"]).expect(2);
+    assert!(
+        run.stderr.contains(
+            "headers 0.1.0 declares no license, using MIT from the SPDX-License-Identifier \
+             headers of its sources"
+        ),
+        "{}",
+        run.stderr
+    );
+    assert!(
+        run.stderr.contains(&format!(
+            "mixed 0.1.0 declares no license and the SPDX-License-Identifier headers of its \
+             sources conflict: MIT in {}, GPL-3.0-only in {}",
+            workspace.path("mixed/src/lib.rs").display(),
+            workspace.path("mixed/src/vendored.rs").display()
+        )),
+        "{}",
+        run.stderr
+    );
+    assert!(
+        !run.stderr.contains("app cannot include package headers")
+            && run.stderr.contains("app cannot include package mixed"),
+        "{}",
+        run.stderr
+    );
+    assert!(
+        run.stderr
+            .contains("app: Licenses inferred from source headers")
+            && run
+                .stderr
+                .contains("app: Conflicting license headers in sources"),
+        "{}",
+        run.stderr
+    );
+
+    let run = workspace
+        .run(&["--scan-source-headers", "list", "--by", "crate"])
+        .expect(0);
+    assert!(
+        run.stdout.contains("(inferred from source headers)"),
+        "{}",
+        run.stdout
+    );
+
+    let run = workspace
+        .run(&[
+            "--scan-source-headers",
+            "bundle",
+            "--package",
+            "app",
+            "--variant",
+            "json",
+            "--reproducible",
+        ])
+        .expect(3);
+    let bundle: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let headers = bundle
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|package| package["name"] == "headers")
+        .unwrap();
+    assert_eq!(headers["license"], "MIT");
+    assert_eq!(headers["licenses"][0]["info"], "template");
+    assert_eq!(headers["licenses"][0]["path"], "headers/src/lib.rs");
+
+    // Declared licenses without a text fall back on the headers too
+    let declared = bundle
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|package| package["name"] == "declared")
+        .unwrap();
+    assert_eq!(declared["licenses"][0]["info"], "template");
+    assert_eq!(declared["licenses"][0]["path"], "declared/src/lib.rs");
+    assert!(
+        run.stderr.contains(
+            "declared has no license text, using the standard text for license MIT which its \
+             source headers also name"
+        ),
+        "{}",
+        run.stderr
+    );
+}
+
+/// `app` declares no license and depends on permissive, weak and strong
+/// copyleft packages, `publish` is added to its manifest
+fn unlicensed(publish: &str) -> Workspace {
+    Workspace::new(&[
+        (
+            "Cargo.toml",
+            &format!(
+                "[package]\nname = \"app\"\nversion = \"0.1.0\"\nedition = \"2018\"\n{}\n\
+                 [dependencies]\napachedep = {{ path = \"apachedep\" }}\n\
+                 mpl = {{ path = \"mpl\" }}\ngpl = {{ path = \"gpl\" }}\n",
+                publish
+            ),
+        ),
+        (
+            "apachedep/Cargo.toml",
+            &manifest("apachedep", "0.1.0", "Apache-2.0", ""),
+        ),
+        ("mpl/Cargo.toml", &manifest("mpl", "0.1.0", "MPL-2.0", "")),
+        ("gpl/Cargo.toml", &manifest("gpl", "0.1.0", "GPL-3.0", "")),
+    ])
+}
+
+#[test]
+fn unlicensed_private_roots() {
+    // Published it can't be granted anything beyond the most permissive
+    let workspace = unlicensed("");
+    let run = workspace.run(&["check"]).expect(2);
+    for dep in ["apachedep", "mpl", "gpl"] {
+        assert!(
+            run.stderr
+                .contains(&format!("app cannot include package {}", dep)),
+            "{}",
+            run.stderr
+        );
+    }
+    assert!(!run.stderr.contains("assumed private"), "{}", run.stderr);
+
+    let run = workspace.run(&["check", "--assume-private"]).expect(3);
+    assert!(
+        run.stderr.contains(
+            "note: app declares no license and --assume-private was passed, checking it as all \
+             rights reserved and not distributed"
+        ),
+        "{}",
+        run.stderr
+    );
+    assert!(!run.stderr.contains("cannot include"), "{}", run.stderr);
+    assert!(!run.stderr.contains("apachedep"), "{}", run.stderr);
+    assert!(
+        run.stderr.contains(
+            "note: mpl 0.1.0: MPL-2.0 is weak copyleft, its obligations only apply if app is \
+             ever distributed (via app -> mpl)"
+        ),
+        "{}",
+        run.stderr
+    );
+    assert!(
+        run.stderr.contains(
+            "app (assumed private) might not be able to include package gpl, license \
+             GPL-3.0-only is not known to be compatible with No license specified: needs \
+             review: GPL-3.0-only is strong copyleft, fine while the work stays private but \
+             distributing it would require releasing the whole work under GPL-3.0-only"
+        ),
+        "{}",
+        run.stderr
+    );
+
+    // Unpublishable roots are assumed to be private without asking
+    let workspace = unlicensed("publish = false\n");
+    let run = workspace.run(&["check"]).expect(3);
+    assert!(
+        run.stderr
+            .contains("note: app declares no license and has `publish = false`"),
+        "{}",
+        run.stderr
+    );
+    assert!(!run.stderr.contains("cannot include"), "{}", run.stderr);
+    assert!(
+        run.stderr
+            .contains("app (assumed private) might not be able to include package gpl"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn vendored_copies_are_cross_checked() {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nmislabeled = { path = \"../registry/mislabeled-1.0.0\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "registry/mislabeled-1.0.0/Cargo.toml",
+            &manifest("mislabeled", "1.0.0", "MIT", ""),
+        ),
+        (
+            "vendor/mislabeled-1.0.0/Cargo.toml",
+            &manifest("mislabeled", "1.0.0", "MIT", ""),
+        ),
+        (
+            "vendor/mislabeled-1.0.0/LICENSE",
+            include_str!("../src/licenses/GPL-3.0"),
+        ),
+    ]);
+    workspace.registry_metadata("app");
+    // Like an air-gapped image with only the vendor directory
+    fs::remove_dir_all(workspace.path("registry")).unwrap();
+
+    let check = |args: &[&str]| {
+        Run::from(
+            Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+                .args(["lichking", "--metadata-json", "metadata.json"])
+                .args(args)
+                .arg("check")
+                .current_dir(&workspace.root)
+                .output()
+                .unwrap(),
+        )
+    };
+    let run = check(&["--vendor-dir", "vendor"]).expect(2);
+    assert!(
+        run.stderr.contains(&format!(
+            "mislabeled 1.0.0 declares license MIT but {} (sha256: {:x}) matches GPL-3.0-only",
+            workspace.path("vendor/mislabeled-1.0.0/LICENSE").display(),
+            Sha256::digest(include_bytes!("../src/licenses/GPL-3.0"))
+        )),
+        "{}",
+        run.stderr
+    );
+    // Without it there are no sources to cross-check
+    check(&[]).expect(0);
+}
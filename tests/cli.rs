@@ -0,0 +1,557 @@
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+use common::{manifest, Run, Workspace, MIT_TEXT};
+
+#[test]
+fn manifest_path_points_at_another_project() {
+    let workspace = Workspace::new(&[
+        (
+            "project/Cargo.toml",
+            &manifest("project", "0.1.0", "MIT", ""),
+        ),
+        ("project/LICENSE", MIT_TEXT),
+        ("elsewhere/.keep", ""),
+    ]);
+    let manifest_path = workspace.path("project/Cargo.toml");
+    let manifest_path = manifest_path.to_str().unwrap();
+
+    let run = workspace
+        .run_in("elsewhere", &["--manifest-path", manifest_path, "list"])
+        .expect(0);
+    assert!(run.stdout.contains("project"), "{}", run.stdout);
+
+    let project = workspace.path("project");
+    let run = workspace
+        .run_in(
+            "elsewhere",
+            &["--workspace-root", project.to_str().unwrap(), "list"],
+        )
+        .expect(0);
+    assert!(run.stdout.contains("project"), "{}", run.stdout);
+
+    // Without it there's no project to find
+    let run = workspace.run_in("elsewhere", &["list"]).expect(1);
+    let elsewhere = workspace.path("elsewhere");
+    assert!(
+        run.stderr.contains(&format!(
+            "No Cargo.toml found in {} or any of its parents",
+            elsewhere.display()
+        )) && run.stderr.contains("--manifest-path")
+            && run.stderr.contains("--workspace-root"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn runs_without_the_cargo_subcommand_argument() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", &manifest("project", "0.1.0", "MIT", "")),
+        ("LICENSE", MIT_TEXT),
+    ]);
+    let run = |args: &[&str]| {
+        Run::from(
+            Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+                .args(args)
+                .current_dir(&workspace.root)
+                .env("RUST_LOG", "warn")
+                .output()
+                .unwrap(),
+        )
+    };
+    let direct = run(&["--offline", "list"]).expect(0);
+    let through_cargo = run(&["lichking", "--offline", "list"]).expect(0);
+    assert!(direct.stdout.contains("project"), "{}", direct.stdout);
+    assert_eq!(direct.stdout, through_cargo.stdout);
+
+    run(&["--offline", "nonexistent"]).expect(1);
+}
+
+#[test]
+fn verbosity_controls_logging() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", &manifest("project", "0.1.0", "MIT", "")),
+        ("LICENSE", MIT_TEXT),
+    ]);
+    let run = |args: &[&str]| {
+        let mut command = workspace.command("", args);
+        command.env_remove("RUST_LOG");
+        Run::from(command.output().unwrap())
+    };
+    const BANNER: &str = "IANAL";
+
+    let default = run(&["bundle"]).expect(0);
+    assert!(default.stderr.contains(BANNER), "{}", default.stderr);
+    assert!(!default.stderr.contains("DEBUG"), "{}", default.stderr);
+
+    let quiet = run(&["-q", "bundle"]).expect(0);
+    assert!(!quiet.stderr.contains(BANNER), "{}", quiet.stderr);
+    assert_eq!(quiet.stdout, default.stdout);
+
+    let debug = run(&["-vv", "bundle"]).expect(0);
+    assert!(debug.stderr.contains("DEBUG"), "{}", debug.stderr);
+    assert!(debug.stderr.contains("LICENSE"), "{}", debug.stderr);
+
+    // RUST_LOG wins over the flags
+    let overridden = Run::from(
+        workspace
+            .command("", &["-vv", "bundle"])
+            .env("RUST_LOG", "error")
+            .output()
+            .unwrap(),
+    )
+    .expect(0);
+    assert!(
+        !overridden.stderr.contains("DEBUG"),
+        "{}",
+        overridden.stderr
+    );
+
+    let conflict = run(&["-q", "-v", "bundle"]).expect(1);
+    assert!(
+        conflict.stderr.contains("cannot be used with"),
+        "{}",
+        conflict.stderr
+    );
+}
+
+#[test]
+fn stale_lockfile_suggests_updating() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", &manifest("project", "0.1.0", "MIT", "")),
+        ("LICENSE", MIT_TEXT),
+        ("Cargo.lock", "version = 3\n"),
+    ]);
+
+    let locked = workspace.run(&["--locked", "list"]).expect(1);
+    assert!(
+        locked.stderr.contains(
+            "Cargo.lock is out of date, run `cargo update` to update it or drop --locked"
+        ),
+        "{}",
+        locked.stderr
+    );
+
+    // Implies --locked and --offline, which cargo accepts alongside it
+    let frozen = workspace.run(&["--frozen", "--locked", "list"]).expect(1);
+    assert!(
+        frozen.stderr.contains("or drop --frozen"),
+        "{}",
+        frozen.stderr
+    );
+    assert!(
+        !frozen.stderr.contains("try passing --offline"),
+        "{}",
+        frozen.stderr
+    );
+}
+
+#[test]
+fn saved_metadata_json() {
+    // The saved metadata's workspace doesn't exist, list and check don't need
+    // the sources
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/metadata.json");
+    let workspace = Workspace::new(&[("elsewhere/.keep", "")]);
+    let run = |args: &[&str]| {
+        Run::from(
+            Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+                .args(["lichking", "--metadata-json", fixture])
+                .args(args)
+                .current_dir(workspace.path("elsewhere"))
+                .env("RUST_LOG", "warn")
+                .output()
+                .unwrap(),
+        )
+    };
+
+    let list = run(&["list", "--by", "crate"]).expect(0);
+    let lines: Vec<_> = list.stdout.lines().collect();
+    assert!(
+        lines[0].starts_with("app:") && lines[0].contains("(workspace root)"),
+        "{}",
+        list.stdout
+    );
+    assert!(
+        lines[1].starts_with("gpl:") && lines[1].contains("GPL-3.0-only"),
+        "{}",
+        list.stdout
+    );
+    assert!(
+        lines[2].starts_with("mitdep:") && lines[2].contains("MIT / Apache-2.0"),
+        "{}",
+        list.stdout
+    );
+
+    let check = run(&["check"]).expect(2);
+    assert!(
+        check.stderr.contains("app cannot include package gpl"),
+        "{}",
+        check.stderr
+    );
+    assert!(!check.stderr.contains("mitdep"), "{}", check.stderr);
+
+    let conflict = run(&["--offline", "list"]).expect(1);
+    assert!(
+        conflict.stderr.contains("cannot be used with"),
+        "{}",
+        conflict.stderr
+    );
+}
+
+#[test]
+fn no_progress_when_piped() {
+    // Enough packages that a terminal would get a progress line
+    let names: Vec<_> = (0..25).map(|i| format!("dep{:02}", i)).collect();
+    let deps: String = names
+        .iter()
+        .map(|name| format!("{} = {{ path = \"../{}\" }}\n", name, name))
+        .collect();
+    let mut files = vec![
+        (
+            "app/Cargo.toml".to_owned(),
+            manifest("app", "0.1.0", "MIT", &format!("[dependencies]\n{}", deps)),
+        ),
+        ("app/LICENSE".to_owned(), MIT_TEXT.to_owned()),
+    ];
+    for name in &names {
+        files.push((
+            format!("{}/Cargo.toml", name),
+            manifest(name, "0.1.0", "MIT", ""),
+        ));
+        files.push((format!("{}/LICENSE", name), MIT_TEXT.to_owned()));
+    }
+    let files: Vec<_> = files
+        .iter()
+        .map(|(path, contents)| (path.as_str(), contents.as_str()))
+        .collect();
+    let workspace = Workspace::new(&files);
+
+    for args in &[
+        &["bundle"][..],
+        &["list"],
+        &["check"],
+        &["bundle", "-j", "2"],
+    ] {
+        let run = workspace.run_in("app", args).expect(0);
+        assert!(!run.stderr.contains('\x1b'), "{:?}: {:?}", args, run.stderr);
+        assert!(!run.stderr.contains("/26]"), "{:?}: {}", args, run.stderr);
+        assert!(
+            run.stderr.contains("analyzed 26 packages"),
+            "{:?}: {}",
+            args,
+            run.stderr
+        );
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn runs_the_invoking_cargo() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let workspace = Workspace::new(&[
+        (
+            "project/Cargo.toml",
+            &manifest("project", "0.1.0", "MIT", ""),
+        ),
+        ("project/LICENSE", MIT_TEXT),
+    ]);
+    // Shims recording their arguments before running the real cargo
+    let shim = |name: &str| {
+        let path = workspace.path(name);
+        fs::write(
+            &path,
+            format!(
+                "#!/bin/sh\necho \"$@\" >> \"{}.log\"\nexec cargo \"$@\"\n",
+                path.display()
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    };
+    let invoking = shim("invoking");
+    let explicit = shim("explicit");
+    let log = |path: &std::path::Path| {
+        fs::read_to_string(format!("{}.log", path.display())).unwrap_or_default()
+    };
+
+    let output = workspace
+        .command(
+            "project",
+            &[
+                "--config",
+                "net.retry=5",
+                "--config",
+                "net.git-fetch-with-cli=true",
+                "list",
+            ],
+        )
+        .env("CARGO", &invoking)
+        .output()
+        .unwrap();
+    Run::from(output).expect(0);
+    let args = log(&invoking);
+    assert!(args.starts_with("metadata "), "{}", args);
+    assert!(args.contains("--offline"), "{}", args);
+    assert!(
+        args.contains("--config net.retry=5 --config net.git-fetch-with-cli=true"),
+        "{}",
+        args
+    );
+
+    let run = Run::from(
+        workspace
+            .command(
+                "project",
+                &["--cargo-path", explicit.to_str().unwrap(), "list"],
+            )
+            .env("CARGO", &invoking)
+            .output()
+            .unwrap(),
+    )
+    .expect(0);
+    assert!(run.stdout.contains("project"), "{}", run.stdout);
+    assert_eq!(log(&invoking).lines().count(), 1);
+    assert!(
+        log(&explicit).starts_with("metadata "),
+        "{}",
+        log(&explicit)
+    );
+}
+
+#[test]
+fn thirdparty_to_a_file() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", &manifest("app", "0.1.0", "MIT", "")),
+        ("LICENSE", MIT_TEXT),
+    ]);
+    let header = "The cargo-lichking package (MIT / Apache-2.0) uses some third party libraries";
+
+    let file = workspace.path("name-only.txt");
+    let run = workspace
+        .run(&[
+            "thirdparty",
+            "--variant",
+            "name-only",
+            "--file",
+            file.to_str().unwrap(),
+        ])
+        .expect(0);
+    assert_eq!(run.stdout, "");
+    let name_only = fs::read_to_string(&file).unwrap();
+    assert!(name_only.starts_with(header), "{}", name_only);
+    assert!(name_only.contains(" * anyhow "), "{}", name_only);
+    assert!(!name_only.contains("Permission is hereby granted"));
+
+    // Missing texts are only a warning, the table is generated by the same
+    // build so it says how many there are
+    let missing = include_str!(concat!(env!("OUT_DIR"), "/thirdparty_generated.rs"))
+        .matches("text: None")
+        .count();
+    let file = workspace.path("inline.txt");
+    let run = workspace
+        .run(&[
+            "thirdparty",
+            "--variant",
+            "inline",
+            "--file",
+            file.to_str().unwrap(),
+        ])
+        .expect(if missing == 0 { 0 } else { 3 });
+    assert_eq!(run.stdout, "");
+    assert_eq!(
+        run.stderr.matches("Missing ").count(),
+        missing,
+        "{}",
+        run.stderr
+    );
+    let inline = fs::read_to_string(&file).unwrap();
+    assert!(inline.starts_with(header), "{}", inline);
+    assert!(inline.contains("Permission is hereby granted"));
+    assert!(!inline.contains("Missing"), "{}", inline);
+}
+
+#[test]
+fn graph_to_a_file() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", &manifest("app", "0.1.0", "MIT", "")),
+        ("LICENSE", MIT_TEXT),
+    ]);
+
+    // Missing parent directories are created like a bundle's
+    let file = workspace.path("out/deps.dot");
+    let run = workspace
+        .run(&["graph", "--file", file.to_str().unwrap()])
+        .expect(0);
+    assert_eq!(run.stdout, "");
+    let graph = fs::read_to_string(&file).unwrap();
+    assert!(graph.starts_with("digraph"), "{}", graph);
+
+    let file = workspace.path("LICENSE/deps.dot");
+    let run = workspace
+        .run(&["graph", "--file", file.to_str().unwrap()])
+        .expect(1);
+    assert!(
+        run.stderr.contains(&format!(
+            "Couldn't create directory {}",
+            workspace.path("LICENSE").display()
+        )),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn fix_writes_missing_license_files() {
+    let workspace = Workspace::new(&[(
+        "Cargo.toml",
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\nlicense = \"MIT\"\n\
+             authors = [\"Jane Doe <jane@example.com>\"]\n",
+    )]);
+    let license = workspace.path("LICENSE");
+
+    let run = workspace.run(&["fix", "--dry-run"]).expect(0);
+    assert_eq!(run.stdout, format!("Would create {}\n", license.display()));
+    assert!(!license.exists());
+
+    let run = workspace.run(&["fix"]).expect(0);
+    assert_eq!(run.stdout, format!("Created {}\n", license.display()));
+    let text = fs::read_to_string(&license).unwrap();
+    assert!(text.starts_with("Copyright "), "{}", text);
+    assert!(text.contains(" Jane Doe\n"), "{}", text);
+
+    let run = workspace.run(&["fix"]).expect(0);
+    assert_eq!(run.stdout, "");
+    assert_eq!(fs::read_to_string(&license).unwrap(), text);
+}
+
+#[test]
+fn inspect_traces_discovery() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest("dual", "0.1.0", "MIT OR Apache-2.0", ""),
+        ),
+        ("LICENSE-MIT", MIT_TEXT),
+        ("README.md", "# dual\n\nDoes things.\n"),
+    ]);
+    let run = workspace.run(&["inspect", "-p", "dual"]).expect(0);
+    let lines = run.stdout.lines().map(str::trim).collect::<Vec<_>>();
+    for expected in &[
+        "dual 0.1.0",
+        "declared license:  \"MIT OR Apache-2.0\"",
+        "parsed license:    MIT / Apache-2.0",
+        "any one of:        MIT, Apache-2.0",
+        "README.md: skipped, not named after MIT",
+        "LICENSE-MIT: skipped, not a generic license file name",
+        "MIT: LICENSE-MIT, the only confident candidate",
+        "Apache-2.0: nothing, no candidate texts",
+        "src/lib.rs: skipped, not named after Apache-2.0",
+        "entries examined:  6",
+    ] {
+        assert!(lines.contains(expected), "{}:\n{}", expected, run.stdout);
+    }
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.starts_with("LICENSE-MIT: name-matched candidate for MIT, Confident")),
+        "{}",
+        run.stdout
+    );
+
+    let run = workspace.run(&["inspect", "-p", "missing"]).expect(1);
+    assert!(run.stderr.contains("missing"), "{}", run.stderr);
+}
+
+#[test]
+fn config_file_defaults() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nlgpl = { path = \"lgpl\" }\n",
+            ),
+        ),
+        (
+            "lgpl/Cargo.toml",
+            &manifest("lgpl", "0.1.0", "LGPL-3.0-or-later", ""),
+        ),
+        (
+            "lichking.toml",
+            "[defaults]\noffline = true\n\n[check]\nlinkage = \"dynamic\"\n",
+        ),
+    ]);
+
+    // Found in the parent directories too, and overridden by the command line
+    workspace.run_in("lgpl", &["check"]).expect(0);
+    let run = workspace.run(&["check", "--linkage", "static"]).expect(3);
+    assert!(run.stderr.contains("statically links"), "{}", run.stderr);
+    workspace.run(&["--no-config", "check"]).expect(3);
+
+    // A missing file is only an error when asked for
+    let run = workspace
+        .run(&["--config-file", "missing.toml", "check"])
+        .expect(1);
+    assert!(
+        run.stderr.contains("Couldn't read missing.toml"),
+        "{}",
+        run.stderr
+    );
+    fs::remove_file(workspace.path("lichking.toml")).unwrap();
+    workspace.run(&["check"]).expect(3);
+
+    fs::write(
+        workspace.path("lichking.toml"),
+        "[check]\nlinkage = \"dynamic\"\nlinkgae = \"static\"\n",
+    )
+    .unwrap();
+    let run = workspace.run(&["check"]).expect(1);
+    let path = workspace.path("lichking.toml");
+    assert!(
+        run.stderr
+            .contains(&format!("Couldn't parse {}: ", path.display())),
+        "{}",
+        run.stderr
+    );
+    assert!(
+        run.stderr.contains("unknown field `linkgae`"),
+        "{}",
+        run.stderr
+    );
+    assert!(run.stderr.contains("line 3"), "{}", run.stderr);
+
+    fs::write(
+        workspace.path("lichking.toml"),
+        "[check]\nlinkage = \"shared\"\n",
+    )
+    .unwrap();
+    let run = workspace.run(&["check"]).expect(1);
+    assert!(
+        run.stderr
+            .contains(&format!("Invalid option from {}: ", path.display())),
+        "{}",
+        run.stderr
+    );
+    // Options conflicting with the command line are left out rather than
+    // failing the run
+    fs::write(
+        workspace.path("lichking.toml"),
+        "[bundle]\nfile = \"THIRDPARTY.md\"\n",
+    )
+    .unwrap();
+    let run = workspace
+        .run(&["bundle", "--per-root", "--output-dir", "out"])
+        .expect(3);
+    assert!(!run.stderr.contains("Invalid option"), "{}", run.stderr);
+    assert!(workspace.path("out").is_dir());
+    assert!(!workspace.path("THIRDPARTY.md").exists());
+    workspace.run(&["bundle"]).expect(3);
+    assert!(workspace.path("THIRDPARTY.md").exists());
+}
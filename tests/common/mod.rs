@@ -0,0 +1,163 @@
+//! Helpers for running cargo-lichking against small workspaces written to a
+//! temporary directory
+
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A workspace of path dependencies in a temporary directory, removed again
+/// when dropped
+pub struct Workspace {
+    pub root: PathBuf,
+}
+
+impl Workspace {
+    /// Writes `files`, paths relative to the workspace root and their
+    /// contents, and adds an empty `src/lib.rs` to every package
+    pub fn new(files: &[(&str, &str)]) -> Workspace {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let root = std::env::temp_dir().join(format!(
+            "cargo-lichking-test-{}-{}",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::SeqCst)
+        ));
+        let _ = fs::remove_dir_all(&root);
+        for (path, contents) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, contents).unwrap();
+            if path.file_name().unwrap() == "Cargo.toml" && contents.contains("[package]") {
+                let src = path.parent().unwrap().join("src");
+                fs::create_dir_all(&src).unwrap();
+                fs::write(src.join("lib.rs"), "").unwrap();
+            }
+        }
+        Workspace { root }
+    }
+
+    pub fn path(&self, path: &str) -> PathBuf {
+        self.root.join(path)
+    }
+
+    /// The command running `cargo lichking` with `args` in `dir`, relative to
+    /// the root, for tests that need to change how it's run
+    pub fn command(&self, dir: &str, args: &[&str]) -> Command {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_cargo-lichking"));
+        command
+            .arg("lichking")
+            .arg("--offline")
+            .args(args)
+            .current_dir(self.root.join(dir))
+            .env("RUST_LOG", "warn");
+        command
+    }
+
+    /// Runs `cargo lichking` with `args` in `dir`, relative to the root
+    pub fn run_in(&self, dir: &str, args: &[&str]) -> Run {
+        Run::from(
+            self.command(dir, args)
+                .output()
+                .expect("cargo-lichking runs"),
+        )
+    }
+
+    pub fn run(&self, args: &[&str]) -> Run {
+        self.run_in("", args)
+    }
+
+    /// Writes the metadata of the package in `dir` to `metadata.json` at the
+    /// root, with its dependencies made to look like they're from crates.io,
+    /// for running with `--metadata-json`
+    pub fn registry_metadata(&self, dir: &str) {
+        let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned());
+        let output = Command::new(cargo)
+            .args(["metadata", "--format-version", "1", "--offline"])
+            .current_dir(self.path(dir))
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let mut metadata: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let root = metadata["resolve"]["root"].clone();
+        for package in metadata["packages"].as_array_mut().unwrap() {
+            if package["id"] != root {
+                package["source"] = "registry+https://github.com/rust-lang/crates.io-index".into();
+            }
+        }
+        fs::write(
+            self.path("metadata.json"),
+            serde_json::to_string(&metadata).unwrap(),
+        )
+        .unwrap();
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+/// The result of running cargo-lichking
+pub struct Run {
+    pub code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl From<Output> for Run {
+    fn from(output: Output) -> Run {
+        Run {
+            code: output.status.code(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        }
+    }
+}
+
+impl Run {
+    /// Panics with the output unless the run exited with `code`
+    pub fn expect(self, code: i32) -> Run {
+        assert_eq!(
+            self.code,
+            Some(code),
+            "stdout:\n{}\nstderr:\n{}",
+            self.stdout,
+            self.stderr
+        );
+        self
+    }
+}
+
+/// A `[package]` manifest with the given license and extra lines
+pub fn manifest(name: &str, version: &str, license: &str, rest: &str) -> String {
+    format!(
+        "[package]\nname = \"{}\"\nversion = \"{}\"\nedition = \"2018\"\nlicense = \"{}\"\n{}",
+        name, version, license, rest
+    )
+}
+
+pub const MIT_TEXT: &str = "MIT License
+
+Copyright (c) 2020 The Authors
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the \"Software\"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+";
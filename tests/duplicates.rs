@@ -0,0 +1,236 @@
+//! Two versions of the same crate under different licenses and with different
+//! texts must never be merged or attributed to each other, in any output
+
+mod common;
+
+use std::fs;
+
+use common::{manifest, Workspace, MIT_TEXT};
+
+const CHECKSUM: &str =
+    "{\"files\":{},\"package\":\"0000000000000000000000000000000000000000000000000000000000000000\"}";
+
+/// `app` depending on `foo 0.9.0` under MIT and `foo 1.0.0` under MIT OR
+/// Apache-2.0, both from a vendored crates.io so they aren't workspace members
+fn duplicates() -> Workspace {
+    let old_mit = MIT_TEXT.replace("2020 The Authors", "2019 Old Foo");
+    let new_mit = MIT_TEXT.replace("2020 The Authors", "2024 New Foo");
+    Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"app\"]\n"),
+        (
+            ".cargo/config.toml",
+            "[source.crates-io]\nreplace-with = \"vendored\"\n\n\
+             [source.vendored]\ndirectory = \"vendor\"\n",
+        ),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nold = { package = \"foo\", version = \"0.9\" }\nfoo = \"1.0\"\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "vendor/foo-0.9.0/Cargo.toml",
+            &manifest("foo", "0.9.0", "MIT", ""),
+        ),
+        ("vendor/foo-0.9.0/.cargo-checksum.json", CHECKSUM),
+        ("vendor/foo-0.9.0/LICENSE", &old_mit),
+        (
+            "vendor/foo-1.0.0/Cargo.toml",
+            &manifest("foo", "1.0.0", "MIT OR Apache-2.0", ""),
+        ),
+        ("vendor/foo-1.0.0/.cargo-checksum.json", CHECKSUM),
+        ("vendor/foo-1.0.0/LICENSE-MIT", &new_mit),
+        (
+            "vendor/foo-1.0.0/LICENSE-APACHE",
+            include_str!("../LICENSE-APACHE"),
+        ),
+    ])
+}
+
+#[test]
+fn license_changes_can_be_denied() {
+    let workspace = duplicates();
+    let run = workspace.run_in("app", &["check"]).expect(0);
+    assert!(
+        run.stderr.contains(
+            "foo has different licenses across versions: 0.9.0 (MIT), 1.0.0 (MIT / Apache-2.0)"
+        ),
+        "{}",
+        run.stderr
+    );
+    workspace
+        .run_in(
+            "app",
+            &["check", "--deny", "license-change-across-versions"],
+        )
+        .expect(2);
+}
+
+#[test]
+fn listed_apart() {
+    let workspace = duplicates();
+    let run = workspace
+        .run_in("app", &["list", "--by", "crate"])
+        .expect(0);
+    assert_eq!(
+        run.stdout,
+        "\
+app:       MIT              (workspace root) [internal]
+foo 0.9.0: MIT              (direct)
+foo 1.0.0: MIT / Apache-2.0 (direct)
+"
+    );
+
+    let run = workspace.run_in("app", &["list"]).expect(0);
+    assert!(
+        run.stdout
+            .contains("MIT (2): app (workspace root) [internal], foo 0.9.0\n")
+            && run.stdout.contains("MIT / Apache-2.0 (1): foo 1.0.0\n"),
+        "{}",
+        run.stdout
+    );
+
+    let run = workspace
+        .run_in("app", &["list", "--format", "json"])
+        .expect(0);
+    let list: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let licenses = list["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|package| {
+            (
+                package["name"].as_str().unwrap(),
+                package["version"].as_str().unwrap(),
+                package["license"].as_str().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        licenses,
+        [
+            ("app", "0.1.0", "MIT"),
+            ("foo", "0.9.0", "MIT"),
+            ("foo", "1.0.0", "MIT / Apache-2.0"),
+        ]
+    );
+}
+
+#[test]
+fn elected_per_version() {
+    let workspace = duplicates();
+    let run = workspace
+        .run_in("app", &["list", "--by", "crate", "--prefer", "Apache-2.0"])
+        .expect(0);
+    assert_eq!(
+        run.stdout,
+        "\
+app:       MIT        (workspace root) [internal]
+foo 0.9.0: MIT        (direct)
+foo 1.0.0: Apache-2.0 (direct) (elected from MIT / Apache-2.0)
+"
+    );
+
+    // Only the version offering a choice is used under the preferred license
+    let run = workspace
+        .run_in("app", &["bundle", "--prefer", "Apache-2.0"])
+        .expect(0);
+    let old = run
+        .stdout
+        .split(" * foo 0.9.0 under the terms of MIT:\n")
+        .nth(1)
+        .unwrap_or_else(|| panic!("{}", run.stdout));
+    let (old, new) = old
+        .split_once(
+            " * foo 1.0.0 under the terms of Apache-2.0 (declared as \"MIT OR Apache-2.0\"):\n",
+        )
+        .unwrap_or_else(|| panic!("{}", run.stdout));
+    assert!(old.contains("2019 Old Foo"), "{}", run.stdout);
+    assert!(new.contains("Apache License"), "{}", run.stdout);
+    assert!(!new.contains("New Foo"), "{}", run.stdout);
+}
+
+#[test]
+fn grouped_with_their_own_holders() {
+    let workspace = duplicates();
+    let run = workspace
+        .run_in("app", &["bundle", "--variant", "grouped"])
+        .expect(0);
+    assert!(
+        run.stdout.contains(
+            " * foo 0.9.0\n     Copyright (c) 2019 Old Foo\n * foo 1.0.0\n     Copyright (c) 2024 New Foo\n"
+        ),
+        "{}",
+        run.stdout
+    );
+}
+
+#[test]
+fn bundled_apart() {
+    let workspace = duplicates();
+    let run = workspace
+        .run_in("app", &["bundle", "--variant", "json"])
+        .expect(0);
+    let bundle: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let foo = bundle
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|package| package["name"] == "foo")
+        .collect::<Vec<_>>();
+    assert_eq!(foo.len(), 2, "{}", run.stdout);
+    assert_eq!(foo[0]["version"], "0.9.0");
+    assert_eq!(foo[0]["license"], "MIT");
+    assert!(foo[0]["licenses"][0]["text"]
+        .as_str()
+        .unwrap()
+        .contains("2019 Old Foo"));
+    assert_eq!(foo[1]["version"], "1.0.0");
+    assert_eq!(foo[1]["license"], "MIT / Apache-2.0");
+    let texts = foo[1]["licenses"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|license| license["text"].as_str().unwrap())
+        .collect::<String>();
+    assert!(texts.contains("2024 New Foo"), "{}", run.stdout);
+    assert!(!texts.contains("Old Foo"), "{}", run.stdout);
+
+    // A text file per version
+    let dir = workspace.path("licenses");
+    workspace
+        .run_in(
+            "app",
+            &[
+                "bundle",
+                "--variant",
+                "split",
+                "--dir",
+                dir.to_str().unwrap(),
+            ],
+        )
+        .expect(0);
+    let old = fs::read_to_string(dir.join("foo-0.9.0")).unwrap();
+    let new = fs::read_to_string(dir.join("foo-1.0.0")).unwrap();
+    assert!(
+        old.contains("2019 Old Foo") && !old.contains("New Foo"),
+        "{}",
+        old
+    );
+    assert!(
+        new.contains("2024 New Foo") && !new.contains("Old Foo"),
+        "{}",
+        new
+    );
+
+    // Every variant has an entry for each version, or verification fails
+    for variant in ["inline", "name-only", "markdown", "html", "grouped"] {
+        workspace
+            .run_in("app", &["bundle", "--variant", variant])
+            .expect(0);
+    }
+}
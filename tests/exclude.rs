@@ -0,0 +1,271 @@
+mod common;
+
+use common::{manifest, Run, Workspace, MIT_TEXT};
+
+/// `app` depends on `left` and `right` which both depend on `shared`, only
+/// `left` depends on `lonely`. `tool-a` and `tool-b` are internal tools that
+/// are never shipped, so may use the GPL `gpl-lib`.
+fn diamond() -> Workspace {
+    let dep = |name: &str| format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app\", \"tool-a\", \"tool-b\"]\n",
+        ),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                &format!("[dependencies]\n{}{}", dep("left"), dep("right")),
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "left/Cargo.toml",
+            &manifest(
+                "left",
+                "0.1.0",
+                "MIT",
+                &format!("[dependencies]\n{}{}", dep("shared"), dep("lonely")),
+            ),
+        ),
+        ("left/LICENSE", MIT_TEXT),
+        (
+            "right/Cargo.toml",
+            &manifest(
+                "right",
+                "0.1.0",
+                "MIT",
+                &format!("[dependencies]\n{}", dep("shared")),
+            ),
+        ),
+        ("right/LICENSE", MIT_TEXT),
+        ("shared/Cargo.toml", &manifest("shared", "0.1.0", "MIT", "")),
+        ("shared/LICENSE", MIT_TEXT),
+        ("lonely/Cargo.toml", &manifest("lonely", "0.1.0", "MIT", "")),
+        ("lonely/LICENSE", MIT_TEXT),
+        (
+            "tool-a/Cargo.toml",
+            &manifest(
+                "tool-a",
+                "0.1.0",
+                "MIT",
+                &format!("[dependencies]\n{}", dep("gpl-lib")),
+            ),
+        ),
+        (
+            "tool-b/Cargo.toml",
+            &manifest(
+                "tool-b",
+                "0.1.0",
+                "MIT",
+                &format!("[dependencies]\n{}", dep("gpl-lib")),
+            ),
+        ),
+        (
+            "gpl-lib/Cargo.toml",
+            &manifest("gpl-lib", "0.1.0", "GPL-3.0", ""),
+        ),
+    ])
+}
+
+/// The names of the packages `list` reports with `args`
+fn listed(workspace: &Workspace, args: &[&str]) -> Vec<String> {
+    let out = workspace
+        .run(&[&["list", "--format", "json"], args].concat())
+        .expect(0)
+        .stdout;
+    let list: serde_json::Value = serde_json::from_str(&out).unwrap();
+    let mut names = list["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|package| package["name"].as_str().unwrap().to_owned())
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+#[test]
+fn excluding_a_parent_keeps_shared_children() {
+    let workspace = diamond();
+    assert_eq!(
+        listed(&workspace, &["-p", "app"]),
+        ["app", "left", "lonely", "right", "shared"]
+    );
+    assert_eq!(
+        listed(&workspace, &["-p", "app", "--exclude-dep", "left"]),
+        ["app", "right", "shared"]
+    );
+    assert_eq!(
+        listed(&workspace, &["-p", "app", "--exclude-dep", "right"]),
+        ["app", "left", "lonely", "shared"]
+    );
+    assert_eq!(
+        listed(
+            &workspace,
+            &[
+                "-p",
+                "app",
+                "--exclude-dep",
+                "left",
+                "--exclude-dep",
+                "right"
+            ]
+        ),
+        ["app"]
+    );
+}
+
+#[test]
+fn excluded_deps_can_name_a_version() {
+    let workspace = diamond();
+    assert_eq!(
+        listed(&workspace, &["-p", "app", "--exclude-dep", "left@0.1.0"]),
+        ["app", "right", "shared"]
+    );
+    assert_eq!(
+        listed(&workspace, &["-p", "app", "--exclude-dep", "left@0.2.0"]),
+        ["app", "left", "lonely", "right", "shared"]
+    );
+}
+
+#[test]
+fn excluded_roots() {
+    let workspace = diamond();
+    workspace.run(&["check", "--all"]).expect(2);
+    workspace
+        .run(&["check", "--all", "--exclude", "tool-*"])
+        .expect(0);
+    workspace
+        .run(&["check", "--all", "--exclude", "tool-a"])
+        .expect(2);
+
+    let run = workspace
+        .run(&[
+            "bundle",
+            "--all",
+            "--exclude",
+            "tool-*",
+            "--variant",
+            "name-only",
+        ])
+        .expect(0);
+    assert!(!run.stdout.contains("tool-"), "{}", run.stdout);
+    assert!(run.stdout.contains("shared 0.1.0"), "{}", run.stdout);
+}
+
+#[test]
+fn excluded_specs() {
+    let workspace = diamond();
+    workspace
+        .run(&[
+            "check",
+            "--all",
+            "--exclude-spec",
+            "tool-a@0.1.0",
+            "--exclude-spec",
+            "tool-b",
+        ])
+        .expect(0);
+    workspace
+        .run(&["check", "--all", "--exclude-spec", "tool-a@0.2"])
+        .expect(2);
+    workspace
+        .run(&["check", "--all", "--exclude-spec", "tool-a@"])
+        .expect(1);
+    // Unlike names, specs aren't globs
+    assert_eq!(
+        listed(&workspace, &["--all", "--exclude-spec", "tool-*"]).len(),
+        8
+    );
+}
+
+#[test]
+fn exclusions_are_mentioned_once() {
+    let workspace = diamond();
+    let run = Run::from(
+        workspace
+            .command(
+                "",
+                &[
+                    "bundle",
+                    "-p",
+                    "app",
+                    "--exclude-dep",
+                    "left",
+                    "--variant",
+                    "name-only",
+                ],
+            )
+            .env("RUST_LOG", "info")
+            .output()
+            .unwrap(),
+    )
+    .expect(0);
+    assert!(!run.stdout.contains("lonely"), "{}", run.stdout);
+    assert!(run.stdout.contains("shared 0.1.0"), "{}", run.stdout);
+    assert_eq!(run.stderr.matches("left").count(), 1, "{}", run.stderr);
+}
+
+#[test]
+fn diffs_against_baselines_saved_with_exclusions() {
+    let workspace = diamond();
+    let baseline = workspace.path("baseline.json");
+    let baseline = baseline.to_str().unwrap();
+    workspace
+        .run(&[
+            "list",
+            "-p",
+            "tool-a",
+            "--exclude-dep",
+            "gpl-lib",
+            "--save-baseline",
+            baseline,
+        ])
+        .expect(0);
+
+    let run = workspace
+        .run(&[
+            "diff",
+            "--baseline",
+            baseline,
+            "-p",
+            "tool-a",
+            "--exclude-dep",
+            "gpl-lib",
+        ])
+        .expect(0);
+    assert_eq!(run.stdout, "");
+    // Without the same exclusion the excluded package is new
+    let run = workspace
+        .run(&["diff", "--baseline", baseline, "-p", "tool-a"])
+        .expect(2);
+    assert_eq!(run.stdout, "added: gpl-lib 0.1.0 (GPL-3.0-only)\n");
+}
+
+#[test]
+fn excluded_roots_are_not_inspected_or_fixed() {
+    let workspace = diamond();
+    let run = workspace
+        .run(&["fix", "--all", "--dry-run", "--exclude", "tool-*"])
+        .expect(0);
+    assert!(run.stdout.contains("gpl-lib/LICENSE"), "{}", run.stdout);
+    assert!(!run.stdout.contains("tool-"), "{}", run.stdout);
+
+    let run = workspace
+        .run(&[
+            "inspect",
+            "--all",
+            "--exclude",
+            "tool-*",
+            "--exclude",
+            "gpl-lib",
+        ])
+        .expect(0);
+    assert!(run.stdout.contains("shared 0.1.0\n"), "{}", run.stdout);
+    assert!(!run.stdout.contains("tool-"), "{}", run.stdout);
+    assert!(!run.stdout.contains("gpl-lib"), "{}", run.stdout);
+}
@@ -0,0 +1,132 @@
+//! Pins the documented exit codes: 0 when clean, 1 for fatal errors and
+//! invalid invocations, 2 for policy failures and 3 for warnings
+
+mod common;
+
+use common::{manifest, Workspace, MIT_TEXT};
+
+/// `app` depending on `texted` which ships its license text, `textless`
+/// which doesn't and the GPL `gpl` which `app` can't include
+fn fixture() -> Workspace {
+    let dep = |name: &str| format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app\", \"clean\"]\n",
+        ),
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                &format!(
+                    "[dependencies]\n{}{}{}",
+                    dep("texted"),
+                    dep("textless"),
+                    dep("gpl")
+                ),
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "clean/Cargo.toml",
+            &manifest(
+                "clean",
+                "0.1.0",
+                "MIT",
+                &format!("[dependencies]\n{}", dep("texted")),
+            ),
+        ),
+        ("clean/LICENSE", MIT_TEXT),
+        ("texted/Cargo.toml", &manifest("texted", "0.1.0", "MIT", "")),
+        ("texted/LICENSE", MIT_TEXT),
+        (
+            "textless/Cargo.toml",
+            &manifest("textless", "0.1.0", "MIT", ""),
+        ),
+        ("gpl/Cargo.toml", &manifest("gpl", "0.1.0", "GPL-3.0", "")),
+    ])
+}
+
+#[test]
+fn clean() {
+    let workspace = fixture();
+    workspace.run(&["check", "-p", "clean"]).expect(0);
+    workspace.run(&["list", "-p", "clean"]).expect(0);
+    workspace.run(&["bundle", "-p", "clean"]).expect(0);
+    workspace
+        .run(&["--warnings-as-errors", "bundle", "-p", "clean"])
+        .expect(0);
+}
+
+#[test]
+fn fatal_errors_and_invalid_invocations() {
+    let workspace = fixture();
+    workspace.run(&["frobnicate"]).expect(1);
+    workspace.run(&["check", "--no-such-flag"]).expect(1);
+    workspace.run(&["check", "-p", "missing"]).expect(1);
+    workspace
+        .run(&["bundle", "--variant", "split", "-p", "clean"])
+        .expect(1);
+    workspace
+        .run(&["--manifest-path", "missing/Cargo.toml", "check"])
+        .expect(1);
+}
+
+#[test]
+fn policy_failures() {
+    let workspace = fixture();
+    workspace.run(&["check", "-p", "app"]).expect(2);
+    workspace
+        .run(&["--no-fail-on-missing-text", "check", "-p", "app"])
+        .expect(2);
+}
+
+#[test]
+fn missing_texts_are_warnings() {
+    let workspace = fixture();
+    let run = workspace
+        .run(&["bundle", "-p", "app", "--exclude-dep", "gpl"])
+        .expect(3);
+    assert!(run.stdout.contains("textless 0.1.0"), "{}", run.stdout);
+    workspace
+        .run(&[
+            "--no-fail-on-missing-text",
+            "bundle",
+            "-p",
+            "app",
+            "--exclude-dep",
+            "gpl",
+        ])
+        .expect(0);
+    workspace
+        .run(&[
+            "--warnings-as-errors",
+            "bundle",
+            "-p",
+            "app",
+            "--exclude-dep",
+            "gpl",
+        ])
+        .expect(2);
+}
+
+#[test]
+fn unsure_license_files_are_warnings() {
+    let workspace = fixture();
+    // `clean`'s generic LICENSE file can't match an impossible threshold
+    let args = [
+        "bundle",
+        "-p",
+        "clean",
+        "--confidence-threshold",
+        "0",
+        "--semi-confidence-threshold",
+        "0",
+    ];
+    workspace.run(&args).expect(3);
+    workspace
+        .run(&[&["--warnings-as-errors"], &args[..]].concat())
+        .expect(2);
+}
@@ -0,0 +1,1158 @@
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+use common::{manifest, Run, Workspace, MIT_TEXT};
+use sha2::{Digest, Sha256};
+
+/// app -> left -> shared and app -> right -> middle -> shared
+fn diamond() -> Workspace {
+    let dep = |name: &str| format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    let package = |name: &str, deps: &[&str]| {
+        let deps = deps.iter().map(|name| dep(name)).collect::<String>();
+        manifest(name, "0.1.0", "MIT", &format!("[dependencies]\n{}", deps))
+    };
+    Workspace::new(&[
+        ("app/Cargo.toml", &package("app", &["left", "right"])),
+        ("app/LICENSE", MIT_TEXT),
+        ("left/Cargo.toml", &package("left", &["shared"])),
+        ("left/LICENSE", MIT_TEXT),
+        ("right/Cargo.toml", &package("right", &["middle"])),
+        ("right/LICENSE", MIT_TEXT),
+        ("middle/Cargo.toml", &package("middle", &["shared"])),
+        ("middle/LICENSE", MIT_TEXT),
+        ("shared/Cargo.toml", &package("shared", &[])),
+        ("shared/LICENSE", MIT_TEXT),
+    ])
+}
+
+/// The details `list` prints after each package's license
+fn details(stdout: &str) -> Vec<(String, String)> {
+    let mut lines = stdout
+        .lines()
+        .filter_map(|line| {
+            let name = line.split(':').next()?.trim();
+            let details = line.split_once(" (")?.1;
+            Some((name.to_owned(), format!("({}", details)))
+        })
+        .collect::<Vec<_>>();
+    lines.sort();
+    lines
+}
+
+#[test]
+fn direct_and_transitive_dependencies_are_annotated() {
+    let workspace = diamond();
+    let expected = |names: &[&str]| {
+        let all = [
+            ("app", "(workspace root) [internal]"),
+            ("left", "(direct) [internal]"),
+            ("middle", "(transitive, via right) [internal]"),
+            ("right", "(direct) [internal]"),
+            ("shared", "(transitive, via left) [internal]"),
+        ];
+        all.iter()
+            .filter(|(name, _)| names.contains(name))
+            .map(|(name, details)| (name.to_string(), details.to_string()))
+            .collect::<Vec<_>>()
+    };
+
+    let run = workspace
+        .run_in("app", &["list", "--by", "crate"])
+        .expect(0);
+    assert_eq!(
+        details(&run.stdout),
+        expected(&["app", "left", "middle", "right", "shared"]),
+        "{}",
+        run.stdout
+    );
+
+    let run = workspace
+        .run_in("app", &["list", "--by", "crate", "--direct-only"])
+        .expect(0);
+    assert_eq!(
+        details(&run.stdout),
+        expected(&["app", "left", "right"]),
+        "{}",
+        run.stdout
+    );
+}
+
+#[test]
+fn equivalent_licenses_share_a_bucket() {
+    let dep = |name: &str| format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                &format!("[dependencies]\n{}{}{}", dep("a"), dep("b"), dep("c")),
+            ),
+        ),
+        (
+            "a/Cargo.toml",
+            &manifest("a", "0.1.0", "MIT OR Apache-2.0", ""),
+        ),
+        (
+            "b/Cargo.toml",
+            &manifest("b", "0.1.0", "Apache-2.0 OR MIT", ""),
+        ),
+        (
+            "c/Cargo.toml",
+            &manifest("c", "0.1.0", "MIT/Apache-2.0", ""),
+        ),
+    ]);
+
+    let run = workspace.run_in("app", &["list"]).expect(0);
+    let buckets = run
+        .stdout
+        .lines()
+        .filter(|line| line.contains("Apache-2.0"))
+        .collect::<Vec<_>>();
+    assert_eq!(buckets.len(), 1, "{}", run.stdout);
+    assert!(buckets[0].contains("(3): a"), "{}", run.stdout);
+
+    let run = workspace.run_in("app", &["list", "--summary"]).expect(0);
+    let buckets = run
+        .stdout
+        .lines()
+        .filter(|line| line.contains("Apache-2.0"))
+        .collect::<Vec<_>>();
+    assert_eq!(buckets.len(), 1, "{}", run.stdout);
+    assert!(buckets[0].trim_end().ends_with('3'), "{}", run.stdout);
+}
+
+#[test]
+fn cyclonedx_document() {
+    let workspace = diamond();
+    let run = workspace
+        .run_in("app", &["list", "--format", "cyclonedx", "--reproducible"])
+        .expect(0);
+    let bom: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    assert_eq!(bom["bomFormat"], "CycloneDX");
+    assert_eq!(bom["specVersion"], "1.5");
+
+    let components = bom["components"].as_array().unwrap();
+    assert_eq!(components.len(), 5, "{}", run.stdout);
+    let purls = components
+        .iter()
+        .map(|component| component["purl"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        purls,
+        [
+            "pkg:cargo/app@0.1.0",
+            "pkg:cargo/left@0.1.0",
+            "pkg:cargo/middle@0.1.0",
+            "pkg:cargo/right@0.1.0",
+            "pkg:cargo/shared@0.1.0",
+        ]
+    );
+    // Each license text is recorded along with the hash of the file
+    let sha256 = format!("{:x}", Sha256::digest(MIT_TEXT.as_bytes()));
+    for component in components {
+        assert_eq!(component["bom-ref"], component["purl"]);
+        assert_eq!(
+            component["licenses"],
+            serde_json::json!([{ "license": { "id": "MIT" } }])
+        );
+        assert_eq!(
+            component["properties"],
+            serde_json::json!([{
+                "name": "cargo-lichking:license-text",
+                "value": format!("LICENSE sha256:{}", sha256),
+            }])
+        );
+    }
+
+    let depends_on = |purl: &str| {
+        bom["dependencies"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|dependency| dependency["ref"] == purl)
+            .unwrap()["dependsOn"]
+            .clone()
+    };
+    assert_eq!(
+        depends_on("pkg:cargo/app@0.1.0"),
+        serde_json::json!(["pkg:cargo/left@0.1.0", "pkg:cargo/right@0.1.0"])
+    );
+    assert_eq!(depends_on("pkg:cargo/shared@0.1.0"), serde_json::json!([]));
+
+    // Only reproducible documents keep their serial number between runs
+    let serial = |args: &[&str]| {
+        let run = workspace.run_in("app", args).expect(0);
+        let bom: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+        bom["serialNumber"].as_str().unwrap().to_owned()
+    };
+    let reproducible = ["list", "--format", "cyclonedx", "--reproducible"];
+    assert_eq!(bom["serialNumber"], serial(&reproducible).as_str());
+    assert!(serial(&reproducible).starts_with("urn:uuid:"));
+    let random = ["list", "--format", "cyclonedx"];
+    assert_ne!(serial(&random), serial(&random));
+}
+
+#[test]
+fn several_packages_are_roots() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"one\", \"two\", \"three\"]\n",
+        ),
+        ("one/Cargo.toml", &manifest("one", "0.1.0", "MIT", "")),
+        (
+            "two/Cargo.toml",
+            &manifest("two", "0.1.0", "Apache-2.0", ""),
+        ),
+        ("three/Cargo.toml", &manifest("three", "0.1.0", "MIT", "")),
+    ]);
+    let run = workspace
+        .run(&[
+            "list",
+            "--by",
+            "crate",
+            "-p",
+            "one",
+            "--package",
+            "two@0.1.0",
+        ])
+        .expect(0);
+    assert_eq!(
+        details(&run.stdout),
+        [
+            ("one".to_owned(), "(workspace root) [internal]".to_owned()),
+            ("two".to_owned(), "(workspace root) [internal]".to_owned()),
+        ],
+        "{}",
+        run.stdout
+    );
+
+    let run = workspace.run(&["list", "-p", "four"]).expect(1);
+    assert!(
+        run.stderr.contains("`four` did not match any packages"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn per_root_scope() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"tool\", \"lib\"]\n"),
+        (
+            "tool/Cargo.toml",
+            &manifest(
+                "tool",
+                "0.1.0",
+                "GPL-3.0-only",
+                "[dependencies]\ngpldep = { path = \"../gpldep\" }\n",
+            ),
+        ),
+        ("lib/Cargo.toml", &manifest("lib", "0.1.0", "MIT", "")),
+        (
+            "gpldep/Cargo.toml",
+            &manifest("gpldep", "0.1.0", "GPL-3.0-only", ""),
+        ),
+    ]);
+    let run = workspace
+        .run(&[
+            "list", "--by", "crate", "-p", "tool", "-p", "lib", "--scope", "per-root",
+        ])
+        .expect(0);
+    let (tool, lib) = run.stdout.split_once("\nlib 0.1.0:\n").unwrap();
+    assert!(
+        tool.starts_with("tool 0.1.0:\n") && tool.contains("gpldep"),
+        "{}",
+        run.stdout
+    );
+    assert!(!lib.contains("gpldep"), "{}", run.stdout);
+    assert_eq!(
+        details(lib),
+        [("lib".to_owned(), "(workspace root) [internal]".to_owned())]
+    );
+
+    let run = workspace
+        .run(&[
+            "list", "--format", "json", "-p", "tool", "-p", "lib", "--scope", "per-root",
+        ])
+        .expect(0);
+    let inventories: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let names = |root: &serde_json::Value| {
+        root["packages"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|package| package["name"].as_str().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(inventories[0]["root"], "tool 0.1.0");
+    assert_eq!(names(&inventories[0]), ["gpldep", "tool"]);
+    assert_eq!(inventories[1]["root"], "lib 0.1.0");
+    assert_eq!(names(&inventories[1]), ["lib"]);
+
+    workspace
+        .run(&["list", "--format", "cyclonedx", "--scope", "per-root"])
+        .expect(1);
+}
+
+/// app with a `metrics` feature enabling an optional dependency, another
+/// enabled through its implicit feature and one by default
+fn optional() -> Workspace {
+    Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[features]\ndefault = [\"fast\"]\nmetrics = [\"dep:gauge\"]\nfast = [\"turbo/simd\"]\n\n\
+                 [dependencies]\n\
+                 gauge = { path = \"../gauge\", optional = true }\n\
+                 extra = { path = \"../extra\", optional = true }\n\
+                 turbo = { path = \"../turbo\", optional = true }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        ("gauge/Cargo.toml", &manifest("gauge", "0.1.0", "MIT", "")),
+        ("gauge/LICENSE", MIT_TEXT),
+        ("extra/Cargo.toml", &manifest("extra", "0.1.0", "MIT", "")),
+        ("extra/LICENSE", MIT_TEXT),
+        (
+            "turbo/Cargo.toml",
+            &manifest("turbo", "0.1.0", "MIT", "[features]\nsimd = []\n"),
+        ),
+        ("turbo/LICENSE", MIT_TEXT),
+    ])
+}
+
+#[test]
+fn features_select_optional_dependencies() {
+    let workspace = optional();
+    let list = |args: &[&str]| {
+        let args: Vec<_> = ["-v"]
+            .iter()
+            .chain(args)
+            .chain(&["list", "--by", "crate"])
+            .copied()
+            .collect();
+        let run = workspace.run_in("app", &args).expect(0);
+        details(&run.stdout)
+            .into_iter()
+            .map(|(name, details)| format!("{} {}", name, details))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        list(&[]),
+        [
+            "app (workspace root) [internal]",
+            "turbo (direct) [internal] (via feature \"fast\")",
+        ]
+    );
+    assert_eq!(
+        list(&["--no-default-features"]),
+        ["app (workspace root) [internal]"]
+    );
+    assert_eq!(
+        list(&["--no-default-features", "--features", "metrics,extra"]),
+        [
+            "app (workspace root) [internal]",
+            "extra (direct) [internal] (via feature \"extra\")",
+            "gauge (direct) [internal] (via feature \"metrics\")",
+        ]
+    );
+    assert_eq!(list(&["--all-features"]).len(), 4);
+}
+
+#[test]
+fn empty_license_fields_are_reported() {
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nblank = { path = \"../blank\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        ("blank/Cargo.toml", &manifest("blank", "0.1.0", "", "")),
+        ("blank/CHANGELOG.md", "# Changelog\n"),
+    ]);
+    let warning = "blank 0.1.0: the license field is empty, treating it as unspecified";
+    let run = workspace
+        .run_in("app", &["list", "--by", "crate"])
+        .expect(0);
+    assert!(
+        run.stdout
+            .contains("blank: No license specified (direct) [internal]"),
+        "{}",
+        run.stdout
+    );
+    assert!(run.stderr.contains(warning), "{}", run.stderr);
+
+    let run = workspace.run_in("app", &["check"]).expect(2);
+    assert!(run.stderr.contains(warning), "{}", run.stderr);
+    assert!(
+        run.stderr
+            .contains("app cannot include package blank, license No license specified"),
+        "{}",
+        run.stderr
+    );
+}
+
+#[test]
+fn workspace_inherited_licenses() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app\", \"lib\"]\ndefault-members = [\"app\"]\n\n\
+             [workspace.package]\nlicense = \"GPL-3.0-only\"\nversion = \"0.1.0\"\n\n\
+             [workspace.dependencies]\nlib = { path = \"lib\" }\n",
+        ),
+        (
+            "app/Cargo.toml",
+            "[package]\nname = \"app\"\nversion.workspace = true\nlicense = \"MIT\"\n\n\
+             [dependencies]\nlib.workspace = true\n",
+        ),
+        (
+            "lib/Cargo.toml",
+            "[package]\nname = \"lib\"\nversion.workspace = true\nlicense.workspace = true\n",
+        ),
+    ]);
+    let run = workspace.run(&["list", "--by", "crate"]).expect(0);
+    assert_eq!(
+        details(&run.stdout),
+        [
+            ("app".to_owned(), "(workspace root) [internal]".to_owned()),
+            ("lib".to_owned(), "(direct) [internal]".to_owned()),
+        ]
+    );
+    assert!(
+        run.stdout
+            .lines()
+            .any(|line| line.starts_with("lib:") && line.contains("GPL-3.0-only")),
+        "{}",
+        run.stdout
+    );
+
+    let run = workspace.run(&["check"]).expect(2);
+    assert!(
+        run.stderr
+            .contains("app cannot include package lib, license GPL-3.0-only"),
+        "{}",
+        run.stderr
+    );
+}
+
+/// app (MIT) -> weak (MPL-2.0), lib (LGPL-2.1-only) and plain (MIT)
+fn copylefts() -> Workspace {
+    Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\n\
+                 weak = { path = \"../weak\" }\n\
+                 lib = { path = \"../lib\" }\n\
+                 plain = { path = \"../plain\" }\n",
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        ("weak/Cargo.toml", &manifest("weak", "0.1.0", "MPL-2.0", "")),
+        (
+            "lib/Cargo.toml",
+            &manifest("lib", "0.1.0", "LGPL-2.1-only", ""),
+        ),
+        ("plain/Cargo.toml", &manifest("plain", "0.1.0", "MIT", "")),
+        ("plain/LICENSE", MIT_TEXT),
+    ])
+}
+
+#[test]
+fn obligations_per_license() {
+    let workspace = copylefts();
+    let run = workspace
+        .run_in("app", &["list", "--obligations"])
+        .expect(0);
+    assert_eq!(
+        run.stdout.lines().collect::<Vec<_>>(),
+        vec![
+            "MIT (2): distribute license text, preserve notices",
+            "LGPL-2.1-only (1): library copyleft, publish source of modifications, \
+             distribute license text, preserve notices",
+            "MPL-2.0 (1): file-level copyleft, publish source of modifications, \
+             distribute license text, preserve notices, patent grant",
+        ]
+    );
+    assert!(run.stderr.contains("IANAL"), "{}", run.stderr);
+}
+
+/// A dependency patched with a git fork in part of the graph, so both the
+/// crates.io and the git copy are in the metadata. Only the fork has a
+/// LICENSE file.
+#[test]
+fn patched_dependencies_are_reported_once() {
+    let workspace = Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"app\"]\n"),
+        ("app/Cargo.toml", &manifest("app", "0.1.0", "MIT", "")),
+        ("app/LICENSE", MIT_TEXT),
+        ("user/Cargo.toml", &manifest("user", "0.1.0", "MIT", "")),
+        ("user/LICENSE", MIT_TEXT),
+        (
+            "registry/dup-1.0.0/Cargo.toml",
+            &manifest("dup", "1.0.0", "MIT", ""),
+        ),
+        ("git/dup/Cargo.toml", &manifest("dup", "1.0.0", "MIT", "")),
+        ("git/dup/LICENSE", MIT_TEXT),
+    ]);
+    let fixture = fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/patched.json"
+    ))
+    .unwrap();
+    let root = workspace.path("app");
+    let root = root.parent().unwrap().to_str().unwrap();
+    fs::write(
+        workspace.path("metadata.json"),
+        fixture.replace("/ROOT", root),
+    )
+    .unwrap();
+    let run = |args: &[&str]| {
+        Run::from(
+            Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+                .args(["lichking", "--metadata-json", "metadata.json"])
+                .args(args)
+                .current_dir(workspace.path(""))
+                .env("RUST_LOG", "info")
+                .output()
+                .unwrap(),
+        )
+    };
+
+    let list = run(&["list", "--by", "crate"]).expect(0);
+    assert_eq!(
+        details(&list.stdout),
+        [
+            ("app".to_owned(), "(workspace root) [internal]".to_owned()),
+            (
+                "dup".to_owned(),
+                "(direct) [crates.io, git https://github.com/example/dup]".to_owned()
+            ),
+            ("user".to_owned(), "(direct) [internal]".to_owned()),
+        ]
+    );
+    assert!(
+        list.stderr.contains(
+            "dup 1.0.0 comes from both crates.io and git https://github.com/example/dup, \
+             reporting it once"
+        ),
+        "{}",
+        list.stderr
+    );
+
+    let list = run(&["list", "--by", "license"]).expect(0);
+    assert_eq!(list.stdout.matches(" dup [").count(), 1, "{}", list.stdout);
+
+    // The texts come from whichever copy has them
+    let bundle = run(&["bundle", "--variant", "inline"]).expect(0);
+    assert_eq!(
+        bundle.stdout.matches(" * dup ").count(),
+        1,
+        "{}",
+        bundle.stdout
+    );
+    let entry = bundle.stdout.split(" * dup 1.0.0").nth(1).unwrap();
+    let entry = entry.split(" * user").next().unwrap();
+    assert!(
+        entry.starts_with(" under the terms of MIT:\n\n    MIT License"),
+        "{}",
+        entry
+    );
+    assert!(
+        bundle.stderr.contains(
+            "Using the license texts of dup 1.0.0 from git https://github.com/example/dup"
+        ),
+        "{}",
+        bundle.stderr
+    );
+}
+
+#[test]
+fn from_lockfile_with_a_registry_cache() {
+    let lockfile = fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/lockfile-v3/Cargo.lock"
+    ))
+    .unwrap();
+    let workspace = Workspace::new(&[
+        ("artifact/Cargo.lock", &lockfile),
+        (
+            "cargo-home/registry/src/index.crates.io-1949cf8c6b5b557f/fixture-cached-1.0.0/Cargo.toml",
+            &manifest("fixture-cached", "1.0.0", "MIT OR Apache-2.0", ""),
+        ),
+    ]);
+    let run = |args: &[&str]| {
+        Run::from(
+            Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+                .arg("lichking")
+                .args(args)
+                .current_dir(workspace.path("artifact"))
+                .env("CARGO_HOME", workspace.path("cargo-home"))
+                .env("RUST_LOG", "warn")
+                .output()
+                .unwrap(),
+        )
+    };
+
+    let list = run(&["list", "--from-lockfile", "Cargo.lock", "--by", "crate"]).expect(0);
+    let lines = list
+        .stdout
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        [
+            "app: No license specified (workspace root) [internal]",
+            "fixture-cached: MIT / Apache-2.0 (direct)",
+            "fixture-uncached 0.3.1: No license specified (direct)",
+            "fixture-uncached 0.4.0: No license specified (direct)",
+        ]
+    );
+    for version in &["0.3.1", "0.4.0"] {
+        let warning = format!(
+            "fixture-uncached {}: license unknown (source not cached)",
+            version
+        );
+        assert!(list.stderr.contains(&warning), "{}", list.stderr);
+    }
+    assert!(
+        list.stderr
+            .contains("2 of 4 packages in Cargo.lock aren't in the registry cache"),
+        "{}",
+        list.stderr
+    );
+
+    let bundle = run(&[
+        "bundle",
+        "--variant",
+        "name-only",
+        "--from-lockfile",
+        "Cargo.lock",
+    ])
+    .expect(0);
+    assert!(
+        bundle
+            .stdout
+            .contains(" * fixture-cached 1.0.0 under the terms of MIT / Apache-2.0"),
+        "{}",
+        bundle.stdout
+    );
+
+    // The rest need what only cargo metadata and the sources have
+    let bundle = run(&["bundle", "--from-lockfile", "Cargo.lock"]).expect(1);
+    assert!(
+        bundle
+            .stderr
+            .contains("--from-lockfile can only be used with --variant name-only"),
+        "{}",
+        bundle.stderr
+    );
+    let check = run(&["check", "--from-lockfile", "Cargo.lock"]).expect(1);
+    assert!(
+        check
+            .stderr
+            .contains("--from-lockfile can't be used with check"),
+        "{}",
+        check.stderr
+    );
+}
+
+#[test]
+fn colored_and_plain() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\n\
+                 weak = { path = \"weak\" }\n\
+                 strong = { path = \"strong\" }\n\
+                 unlicensed = { path = \"unlicensed\" }\n",
+            ),
+        ),
+        ("LICENSE", MIT_TEXT),
+        ("weak/Cargo.toml", &manifest("weak", "0.1.0", "MPL-2.0", "")),
+        (
+            "strong/Cargo.toml",
+            &manifest("strong", "0.1.0", "GPL-3.0-only", ""),
+        ),
+        (
+            "unlicensed/Cargo.toml",
+            "[package]\nname = \"unlicensed\"\nversion = \"0.1.0\"\n",
+        ),
+    ]);
+    let plain = "\
+app:        MIT                  (workspace root) [internal]
+strong:     GPL-3.0-only         (direct) [internal]
+unlicensed: No license specified (direct) [internal]
+weak:       MPL-2.0              (direct) [internal]
+";
+    let colored = "\
+app:        \x1b[32mMIT\x1b[0m                  (workspace root) [internal]
+strong:     \x1b[31mGPL-3.0-only\x1b[0m         (direct) [internal]
+unlicensed: \x1b[1;35mNo license specified\x1b[0m (direct) [internal]
+weak:       \x1b[33mMPL-2.0\x1b[0m              (direct) [internal]
+";
+
+    let run = workspace
+        .run(&["--color", "always", "list", "--by", "crate"])
+        .expect(0);
+    assert_eq!(run.stdout, colored);
+    let run = workspace
+        .run(&["--color", "never", "list", "--by", "crate"])
+        .expect(0);
+    assert_eq!(run.stdout, plain);
+    // Piped output isn't a terminal
+    let run = workspace.run(&["list", "--by", "crate"]).expect(0);
+    assert_eq!(run.stdout, plain);
+
+    // Forcing colors wins over NO_COLOR, which only changes the default
+    let run = Run::from(
+        workspace
+            .command(".", &["--color", "always", "list", "--by", "crate"])
+            .env("NO_COLOR", "1")
+            .output()
+            .unwrap(),
+    );
+    assert_eq!(run.stdout, colored);
+}
+
+/// A package for each license family
+fn families() -> Workspace {
+    let deps = [
+        ("cc0", "CC0-1.0"),
+        ("mit", "MIT"),
+        ("apache", "Apache-2.0"),
+        ("mit2", "MIT"),
+        ("mpl", "MPL-2.0"),
+        ("gpl", "GPL-3.0-only"),
+        ("agpl", "AGPL-3.0-only"),
+        ("custom", "LicenseRef-Internal"),
+    ];
+    let mut files = deps
+        .iter()
+        .map(|(name, license)| {
+            (
+                format!("{}/Cargo.toml", name),
+                manifest(name, "0.1.0", license, ""),
+            )
+        })
+        .collect::<Vec<_>>();
+    files.push((
+        "unspecified/Cargo.toml".to_owned(),
+        "[package]\nname = \"unspecified\"\nversion = \"0.1.0\"\n".to_owned(),
+    ));
+    files.push((
+        "file/Cargo.toml".to_owned(),
+        "[package]\nname = \"file\"\nversion = \"0.1.0\"\nlicense-file = \"TERMS\"\n".to_owned(),
+    ));
+    files.push(("file/TERMS".to_owned(), "Terms\n".to_owned()));
+    let deps = deps
+        .iter()
+        .map(|(name, _)| *name)
+        .chain(vec!["unspecified", "file"])
+        .map(|name| format!("{} = {{ path = \"{}\" }}\n", name, name))
+        .collect::<String>();
+    files.push((
+        "Cargo.toml".to_owned(),
+        manifest("app", "0.1.0", "MIT", &format!("[dependencies]\n{}", deps)),
+    ));
+    let files = files
+        .iter()
+        .map(|(path, contents)| (path.as_str(), contents.as_str()))
+        .collect::<Vec<_>>();
+    Workspace::new(&files)
+}
+
+#[test]
+fn family_subtotals_and_filters() {
+    let workspace = families();
+    let terms = workspace.path("file/TERMS");
+    let terms = terms.to_str().unwrap();
+    let stdout = |args: &[&str]| workspace.run(args).expect(0).stdout.replace(terms, "TERMS");
+    let unknown = "\
+LicenseRef-Internal (1): custom [internal]
+License specified in file (TERMS) (1): file [internal]
+No license specified (1): unspecified [internal]
+";
+
+    assert_eq!(
+        stdout(&["list"]),
+        format!(
+            "\
+== Public domain (1 package, 1 license) ==
+CC0-1.0 (1): cc0 [internal]
+== Permissive (4 packages, 2 licenses) ==
+MIT (3): app (workspace root) [internal], mit [internal], mit2 [internal]
+Apache-2.0 (1): apache [internal]
+== Weak copyleft (1 package, 1 license) ==
+MPL-2.0 (1): mpl [internal]
+== Strong copyleft (1 package, 1 license) ==
+GPL-3.0-only (1): gpl [internal]
+== Network copyleft (1 package, 1 license) ==
+AGPL-3.0-only (1): agpl [internal]
+== Unknown (3 packages, 3 licenses) ==
+{}",
+            unknown
+        )
+    );
+    assert_eq!(stdout(&["list", "--filter", "unknown"]), unknown);
+    assert_eq!(stdout(&["list", "--unknown-only"]), unknown);
+    assert_eq!(
+        stdout(&["list", "--filter", "copyleft"]),
+        "\
+MPL-2.0 (1): mpl [internal]
+GPL-3.0-only (1): gpl [internal]
+AGPL-3.0-only (1): agpl [internal]
+"
+    );
+    assert_eq!(
+        stdout(&["list", "--filter", "permissive"]),
+        "\
+CC0-1.0 (1): cc0 [internal]
+MIT (3): app (workspace root) [internal], mit [internal], mit2 [internal]
+Apache-2.0 (1): apache [internal]
+"
+    );
+    assert_eq!(
+        stdout(&["list", "--filter", "MIT,MPL-2.0"]),
+        "\
+MIT (3): app (workspace root) [internal], mit [internal], mit2 [internal]
+MPL-2.0 (1): mpl [internal]
+"
+    );
+    let by_crate = stdout(&["list", "--by", "crate", "--filter", "unknown"]);
+    let names = by_crate
+        .lines()
+        .map(|line| line.split(':').next().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["custom", "file", "unspecified"]);
+}
+
+#[test]
+fn license_resolutions() {
+    let dep = |name: &str| format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    let deps = ["slashed", "filed", "headed", "bare"]
+        .iter()
+        .map(|name| dep(name))
+        .collect::<String>();
+    let unlicensed = |name: &str, rest: &str| {
+        format!(
+            "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2018\"\n{}",
+            name, rest
+        )
+    };
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app\", \"slashed\", \"filed\", \"headed\", \"bare\"]\n",
+        ),
+        (
+            "app/Cargo.toml",
+            &manifest("app", "0.1.0", "MIT", &format!("[dependencies]\n{}", deps)),
+        ),
+        (
+            "slashed/Cargo.toml",
+            &manifest("slashed", "0.1.0", "MIT/Apache-2.0", ""),
+        ),
+        (
+            "filed/Cargo.toml",
+            &unlicensed("filed", "license-file = \"COPYING\"\n"),
+        ),
+        ("filed/COPYING", MIT_TEXT),
+        ("headed/Cargo.toml", &unlicensed("headed", "")),
+        (
+            "headed/src/lib.rs",
+            "// SPDX-License-Identifier: Apache-2.0\n",
+        ),
+        ("bare/Cargo.toml", &unlicensed("bare", "")),
+    ]);
+    let expected = |path: &str| {
+        serde_json::json!({
+            "app": { "kind": "declared" },
+            "slashed": { "kind": "normalized", "original": "MIT/Apache-2.0" },
+            "filed": { "kind": "declared" },
+            "headed": {
+                "kind": "inferred-headers",
+                "path": workspace.path(path).display().to_string(),
+                "confidence": 100,
+            },
+            "bare": { "kind": "unspecified" },
+        })
+    };
+    let resolutions = |packages: &serde_json::Value| {
+        packages
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|package| {
+                (
+                    package["name"].as_str().unwrap().to_owned(),
+                    package["resolution"].clone(),
+                )
+            })
+            .collect::<serde_json::Map<_, _>>()
+    };
+
+    let run = workspace
+        .run_in(
+            "app",
+            &["--scan-source-headers", "list", "--format", "json"],
+        )
+        .expect(0);
+    let inventory: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    assert_eq!(
+        serde_json::Value::from(resolutions(&inventory["packages"])),
+        expected("headed/src/lib.rs")
+    );
+
+    let run = workspace
+        .run_in(
+            "app",
+            &["--scan-source-headers", "bundle", "--variant", "json"],
+        )
+        .expect(3);
+    let bundle: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    assert_eq!(
+        serde_json::Value::from(resolutions(&bundle)),
+        expected("headed/src/lib.rs")
+    );
+
+    // Human readable output is unchanged
+    let run = workspace
+        .run_in("app", &["--scan-source-headers", "list", "--by", "crate"])
+        .expect(0);
+    assert!(!run.stdout.contains("normalized"), "{}", run.stdout);
+}
+
+#[test]
+fn elected_licenses() {
+    let dep = |name: &str| format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    let deps = ["dual", "reversed", "copyleft"]
+        .iter()
+        .map(|name| dep(name))
+        .collect::<String>();
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app\", \"dual\", \"reversed\", \"copyleft\"]\n",
+        ),
+        (
+            "app/Cargo.toml",
+            &manifest("app", "0.1.0", "MIT", &format!("[dependencies]\n{}", deps)),
+        ),
+        (
+            "dual/Cargo.toml",
+            &manifest("dual", "0.1.0", "MIT OR Apache-2.0", ""),
+        ),
+        (
+            "reversed/Cargo.toml",
+            &manifest("reversed", "0.1.0", "Apache-2.0 OR MIT", ""),
+        ),
+        (
+            "copyleft/Cargo.toml",
+            &manifest("copyleft", "0.1.0", "GPL-3.0-only OR LGPL-3.0-only", ""),
+        ),
+        ("about.toml", "accepted = [\"Apache-2.0\"]\n"),
+    ]);
+    let no_match = "copyleft 0.1.0 is licensed under LGPL-3.0-only / GPL-3.0-only and none of \
+                    them is preferred, using all of them";
+
+    let run = workspace
+        .run_in("app", &["list", "--prefer", "MIT,Apache-2.0"])
+        .expect(0);
+    let mit = run
+        .stdout
+        .lines()
+        .find(|line| line.starts_with("MIT ("))
+        .unwrap_or_default();
+    assert_eq!(
+        mit,
+        "MIT (3): app (workspace root) [internal], \
+         dual [internal] (elected from MIT / Apache-2.0), \
+         reversed [internal] (elected from MIT / Apache-2.0)",
+        "{}",
+        run.stdout
+    );
+    assert!(
+        run.stdout
+            .contains("LGPL-3.0-only / GPL-3.0-only (1): copyleft [internal]"),
+        "{}",
+        run.stdout
+    );
+    assert!(run.stderr.contains(no_match), "{}", run.stderr);
+
+    // Or the first alternative the policy accepts
+    let run = workspace
+        .run_in(
+            "app",
+            &["list", "--by", "crate", "--policy", "../about.toml"],
+        )
+        .expect(0);
+    assert_eq!(
+        details(&run.stdout),
+        [
+            ("app".to_owned(), "(workspace root) [internal]".to_owned()),
+            ("copyleft".to_owned(), "(direct) [internal]".to_owned()),
+            (
+                "dual".to_owned(),
+                "(direct) [internal] (elected from MIT / Apache-2.0)".to_owned()
+            ),
+            (
+                "reversed".to_owned(),
+                "(direct) [internal] (elected from MIT / Apache-2.0)".to_owned()
+            ),
+        ]
+    );
+    assert!(
+        run.stdout
+            .lines()
+            .filter(|line| line.contains("elected"))
+            .all(|line| line.contains(" Apache-2.0 ")),
+        "{}",
+        run.stdout
+    );
+
+    // Without electing the full expressions are listed
+    let run = workspace.run_in("app", &["list"]).expect(0);
+    assert!(!run.stdout.contains("elected"), "{}", run.stdout);
+    assert!(!run.stderr.contains(no_match), "{}", run.stderr);
+
+    workspace
+        .run_in("app", &["list", "--format", "json", "--prefer", "MIT"])
+        .expect(1);
+}
+
+#[test]
+fn compile_time_only_packages_are_annotated() {
+    let workspace = Workspace::new(&[
+        (
+            "Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nmacros = { path = \"macros\" }\nshared = { path = \"shared\" }\n",
+            ),
+        ),
+        (
+            "macros/Cargo.toml",
+            &manifest(
+                "macros",
+                "0.1.0",
+                "MIT",
+                "[lib]\nproc-macro = true\n\n[dependencies]\nhelper = { path = \"../helper\" }\n\
+                 shared = { path = \"../shared\" }\n",
+            ),
+        ),
+        (
+            "helper/Cargo.toml",
+            &manifest("helper", "0.1.0", "Apache-2.0", ""),
+        ),
+        ("shared/Cargo.toml", &manifest("shared", "0.1.0", "MIT", "")),
+    ]);
+
+    let run = workspace.run(&["list", "--by", "crate"]).expect(0);
+    let lines = run.stdout.lines().collect::<Vec<_>>();
+    for (name, proc_macro) in [
+        ("app", false),
+        ("helper", true),
+        ("macros", true),
+        ("shared", false),
+    ] {
+        let line = lines
+            .iter()
+            .find(|line| line.starts_with(&format!("{}:", name)))
+            .unwrap();
+        assert_eq!(
+            line.ends_with(" [proc-macro]"),
+            proc_macro,
+            "{}",
+            run.stdout
+        );
+    }
+
+    let run = workspace
+        .run(&["bundle", "--variant", "name-only", "--distribution-only"])
+        .expect(0);
+    assert!(run.stdout.contains(" * shared 0.1.0"), "{}", run.stdout);
+    assert!(!run.stdout.contains("helper"), "{}", run.stdout);
+    assert!(!run.stdout.contains("macros"), "{}", run.stdout);
+}
+
+#[test]
+fn source_headers_of_vendored_copies() {
+    let unlicensed = "[package]\nname = \"headed\"\nversion = \"1.0.0\"\nedition = \"2018\"\n";
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                "[dependencies]\nheaded = { path = \"../registry/headed-1.0.0\" }\n",
+            ),
+        ),
+        ("registry/headed-1.0.0/Cargo.toml", unlicensed),
+        ("vendor/headed/Cargo.toml", unlicensed),
+        (
+            "vendor/headed/src/lib.rs",
+            "// SPDX-License-Identifier: Apache-2.0\n",
+        ),
+    ]);
+    workspace.registry_metadata("app");
+    // Like an air-gapped image with only the vendor directory
+    fs::remove_dir_all(workspace.path("registry")).unwrap();
+
+    let list = |args: &[&str]| {
+        let run = Run::from(
+            Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+                .args(["lichking", "--metadata-json", "metadata.json"])
+                .arg("--scan-source-headers")
+                .args(args)
+                .args(["list", "--format", "json"])
+                .current_dir(&workspace.root)
+                .output()
+                .unwrap(),
+        )
+        .expect(0);
+        let inventory: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+        inventory["packages"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|package| package["name"] == "headed")
+            .unwrap()["resolution"]
+            .clone()
+    };
+    assert_eq!(
+        list(&["--vendor-dir", "vendor"]),
+        serde_json::json!({
+            "kind": "inferred-headers",
+            "path": workspace.path("vendor/headed/src/lib.rs").display().to_string(),
+            "confidence": 100,
+        })
+    );
+    assert_eq!(list(&[]), serde_json::json!({ "kind": "unspecified" }));
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use std::path::Path;
+use std::process::Command;
+
+use common::{manifest, Run, Workspace, MIT_TEXT};
+
+/// The columns of each line `locate` printed
+fn located(stdout: &str) -> Vec<Vec<&str>> {
+    let mut lines = stdout
+        .lines()
+        .map(|line| line.split('\t').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    lines.sort();
+    lines
+}
+
+#[test]
+fn own_dependencies() {
+    let run = Run::from(
+        Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+            .args(["lichking", "--offline", "locate"])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .env("RUST_LOG", "error")
+            .output()
+            .expect("cargo-lichking runs"),
+    );
+    assert!(matches!(run.code, Some(0) | Some(3)), "{}", run.stderr);
+    let lines = located(&run.stdout);
+    let anyhow = lines
+        .iter()
+        .find(|line| line[0].starts_with("anyhow ") && line[1] == "MIT")
+        .unwrap_or_else(|| panic!("no MIT text for anyhow in:\n{}", run.stdout));
+    let path = Path::new(anyhow[2]);
+    assert!(path.is_absolute() && path.is_file(), "{}", anyhow[2]);
+    assert_eq!(path.canonicalize().unwrap(), path);
+}
+
+#[test]
+fn missing_texts_and_relative_paths() {
+    let dep = |name: &str| format!("{} = {{ path = \"../{}\" }}\n", name, name);
+    let workspace = Workspace::new(&[
+        (
+            "app/Cargo.toml",
+            &manifest(
+                "app",
+                "0.1.0",
+                "MIT",
+                &format!(
+                    "[dependencies]\n{}{}",
+                    dep("documented"),
+                    dep("undocumented")
+                ),
+            ),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "documented/Cargo.toml",
+            &manifest("documented", "0.1.0", "MIT", ""),
+        ),
+        ("documented/LICENSE", MIT_TEXT),
+        (
+            "undocumented/Cargo.toml",
+            &manifest("undocumented", "0.1.0", "MIT", ""),
+        ),
+    ]);
+    let path = |path: &str| workspace.path(path).canonicalize().unwrap();
+    let (app, documented) = (path("app/LICENSE"), path("documented/LICENSE"));
+
+    let run = workspace.run_in("app", &["locate"]).expect(3);
+    assert_eq!(
+        located(&run.stdout),
+        [
+            ["app 0.1.0", "MIT", app.to_str().unwrap()],
+            ["documented 0.1.0", "MIT", documented.to_str().unwrap()],
+            ["undocumented 0.1.0", "MIT", "MISSING"],
+        ]
+    );
+
+    let root = workspace.root.to_str().unwrap();
+    let run = workspace
+        .run_in("app", &["locate", "--relative-to", root])
+        .expect(3);
+    assert_eq!(
+        located(&run.stdout)[1],
+        ["documented 0.1.0", "MIT", "documented/LICENSE"]
+    );
+    let run = workspace
+        .run_in("app", &["locate", "--relative-to", "."])
+        .expect(3);
+    assert_eq!(
+        located(&run.stdout)[1],
+        ["documented 0.1.0", "MIT", "../documented/LICENSE"]
+    );
+}
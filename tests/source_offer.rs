@@ -0,0 +1,130 @@
+mod common;
+
+use std::fs;
+use std::process::Command;
+
+use common::{manifest, Run, Workspace};
+
+const CRATES_IO: &str = "registry+https://github.com/rust-lang/crates.io-index";
+
+/// An MIT app depending on MPL-2.0 and MIT crates from crates.io and an LGPL
+/// crate from git, loaded from a metadata fixture with a lockfile beside it
+fn source_offer(args: &[&str]) -> Run {
+    let workspace = Workspace::new(&[
+        ("app/Cargo.toml", &manifest("app", "0.1.0", "MIT", "")),
+        (
+            "registry/mpl-1.2.3/Cargo.toml",
+            &manifest("mpl", "1.2.3", "MPL-2.0", ""),
+        ),
+        (
+            "git/lgpl/Cargo.toml",
+            &manifest("lgpl", "0.3.0", "LGPL-2.1-or-later", ""),
+        ),
+        (
+            "registry/mitdep-1.0.0/Cargo.toml",
+            &manifest("mitdep", "1.0.0", "MIT", ""),
+        ),
+    ]);
+    let lockfile = format!(
+        "version = 3\n\n\
+         [[package]]\nname = \"mitdep\"\nversion = \"1.0.0\"\nsource = \"{source}\"\nchecksum = \"{mitdep}\"\n\n\
+         [[package]]\nname = \"mpl\"\nversion = \"1.2.3\"\nsource = \"{source}\"\nchecksum = \"{mpl}\"\n",
+        source = CRATES_IO,
+        mitdep = "1".repeat(64),
+        mpl = "2".repeat(64),
+    );
+    fs::write(workspace.path("Cargo.lock"), lockfile).unwrap();
+    let fixture = fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/source-offer.json"
+    ))
+    .unwrap();
+    let root = workspace.path("app");
+    let root = root.parent().unwrap().to_str().unwrap();
+    fs::write(
+        workspace.path("metadata.json"),
+        fixture.replace("/ROOT", root),
+    )
+    .unwrap();
+    Run::from(
+        Command::new(env!("CARGO_BIN_EXE_cargo-lichking"))
+            .args([
+                "lichking",
+                "--metadata-json",
+                "metadata.json",
+                "source-offer",
+            ])
+            .args(args)
+            .current_dir(workspace.path(""))
+            .env("RUST_LOG", "error")
+            .output()
+            .unwrap(),
+    )
+    .expect(0)
+}
+
+#[test]
+fn copyleft_packages_as_text() {
+    let run = source_offer(&[]);
+    let rows = run
+        .stdout
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mpl_checksum = "2".repeat(64);
+    assert_eq!(
+        rows,
+        [
+            vec!["Name", "Version", "License", "Source", "Checksum"],
+            vec![
+                "lgpl",
+                "0.3.0",
+                "LGPL-2.1-or-later",
+                "git",
+                "https://github.com/example/lgpl#0123abcdef0123abcdef0123abcdef0123abcd",
+                "-",
+            ],
+            vec![
+                "mpl",
+                "1.2.3",
+                "MPL-2.0",
+                "https://static.crates.io/crates/mpl/mpl-1.2.3.crate",
+                &mpl_checksum,
+            ],
+        ],
+        "{}",
+        run.stdout
+    );
+}
+
+#[test]
+fn copyleft_packages_as_json() {
+    let run = source_offer(&["--format", "json"]);
+    let entries: serde_json::Value = serde_json::from_str(&run.stdout).unwrap();
+    let names = entries
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["lgpl", "mpl"]);
+
+    let lgpl = &entries[0];
+    assert_eq!(lgpl["license"], "LGPL-2.1-or-later");
+    assert_eq!(lgpl["source"]["kind"], "git");
+    assert_eq!(lgpl["source"]["url"], "https://github.com/example/lgpl");
+    assert_eq!(
+        lgpl["source"]["rev"],
+        "0123abcdef0123abcdef0123abcdef0123abcd"
+    );
+    assert_eq!(lgpl["checksum"], serde_json::Value::Null);
+
+    let mpl = &entries[1];
+    assert_eq!(mpl["license"], "MPL-2.0");
+    assert_eq!(mpl["source"]["kind"], "crates-io");
+    assert_eq!(
+        mpl["source"]["url"],
+        "https://static.crates.io/crates/mpl/mpl-1.2.3.crate"
+    );
+    assert_eq!(mpl["checksum"], "2".repeat(64));
+}
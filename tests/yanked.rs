@@ -0,0 +1,139 @@
+mod common;
+
+use std::fs;
+
+use common::{manifest, Workspace, MIT_TEXT};
+
+const CHECKSUM: &str =
+    "{\"files\":{},\"package\":\"0000000000000000000000000000000000000000000000000000000000000000\"}";
+
+/// `app` depending on `foo 1.0.0` from a vendored crates.io, with an empty
+/// CARGO_HOME next to it to fabricate index data in
+fn vendored() -> Workspace {
+    Workspace::new(&[
+        ("Cargo.toml", "[workspace]\nmembers = [\"app\"]\n"),
+        (
+            ".cargo/config.toml",
+            "[source.crates-io]\nreplace-with = \"vendored\"\n\n\
+             [source.vendored]\ndirectory = \"vendor\"\n",
+        ),
+        (
+            "app/Cargo.toml",
+            &manifest("app", "0.1.0", "MIT", "[dependencies]\nfoo = \"1.0\"\n"),
+        ),
+        ("app/LICENSE", MIT_TEXT),
+        (
+            "vendor/foo-1.0.0/Cargo.toml",
+            &manifest("foo", "1.0.0", "MIT", ""),
+        ),
+        ("vendor/foo-1.0.0/.cargo-checksum.json", CHECKSUM),
+        ("vendor/foo-1.0.0/LICENSE", MIT_TEXT),
+        ("home/.keep", ""),
+    ])
+}
+
+/// Writes a sparse index cache file for `foo` as cargo would
+fn index(workspace: &Workspace, yanked: bool) {
+    let dir = workspace.path("home/registry/index/index.crates.io-0123456789abcdef/.cache/3/f");
+    fs::create_dir_all(&dir).unwrap();
+    let mut cache = vec![3, 2, 0, 0, 0];
+    cache.extend_from_slice(b"etag: \"abc\"\0");
+    for (version, yanked) in &[("0.9.0", false), ("1.0.0", yanked)] {
+        cache.extend_from_slice(version.as_bytes());
+        cache.push(0);
+        cache.extend_from_slice(
+            format!(
+                "{{\"name\":\"foo\",\"vers\":\"{}\",\"deps\":[],\"cksum\":\"\",\"features\":{{}},\"yanked\":{}}}",
+                version, yanked
+            )
+            .as_bytes(),
+        );
+        cache.push(0);
+    }
+    fs::write(dir.join("foo"), cache).unwrap();
+}
+
+fn list(workspace: &Workspace) -> String {
+    let output = workspace
+        .command("app", &["list", "--by", "crate", "--check-yanked"])
+        .env("CARGO_HOME", workspace.path("home"))
+        .env("RUST_LOG", "info")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap() + &String::from_utf8(output.stderr).unwrap()
+}
+
+#[test]
+fn yanked_versions_are_labelled() {
+    let workspace = vendored();
+
+    // Without any index data it's unknown
+    let output = list(&workspace);
+    assert!(!output.contains("[YANKED]"), "{}", output);
+    assert!(
+        output.contains("Couldn't find index data for foo 1.0.0"),
+        "{}",
+        output
+    );
+
+    index(&workspace, false);
+    let output = list(&workspace);
+    assert!(!output.contains("[YANKED]"), "{}", output);
+    assert!(!output.contains("Couldn't find index data"), "{}", output);
+
+    index(&workspace, true);
+    let output = list(&workspace);
+    let foo = output
+        .lines()
+        .find(|line| line.starts_with("foo:"))
+        .unwrap_or_else(|| panic!("{}", output));
+    assert!(foo.ends_with("[YANKED]"), "{}", output);
+}
+
+#[cfg(unix)]
+#[test]
+fn unreachable_index_is_reported() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let workspace = vendored();
+    // A curl that can't reach anything, first on the path
+    let curl = workspace.path("bin/curl");
+    fs::create_dir_all(curl.parent().unwrap()).unwrap();
+    fs::write(
+        &curl,
+        "#!/bin/sh\necho \"curl: (6) Could not resolve host: index.crates.io\" >&2\nexit 6\n",
+    )
+    .unwrap();
+    fs::set_permissions(&curl, fs::Permissions::from_mode(0o755)).unwrap();
+    let path = format!(
+        "{}:{}",
+        workspace.path("bin").display(),
+        std::env::var("PATH").unwrap()
+    );
+
+    let output = workspace
+        .command(
+            "app",
+            &["list", "--by", "crate", "--check-yanked", "--allow-network"],
+        )
+        .env("CARGO_HOME", workspace.path("home"))
+        .env("PATH", path)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "{}", stderr);
+    assert_eq!(
+        stderr
+            .matches("warning: couldn't check crates.io for yanked versions")
+            .count(),
+        1,
+        "{}",
+        stderr
+    );
+    assert!(
+        stderr.contains("Could not resolve host: index.crates.io"),
+        "{}",
+        stderr
+    );
+}